@@ -0,0 +1,77 @@
+use std::{fs, path::PathBuf};
+
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use okapi_js_parser::{Parser, ParserOptions};
+
+const FIXTURES: [&str; 4] = [
+    "react@18.2.0.development.js",
+    "react-dom@18.2.0.development.js",
+    "angular@1.8.3.js",
+    "three@0.163.0.js",
+];
+
+fn read_fixture(file_name: &str) -> String {
+    let mut full_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+
+    full_path.push("tests/parser/acorn/fixtures");
+    full_path.push(file_name);
+
+    fs::read_to_string(full_path).unwrap()
+}
+
+// Throughput in bytes per second should be roughly the same for every fixture if parse time is linear in the input size.
+fn parse_fixtures(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_module");
+
+    group.sample_size(10);
+
+    for file_name in FIXTURES {
+        let source = read_fixture(file_name);
+
+        group.throughput(Throughput::Bytes(source.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(file_name),
+            &source,
+            |b, source| {
+                b.iter(|| {
+                    Parser::new(black_box(source), ParserOptions::default())
+                        .parse_module()
+                        .unwrap()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+// Parse the same fixture repeated an increasing number of times, so that any super-linear behaviour shows up as a drop in throughput.
+fn parse_scaling(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse_module_scaling");
+
+    group.sample_size(10);
+
+    let source = read_fixture(FIXTURES[0]);
+
+    for repeat in [1, 2, 4, 8] {
+        let repeated_source = source.repeat(repeat);
+
+        group.throughput(Throughput::Bytes(repeated_source.len() as u64));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(repeat),
+            &repeated_source,
+            |b, source| {
+                b.iter(|| {
+                    Parser::new(black_box(source), ParserOptions::default())
+                        .parse_module()
+                        .unwrap()
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parse_fixtures, parse_scaling);
+criterion_main!(benches);
@@ -1,4 +1,5 @@
 import acorn from "acorn";
+import jsx from "acorn-jsx";
 import path from "path";
 import { promises as fs } from "fs";
 
@@ -8,7 +9,7 @@ async function writeFile(
   newExtension: string
 ) {
   try {
-    let newFilePath = filePath.replace(/\.js$/, newExtension);
+    let newFilePath = filePath.replace(/\.jsx?$/, newExtension);
     await fs.mkdir(path.dirname(newFilePath), { recursive: true });
     await fs.writeFile(newFilePath, content);
   } catch (err) {
@@ -16,13 +17,18 @@ async function writeFile(
   }
 }
 
+// JSX fixtures are parsed with the acorn-jsx plugin, which the JSX extension follows.
+const jsxParser = acorn.Parser.extend(jsx());
+
 async function writeFixture(fileName: string) {
   const buffer = await fs.readFile(
     path.join(process.cwd(), "tests/parser/acorn/fixtures", fileName),
     "utf8"
   );
 
-  const json = acorn.parse(buffer, {
+  const parser = fileName.endsWith(".jsx") ? jsxParser : acorn.Parser;
+
+  const json = parser.parse(buffer, {
     ecmaVersion: "latest",
     sourceType: "module",
   });
@@ -32,7 +38,7 @@ async function writeFixture(fileName: string) {
   }
 
   writeFile(
-    path.join(process.cwd(), "tests/parser/fixtures/acorn", fileName),
+    path.join(process.cwd(), "tests/parser/acorn/fixtures/acorn", fileName),
     JSON.stringify(json, null, 2),
     ".json"
   );
@@ -42,3 +48,4 @@ writeFixture("angular@1.8.3.js");
 writeFixture("react-dom@18.2.0.development.js");
 writeFixture("react@18.2.0.development.js");
 writeFixture("three@0.163.0.js");
+writeFixture("jsx-elements.jsx");
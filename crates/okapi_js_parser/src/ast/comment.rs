@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+use crate::ast::Node;
+
+// 12.4 Comments
+// https://tc39.es/ecma262/#sec-comments
+// Comments are not part of ESTree, so they are serialized in the same format as Espree and Acorn's `onComment` option.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Comment {
+    #[serde(rename = "type")]
+    pub kind: CommentKind,
+    // The text between the delimiters, i.e. without the `//`, or the `/*` and `*/`.
+    pub value: String,
+    #[serde(flatten)]
+    pub node: Node,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum CommentKind {
+    Line,
+    Block,
+}
@@ -1,7 +1,10 @@
 use serde::Serialize;
 
 use crate::ast::{
-    BlockStatement, ClassBody, Expression, FunctionParameter, Identifier, Node, Pattern,
+    is_false, BlockStatement, ClassBody, Decorator, Expression, FunctionParameter, Identifier,
+    Node, Pattern, TSClassImplements, TSDeclareFunction, TSEnumDeclaration,
+    TSImportEqualsDeclaration, TSInterfaceDeclaration, TSModuleDeclaration, TSTypeAliasDeclaration,
+    TSTypeAnnotation, TSTypeParameterDeclaration, TSTypeParameterInstantiation,
 };
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -10,6 +13,12 @@ pub enum Declaration {
     Class(ClassDeclaration),
     Function(FunctionDeclaration),
     Variable(VariableDeclaration),
+    TSDeclareFunction(TSDeclareFunction),
+    TSEnum(TSEnumDeclaration),
+    TSImportEquals(TSImportEqualsDeclaration),
+    TSInterface(TSInterfaceDeclaration),
+    TSModule(TSModuleDeclaration),
+    TSTypeAlias(TSTypeAliasDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -21,6 +30,18 @@ pub struct ClassDeclaration {
     #[serde(rename = "superClass")]
     pub super_class: Option<Expression>,
     pub body: ClassBody,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<Decorator>,
+    #[serde(rename = "typeParameters", skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<Box<TSTypeParameterDeclaration>>,
+    #[serde(rename = "superTypeArguments", skip_serializing_if = "Option::is_none")]
+    pub super_type_arguments: Option<Box<TSTypeParameterInstantiation>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub implements: Vec<TSClassImplements>,
+    #[serde(rename = "abstract", skip_serializing_if = "is_false")]
+    pub is_abstract: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub declare: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -35,6 +56,10 @@ pub struct FunctionDeclaration {
     pub asynchronous: bool,
     pub params: Vec<FunctionParameter>,
     pub body: BlockStatement,
+    #[serde(rename = "typeParameters", skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<Box<TSTypeParameterDeclaration>>,
+    #[serde(rename = "returnType", skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<Box<TSTypeAnnotation>>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -44,6 +69,8 @@ pub struct VariableDeclaration {
     pub node: Node,
     pub declarations: Vec<VariableDeclarator>,
     pub kind: VariableKind,
+    #[serde(skip_serializing_if = "is_false")]
+    pub declare: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -52,6 +79,9 @@ pub enum VariableKind {
     Var,
     Let,
     Const,
+    Using,
+    #[serde(rename = "await using")]
+    AwaitUsing,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -61,4 +91,6 @@ pub struct VariableDeclarator {
     pub node: Node,
     pub id: Pattern,
     pub init: Option<Expression>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub definite: bool,
 }
@@ -1,8 +1,13 @@
 use crate::{
     ast::{
-        ArrayPattern, BlockStatement, FunctionParameter, Node, ObjectPattern, Pattern, StaticBlock,
+        is_false, regexp::RegExp, ArrayPattern, BlockStatement, FunctionParameter, JSXElement,
+        JSXFragment, Node, ObjectPattern, Pattern, StaticBlock, TSAbstractMethodDefinition,
+        TSAbstractPropertyDefinition, TSAccessibility, TSAsExpression, TSClassImplements,
+        TSEmptyBodyFunctionExpression, TSIndexSignature, TSNonNullExpression,
+        TSSatisfiesExpression, TSTypeAnnotation, TSTypeAssertion, TSTypeParameterDeclaration,
+        TSTypeParameterInstantiation,
     },
-    ParserError,
+    ParserError, ParserErrorKind,
 };
 use serde::Serialize;
 
@@ -28,13 +33,17 @@ pub enum Expression {
     Function(FunctionExpression),
     Identifier(Identifier),
     Import(ImportExpression),
+    JSXElement(Box<JSXElement>),
+    JSXFragment(Box<JSXFragment>),
     Literal(Literal),
     RegExpLiteral(RegExpLiteral),
+    BigIntLiteral(BigIntLiteral),
     Logical(LogicalExpression),
     Member(MemberExpression),
     MetaProperty(MetaProperty),
     New(NewExpression),
     Object(ObjectExpression),
+    Parenthesized(ParenthesizedExpression),
     Sequence(SequenceExpression),
     Super(SuperExpression),
     TaggedTemplate(TaggedTemplateExpression),
@@ -43,6 +52,52 @@ pub enum Expression {
     Unary(UnaryExpression),
     Update(UpdateExpression),
     Yield(YieldExpression),
+    TSAs(Box<TSAsExpression>),
+    TSNonNull(TSNonNullExpression),
+    TSSatisfies(Box<TSSatisfiesExpression>),
+    TSTypeAssertion(Box<TSTypeAssertion>),
+}
+
+impl Expression {
+    pub fn node(&self) -> &Node {
+        match self {
+            Expression::Array(expression) => &expression.node,
+            Expression::ArrowFunction(expression) => &expression.node,
+            Expression::Assignment(expression) => &expression.node,
+            Expression::Await(expression) => &expression.node,
+            Expression::Binary(expression) => &expression.node,
+            Expression::Call(expression) => &expression.node,
+            Expression::Chain(expression) => &expression.node,
+            Expression::Class(expression) => &expression.node,
+            Expression::Conditional(expression) => &expression.node,
+            Expression::Function(expression) => &expression.node,
+            Expression::Identifier(expression) => &expression.node,
+            Expression::Import(expression) => &expression.node,
+            Expression::JSXElement(expression) => &expression.node,
+            Expression::JSXFragment(expression) => &expression.node,
+            Expression::Literal(expression) => &expression.node,
+            Expression::RegExpLiteral(expression) => &expression.node,
+            Expression::BigIntLiteral(expression) => &expression.node,
+            Expression::Logical(expression) => &expression.node,
+            Expression::Member(expression) => &expression.node,
+            Expression::MetaProperty(expression) => &expression.node,
+            Expression::New(expression) => &expression.node,
+            Expression::Object(expression) => &expression.node,
+            Expression::Parenthesized(expression) => &expression.node,
+            Expression::Sequence(expression) => &expression.node,
+            Expression::Super(expression) => &expression.node,
+            Expression::TaggedTemplate(expression) => &expression.node,
+            Expression::TemplateLiteral(expression) => &expression.node,
+            Expression::This(expression) => &expression.node,
+            Expression::Unary(expression) => &expression.node,
+            Expression::Update(expression) => &expression.node,
+            Expression::Yield(expression) => &expression.node,
+            Expression::TSAs(expression) => &expression.node,
+            Expression::TSNonNull(expression) => &expression.node,
+            Expression::TSSatisfies(expression) => &expression.node,
+            Expression::TSTypeAssertion(expression) => &expression.node,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -55,7 +110,6 @@ pub struct ArrayExpression {
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
-
 pub enum ArrayExpressionElement {
     Expression(Expression),
     SpreadElement(SpreadElement),
@@ -73,6 +127,10 @@ pub struct ArrowFunctionExpression {
     pub is_async: bool,
     pub params: Vec<Pattern>,
     pub body: ArrowFunctionExpressionBody,
+    #[serde(rename = "typeParameters", skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<Box<TSTypeParameterDeclaration>>,
+    #[serde(rename = "returnType", skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<Box<TSTypeAnnotation>>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -98,7 +156,10 @@ impl TryFrom<Expression> for AssignmentExpression {
     fn try_from(expression: Expression) -> Result<Self, Self::Error> {
         match expression {
             Expression::Assignment(assignment_expression) => Ok(assignment_expression),
-            _ => Err(ParserError::InvalidExpressionToAssignmentExpressionConversion),
+            expression => Err(ParserError::from_node(
+                ParserErrorKind::InvalidExpressionToAssignmentExpressionConversion,
+                expression.node(),
+            )),
         }
     }
 }
@@ -229,6 +290,8 @@ pub struct CallExpression {
     pub callee: CallExpressionCallee,
     pub arguments: Vec<CallExpressionArgument>,
     pub optional: bool,
+    #[serde(rename = "typeArguments", skip_serializing_if = "Option::is_none")]
+    pub type_arguments: Option<Box<TSTypeParameterInstantiation>>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -269,7 +332,10 @@ impl TryFrom<Expression> for ChainElement {
             Expression::Member(member_expression) => {
                 Ok(ChainElement::MemberExpression(member_expression))
             }
-            _ => Err(ParserError::InvalidExpressionToChainElementConversion),
+            expression => Err(ParserError::from_node(
+                ParserErrorKind::InvalidExpressionToChainElementConversion,
+                expression.node(),
+            )),
         }
     }
 }
@@ -283,6 +349,18 @@ pub struct ClassExpression {
     #[serde(rename = "superClass")]
     pub super_class: Option<Box<Expression>>,
     pub body: ClassBody,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<Decorator>,
+    #[serde(rename = "typeParameters", skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<Box<TSTypeParameterDeclaration>>,
+    #[serde(rename = "superTypeArguments", skip_serializing_if = "Option::is_none")]
+    pub super_type_arguments: Option<Box<TSTypeParameterInstantiation>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub implements: Vec<TSClassImplements>,
+    #[serde(rename = "abstract", skip_serializing_if = "is_false")]
+    pub is_abstract: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub declare: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -298,7 +376,25 @@ pub struct ClassBody {
 pub enum ClassBodyBody {
     MethodDefinition(MethodDefinition),
     PropertyDefinition(PropertyDefinition),
+    AccessorProperty(AccessorProperty),
     StaticBlock(StaticBlock),
+    TSAbstractMethodDefinition(TSAbstractMethodDefinition),
+    TSAbstractPropertyDefinition(TSAbstractPropertyDefinition),
+    TSIndexSignature(TSIndexSignature),
+}
+
+impl ClassBodyBody {
+    pub fn node(&self) -> &Node {
+        match self {
+            ClassBodyBody::MethodDefinition(class_element) => &class_element.node,
+            ClassBodyBody::PropertyDefinition(class_element) => &class_element.node,
+            ClassBodyBody::AccessorProperty(class_element) => &class_element.node,
+            ClassBodyBody::StaticBlock(class_element) => &class_element.node,
+            ClassBodyBody::TSAbstractMethodDefinition(class_element) => &class_element.node,
+            ClassBodyBody::TSAbstractPropertyDefinition(class_element) => &class_element.node,
+            ClassBodyBody::TSIndexSignature(class_element) => &class_element.node,
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -311,6 +407,38 @@ pub struct PropertyDefinition {
     pub computed: bool,
     pub key: Option<PropertyDefinitionKey>,
     pub value: Option<Expression>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<Decorator>,
+    #[serde(rename = "typeAnnotation", skip_serializing_if = "Option::is_none")]
+    pub type_annotation: Option<Box<TSTypeAnnotation>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessibility: Option<TSAccessibility>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub readonly: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub optional: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub definite: bool,
+    #[serde(skip_serializing_if = "is_false")]
+    pub declare: bool,
+    #[serde(rename = "override", skip_serializing_if = "is_false")]
+    pub is_override: bool,
+}
+
+// An `accessor` class field, which defines a getter and setter pair backed by a private field.
+// https://github.com/tc39/proposal-decorators#class-auto-accessors
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct AccessorProperty {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "static")]
+    pub is_static: bool,
+    pub computed: bool,
+    pub key: PropertyDefinitionKey,
+    pub value: Option<Expression>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<Decorator>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -330,7 +458,31 @@ pub struct MethodDefinition {
     pub computed: bool,
     pub key: Option<PropertyDefinitionKey>,
     pub kind: MethodDefinitionKind,
-    pub value: Option<FunctionExpression>,
+    pub value: Option<MethodDefinitionValue>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<Decorator>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessibility: Option<TSAccessibility>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub optional: bool,
+    #[serde(rename = "override", skip_serializing_if = "is_false")]
+    pub is_override: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum MethodDefinitionValue {
+    FunctionExpression(FunctionExpression),
+    TSEmptyBodyFunctionExpression(TSEmptyBodyFunctionExpression),
+}
+
+// https://github.com/tc39/proposal-decorators
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct Decorator {
+    #[serde(flatten)]
+    pub node: Node,
+    pub expression: Expression,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -364,6 +516,10 @@ pub struct FunctionExpression {
     pub is_async: bool,
     pub params: Vec<FunctionParameter>,
     pub body: BlockStatement,
+    #[serde(rename = "typeParameters", skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<Box<TSTypeParameterDeclaration>>,
+    #[serde(rename = "returnType", skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<Box<TSTypeAnnotation>>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -372,6 +528,10 @@ pub struct Identifier {
     #[serde(flatten)]
     pub node: Node,
     pub name: String,
+    #[serde(rename = "typeAnnotation", skip_serializing_if = "Option::is_none")]
+    pub type_annotation: Option<Box<TSTypeAnnotation>>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub optional: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -380,6 +540,7 @@ pub struct ImportExpression {
     #[serde(flatten)]
     pub node: Node,
     pub source: Box<Expression>,
+    pub options: Option<Box<Expression>>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -408,6 +569,8 @@ pub enum LiteralValue {
 
     Number(f64),
     Regex {},
+    // BigInt values are not representable in JSON, so the value is null as per ESTree.
+    BigInt,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -419,6 +582,20 @@ pub struct RegExpLiteral {
     pub value: LiteralValue,
     pub raw: String,
     pub regex: Regex,
+    // The parsed pattern and flags, which are not part of ESTree.
+    #[serde(skip)]
+    pub regexp: Box<RegExp>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+#[serde(rename = "Literal")]
+pub struct BigIntLiteral {
+    #[serde(flatten)]
+    pub node: Node,
+    pub value: LiteralValue,
+    pub raw: String,
+    pub bigint: String,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -481,6 +658,8 @@ pub struct NewExpression {
     pub node: Node,
     pub callee: Box<Expression>,
     pub arguments: Vec<NewExpressionArguments>,
+    #[serde(rename = "typeArguments", skip_serializing_if = "Option::is_none")]
+    pub type_arguments: Option<Box<TSTypeParameterInstantiation>>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -523,7 +702,6 @@ pub struct ObjectExpression {
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
-
 pub enum ObjectExpressionProperty {
     Property(Property),
     SpreadElement(SpreadElement),
@@ -553,6 +731,10 @@ impl TryFrom<MethodDefinition> for Property {
             key,
             kind,
             value,
+            decorators: _,
+            accessibility: _,
+            optional: _,
+            is_override: _,
         } = method_definition;
 
         let is_method = kind == MethodDefinitionKind::Method;
@@ -561,14 +743,22 @@ impl TryFrom<MethodDefinition> for Property {
         let property_key = if let Some(PropertyDefinitionKey::Expression(expression)) = key {
             expression
         } else {
-            return Err(ParserError::InvalidMethodDefinitionToPropertyConversion);
+            return Err(ParserError::from_node(
+                ParserErrorKind::InvalidMethodDefinitionToPropertyConversion,
+                &node,
+            ));
         };
 
         let propery_value = match value {
-            Some(function_expression) => {
+            Some(MethodDefinitionValue::FunctionExpression(function_expression)) => {
                 PropertyValue::Expression(Expression::Function(function_expression))
             }
-            None => return Err(ParserError::InvalidMethodDefinitionToPropertyConversion),
+            _ => {
+                return Err(ParserError::from_node(
+                    ParserErrorKind::InvalidMethodDefinitionToPropertyConversion,
+                    &node,
+                ))
+            }
         };
 
         let property_kind = match kind {
@@ -618,6 +808,15 @@ pub enum PropertyValue {
     Pattern(Pattern),
 }
 
+// Only added to the tree when the parser is created with the `preserveParens` option, as per Acorn.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct ParenthesizedExpression {
+    #[serde(flatten)]
+    pub node: Node,
+    pub expression: Box<Expression>,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(tag = "type")]
 pub struct SequenceExpression {
@@ -0,0 +1,199 @@
+use serde::Serialize;
+
+use crate::ast::{Expression, Literal, Node};
+
+// JSX
+// https://facebook.github.io/jsx/
+// https://github.com/facebook/jsx/blob/main/AST.md
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXElement {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "openingElement")]
+    pub opening_element: JSXOpeningElement,
+    #[serde(rename = "closingElement")]
+    pub closing_element: Option<JSXClosingElement>,
+    pub children: Vec<JSXChild>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXOpeningElement {
+    #[serde(flatten)]
+    pub node: Node,
+    pub attributes: Vec<JSXAttributeItem>,
+    pub name: JSXElementName,
+    #[serde(rename = "selfClosing")]
+    pub self_closing: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXClosingElement {
+    #[serde(flatten)]
+    pub node: Node,
+    pub name: JSXElementName,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXFragment {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "openingFragment")]
+    pub opening_fragment: JSXOpeningFragment,
+    #[serde(rename = "closingFragment")]
+    pub closing_fragment: JSXClosingFragment,
+    pub children: Vec<JSXChild>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXOpeningFragment {
+    #[serde(flatten)]
+    pub node: Node,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXClosingFragment {
+    #[serde(flatten)]
+    pub node: Node,
+}
+
+// https://facebook.github.io/jsx/#prod-JSXElementName
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum JSXElementName {
+    Identifier(JSXIdentifier),
+    NamespacedName(JSXNamespacedName),
+    MemberExpression(JSXMemberExpression),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXIdentifier {
+    #[serde(flatten)]
+    pub node: Node,
+    pub name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXNamespacedName {
+    #[serde(flatten)]
+    pub node: Node,
+    pub namespace: JSXIdentifier,
+    pub name: JSXIdentifier,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXMemberExpression {
+    #[serde(flatten)]
+    pub node: Node,
+    pub object: JSXMemberExpressionObject,
+    pub property: JSXIdentifier,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum JSXMemberExpressionObject {
+    Identifier(JSXIdentifier),
+    MemberExpression(Box<JSXMemberExpression>),
+}
+
+// https://facebook.github.io/jsx/#prod-JSXAttributes
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum JSXAttributeItem {
+    Attribute(JSXAttribute),
+    SpreadAttribute(JSXSpreadAttribute),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXAttribute {
+    #[serde(flatten)]
+    pub node: Node,
+    pub name: JSXAttributeName,
+    pub value: Option<JSXAttributeValue>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum JSXAttributeName {
+    Identifier(JSXIdentifier),
+    NamespacedName(JSXNamespacedName),
+}
+
+// https://facebook.github.io/jsx/#prod-JSXAttributeValue
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum JSXAttributeValue {
+    Literal(Literal),
+    ExpressionContainer(JSXExpressionContainer),
+    Element(Box<JSXElement>),
+    Fragment(Box<JSXFragment>),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXSpreadAttribute {
+    #[serde(flatten)]
+    pub node: Node,
+    pub argument: Expression,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXExpressionContainer {
+    #[serde(flatten)]
+    pub node: Node,
+    pub expression: JSXExpression,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum JSXExpression {
+    Expression(Expression),
+    Empty(JSXEmptyExpression),
+}
+
+// The empty expression in `{}` or `{/* comment */}`, which spans the contents of the braces.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXEmptyExpression {
+    #[serde(flatten)]
+    pub node: Node,
+}
+
+// https://facebook.github.io/jsx/#prod-JSXChild
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum JSXChild {
+    Text(JSXText),
+    Element(JSXElement),
+    Fragment(JSXFragment),
+    ExpressionContainer(JSXExpressionContainer),
+    SpreadChild(JSXSpreadChild),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXSpreadChild {
+    #[serde(flatten)]
+    pub node: Node,
+    pub expression: Expression,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct JSXText {
+    #[serde(flatten)]
+    pub node: Node,
+    pub value: String,
+    pub raw: String,
+}
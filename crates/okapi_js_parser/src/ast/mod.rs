@@ -1,13 +1,34 @@
+//! ESTree compatible syntax tree produced by the [`Parser`](crate::Parser).
+//!
+//! Every node type is re-exported from this module so it can be pattern
+//! matched directly, without serializing the tree to JSON first. The syntax
+//! tree of regular expression patterns lives in [`regexp`], as its node names
+//! overlap with the ESTree ones.
+//! https://github.com/estree/estree
+
+// Nodes are stored inline in their parent enums rather than boxed, so variant sizes vary a lot.
+#![allow(clippy::large_enum_variant)]
+
+pub use comment::*;
 pub use declaration::*;
 pub use expression::*;
+pub use jsx::*;
 pub use node::*;
 pub use pattern::*;
 pub use scripts_and_modules::*;
 pub use statement::*;
+pub use token::*;
+pub use typescript::*;
 
+mod comment;
 mod declaration;
 mod expression;
+mod jsx;
 mod node;
 mod pattern;
+pub mod regexp;
 mod scripts_and_modules;
 mod statement;
+mod token;
+mod typescript;
+pub(crate) mod visit;
@@ -1,39 +1,73 @@
+use std::sync::Arc;
+
 use serde::Serialize;
 
-#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
+use crate::ast::Comment;
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct Node {
+    pub start: usize,
+    pub end: usize,
+    // Fields which are only added by parser options are boxed together, so they don't increase the size of every node.
     #[serde(flatten)]
-    pub loc: SourceLocation,
+    pub details: Option<Box<NodeDetails>>,
 }
 
 impl Node {
     pub fn new(start_column: usize, end_column: usize) -> Self {
         Self {
-            loc: SourceLocation {
-                start: start_column,
-                end: end_column,
-            },
+            start: start_column,
+            end: end_column,
+            details: None,
         }
     }
+
+    pub(crate) fn details_mut(&mut self) -> &mut NodeDetails {
+        self.details.get_or_insert_with(Box::default)
+    }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Serialize)]
-pub struct SourceLocation {
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct NodeDetails {
+    // Only added to nodes when the parser is created with locations enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loc: Option<SourceLocation>,
+    // Only added to nodes when the parser is created with ranges enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub range: Option<[usize; 2]>,
+    // Only added to nodes when the parser is created with comment attachment enabled, as per Espree.
+    #[serde(rename = "leadingComments", skip_serializing_if = "Vec::is_empty")]
+    pub leading_comments: Vec<Comment>,
+    #[serde(rename = "trailingComments", skip_serializing_if = "Vec::is_empty")]
+    pub trailing_comments: Vec<Comment>,
+}
+
+// The start and end offsets of a node, in the offset encoding of the parser.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Span {
     pub start: usize,
     pub end: usize,
 }
 
-// #[derive(Debug, PartialEq, Serialize)]
-// #[serde(tag = "type")]
-// pub struct SourceLocation {
-//     pub source: Option<String>,
-//     pub start: Position,
-//     pub end: Position,
-// }
-
-// #[derive(Debug, PartialEq, Serialize)]
-// #[serde(tag = "type")]
-// pub struct Position {
-//     pub line: usize,   // >= 1
-//     pub column: usize, // >= 0
-// }
+impl From<&Node> for Span {
+    fn from(node: &Node) -> Self {
+        Self {
+            start: node.start,
+            end: node.end,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct SourceLocation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<Arc<str>>,
+    pub start: Position,
+    pub end: Position,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Position {
+    pub line: usize,   // >= 1
+    pub column: usize, // >= 0
+}
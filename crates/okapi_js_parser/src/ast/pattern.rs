@@ -2,9 +2,9 @@ use crate::{
     ast::{
         ArrayExpression, ArrayExpressionElement, AssignmentExpression, AssignmentExpressionLeft,
         Expression, Identifier, MemberExpression, Node, ObjectExpression, ObjectExpressionProperty,
-        Property,
+        ParenthesizedExpression, Property, TSParameterProperty, TSTypeAnnotation,
     },
-    ParserError,
+    ParserError, ParserErrorKind,
 };
 use serde::Serialize;
 
@@ -17,6 +17,7 @@ pub enum FunctionParameter {
     Assignment(AssignmentPattern),
     RestElement(RestElement),
     MemberExpression(MemberExpression),
+    TSParameterProperty(TSParameterProperty),
 }
 
 impl TryFrom<ArrayPatternElement> for FunctionParameter {
@@ -75,7 +76,19 @@ impl TryFrom<Expression> for Pattern {
             Expression::Assignment(assignment_expression) => Ok(Pattern::Assignment(
                 AssignmentPattern::try_from(assignment_expression)?,
             )),
-            _ => Err(ParserError::InvalidExpressionToPatternConversion),
+            // Only simple assignment targets can be parenthesized, e.g. `[(a)] = b`, but not `[({ a })] = b`.
+            Expression::Parenthesized(ParenthesizedExpression { expression, .. })
+                if matches!(
+                    *expression,
+                    Expression::Identifier(_) | Expression::Member(_)
+                ) =>
+            {
+                Pattern::try_from(*expression)
+            }
+            expression => Err(ParserError::from_node(
+                ParserErrorKind::InvalidExpressionToPatternConversion,
+                expression.node(),
+            )),
         }
     }
 }
@@ -127,6 +140,12 @@ impl TryFrom<FunctionParameter> for Pattern {
             FunctionParameter::MemberExpression(member_expression) => {
                 Ok(Pattern::MemberExpression(member_expression))
             }
+            FunctionParameter::TSParameterProperty(parameter_property) => {
+                Err(ParserError::from_node(
+                    ParserErrorKind::InvalidFunctionParameterToPatternConversion,
+                    &parameter_property.node,
+                ))
+            }
         }
     }
 }
@@ -137,6 +156,8 @@ pub struct ArrayPattern {
     #[serde(flatten)]
     pub node: Node,
     pub elements: Vec<Option<ArrayPatternElement>>,
+    #[serde(rename = "typeAnnotation", skip_serializing_if = "Option::is_none")]
+    pub type_annotation: Option<Box<TSTypeAnnotation>>,
 }
 
 impl TryFrom<ArrayExpression> for ArrayPattern {
@@ -154,6 +175,7 @@ impl TryFrom<ArrayExpression> for ArrayPattern {
         Ok(ArrayPattern {
             node: array_expression.node,
             elements,
+            type_annotation: None,
         })
     }
 }
@@ -187,7 +209,18 @@ impl TryFrom<Expression> for ArrayPatternElement {
             Expression::Assignment(assignment_expression) => Ok(ArrayPatternElement::Assignment(
                 AssignmentPattern::try_from(assignment_expression)?,
             )),
-            _ => Err(ParserError::InvalidExpressionToArrayPatternElementConversion),
+            Expression::Parenthesized(ParenthesizedExpression { expression, .. })
+                if matches!(
+                    *expression,
+                    Expression::Identifier(_) | Expression::Member(_)
+                ) =>
+            {
+                ArrayPatternElement::try_from(*expression)
+            }
+            expression => Err(ParserError::from_node(
+                ParserErrorKind::InvalidExpressionToArrayPatternElementConversion,
+                expression.node(),
+            )),
         }
     }
 }
@@ -225,6 +258,7 @@ impl TryFrom<ArrayExpressionElement> for ArrayPatternElement {
                 Ok(ArrayPatternElement::RestElement(RestElement {
                     node: spread_element.node,
                     argument: Box::new(Pattern::try_from(spread_element.argument)?),
+                    type_annotation: None,
                 }))
             }
         }
@@ -237,6 +271,8 @@ pub struct RestElement {
     #[serde(flatten)]
     pub node: Node,
     pub argument: Box<Pattern>,
+    #[serde(rename = "typeAnnotation", skip_serializing_if = "Option::is_none")]
+    pub type_annotation: Option<Box<TSTypeAnnotation>>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -266,6 +302,8 @@ pub struct ObjectPattern {
     #[serde(flatten)]
     pub node: Node,
     pub properties: Vec<ObjectPatternProperty>,
+    #[serde(rename = "typeAnnotation", skip_serializing_if = "Option::is_none")]
+    pub type_annotation: Option<Box<TSTypeAnnotation>>,
 }
 
 impl TryFrom<ObjectExpression> for ObjectPattern {
@@ -281,6 +319,7 @@ impl TryFrom<ObjectExpression> for ObjectPattern {
         Ok(ObjectPattern {
             node: object_expression.node,
             properties,
+            type_annotation: None,
         })
     }
 }
@@ -304,6 +343,7 @@ impl TryFrom<ObjectExpressionProperty> for ObjectPatternProperty {
                 Ok(ObjectPatternProperty::Rest(RestElement {
                     node: spread_element.node,
                     argument: Box::new(Pattern::try_from(spread_element.argument)?),
+                    type_annotation: None,
                 }))
             }
         }
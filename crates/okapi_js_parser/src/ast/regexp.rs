@@ -0,0 +1,273 @@
+use serde::Serialize;
+
+// 22.2 RegExp (Regular Expression) Objects
+// https://tc39.es/ecma262/#sec-regexp-regular-expression-objects
+
+// The syntax tree of a regular expression literal, modelled after eslint's regexpp.
+// Node offsets are relative to the start of the pattern, i.e. the first character after the opening `/`, in the
+// parser's offset encoding.
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct RegExp {
+    pub pattern: Pattern,
+    pub flags: Flags,
+}
+
+// https://tc39.es/ecma262/#prod-RegularExpressionFlags
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Flags {
+    pub has_indices: bool,
+    pub global: bool,
+    pub ignore_case: bool,
+    pub multiline: bool,
+    pub dot_all: bool,
+    pub unicode: bool,
+    pub unicode_sets: bool,
+    pub sticky: bool,
+}
+
+// https://tc39.es/ecma262/#prod-Pattern
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct Pattern {
+    pub start: usize,
+    pub end: usize,
+    pub alternatives: Vec<Alternative>,
+}
+
+// https://tc39.es/ecma262/#prod-Alternative
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct Alternative {
+    pub start: usize,
+    pub end: usize,
+    pub elements: Vec<Element>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Element {
+    BoundaryAssertion(BoundaryAssertion),
+    LookaroundAssertion(LookaroundAssertion),
+    Quantifier(Quantifier),
+    Group(Group),
+    CapturingGroup(CapturingGroup),
+    Character(Character),
+    CharacterSet(CharacterSet),
+    CharacterClass(CharacterClass),
+    Backreference(Backreference),
+}
+
+// https://tc39.es/ecma262/#prod-Assertion
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct BoundaryAssertion {
+    pub start: usize,
+    pub end: usize,
+    pub kind: BoundaryAssertionKind,
+    pub negate: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum BoundaryAssertionKind {
+    Start,
+    End,
+    Word,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct LookaroundAssertion {
+    pub start: usize,
+    pub end: usize,
+    pub kind: LookaroundAssertionKind,
+    pub negate: bool,
+    pub alternatives: Vec<Alternative>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LookaroundAssertionKind {
+    Lookahead,
+    Lookbehind,
+}
+
+// https://tc39.es/ecma262/#prod-Quantifier
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct Quantifier {
+    pub start: usize,
+    pub end: usize,
+    pub min: u32,
+    // `None` when the quantifier has no upper bound, e.g. `*` or `{2,}`.
+    pub max: Option<u32>,
+    pub greedy: bool,
+    pub element: Box<Element>,
+}
+
+// A non-capturing group, e.g. `(?:a|b)`, which may change the `i`, `m` and `s` flags within it, e.g. `(?i-m:a)`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct Group {
+    pub start: usize,
+    pub end: usize,
+    pub modifiers: Option<Modifiers>,
+    pub alternatives: Vec<Alternative>,
+}
+
+// https://tc39.es/ecma262/#prod-RegularExpressionModifiers
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct Modifiers {
+    pub start: usize,
+    pub end: usize,
+    pub add: ModifierFlags,
+    // `None` when there is no `-`, e.g. `(?i:a)`.
+    pub remove: Option<ModifierFlags>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub struct ModifierFlags {
+    pub start: usize,
+    pub end: usize,
+    pub ignore_case: bool,
+    pub multiline: bool,
+    pub dot_all: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct CapturingGroup {
+    pub start: usize,
+    pub end: usize,
+    // One-based index of the group, in order of the opening parentheses.
+    pub index: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub alternatives: Vec<Alternative>,
+}
+
+// A single character, either literal or escaped. The value is a code point rather than a `char` as escapes can produce lone surrogates.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct Character {
+    pub start: usize,
+    pub end: usize,
+    pub value: u32,
+}
+
+// https://tc39.es/ecma262/#prod-CharacterClassEscape
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct CharacterSet {
+    pub start: usize,
+    pub end: usize,
+    pub kind: CharacterSetKind,
+    pub negate: bool,
+    // The property name and value of a Unicode property escape, e.g. `Script` and `Greek` in `\p{Script=Greek}`.
+    // A lone General_Category value such as `\p{L}` has the key `General_Category`, and a binary property such as `\p{ASCII}` has no value.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CharacterSetKind {
+    Any,
+    Digit,
+    Space,
+    Word,
+    Property,
+}
+
+// https://tc39.es/ecma262/#prod-CharacterClass
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct CharacterClass {
+    pub start: usize,
+    pub end: usize,
+    pub negate: bool,
+    pub elements: Vec<CharacterClassElement>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum CharacterClassElement {
+    Character(Character),
+    CharacterSet(CharacterSet),
+    CharacterClassRange(CharacterClassRange),
+    // The remaining elements are only allowed with the `v` flag.
+    CharacterClass(CharacterClass),
+    ClassStringDisjunction(ClassStringDisjunction),
+    ClassIntersection(ClassIntersection),
+    ClassSubtraction(ClassSubtraction),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct CharacterClassRange {
+    pub start: usize,
+    pub end: usize,
+    pub min: Character,
+    pub max: Character,
+}
+
+// https://tc39.es/ecma262/#prod-ClassStringDisjunction
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct ClassStringDisjunction {
+    pub start: usize,
+    pub end: usize,
+    pub alternatives: Vec<StringAlternative>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct StringAlternative {
+    pub start: usize,
+    pub end: usize,
+    pub elements: Vec<Character>,
+}
+
+// https://tc39.es/ecma262/#prod-ClassIntersection
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct ClassIntersection {
+    pub start: usize,
+    pub end: usize,
+    pub left: Box<CharacterClassElement>,
+    pub right: Box<CharacterClassElement>,
+}
+
+// https://tc39.es/ecma262/#prod-ClassSubtraction
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct ClassSubtraction {
+    pub start: usize,
+    pub end: usize,
+    pub left: Box<CharacterClassElement>,
+    pub right: Box<CharacterClassElement>,
+}
+
+// https://tc39.es/ecma262/#prod-AtomEscape
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct Backreference {
+    pub start: usize,
+    pub end: usize,
+    #[serde(rename = "ref")]
+    pub reference: BackreferenceReference,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum BackreferenceReference {
+    Index(u32),
+    Name(String),
+}
@@ -1,10 +1,12 @@
 use crate::ast::{
-    BindingPattern, ClassDeclaration, Expression, FunctionDeclaration, Identifier, Literal, Node,
-    StatementListItem, VariableDeclaration,
+    BindingPattern, ClassDeclaration, Comment, Declaration, Expression, FunctionDeclaration,
+    Identifier, Literal, Node, ProgramToken, StatementListItem, TSDeclareFunction,
+    TSEnumDeclaration, TSExportAssignment, TSImportEqualsDeclaration, TSImportExportKind,
+    TSInterfaceDeclaration, TSModuleDeclaration, TSTypeAliasDeclaration, VariableDeclaration,
 };
 use serde::Serialize;
 
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(tag = "type")]
 pub struct Program {
     #[serde(flatten)]
@@ -12,23 +14,33 @@ pub struct Program {
     pub body: ProgramBody,
     #[serde(rename = "sourceType")]
     pub source_type: ProgramSource,
+    // The text of a leading `#!` comment, without the `#!` or the line terminator.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hashbang: Option<String>,
+    // Only added when the parser is created with the `comments` option, in source order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<Vec<Comment>>,
+    // Only added when the parser is created with the `tokens` option, in source order.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tokens: Option<Vec<ProgramToken>>,
 }
 
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ProgramBody {
     StatementList(Vec<StatementListItem>),
     Module(Vec<ModuleItem>),
 }
 
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ProgramSource {
+    #[default]
     Script,
     Module,
 }
 
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ModuleItem {
     ImportDeclaration(ImportDeclaration),
@@ -36,16 +48,34 @@ pub enum ModuleItem {
     StatementListItem(StatementListItem),
 }
 
-#[derive(Debug, PartialEq, Serialize)]
+impl From<ModuleItem> for StatementListItem {
+    fn from(module_item: ModuleItem) -> Self {
+        match module_item {
+            ModuleItem::ImportDeclaration(import_declaration) => {
+                StatementListItem::ImportDeclaration(import_declaration)
+            }
+            ModuleItem::ExportDeclaration(export_declaration) => {
+                StatementListItem::ExportDeclaration(export_declaration)
+            }
+            ModuleItem::StatementListItem(statement_list_item) => statement_list_item,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(tag = "type")]
 pub struct ImportDeclaration {
     #[serde(flatten)]
     pub node: Node,
     pub specifiers: Vec<ImportSpecifier>,
     pub source: Literal,
+    pub attributes: Vec<ImportAttribute>,
+    // Only added when parsing TypeScript.
+    #[serde(rename = "importKind", skip_serializing_if = "Option::is_none")]
+    pub import_kind: Option<TSImportExportKind>,
 }
 
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 pub struct ImportSpecifier {
     #[serde(rename = "type")]
     pub kind: ImportSpecifierKind,
@@ -54,6 +84,9 @@ pub struct ImportSpecifier {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub imported: Option<ModuleExportName>,
     pub local: Identifier,
+    // Only added to named specifiers when parsing TypeScript.
+    #[serde(rename = "importKind", skip_serializing_if = "Option::is_none")]
+    pub import_kind: Option<TSImportExportKind>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -73,7 +106,24 @@ pub enum ModuleExportName {
     Literal(Literal),
 }
 
-#[derive(Debug, PartialEq, Serialize)]
+// https://tc39.es/ecma262/#prod-WithClause
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct ImportAttribute {
+    #[serde(flatten)]
+    pub node: Node,
+    pub key: ImportAttributeKey,
+    pub value: Literal,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum ImportAttributeKey {
+    Identifier(Identifier),
+    Literal(Literal),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ExportDeclaration {
     #[serde(rename = "ExportAllDeclaration")]
@@ -82,26 +132,35 @@ pub enum ExportDeclaration {
     Default(ExportDefaultDeclaration),
     #[serde(rename = "ExportNamedDeclaration")]
     Named(ExportNamedDeclaration),
+    #[serde(rename = "TSExportAssignment")]
+    TSAssignment(TSExportAssignment),
 }
 
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(tag = "type")]
 pub struct ExportAllDeclaration {
     #[serde(flatten)]
     pub node: Node,
     pub exported: Option<ModuleExportName>,
     pub source: Literal,
+    pub attributes: Vec<ImportAttribute>,
+    // Only added when parsing TypeScript.
+    #[serde(rename = "exportKind", skip_serializing_if = "Option::is_none")]
+    pub export_kind: Option<TSImportExportKind>,
 }
 
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(tag = "type")]
 pub struct ExportDefaultDeclaration {
     #[serde(flatten)]
     pub node: Node,
     pub declaration: ExportDefaultDeclarationDeclaration,
+    // Only added when parsing TypeScript.
+    #[serde(rename = "exportKind", skip_serializing_if = "Option::is_none")]
+    pub export_kind: Option<TSImportExportKind>,
 }
 
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ExportDefaultDeclarationDeclaration {
     Identifier(Identifier),
@@ -109,9 +168,10 @@ pub enum ExportDefaultDeclarationDeclaration {
     ClassDeclaration(ClassDeclaration),
     Expression(Expression),
     FunctionDeclaration(FunctionDeclaration),
+    TSInterfaceDeclaration(TSInterfaceDeclaration),
 }
 
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(tag = "type")]
 pub struct ExportNamedDeclaration {
     #[serde(flatten)]
@@ -119,21 +179,59 @@ pub struct ExportNamedDeclaration {
     pub declaration: Option<ExportNamedDeclarationDeclaration>,
     pub specifiers: Vec<ExportSpecifier>,
     pub source: Option<Literal>,
+    // Always empty when there is no source, as per Acorn.
+    pub attributes: Vec<ImportAttribute>,
+    // Only added when parsing TypeScript.
+    #[serde(rename = "exportKind", skip_serializing_if = "Option::is_none")]
+    pub export_kind: Option<TSImportExportKind>,
 }
 
-#[derive(Debug, PartialEq, Serialize)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ExportNamedDeclarationDeclaration {
     Class(ClassDeclaration),
     Function(FunctionDeclaration),
     Variable(VariableDeclaration),
+    TSDeclareFunction(TSDeclareFunction),
+    TSEnum(TSEnumDeclaration),
+    TSImportEquals(TSImportEqualsDeclaration),
+    TSInterface(TSInterfaceDeclaration),
+    TSModule(TSModuleDeclaration),
+    TSTypeAlias(TSTypeAliasDeclaration),
 }
 
-#[derive(Debug, PartialEq, Serialize)]
+impl From<Declaration> for ExportNamedDeclarationDeclaration {
+    fn from(declaration: Declaration) -> Self {
+        match declaration {
+            Declaration::Class(class_declaration) => Self::Class(class_declaration),
+            Declaration::Function(function_declaration) => Self::Function(function_declaration),
+            Declaration::Variable(variable_declaration) => Self::Variable(variable_declaration),
+            Declaration::TSDeclareFunction(declare_function) => {
+                Self::TSDeclareFunction(declare_function)
+            }
+            Declaration::TSEnum(enum_declaration) => Self::TSEnum(enum_declaration),
+            Declaration::TSImportEquals(import_equals_declaration) => {
+                Self::TSImportEquals(import_equals_declaration)
+            }
+            Declaration::TSInterface(interface_declaration) => {
+                Self::TSInterface(interface_declaration)
+            }
+            Declaration::TSModule(module_declaration) => Self::TSModule(module_declaration),
+            Declaration::TSTypeAlias(type_alias_declaration) => {
+                Self::TSTypeAlias(type_alias_declaration)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(tag = "type")]
 pub struct ExportSpecifier {
     #[serde(flatten)]
     pub node: Node,
     pub local: Box<ModuleExportName>,
     pub exported: Box<ModuleExportName>,
+    // Only added when parsing TypeScript.
+    #[serde(rename = "exportKind", skip_serializing_if = "Option::is_none")]
+    pub export_kind: Option<TSImportExportKind>,
 }
@@ -1,6 +1,9 @@
 use serde::Serialize;
 
-use crate::ast::{Declaration, Expression, Identifier, Node, Pattern, VariableDeclaration};
+use crate::ast::{
+    Declaration, ExportDeclaration, Expression, Identifier, ImportDeclaration, Node, Pattern,
+    VariableDeclaration,
+};
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(untagged)]
@@ -18,6 +21,8 @@ pub enum Statement {
     ForIn(ForInStatement),
     ForOf(ForOfStatement),
     If(IfStatement),
+    // Only added when parsing with error recovery, in place of a statement which could not be parsed.
+    Invalid(InvalidStatement),
     Labeled(LabeledStatement),
     Return(ReturnStatement),
     StaticBlock(StaticBlock),
@@ -33,6 +38,9 @@ pub enum Statement {
 pub enum StatementListItem {
     Declaration(Declaration),
     Statement(Statement),
+    // Only outside of the top level of a module when import and export declarations are allowed everywhere.
+    ImportDeclaration(ImportDeclaration),
+    ExportDeclaration(ExportDeclaration),
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize)]
@@ -91,6 +99,14 @@ pub struct EmptyStatement {
     pub node: Node,
 }
 
+// Not part of ESTree. The span covers the tokens which were skipped to recover from the error.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct InvalidStatement {
+    #[serde(flatten)]
+    pub node: Node,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize)]
 #[serde(tag = "type")]
 pub struct ExpressionStatement {
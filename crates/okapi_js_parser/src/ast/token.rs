@@ -0,0 +1,36 @@
+use serde::Serialize;
+
+use crate::ast::{Node, Regex};
+
+// 12 ECMAScript Language: Lexical Grammar
+// https://tc39.es/ecma262/#sec-ecmascript-language-lexical-grammar
+// Tokens are not part of ESTree, so they are serialized in the same format as Espree's `tokens` option.
+// https://github.com/eslint/js/tree/main/packages/espree#options
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ProgramToken {
+    #[serde(rename = "type")]
+    pub kind: ProgramTokenKind,
+    // The name of identifiers and keywords, and the source text of every other token.
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regex: Option<Regex>,
+    #[serde(flatten)]
+    pub node: Node,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ProgramTokenKind {
+    Boolean,
+    Identifier,
+    Keyword,
+    Null,
+    Numeric,
+    Punctuator,
+    RegularExpression,
+    String,
+    // Each part of a template literal, from a "`" or "}" up to and including the next "${" or "`".
+    Template,
+    PrivateIdentifier,
+    JSXIdentifier,
+    JSXText,
+}
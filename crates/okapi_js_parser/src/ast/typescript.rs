@@ -0,0 +1,833 @@
+use serde::{Serialize, Serializer};
+
+use crate::ast::{
+    AssignmentPattern, BlockStatement, Decorator, Expression, FunctionParameter, Identifier,
+    Literal, MethodDefinitionKind, MethodDefinitionValue, ModuleItem, Node, PropertyDefinitionKey,
+    TemplateElement,
+};
+
+// TypeScript syntax nodes, in the shape produced by typescript-estree.
+// https://typescript-eslint.io/packages/typescript-estree/
+// https://github.com/typescript-eslint/typescript-eslint/tree/main/packages/ast-spec
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSTypeAnnotation {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "typeAnnotation")]
+    pub type_annotation: TSType,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum TSType {
+    Keyword(TSKeywordType),
+    This(TSThisType),
+    TypeReference(TSTypeReference),
+    Union(TSUnionType),
+    Intersection(TSIntersectionType),
+    Array(Box<TSArrayType>),
+    Tuple(TSTupleType),
+    NamedTupleMember(Box<TSNamedTupleMember>),
+    Optional(Box<TSOptionalType>),
+    Rest(Box<TSRestType>),
+    Function(Box<TSFunctionType>),
+    Constructor(Box<TSConstructorType>),
+    TypeLiteral(TSTypeLiteral),
+    Literal(Box<TSLiteralType>),
+    TypeQuery(TSTypeQuery),
+    Import(Box<TSImportType>),
+    TemplateLiteral(TSTemplateLiteralType),
+    TypeOperator(Box<TSTypeOperator>),
+    IndexedAccess(Box<TSIndexedAccessType>),
+    Conditional(Box<TSConditionalType>),
+    Infer(Box<TSInferType>),
+    Mapped(Box<TSMappedType>),
+    TypePredicate(Box<TSTypePredicate>),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct TSKeywordType {
+    #[serde(rename = "type")]
+    pub kind: TSKeywordKind,
+    #[serde(flatten)]
+    pub node: Node,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum TSKeywordKind {
+    #[serde(rename = "TSAnyKeyword")]
+    Any,
+    #[serde(rename = "TSBigIntKeyword")]
+    BigInt,
+    #[serde(rename = "TSBooleanKeyword")]
+    Boolean,
+    #[serde(rename = "TSIntrinsicKeyword")]
+    Intrinsic,
+    #[serde(rename = "TSNeverKeyword")]
+    Never,
+    #[serde(rename = "TSNullKeyword")]
+    Null,
+    #[serde(rename = "TSNumberKeyword")]
+    Number,
+    #[serde(rename = "TSObjectKeyword")]
+    Object,
+    #[serde(rename = "TSStringKeyword")]
+    String,
+    #[serde(rename = "TSSymbolKeyword")]
+    Symbol,
+    #[serde(rename = "TSUndefinedKeyword")]
+    Undefined,
+    #[serde(rename = "TSUnknownKeyword")]
+    Unknown,
+    #[serde(rename = "TSVoidKeyword")]
+    Void,
+}
+
+impl TSKeywordKind {
+    // The predefined types which are spelled as identifiers, rather than reserved words.
+    pub(crate) fn from_identifier(name: &str) -> Option<Self> {
+        match name {
+            "any" => Some(TSKeywordKind::Any),
+            "bigint" => Some(TSKeywordKind::BigInt),
+            "boolean" => Some(TSKeywordKind::Boolean),
+            "intrinsic" => Some(TSKeywordKind::Intrinsic),
+            "never" => Some(TSKeywordKind::Never),
+            "number" => Some(TSKeywordKind::Number),
+            "object" => Some(TSKeywordKind::Object),
+            "string" => Some(TSKeywordKind::String),
+            "symbol" => Some(TSKeywordKind::Symbol),
+            "undefined" => Some(TSKeywordKind::Undefined),
+            "unknown" => Some(TSKeywordKind::Unknown),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSThisType {
+    #[serde(flatten)]
+    pub node: Node,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum TSEntityName {
+    Identifier(Identifier),
+    QualifiedName(Box<TSQualifiedName>),
+    This(TSThisType),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSQualifiedName {
+    #[serde(flatten)]
+    pub node: Node,
+    pub left: TSEntityName,
+    pub right: Identifier,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSTypeReference {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "typeName")]
+    pub type_name: TSEntityName,
+    #[serde(rename = "typeArguments", skip_serializing_if = "Option::is_none")]
+    pub type_arguments: Option<TSTypeParameterInstantiation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSUnionType {
+    #[serde(flatten)]
+    pub node: Node,
+    pub types: Vec<TSType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSIntersectionType {
+    #[serde(flatten)]
+    pub node: Node,
+    pub types: Vec<TSType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSArrayType {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "elementType")]
+    pub element_type: TSType,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSTupleType {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "elementTypes")]
+    pub element_types: Vec<TSType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSNamedTupleMember {
+    #[serde(flatten)]
+    pub node: Node,
+    pub label: Identifier,
+    #[serde(rename = "elementType")]
+    pub element_type: TSType,
+    pub optional: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSOptionalType {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "typeAnnotation")]
+    pub type_annotation: TSType,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSRestType {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "typeAnnotation")]
+    pub type_annotation: TSType,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSFunctionType {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "typeParameters", skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<TSTypeParameterDeclaration>,
+    pub params: Vec<FunctionParameter>,
+    #[serde(rename = "returnType")]
+    pub return_type: TSTypeAnnotation,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSConstructorType {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "abstract")]
+    pub is_abstract: bool,
+    #[serde(rename = "typeParameters", skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<TSTypeParameterDeclaration>,
+    pub params: Vec<FunctionParameter>,
+    #[serde(rename = "returnType")]
+    pub return_type: TSTypeAnnotation,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSTypeLiteral {
+    #[serde(flatten)]
+    pub node: Node,
+    pub members: Vec<TSTypeElement>,
+}
+
+// The literal is a `Literal`, a negated number `UnaryExpression` or a `TemplateLiteral`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSLiteralType {
+    #[serde(flatten)]
+    pub node: Node,
+    pub literal: Expression,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSTypeQuery {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "exprName")]
+    pub expr_name: TSTypeQueryExprName,
+    #[serde(rename = "typeArguments", skip_serializing_if = "Option::is_none")]
+    pub type_arguments: Option<TSTypeParameterInstantiation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum TSTypeQueryExprName {
+    EntityName(TSEntityName),
+    Import(Box<TSImportType>),
+}
+
+// `import("x").Qualifier<TypeArguments>`, where the argument is a string `TSLiteralType`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSImportType {
+    #[serde(flatten)]
+    pub node: Node,
+    pub argument: TSType,
+    pub qualifier: Option<TSEntityName>,
+    #[serde(rename = "typeArguments", skip_serializing_if = "Option::is_none")]
+    pub type_arguments: Option<TSTypeParameterInstantiation>,
+}
+
+// A template literal type with substitutions, e.g. `` `a${B}c` ``. A template without substitutions is a
+// `TSLiteralType`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSTemplateLiteralType {
+    #[serde(flatten)]
+    pub node: Node,
+    pub quasis: Vec<TemplateElement>,
+    pub types: Vec<TSType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSTypeOperator {
+    #[serde(flatten)]
+    pub node: Node,
+    pub operator: TSTypeOperatorKind,
+    #[serde(rename = "typeAnnotation")]
+    pub type_annotation: TSType,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TSTypeOperatorKind {
+    Keyof,
+    Readonly,
+    Unique,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSIndexedAccessType {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "objectType")]
+    pub object_type: TSType,
+    #[serde(rename = "indexType")]
+    pub index_type: TSType,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSConditionalType {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "checkType")]
+    pub check_type: TSType,
+    #[serde(rename = "extendsType")]
+    pub extends_type: TSType,
+    #[serde(rename = "trueType")]
+    pub true_type: TSType,
+    #[serde(rename = "falseType")]
+    pub false_type: TSType,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSInferType {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "typeParameter")]
+    pub type_parameter: TSTypeParameter,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSMappedType {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "typeParameter")]
+    pub type_parameter: TSTypeParameter,
+    #[serde(rename = "nameType")]
+    pub name_type: Option<TSType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional: Option<TSMappedTypeModifier>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub readonly: Option<TSMappedTypeModifier>,
+    #[serde(rename = "typeAnnotation", skip_serializing_if = "Option::is_none")]
+    pub type_annotation: Option<TSType>,
+}
+
+// A mapped type `?` or `readonly` modifier, which is serialized as `true`, `"+"` or `"-"`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TSMappedTypeModifier {
+    Present,
+    Plus,
+    Minus,
+}
+
+impl Serialize for TSMappedTypeModifier {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            TSMappedTypeModifier::Present => serializer.serialize_bool(true),
+            TSMappedTypeModifier::Plus => serializer.serialize_str("+"),
+            TSMappedTypeModifier::Minus => serializer.serialize_str("-"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSTypePredicate {
+    #[serde(flatten)]
+    pub node: Node,
+    pub asserts: bool,
+    #[serde(rename = "parameterName")]
+    pub parameter_name: TSTypePredicateName,
+    #[serde(rename = "typeAnnotation")]
+    pub type_annotation: Option<TSTypeAnnotation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum TSTypePredicateName {
+    Identifier(Identifier),
+    This(TSThisType),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum TSTypeElement {
+    PropertySignature(TSPropertySignature),
+    MethodSignature(TSMethodSignature),
+    CallSignature(TSCallSignatureDeclaration),
+    ConstructSignature(TSConstructSignatureDeclaration),
+    IndexSignature(TSIndexSignature),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSPropertySignature {
+    #[serde(flatten)]
+    pub node: Node,
+    pub computed: bool,
+    pub key: Expression,
+    pub optional: bool,
+    pub readonly: bool,
+    #[serde(rename = "typeAnnotation", skip_serializing_if = "Option::is_none")]
+    pub type_annotation: Option<TSTypeAnnotation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSMethodSignature {
+    #[serde(flatten)]
+    pub node: Node,
+    pub computed: bool,
+    pub key: Expression,
+    pub kind: TSMethodSignatureKind,
+    pub optional: bool,
+    #[serde(rename = "typeParameters", skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<TSTypeParameterDeclaration>,
+    pub params: Vec<FunctionParameter>,
+    #[serde(rename = "returnType", skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<TSTypeAnnotation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TSMethodSignatureKind {
+    Method,
+    Get,
+    Set,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSCallSignatureDeclaration {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "typeParameters", skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<TSTypeParameterDeclaration>,
+    pub params: Vec<FunctionParameter>,
+    #[serde(rename = "returnType", skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<TSTypeAnnotation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSConstructSignatureDeclaration {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "typeParameters", skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<TSTypeParameterDeclaration>,
+    pub params: Vec<FunctionParameter>,
+    #[serde(rename = "returnType", skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<TSTypeAnnotation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSIndexSignature {
+    #[serde(flatten)]
+    pub node: Node,
+    pub parameters: Vec<Identifier>,
+    #[serde(rename = "typeAnnotation", skip_serializing_if = "Option::is_none")]
+    pub type_annotation: Option<TSTypeAnnotation>,
+    pub readonly: bool,
+    #[serde(rename = "static")]
+    pub is_static: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessibility: Option<TSAccessibility>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSTypeParameterDeclaration {
+    #[serde(flatten)]
+    pub node: Node,
+    pub params: Vec<TSTypeParameter>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSTypeParameter {
+    #[serde(flatten)]
+    pub node: Node,
+    pub name: Identifier,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<TSType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<TSType>,
+    #[serde(rename = "in")]
+    pub is_in: bool,
+    #[serde(rename = "out")]
+    pub is_out: bool,
+    #[serde(rename = "const")]
+    pub is_const: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSTypeParameterInstantiation {
+    #[serde(flatten)]
+    pub node: Node,
+    pub params: Vec<TSType>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TSAccessibility {
+    Private,
+    Protected,
+    Public,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSInterfaceDeclaration {
+    #[serde(flatten)]
+    pub node: Node,
+    pub id: Identifier,
+    #[serde(rename = "typeParameters", skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<TSTypeParameterDeclaration>,
+    pub extends: Vec<TSInterfaceHeritage>,
+    pub body: TSInterfaceBody,
+    pub declare: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSInterfaceHeritage {
+    #[serde(flatten)]
+    pub node: Node,
+    pub expression: Expression,
+    #[serde(rename = "typeArguments", skip_serializing_if = "Option::is_none")]
+    pub type_arguments: Option<TSTypeParameterInstantiation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSInterfaceBody {
+    #[serde(flatten)]
+    pub node: Node,
+    pub body: Vec<TSTypeElement>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSClassImplements {
+    #[serde(flatten)]
+    pub node: Node,
+    pub expression: Expression,
+    #[serde(rename = "typeArguments", skip_serializing_if = "Option::is_none")]
+    pub type_arguments: Option<TSTypeParameterInstantiation>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSTypeAliasDeclaration {
+    #[serde(flatten)]
+    pub node: Node,
+    pub id: Identifier,
+    #[serde(rename = "typeParameters", skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<TSTypeParameterDeclaration>,
+    #[serde(rename = "typeAnnotation")]
+    pub type_annotation: TSType,
+    pub declare: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSEnumDeclaration {
+    #[serde(flatten)]
+    pub node: Node,
+    pub id: Identifier,
+    pub members: Vec<TSEnumMember>,
+    #[serde(rename = "const")]
+    pub is_const: bool,
+    pub declare: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSEnumMember {
+    #[serde(flatten)]
+    pub node: Node,
+    // Either an `Identifier` or a string `Literal`.
+    pub id: Expression,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub initializer: Option<Expression>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSModuleDeclaration {
+    #[serde(flatten)]
+    pub node: Node,
+    pub id: TSModuleName,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<TSModuleBlock>,
+    pub kind: TSModuleDeclarationKind,
+    pub declare: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum TSModuleName {
+    EntityName(TSEntityName),
+    Literal(Literal),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TSModuleDeclarationKind {
+    Global,
+    Module,
+    Namespace,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSModuleBlock {
+    #[serde(flatten)]
+    pub node: Node,
+    pub body: Vec<ModuleItem>,
+}
+
+// A function overload signature, or a function declared with `declare`, which has no body.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSDeclareFunction {
+    #[serde(flatten)]
+    pub node: Node,
+    pub id: Option<Identifier>,
+    pub expression: bool,
+    pub generator: bool,
+    #[serde(rename = "async")]
+    pub asynchronous: bool,
+    #[serde(rename = "typeParameters", skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<TSTypeParameterDeclaration>,
+    pub params: Vec<FunctionParameter>,
+    #[serde(rename = "returnType", skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<TSTypeAnnotation>,
+    pub declare: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSAsExpression {
+    #[serde(flatten)]
+    pub node: Node,
+    pub expression: Box<Expression>,
+    #[serde(rename = "typeAnnotation")]
+    pub type_annotation: TSType,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSSatisfiesExpression {
+    #[serde(flatten)]
+    pub node: Node,
+    pub expression: Box<Expression>,
+    #[serde(rename = "typeAnnotation")]
+    pub type_annotation: TSType,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSNonNullExpression {
+    #[serde(flatten)]
+    pub node: Node,
+    pub expression: Box<Expression>,
+}
+
+// The `<Type>expression` assertion, which is not available when JSX is enabled.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSTypeAssertion {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "typeAnnotation")]
+    pub type_annotation: TSType,
+    pub expression: Box<Expression>,
+}
+
+// A constructor parameter with an accessibility, `readonly` or `override` modifier, which is also declared as a class field.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSParameterProperty {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessibility: Option<TSAccessibility>,
+    pub readonly: bool,
+    #[serde(rename = "override")]
+    pub is_override: bool,
+    #[serde(rename = "static")]
+    pub is_static: bool,
+    pub parameter: TSParameterPropertyParameter,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<Decorator>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum TSParameterPropertyParameter {
+    Identifier(Identifier),
+    Assignment(AssignmentPattern),
+}
+
+// An abstract class field, which has no initializer.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSAbstractPropertyDefinition {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "static")]
+    pub is_static: bool,
+    pub computed: bool,
+    pub key: Option<PropertyDefinitionKey>,
+    pub value: Option<Expression>,
+    #[serde(rename = "typeAnnotation", skip_serializing_if = "Option::is_none")]
+    pub type_annotation: Option<TSTypeAnnotation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessibility: Option<TSAccessibility>,
+    pub readonly: bool,
+    pub optional: bool,
+    #[serde(rename = "override")]
+    pub is_override: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<Decorator>,
+}
+
+// An abstract class method, which has no body.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSAbstractMethodDefinition {
+    #[serde(flatten)]
+    pub node: Node,
+    #[serde(rename = "static")]
+    pub is_static: bool,
+    pub computed: bool,
+    pub key: Option<PropertyDefinitionKey>,
+    pub kind: MethodDefinitionKind,
+    pub value: Option<MethodDefinitionValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessibility: Option<TSAccessibility>,
+    pub optional: bool,
+    #[serde(rename = "override")]
+    pub is_override: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<Decorator>,
+}
+
+// The value of a method overload or abstract method, which has no body.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSEmptyBodyFunctionExpression {
+    #[serde(flatten)]
+    pub node: Node,
+    pub id: Option<Identifier>,
+    pub expression: bool,
+    pub generator: bool,
+    #[serde(rename = "async")]
+    pub is_async: bool,
+    #[serde(rename = "typeParameters", skip_serializing_if = "Option::is_none")]
+    pub type_parameters: Option<TSTypeParameterDeclaration>,
+    pub params: Vec<FunctionParameter>,
+    #[serde(rename = "returnType", skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<TSTypeAnnotation>,
+    // Always `None`, which is serialized as `null`.
+    pub body: Option<BlockStatement>,
+}
+
+// `export = Expression;`
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSExportAssignment {
+    #[serde(flatten)]
+    pub node: Node,
+    pub expression: Expression,
+}
+
+// `import Identifier = require("x");` or `import Identifier = EntityName;`
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSImportEqualsDeclaration {
+    #[serde(flatten)]
+    pub node: Node,
+    pub id: Identifier,
+    #[serde(rename = "moduleReference")]
+    pub module_reference: TSModuleReference,
+    #[serde(rename = "importKind")]
+    pub import_kind: TSImportExportKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum TSModuleReference {
+    EntityName(TSEntityName),
+    External(TSExternalModuleReference),
+}
+
+// `require("x")`, where the expression is a string literal.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type")]
+pub struct TSExternalModuleReference {
+    #[serde(flatten)]
+    pub node: Node,
+    pub expression: Literal,
+}
+
+// Whether an import or export only refers to types, as with `import type` and `export type`, which are removed when
+// compiling to JavaScript.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TSImportExportKind {
+    Type,
+    Value,
+}
+
+pub(crate) fn is_false(value: &bool) -> bool {
+    !value
+}
@@ -0,0 +1,1390 @@
+use crate::ast::*;
+
+// Visits every node of a program in source order, with each node passed to `visit_node` before its children. The
+// `Visit` trait borrows the tree and `VisitMut` borrows it mutably, e.g. to attach comments to nodes, and both are
+// generated from the same definition so they always walk the tree in the same order.
+//
+// Each `visit_` method walks the children of its node by default. A visitor overrides the methods of the nodes it is
+// interested in, and calls the matching `walk_` function to continue into the children. TypeScript types and
+// type-only declarations are not walked, apart from their own node, as they have no runtime syntax.
+macro_rules! define_visitor {
+    ($visitor:ident, $walk:ident, $($mutability:tt)?) => {
+        pub(crate) trait $visitor<'a> {
+            fn visit_node(&mut self, _node: &'a $($mutability)? Node) {}
+
+            fn visit_program(&mut self, program: &'a $($mutability)? Program) {
+                $walk::walk_program(self, program)
+            }
+
+            fn visit_module_item(&mut self, module_item: &'a $($mutability)? ModuleItem) {
+                $walk::walk_module_item(self, module_item)
+            }
+
+            fn visit_import_declaration(
+                &mut self,
+                import_declaration: &'a $($mutability)? ImportDeclaration,
+            ) {
+                $walk::walk_import_declaration(self, import_declaration)
+            }
+
+            fn visit_export_declaration(
+                &mut self,
+                export_declaration: &'a $($mutability)? ExportDeclaration,
+            ) {
+                $walk::walk_export_declaration(self, export_declaration)
+            }
+
+            fn visit_statement_list_item(
+                &mut self,
+                statement_list_item: &'a $($mutability)? StatementListItem,
+            ) {
+                $walk::walk_statement_list_item(self, statement_list_item)
+            }
+
+            fn visit_declaration(&mut self, declaration: &'a $($mutability)? Declaration) {
+                $walk::walk_declaration(self, declaration)
+            }
+
+            fn visit_variable_declaration(
+                &mut self,
+                variable_declaration: &'a $($mutability)? VariableDeclaration,
+            ) {
+                $walk::walk_variable_declaration(self, variable_declaration)
+            }
+
+            fn visit_block_statement(&mut self, block_statement: &'a $($mutability)? BlockStatement) {
+                $walk::walk_block_statement(self, block_statement)
+            }
+
+            fn visit_statement(&mut self, statement: &'a $($mutability)? Statement) {
+                $walk::walk_statement(self, statement)
+            }
+
+            fn visit_function_declaration(
+                &mut self,
+                function_declaration: &'a $($mutability)? FunctionDeclaration,
+            ) {
+                $walk::walk_function_declaration(self, function_declaration)
+            }
+
+            fn visit_function_expression(
+                &mut self,
+                function_expression: &'a $($mutability)? FunctionExpression,
+            ) {
+                $walk::walk_function_expression(self, function_expression)
+            }
+
+            fn visit_function_parameter(&mut self, param: &'a $($mutability)? FunctionParameter) {
+                $walk::walk_function_parameter(self, param)
+            }
+
+            // The body of a function declaration, function expression or method.
+            fn visit_function_body(&mut self, body: &'a $($mutability)? BlockStatement) {
+                self.visit_block_statement(body)
+            }
+
+            fn visit_class_declaration(
+                &mut self,
+                class_declaration: &'a $($mutability)? ClassDeclaration,
+            ) {
+                $walk::walk_class_declaration(self, class_declaration)
+            }
+
+            fn visit_class_element(&mut self, class_element: &'a $($mutability)? ClassBodyBody) {
+                $walk::walk_class_element(self, class_element)
+            }
+
+            fn visit_pattern(&mut self, pattern: &'a $($mutability)? Pattern) {
+                $walk::walk_pattern(self, pattern)
+            }
+
+            fn visit_object_pattern(&mut self, object_pattern: &'a $($mutability)? ObjectPattern) {
+                $walk::walk_object_pattern(self, object_pattern)
+            }
+
+            fn visit_array_pattern(&mut self, array_pattern: &'a $($mutability)? ArrayPattern) {
+                $walk::walk_array_pattern(self, array_pattern)
+            }
+
+            fn visit_expression(&mut self, expression: &'a $($mutability)? Expression) {
+                $walk::walk_expression(self, expression)
+            }
+
+            fn visit_arrow_function_body(
+                &mut self,
+                body: &'a $($mutability)? ArrowFunctionExpressionBody,
+            ) {
+                $walk::walk_arrow_function_body(self, body)
+            }
+
+            fn visit_call_expression(&mut self, call_expression: &'a $($mutability)? CallExpression) {
+                $walk::walk_call_expression(self, call_expression)
+            }
+
+            // Shared by object literals and object patterns.
+            fn visit_property(&mut self, property: &'a $($mutability)? Property) {
+                $walk::walk_property(self, property)
+            }
+
+            fn visit_template_literal(
+                &mut self,
+                template_literal: &'a $($mutability)? TemplateLiteral,
+            ) {
+                $walk::walk_template_literal(self, template_literal)
+            }
+
+            fn visit_identifier(&mut self, identifier: &'a $($mutability)? Identifier) {
+                self.visit_node(& $($mutability)? identifier.node)
+            }
+
+            fn visit_private_identifier(
+                &mut self,
+                private_identifier: &'a $($mutability)? PrivateIdentifier,
+            ) {
+                self.visit_node(& $($mutability)? private_identifier.node)
+            }
+        }
+
+        pub(crate) mod $walk {
+            use super::$visitor;
+            use crate::ast::*;
+
+            // Scripts and modules
+
+            pub(crate) fn walk_program<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                program: &'a $($mutability)? Program,
+            ) {
+                match & $($mutability)? program.body {
+                    ProgramBody::StatementList(statement_list) => {
+                        for statement_list_item in statement_list {
+                            visitor.visit_statement_list_item(statement_list_item);
+                        }
+                    }
+                    ProgramBody::Module(module_items) => {
+                        for module_item in module_items {
+                            visitor.visit_module_item(module_item);
+                        }
+                    }
+                }
+            }
+
+            pub(crate) fn walk_module_item<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                module_item: &'a $($mutability)? ModuleItem,
+            ) {
+                match module_item {
+                    ModuleItem::ImportDeclaration(import_declaration) => {
+                        visitor.visit_import_declaration(import_declaration)
+                    }
+                    ModuleItem::ExportDeclaration(export_declaration) => {
+                        visitor.visit_export_declaration(export_declaration)
+                    }
+                    ModuleItem::StatementListItem(statement_list_item) => {
+                        visitor.visit_statement_list_item(statement_list_item)
+                    }
+                }
+            }
+
+            pub(crate) fn walk_import_declaration<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                import_declaration: &'a $($mutability)? ImportDeclaration,
+            ) {
+                visitor.visit_node(& $($mutability)? import_declaration.node);
+
+                for specifier in & $($mutability)? import_declaration.specifiers {
+                    visitor.visit_node(& $($mutability)? specifier.node);
+
+                    // The imported name is the same node as the local name of a default, namespace or shorthand
+                    // specifier.
+                    match & $($mutability)? specifier.imported {
+                        Some(imported)
+                            if !same_span(module_export_name_node(imported), &specifier.local.node) =>
+                        {
+                            walk_module_export_name(visitor, imported);
+                            visitor.visit_identifier(& $($mutability)? specifier.local);
+                        }
+                        _ => visitor.visit_identifier(& $($mutability)? specifier.local),
+                    }
+                }
+
+                visitor.visit_node(& $($mutability)? import_declaration.source.node);
+                walk_import_attributes(visitor, & $($mutability)? import_declaration.attributes);
+            }
+
+            pub(crate) fn walk_export_declaration<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                export_declaration: &'a $($mutability)? ExportDeclaration,
+            ) {
+                match export_declaration {
+                    ExportDeclaration::All(export_all_declaration) => {
+                        visitor.visit_node(& $($mutability)? export_all_declaration.node);
+
+                        if let Some(exported) = & $($mutability)? export_all_declaration.exported {
+                            walk_module_export_name(visitor, exported);
+                        }
+
+                        visitor.visit_node(& $($mutability)? export_all_declaration.source.node);
+                        walk_import_attributes(visitor, & $($mutability)? export_all_declaration.attributes);
+                    }
+                    ExportDeclaration::Default(export_default_declaration) => {
+                        visitor.visit_node(& $($mutability)? export_default_declaration.node);
+
+                        match & $($mutability)? export_default_declaration.declaration {
+                            ExportDefaultDeclarationDeclaration::Identifier(identifier) => {
+                                visitor.visit_identifier(identifier)
+                            }
+                            ExportDefaultDeclarationDeclaration::BindingPattern(
+                                BindingPattern::Array(array_pattern),
+                            ) => visitor.visit_array_pattern(array_pattern),
+                            ExportDefaultDeclarationDeclaration::BindingPattern(
+                                BindingPattern::Object(object_pattern),
+                            ) => visitor.visit_object_pattern(object_pattern),
+                            ExportDefaultDeclarationDeclaration::ClassDeclaration(class_declaration) => {
+                                visitor.visit_class_declaration(class_declaration)
+                            }
+                            ExportDefaultDeclarationDeclaration::Expression(expression) => {
+                                visitor.visit_expression(expression)
+                            }
+                            ExportDefaultDeclarationDeclaration::FunctionDeclaration(
+                                function_declaration,
+                            ) => visitor.visit_function_declaration(function_declaration),
+                            ExportDefaultDeclarationDeclaration::TSInterfaceDeclaration(
+                                interface_declaration,
+                            ) => visitor.visit_node(& $($mutability)? interface_declaration.node),
+                        }
+                    }
+                    ExportDeclaration::Named(export_named_declaration) => {
+                        visitor.visit_node(& $($mutability)? export_named_declaration.node);
+
+                        match & $($mutability)? export_named_declaration.declaration {
+                            Some(ExportNamedDeclarationDeclaration::Class(class_declaration)) => {
+                                visitor.visit_class_declaration(class_declaration)
+                            }
+                            Some(ExportNamedDeclarationDeclaration::Function(function_declaration)) => {
+                                visitor.visit_function_declaration(function_declaration)
+                            }
+                            Some(ExportNamedDeclarationDeclaration::Variable(variable_declaration)) => {
+                                visitor.visit_variable_declaration(variable_declaration)
+                            }
+                            Some(ExportNamedDeclarationDeclaration::TSDeclareFunction(
+                                declare_function,
+                            )) => visitor.visit_node(& $($mutability)? declare_function.node),
+                            Some(ExportNamedDeclarationDeclaration::TSEnum(enum_declaration)) => {
+                                walk_enum_declaration(visitor, enum_declaration)
+                            }
+                            Some(ExportNamedDeclarationDeclaration::TSImportEquals(
+                                import_equals_declaration,
+                            )) => walk_import_equals_declaration(visitor, import_equals_declaration),
+                            Some(ExportNamedDeclarationDeclaration::TSInterface(
+                                interface_declaration,
+                            )) => visitor.visit_node(& $($mutability)? interface_declaration.node),
+                            Some(ExportNamedDeclarationDeclaration::TSModule(module_declaration)) => {
+                                walk_module_declaration(visitor, module_declaration)
+                            }
+                            Some(ExportNamedDeclarationDeclaration::TSTypeAlias(
+                                type_alias_declaration,
+                            )) => visitor.visit_node(& $($mutability)? type_alias_declaration.node),
+                            None => {}
+                        }
+
+                        for specifier in & $($mutability)? export_named_declaration.specifiers {
+                            visitor.visit_node(& $($mutability)? specifier.node);
+
+                            // The exported name is the same node as the local name of a shorthand specifier.
+                            let is_shorthand = same_span(
+                                module_export_name_node(&specifier.local),
+                                module_export_name_node(&specifier.exported),
+                            );
+
+                            walk_module_export_name(visitor, & $($mutability)? specifier.local);
+
+                            if !is_shorthand {
+                                walk_module_export_name(visitor, & $($mutability)? specifier.exported);
+                            }
+                        }
+
+                        if let Some(source) = & $($mutability)? export_named_declaration.source {
+                            visitor.visit_node(& $($mutability)? source.node);
+                        }
+
+                        walk_import_attributes(visitor, & $($mutability)? export_named_declaration.attributes);
+                    }
+                    ExportDeclaration::TSAssignment(export_assignment) => {
+                        visitor.visit_node(& $($mutability)? export_assignment.node);
+                        visitor.visit_expression(& $($mutability)? export_assignment.expression);
+                    }
+                }
+            }
+
+            fn walk_module_export_name<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                module_export_name: &'a $($mutability)? ModuleExportName,
+            ) {
+                match module_export_name {
+                    ModuleExportName::Identifier(identifier) => visitor.visit_identifier(identifier),
+                    ModuleExportName::Literal(literal) => visitor.visit_node(& $($mutability)? literal.node),
+                }
+            }
+
+            fn walk_import_attributes<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                attributes: &'a $($mutability)? [ImportAttribute],
+            ) {
+                for attribute in attributes {
+                    visitor.visit_node(& $($mutability)? attribute.node);
+
+                    match & $($mutability)? attribute.key {
+                        ImportAttributeKey::Identifier(identifier) => visitor.visit_identifier(identifier),
+                        ImportAttributeKey::Literal(literal) => visitor.visit_node(& $($mutability)? literal.node),
+                    }
+
+                    visitor.visit_node(& $($mutability)? attribute.value.node);
+                }
+            }
+
+            // Statements and declarations
+
+            pub(crate) fn walk_statement_list_item<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                statement_list_item: &'a $($mutability)? StatementListItem,
+            ) {
+                match statement_list_item {
+                    StatementListItem::Declaration(declaration) => visitor.visit_declaration(declaration),
+                    StatementListItem::Statement(statement) => visitor.visit_statement(statement),
+                    StatementListItem::ImportDeclaration(import_declaration) => {
+                        visitor.visit_import_declaration(import_declaration)
+                    }
+                    StatementListItem::ExportDeclaration(export_declaration) => {
+                        visitor.visit_export_declaration(export_declaration)
+                    }
+                }
+            }
+
+            pub(crate) fn walk_declaration<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                declaration: &'a $($mutability)? Declaration,
+            ) {
+                match declaration {
+                    Declaration::Class(class_declaration) => visitor.visit_class_declaration(class_declaration),
+                    Declaration::Function(function_declaration) => {
+                        visitor.visit_function_declaration(function_declaration)
+                    }
+                    Declaration::Variable(variable_declaration) => {
+                        visitor.visit_variable_declaration(variable_declaration)
+                    }
+                    Declaration::TSDeclareFunction(declare_function) => {
+                        visitor.visit_node(& $($mutability)? declare_function.node)
+                    }
+                    Declaration::TSEnum(enum_declaration) => walk_enum_declaration(visitor, enum_declaration),
+                    Declaration::TSImportEquals(import_equals_declaration) => {
+                        walk_import_equals_declaration(visitor, import_equals_declaration)
+                    }
+                    Declaration::TSInterface(interface_declaration) => {
+                        visitor.visit_node(& $($mutability)? interface_declaration.node)
+                    }
+                    Declaration::TSModule(module_declaration) => {
+                        walk_module_declaration(visitor, module_declaration)
+                    }
+                    Declaration::TSTypeAlias(type_alias_declaration) => {
+                        visitor.visit_node(& $($mutability)? type_alias_declaration.node)
+                    }
+                }
+            }
+
+            // The module reference is a TypeScript type, unless it is a `require` call.
+            fn walk_import_equals_declaration<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                import_equals_declaration: &'a $($mutability)? TSImportEqualsDeclaration,
+            ) {
+                visitor.visit_node(& $($mutability)? import_equals_declaration.node);
+                visitor.visit_identifier(& $($mutability)? import_equals_declaration.id);
+
+                if let TSModuleReference::External(external_module_reference) =
+                    & $($mutability)? import_equals_declaration.module_reference
+                {
+                    visitor.visit_node(& $($mutability)? external_module_reference.node);
+                    visitor.visit_node(& $($mutability)? external_module_reference.expression.node);
+                }
+            }
+
+            fn walk_enum_declaration<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                enum_declaration: &'a $($mutability)? TSEnumDeclaration,
+            ) {
+                visitor.visit_node(& $($mutability)? enum_declaration.node);
+                visitor.visit_identifier(& $($mutability)? enum_declaration.id);
+
+                for member in & $($mutability)? enum_declaration.members {
+                    visitor.visit_node(& $($mutability)? member.node);
+                    visitor.visit_expression(& $($mutability)? member.id);
+
+                    if let Some(initializer) = & $($mutability)? member.initializer {
+                        visitor.visit_expression(initializer);
+                    }
+                }
+            }
+
+            fn walk_module_declaration<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                module_declaration: &'a $($mutability)? TSModuleDeclaration,
+            ) {
+                visitor.visit_node(& $($mutability)? module_declaration.node);
+
+                if let Some(module_block) = & $($mutability)? module_declaration.body {
+                    visitor.visit_node(& $($mutability)? module_block.node);
+
+                    for module_item in & $($mutability)? module_block.body {
+                        visitor.visit_module_item(module_item);
+                    }
+                }
+            }
+
+            pub(crate) fn walk_variable_declaration<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                variable_declaration: &'a $($mutability)? VariableDeclaration,
+            ) {
+                visitor.visit_node(& $($mutability)? variable_declaration.node);
+
+                for declarator in & $($mutability)? variable_declaration.declarations {
+                    visitor.visit_node(& $($mutability)? declarator.node);
+                    visitor.visit_pattern(& $($mutability)? declarator.id);
+
+                    if let Some(init) = & $($mutability)? declarator.init {
+                        visitor.visit_expression(init);
+                    }
+                }
+            }
+
+            pub(crate) fn walk_block_statement<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                block_statement: &'a $($mutability)? BlockStatement,
+            ) {
+                visitor.visit_node(& $($mutability)? block_statement.node);
+
+                for statement_list_item in & $($mutability)? block_statement.body {
+                    visitor.visit_statement_list_item(statement_list_item);
+                }
+            }
+
+            pub(crate) fn walk_statement<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                statement: &'a $($mutability)? Statement,
+            ) {
+                match statement {
+                    Statement::Block(block_statement) => visitor.visit_block_statement(block_statement),
+                    Statement::Break(break_statement) => {
+                        visitor.visit_node(& $($mutability)? break_statement.node);
+
+                        if let Some(label) = & $($mutability)? break_statement.label {
+                            visitor.visit_identifier(label);
+                        }
+                    }
+                    Statement::Continue(continue_statement) => {
+                        visitor.visit_node(& $($mutability)? continue_statement.node);
+
+                        if let Some(label) = & $($mutability)? continue_statement.label {
+                            visitor.visit_identifier(label);
+                        }
+                    }
+                    Statement::Debugger(debugger_statement) => {
+                        visitor.visit_node(& $($mutability)? debugger_statement.node)
+                    }
+                    Statement::Directive(directive_statement) => {
+                        visitor.visit_node(& $($mutability)? directive_statement.node);
+                        visitor.visit_expression(& $($mutability)? directive_statement.expression);
+                    }
+                    Statement::Declaration(declaration) => visitor.visit_declaration(declaration),
+                    Statement::DoWhile(do_while_statement) => {
+                        visitor.visit_node(& $($mutability)? do_while_statement.node);
+                        visitor.visit_statement(& $($mutability)? do_while_statement.body);
+                        visitor.visit_expression(& $($mutability)? do_while_statement.test);
+                    }
+                    Statement::Empty(empty_statement) => {
+                        visitor.visit_node(& $($mutability)? empty_statement.node)
+                    }
+                    Statement::Invalid(invalid_statement) => {
+                        visitor.visit_node(& $($mutability)? invalid_statement.node)
+                    }
+                    Statement::Expression(expression_statement) => {
+                        visitor.visit_node(& $($mutability)? expression_statement.node);
+                        visitor.visit_expression(& $($mutability)? expression_statement.expression);
+                    }
+                    Statement::For(for_statement) => {
+                        visitor.visit_node(& $($mutability)? for_statement.node);
+
+                        match & $($mutability)? for_statement.init {
+                            Some(ForStatementInit::VariableDeclaration(variable_declaration)) => {
+                                visitor.visit_variable_declaration(variable_declaration)
+                            }
+                            Some(ForStatementInit::Expression(expression)) => {
+                                visitor.visit_expression(expression)
+                            }
+                            None => {}
+                        }
+
+                        if let Some(test) = & $($mutability)? for_statement.test {
+                            visitor.visit_expression(test);
+                        }
+
+                        if let Some(update) = & $($mutability)? for_statement.update {
+                            visitor.visit_expression(update);
+                        }
+
+                        visitor.visit_statement(& $($mutability)? for_statement.body);
+                    }
+                    Statement::ForIn(for_in_statement) => {
+                        visitor.visit_node(& $($mutability)? for_in_statement.node);
+
+                        walk_for_in_statement_left(visitor, & $($mutability)? for_in_statement.left);
+                        visitor.visit_expression(& $($mutability)? for_in_statement.right);
+                        visitor.visit_statement(& $($mutability)? for_in_statement.body);
+                    }
+                    Statement::ForOf(for_of_statement) => {
+                        visitor.visit_node(& $($mutability)? for_of_statement.node);
+
+                        walk_for_in_statement_left(visitor, & $($mutability)? for_of_statement.left);
+                        visitor.visit_expression(& $($mutability)? for_of_statement.right);
+                        visitor.visit_statement(& $($mutability)? for_of_statement.body);
+                    }
+                    Statement::If(if_statement) => {
+                        visitor.visit_node(& $($mutability)? if_statement.node);
+                        visitor.visit_expression(& $($mutability)? if_statement.test);
+                        visitor.visit_statement(& $($mutability)? if_statement.consequent);
+
+                        if let Some(alternate) = & $($mutability)? if_statement.alternate {
+                            visitor.visit_statement(alternate);
+                        }
+                    }
+                    Statement::Labeled(labeled_statement) => {
+                        visitor.visit_node(& $($mutability)? labeled_statement.node);
+                        visitor.visit_identifier(& $($mutability)? labeled_statement.label);
+                        visitor.visit_statement(& $($mutability)? labeled_statement.body);
+                    }
+                    Statement::Return(return_statement) => {
+                        visitor.visit_node(& $($mutability)? return_statement.node);
+
+                        if let Some(argument) = & $($mutability)? return_statement.argument {
+                            visitor.visit_expression(argument);
+                        }
+                    }
+                    Statement::StaticBlock(static_block) => {
+                        visitor.visit_node(& $($mutability)? static_block.node);
+
+                        for statement_list_item in & $($mutability)? static_block.body {
+                            visitor.visit_statement_list_item(statement_list_item);
+                        }
+                    }
+                    Statement::Switch(switch_statement) => {
+                        visitor.visit_node(& $($mutability)? switch_statement.node);
+                        visitor.visit_expression(& $($mutability)? switch_statement.discriminant);
+
+                        for case in & $($mutability)? switch_statement.cases {
+                            visitor.visit_node(& $($mutability)? case.node);
+
+                            if let Some(test) = & $($mutability)? case.test {
+                                visitor.visit_expression(test);
+                            }
+
+                            for statement in & $($mutability)? case.consequent {
+                                visitor.visit_statement(statement);
+                            }
+                        }
+                    }
+                    Statement::Throw(throw_statement) => {
+                        visitor.visit_node(& $($mutability)? throw_statement.node);
+                        visitor.visit_expression(& $($mutability)? throw_statement.argument);
+                    }
+                    Statement::Try(try_statement) => {
+                        visitor.visit_node(& $($mutability)? try_statement.node);
+                        visitor.visit_block_statement(& $($mutability)? try_statement.block);
+
+                        if let Some(handler) = & $($mutability)? try_statement.handler {
+                            visitor.visit_node(& $($mutability)? handler.node);
+
+                            if let Some(param) = & $($mutability)? handler.param {
+                                visitor.visit_pattern(param);
+                            }
+
+                            visitor.visit_block_statement(& $($mutability)? handler.body);
+                        }
+
+                        if let Some(finalizer) = & $($mutability)? try_statement.finalizer {
+                            visitor.visit_block_statement(finalizer);
+                        }
+                    }
+                    Statement::While(while_statement) => {
+                        visitor.visit_node(& $($mutability)? while_statement.node);
+                        visitor.visit_expression(& $($mutability)? while_statement.test);
+                        visitor.visit_statement(& $($mutability)? while_statement.body);
+                    }
+                    Statement::With(with_statement) => {
+                        visitor.visit_node(& $($mutability)? with_statement.node);
+                        visitor.visit_expression(& $($mutability)? with_statement.object);
+                        visitor.visit_statement(& $($mutability)? with_statement.body);
+                    }
+                }
+            }
+
+            fn walk_for_in_statement_left<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                left: &'a $($mutability)? ForInStatementLeft,
+            ) {
+                match left {
+                    ForInStatementLeft::VariableDeclaration(variable_declaration) => {
+                        visitor.visit_variable_declaration(variable_declaration)
+                    }
+                    ForInStatementLeft::Expression(expression) => visitor.visit_expression(expression),
+                    ForInStatementLeft::Pattern(pattern) => visitor.visit_pattern(pattern),
+                }
+            }
+
+            // Functions and classes
+
+            pub(crate) fn walk_function_declaration<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                function_declaration: &'a $($mutability)? FunctionDeclaration,
+            ) {
+                visitor.visit_node(& $($mutability)? function_declaration.node);
+
+                if let Some(id) = & $($mutability)? function_declaration.id {
+                    visitor.visit_identifier(id);
+                }
+
+                for param in & $($mutability)? function_declaration.params {
+                    visitor.visit_function_parameter(param);
+                }
+
+                visitor.visit_function_body(& $($mutability)? function_declaration.body);
+            }
+
+            pub(crate) fn walk_function_expression<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                function_expression: &'a $($mutability)? FunctionExpression,
+            ) {
+                visitor.visit_node(& $($mutability)? function_expression.node);
+
+                if let Some(id) = & $($mutability)? function_expression.id {
+                    visitor.visit_identifier(id);
+                }
+
+                for param in & $($mutability)? function_expression.params {
+                    visitor.visit_function_parameter(param);
+                }
+
+                visitor.visit_function_body(& $($mutability)? function_expression.body);
+            }
+
+            pub(crate) fn walk_function_parameter<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                param: &'a $($mutability)? FunctionParameter,
+            ) {
+                match param {
+                    FunctionParameter::Identifier(identifier) => visitor.visit_identifier(identifier),
+                    FunctionParameter::Object(object_pattern) => visitor.visit_object_pattern(object_pattern),
+                    FunctionParameter::Array(array_pattern) => visitor.visit_array_pattern(array_pattern),
+                    FunctionParameter::Assignment(assignment_pattern) => {
+                        walk_assignment_pattern(visitor, assignment_pattern)
+                    }
+                    FunctionParameter::RestElement(rest_element) => walk_rest_element(visitor, rest_element),
+                    FunctionParameter::MemberExpression(member_expression) => {
+                        walk_member_expression(visitor, member_expression)
+                    }
+                    FunctionParameter::TSParameterProperty(parameter_property) => {
+                        visitor.visit_node(& $($mutability)? parameter_property.node);
+
+                        walk_decorators(visitor, & $($mutability)? parameter_property.decorators);
+
+                        match & $($mutability)? parameter_property.parameter {
+                            TSParameterPropertyParameter::Identifier(identifier) => {
+                                visitor.visit_identifier(identifier)
+                            }
+                            TSParameterPropertyParameter::Assignment(assignment_pattern) => {
+                                walk_assignment_pattern(visitor, assignment_pattern)
+                            }
+                        }
+                    }
+                }
+            }
+
+            pub(crate) fn walk_class_declaration<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                class_declaration: &'a $($mutability)? ClassDeclaration,
+            ) {
+                visitor.visit_node(& $($mutability)? class_declaration.node);
+
+                walk_decorators(visitor, & $($mutability)? class_declaration.decorators);
+
+                if let Some(id) = & $($mutability)? class_declaration.id {
+                    visitor.visit_identifier(id);
+                }
+
+                if let Some(super_class) = & $($mutability)? class_declaration.super_class {
+                    visitor.visit_expression(super_class);
+                }
+
+                walk_class_body(visitor, & $($mutability)? class_declaration.body);
+            }
+
+            fn walk_class_body<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                class_body: &'a $($mutability)? ClassBody,
+            ) {
+                visitor.visit_node(& $($mutability)? class_body.node);
+
+                for class_element in & $($mutability)? class_body.body {
+                    visitor.visit_class_element(class_element);
+                }
+            }
+
+            pub(crate) fn walk_class_element<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                class_element: &'a $($mutability)? ClassBodyBody,
+            ) {
+                match class_element {
+                    ClassBodyBody::MethodDefinition(method_definition) => {
+                        visitor.visit_node(& $($mutability)? method_definition.node);
+
+                        walk_decorators(visitor, & $($mutability)? method_definition.decorators);
+
+                        if let Some(key) = & $($mutability)? method_definition.key {
+                            walk_property_definition_key(visitor, key);
+                        }
+
+                        match & $($mutability)? method_definition.value {
+                            Some(MethodDefinitionValue::FunctionExpression(function_expression)) => {
+                                visitor.visit_function_expression(function_expression)
+                            }
+                            Some(MethodDefinitionValue::TSEmptyBodyFunctionExpression(
+                                function_expression,
+                            )) => {
+                                visitor.visit_node(& $($mutability)? function_expression.node);
+
+                                for param in & $($mutability)? function_expression.params {
+                                    visitor.visit_function_parameter(param);
+                                }
+                            }
+                            None => {}
+                        }
+                    }
+                    ClassBodyBody::PropertyDefinition(property_definition) => {
+                        visitor.visit_node(& $($mutability)? property_definition.node);
+
+                        walk_decorators(visitor, & $($mutability)? property_definition.decorators);
+
+                        if let Some(key) = & $($mutability)? property_definition.key {
+                            walk_property_definition_key(visitor, key);
+                        }
+
+                        if let Some(value) = & $($mutability)? property_definition.value {
+                            visitor.visit_expression(value);
+                        }
+                    }
+                    ClassBodyBody::AccessorProperty(accessor_property) => {
+                        visitor.visit_node(& $($mutability)? accessor_property.node);
+
+                        walk_decorators(visitor, & $($mutability)? accessor_property.decorators);
+                        walk_property_definition_key(visitor, & $($mutability)? accessor_property.key);
+
+                        if let Some(value) = & $($mutability)? accessor_property.value {
+                            visitor.visit_expression(value);
+                        }
+                    }
+                    ClassBodyBody::StaticBlock(static_block) => {
+                        visitor.visit_node(& $($mutability)? static_block.node);
+
+                        for statement_list_item in & $($mutability)? static_block.body {
+                            visitor.visit_statement_list_item(statement_list_item);
+                        }
+                    }
+                    ClassBodyBody::TSAbstractMethodDefinition(method_definition) => {
+                        visitor.visit_node(& $($mutability)? method_definition.node);
+
+                        if let Some(key) = & $($mutability)? method_definition.key {
+                            walk_property_definition_key(visitor, key);
+                        }
+                    }
+                    ClassBodyBody::TSAbstractPropertyDefinition(property_definition) => {
+                        visitor.visit_node(& $($mutability)? property_definition.node);
+
+                        if let Some(key) = & $($mutability)? property_definition.key {
+                            walk_property_definition_key(visitor, key);
+                        }
+                    }
+                    ClassBodyBody::TSIndexSignature(index_signature) => {
+                        visitor.visit_node(& $($mutability)? index_signature.node)
+                    }
+                }
+            }
+
+            fn walk_property_definition_key<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                key: &'a $($mutability)? PropertyDefinitionKey,
+            ) {
+                match key {
+                    PropertyDefinitionKey::Expression(expression) => visitor.visit_expression(expression),
+                    PropertyDefinitionKey::PrivateIdentifier(private_identifier) => {
+                        visitor.visit_private_identifier(private_identifier)
+                    }
+                }
+            }
+
+            fn walk_decorators<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                decorators: &'a $($mutability)? [Decorator],
+            ) {
+                for decorator in decorators {
+                    visitor.visit_node(& $($mutability)? decorator.node);
+                    visitor.visit_expression(& $($mutability)? decorator.expression);
+                }
+            }
+
+            // Patterns
+
+            pub(crate) fn walk_pattern<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                pattern: &'a $($mutability)? Pattern,
+            ) {
+                match pattern {
+                    Pattern::Identifier(identifier) => visitor.visit_identifier(identifier),
+                    Pattern::Object(object_pattern) => visitor.visit_object_pattern(object_pattern),
+                    Pattern::Array(array_pattern) => visitor.visit_array_pattern(array_pattern),
+                    Pattern::RestElement(rest_element) => walk_rest_element(visitor, rest_element),
+                    Pattern::Assignment(assignment_pattern) => {
+                        walk_assignment_pattern(visitor, assignment_pattern)
+                    }
+                    Pattern::MemberExpression(member_expression) => {
+                        walk_member_expression(visitor, member_expression)
+                    }
+                }
+            }
+
+            pub(crate) fn walk_object_pattern<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                object_pattern: &'a $($mutability)? ObjectPattern,
+            ) {
+                visitor.visit_node(& $($mutability)? object_pattern.node);
+
+                for property in & $($mutability)? object_pattern.properties {
+                    match property {
+                        ObjectPatternProperty::Property(property) => visitor.visit_property(property),
+                        ObjectPatternProperty::Rest(rest_element) => walk_rest_element(visitor, rest_element),
+                    }
+                }
+            }
+
+            pub(crate) fn walk_array_pattern<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                array_pattern: &'a $($mutability)? ArrayPattern,
+            ) {
+                visitor.visit_node(& $($mutability)? array_pattern.node);
+
+                for element in (& $($mutability)? array_pattern.elements).into_iter().flatten() {
+                    match element {
+                        ArrayPatternElement::Identifier(identifier) => visitor.visit_identifier(identifier),
+                        ArrayPatternElement::Object(object_pattern) => {
+                            visitor.visit_object_pattern(object_pattern)
+                        }
+                        ArrayPatternElement::Array(array_pattern) => visitor.visit_array_pattern(array_pattern),
+                        ArrayPatternElement::RestElement(rest_element) => {
+                            walk_rest_element(visitor, rest_element)
+                        }
+                        ArrayPatternElement::Assignment(assignment_pattern) => {
+                            walk_assignment_pattern(visitor, assignment_pattern)
+                        }
+                        ArrayPatternElement::MemberExpression(member_expression) => {
+                            walk_member_expression(visitor, member_expression)
+                        }
+                    }
+                }
+            }
+
+            fn walk_rest_element<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                rest_element: &'a $($mutability)? RestElement,
+            ) {
+                visitor.visit_node(& $($mutability)? rest_element.node);
+                visitor.visit_pattern(& $($mutability)? rest_element.argument);
+            }
+
+            fn walk_assignment_pattern<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                assignment_pattern: &'a $($mutability)? AssignmentPattern,
+            ) {
+                visitor.visit_node(& $($mutability)? assignment_pattern.node);
+                visitor.visit_pattern(& $($mutability)? assignment_pattern.left);
+                visitor.visit_expression(& $($mutability)? assignment_pattern.right);
+            }
+
+            // Expressions
+
+            pub(crate) fn walk_expression<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                expression: &'a $($mutability)? Expression,
+            ) {
+                match expression {
+                    Expression::Array(array_expression) => {
+                        visitor.visit_node(& $($mutability)? array_expression.node);
+
+                        for element in (& $($mutability)? array_expression.elements).into_iter().flatten() {
+                            match element {
+                                ArrayExpressionElement::Expression(expression) => {
+                                    visitor.visit_expression(expression)
+                                }
+                                ArrayExpressionElement::SpreadElement(spread_element) => {
+                                    walk_spread_element(visitor, spread_element)
+                                }
+                            }
+                        }
+                    }
+                    Expression::ArrowFunction(arrow_function_expression) => {
+                        visitor.visit_node(& $($mutability)? arrow_function_expression.node);
+
+                        for param in & $($mutability)? arrow_function_expression.params {
+                            visitor.visit_pattern(param);
+                        }
+
+                        visitor.visit_arrow_function_body(& $($mutability)? arrow_function_expression.body);
+                    }
+                    Expression::Assignment(assignment_expression) => {
+                        visitor.visit_node(& $($mutability)? assignment_expression.node);
+
+                        match & $($mutability)? *assignment_expression.left {
+                            AssignmentExpressionLeft::Expression(expression) => {
+                                visitor.visit_expression(expression)
+                            }
+                            AssignmentExpressionLeft::Pattern(pattern) => visitor.visit_pattern(pattern),
+                        }
+
+                        visitor.visit_expression(& $($mutability)? assignment_expression.right);
+                    }
+                    Expression::Await(await_expression) => {
+                        visitor.visit_node(& $($mutability)? await_expression.node);
+                        visitor.visit_expression(& $($mutability)? await_expression.argument);
+                    }
+                    Expression::Binary(binary_expression) => {
+                        visitor.visit_node(& $($mutability)? binary_expression.node);
+
+                        match & $($mutability)? binary_expression.left {
+                            BinaryExpressionLeft::Expression(expression) => visitor.visit_expression(expression),
+                            BinaryExpressionLeft::PrivateIdentifier(private_identifier) => {
+                                visitor.visit_private_identifier(private_identifier)
+                            }
+                        }
+
+                        visitor.visit_expression(& $($mutability)? binary_expression.right);
+                    }
+                    Expression::Call(call_expression) => visitor.visit_call_expression(call_expression),
+                    Expression::Chain(chain_expression) => {
+                        visitor.visit_node(& $($mutability)? chain_expression.node);
+
+                        match & $($mutability)? chain_expression.expression {
+                            ChainElement::CallExpression(call_expression) => {
+                                visitor.visit_call_expression(call_expression)
+                            }
+                            ChainElement::MemberExpression(member_expression) => {
+                                walk_member_expression(visitor, member_expression)
+                            }
+                        }
+                    }
+                    Expression::Class(class_expression) => {
+                        visitor.visit_node(& $($mutability)? class_expression.node);
+
+                        walk_decorators(visitor, & $($mutability)? class_expression.decorators);
+
+                        if let Some(id) = & $($mutability)? class_expression.id {
+                            visitor.visit_identifier(id);
+                        }
+
+                        if let Some(super_class) = & $($mutability)? class_expression.super_class {
+                            visitor.visit_expression(super_class);
+                        }
+
+                        walk_class_body(visitor, & $($mutability)? class_expression.body);
+                    }
+                    Expression::Conditional(conditional_expression) => {
+                        visitor.visit_node(& $($mutability)? conditional_expression.node);
+                        visitor.visit_expression(& $($mutability)? conditional_expression.test);
+                        visitor.visit_expression(& $($mutability)? conditional_expression.consequent);
+                        visitor.visit_expression(& $($mutability)? conditional_expression.alternate);
+                    }
+                    Expression::Function(function_expression) => {
+                        visitor.visit_function_expression(function_expression)
+                    }
+                    Expression::Identifier(identifier) => visitor.visit_identifier(identifier),
+                    Expression::Import(import_expression) => walk_import_expression(visitor, import_expression),
+                    Expression::JSXElement(jsx_element) => walk_jsx_element(visitor, jsx_element),
+                    Expression::JSXFragment(jsx_fragment) => walk_jsx_fragment(visitor, jsx_fragment),
+                    Expression::Literal(literal) => visitor.visit_node(& $($mutability)? literal.node),
+                    Expression::RegExpLiteral(regexp_literal) => {
+                        visitor.visit_node(& $($mutability)? regexp_literal.node)
+                    }
+                    Expression::BigIntLiteral(big_int_literal) => {
+                        visitor.visit_node(& $($mutability)? big_int_literal.node)
+                    }
+                    Expression::Logical(logical_expression) => {
+                        visitor.visit_node(& $($mutability)? logical_expression.node);
+                        visitor.visit_expression(& $($mutability)? logical_expression.left);
+                        visitor.visit_expression(& $($mutability)? logical_expression.right);
+                    }
+                    Expression::Member(member_expression) => walk_member_expression(visitor, member_expression),
+                    Expression::MetaProperty(meta_property) => {
+                        visitor.visit_node(& $($mutability)? meta_property.node);
+                        visitor.visit_identifier(& $($mutability)? meta_property.meta);
+                        visitor.visit_identifier(& $($mutability)? meta_property.property);
+                    }
+                    Expression::New(new_expression) => {
+                        visitor.visit_node(& $($mutability)? new_expression.node);
+                        visitor.visit_expression(& $($mutability)? new_expression.callee);
+
+                        for argument in & $($mutability)? new_expression.arguments {
+                            match argument {
+                                NewExpressionArguments::Expression(expression) => {
+                                    visitor.visit_expression(expression)
+                                }
+                                NewExpressionArguments::SpreadElement(spread_element) => {
+                                    walk_spread_element(visitor, spread_element)
+                                }
+                            }
+                        }
+                    }
+                    Expression::Object(object_expression) => {
+                        visitor.visit_node(& $($mutability)? object_expression.node);
+
+                        for property in & $($mutability)? object_expression.properties {
+                            match property {
+                                ObjectExpressionProperty::Property(property) => visitor.visit_property(property),
+                                ObjectExpressionProperty::SpreadElement(spread_element) => {
+                                    walk_spread_element(visitor, spread_element)
+                                }
+                            }
+                        }
+                    }
+                    Expression::Parenthesized(parenthesized_expression) => {
+                        visitor.visit_node(& $($mutability)? parenthesized_expression.node);
+                        visitor.visit_expression(& $($mutability)? parenthesized_expression.expression);
+                    }
+                    Expression::Sequence(sequence_expression) => {
+                        visitor.visit_node(& $($mutability)? sequence_expression.node);
+
+                        for expression in & $($mutability)? sequence_expression.expressions {
+                            visitor.visit_expression(expression);
+                        }
+                    }
+                    Expression::Super(super_expression) => {
+                        visitor.visit_node(& $($mutability)? super_expression.node)
+                    }
+                    Expression::TaggedTemplate(tagged_template_expression) => {
+                        visitor.visit_node(& $($mutability)? tagged_template_expression.node);
+                        visitor.visit_expression(& $($mutability)? tagged_template_expression.tag);
+                        visitor.visit_template_literal(& $($mutability)? tagged_template_expression.quasi);
+                    }
+                    Expression::TemplateLiteral(template_literal) => {
+                        visitor.visit_template_literal(template_literal)
+                    }
+                    Expression::This(this_expression) => visitor.visit_node(& $($mutability)? this_expression.node),
+                    Expression::Unary(unary_expression) => {
+                        visitor.visit_node(& $($mutability)? unary_expression.node);
+                        visitor.visit_expression(& $($mutability)? unary_expression.argument);
+                    }
+                    Expression::Update(update_expression) => {
+                        visitor.visit_node(& $($mutability)? update_expression.node);
+                        visitor.visit_expression(& $($mutability)? update_expression.argument);
+                    }
+                    Expression::Yield(yield_expression) => {
+                        visitor.visit_node(& $($mutability)? yield_expression.node);
+
+                        if let Some(argument) = & $($mutability)? yield_expression.argument {
+                            visitor.visit_expression(argument);
+                        }
+                    }
+                    Expression::TSAs(as_expression) => {
+                        visitor.visit_node(& $($mutability)? as_expression.node);
+                        visitor.visit_expression(& $($mutability)? as_expression.expression);
+                    }
+                    Expression::TSNonNull(non_null_expression) => {
+                        visitor.visit_node(& $($mutability)? non_null_expression.node);
+                        visitor.visit_expression(& $($mutability)? non_null_expression.expression);
+                    }
+                    Expression::TSSatisfies(satisfies_expression) => {
+                        visitor.visit_node(& $($mutability)? satisfies_expression.node);
+                        visitor.visit_expression(& $($mutability)? satisfies_expression.expression);
+                    }
+                    Expression::TSTypeAssertion(type_assertion) => {
+                        visitor.visit_node(& $($mutability)? type_assertion.node);
+                        visitor.visit_expression(& $($mutability)? type_assertion.expression);
+                    }
+                }
+            }
+
+            pub(crate) fn walk_arrow_function_body<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                body: &'a $($mutability)? ArrowFunctionExpressionBody,
+            ) {
+                match body {
+                    ArrowFunctionExpressionBody::BlockStatement(block_statement) => {
+                        visitor.visit_block_statement(block_statement)
+                    }
+                    ArrowFunctionExpressionBody::Expression(expression) => visitor.visit_expression(expression),
+                }
+            }
+
+            pub(crate) fn walk_call_expression<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                call_expression: &'a $($mutability)? CallExpression,
+            ) {
+                visitor.visit_node(& $($mutability)? call_expression.node);
+
+                match & $($mutability)? call_expression.callee {
+                    CallExpressionCallee::Expression(expression) => visitor.visit_expression(expression),
+                    CallExpressionCallee::Import(import_expression) => {
+                        walk_import_expression(visitor, import_expression)
+                    }
+                }
+
+                for argument in & $($mutability)? call_expression.arguments {
+                    match argument {
+                        CallExpressionArgument::Expression(expression) => visitor.visit_expression(expression),
+                        CallExpressionArgument::SpreadElement(spread_element) => {
+                            walk_spread_element(visitor, spread_element)
+                        }
+                    }
+                }
+            }
+
+            fn walk_import_expression<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                import_expression: &'a $($mutability)? ImportExpression,
+            ) {
+                visitor.visit_node(& $($mutability)? import_expression.node);
+                visitor.visit_expression(& $($mutability)? import_expression.source);
+
+                if let Some(options) = & $($mutability)? import_expression.options {
+                    visitor.visit_expression(options);
+                }
+            }
+
+            fn walk_member_expression<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                member_expression: &'a $($mutability)? MemberExpression,
+            ) {
+                visitor.visit_node(& $($mutability)? member_expression.node);
+                visitor.visit_expression(& $($mutability)? member_expression.object);
+
+                match & $($mutability)? member_expression.property {
+                    MemberExpressionProperty::Expression(expression) => visitor.visit_expression(expression),
+                    MemberExpressionProperty::PrivateIdentifier(private_identifier) => {
+                        visitor.visit_private_identifier(private_identifier)
+                    }
+                }
+            }
+
+            pub(crate) fn walk_property<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                property: &'a $($mutability)? Property,
+            ) {
+                visitor.visit_node(& $($mutability)? property.node);
+
+                // The key and value of a shorthand property are the same node, e.g. `a` in `{ a }`.
+                if !property.shorthand {
+                    visitor.visit_expression(& $($mutability)? property.key);
+                }
+
+                match & $($mutability)? property.value {
+                    PropertyValue::Expression(expression) => visitor.visit_expression(expression),
+                    PropertyValue::Pattern(pattern) => visitor.visit_pattern(pattern),
+                }
+            }
+
+            fn walk_spread_element<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                spread_element: &'a $($mutability)? SpreadElement,
+            ) {
+                visitor.visit_node(& $($mutability)? spread_element.node);
+                visitor.visit_expression(& $($mutability)? spread_element.argument);
+            }
+
+            pub(crate) fn walk_template_literal<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                template_literal: &'a $($mutability)? TemplateLiteral,
+            ) {
+                visitor.visit_node(& $($mutability)? template_literal.node);
+
+                // Template elements and expressions alternate, starting and ending with an element.
+                let mut expressions = (& $($mutability)? template_literal.expressions).into_iter();
+
+                for quasi in & $($mutability)? template_literal.quasis {
+                    visitor.visit_node(& $($mutability)? quasi.node);
+
+                    if let Some(expression) = expressions.next() {
+                        visitor.visit_expression(expression);
+                    }
+                }
+            }
+
+            // JSX
+
+            fn walk_jsx_element<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                jsx_element: &'a $($mutability)? JSXElement,
+            ) {
+                visitor.visit_node(& $($mutability)? jsx_element.node);
+
+                let opening_element = & $($mutability)? jsx_element.opening_element;
+
+                visitor.visit_node(& $($mutability)? opening_element.node);
+
+                walk_jsx_element_name(visitor, & $($mutability)? opening_element.name);
+
+                for attribute in & $($mutability)? opening_element.attributes {
+                    match attribute {
+                        JSXAttributeItem::Attribute(jsx_attribute) => {
+                            visitor.visit_node(& $($mutability)? jsx_attribute.node);
+
+                            match & $($mutability)? jsx_attribute.name {
+                                JSXAttributeName::Identifier(jsx_identifier) => {
+                                    visitor.visit_node(& $($mutability)? jsx_identifier.node)
+                                }
+                                JSXAttributeName::NamespacedName(namespaced_name) => {
+                                    walk_jsx_namespaced_name(visitor, namespaced_name)
+                                }
+                            }
+
+                            match & $($mutability)? jsx_attribute.value {
+                                Some(JSXAttributeValue::Literal(literal)) => {
+                                    visitor.visit_node(& $($mutability)? literal.node)
+                                }
+                                Some(JSXAttributeValue::ExpressionContainer(expression_container)) => {
+                                    walk_jsx_expression_container(visitor, expression_container)
+                                }
+                                Some(JSXAttributeValue::Element(jsx_element)) => {
+                                    walk_jsx_element(visitor, jsx_element)
+                                }
+                                Some(JSXAttributeValue::Fragment(jsx_fragment)) => {
+                                    walk_jsx_fragment(visitor, jsx_fragment)
+                                }
+                                None => {}
+                            }
+                        }
+                        JSXAttributeItem::SpreadAttribute(spread_attribute) => {
+                            visitor.visit_node(& $($mutability)? spread_attribute.node);
+                            visitor.visit_expression(& $($mutability)? spread_attribute.argument);
+                        }
+                    }
+                }
+
+                walk_jsx_children(visitor, & $($mutability)? jsx_element.children);
+
+                if let Some(closing_element) = & $($mutability)? jsx_element.closing_element {
+                    visitor.visit_node(& $($mutability)? closing_element.node);
+
+                    walk_jsx_element_name(visitor, & $($mutability)? closing_element.name);
+                }
+            }
+
+            fn walk_jsx_fragment<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                jsx_fragment: &'a $($mutability)? JSXFragment,
+            ) {
+                visitor.visit_node(& $($mutability)? jsx_fragment.node);
+                visitor.visit_node(& $($mutability)? jsx_fragment.opening_fragment.node);
+
+                walk_jsx_children(visitor, & $($mutability)? jsx_fragment.children);
+
+                visitor.visit_node(& $($mutability)? jsx_fragment.closing_fragment.node);
+            }
+
+            fn walk_jsx_children<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                children: &'a $($mutability)? [JSXChild],
+            ) {
+                for child in children {
+                    match child {
+                        JSXChild::Text(jsx_text) => visitor.visit_node(& $($mutability)? jsx_text.node),
+                        JSXChild::Element(jsx_element) => walk_jsx_element(visitor, jsx_element),
+                        JSXChild::Fragment(jsx_fragment) => walk_jsx_fragment(visitor, jsx_fragment),
+                        JSXChild::ExpressionContainer(expression_container) => {
+                            walk_jsx_expression_container(visitor, expression_container)
+                        }
+                        JSXChild::SpreadChild(spread_child) => {
+                            visitor.visit_node(& $($mutability)? spread_child.node);
+                            visitor.visit_expression(& $($mutability)? spread_child.expression);
+                        }
+                    }
+                }
+            }
+
+            fn walk_jsx_expression_container<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                expression_container: &'a $($mutability)? JSXExpressionContainer,
+            ) {
+                visitor.visit_node(& $($mutability)? expression_container.node);
+
+                match & $($mutability)? expression_container.expression {
+                    JSXExpression::Expression(expression) => visitor.visit_expression(expression),
+                    JSXExpression::Empty(empty_expression) => {
+                        visitor.visit_node(& $($mutability)? empty_expression.node)
+                    }
+                }
+            }
+
+            fn walk_jsx_element_name<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                name: &'a $($mutability)? JSXElementName,
+            ) {
+                match name {
+                    JSXElementName::Identifier(jsx_identifier) => {
+                        visitor.visit_node(& $($mutability)? jsx_identifier.node)
+                    }
+                    JSXElementName::NamespacedName(namespaced_name) => {
+                        walk_jsx_namespaced_name(visitor, namespaced_name)
+                    }
+                    JSXElementName::MemberExpression(member_expression) => {
+                        walk_jsx_member_expression(visitor, member_expression)
+                    }
+                }
+            }
+
+            fn walk_jsx_member_expression<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                member_expression: &'a $($mutability)? JSXMemberExpression,
+            ) {
+                visitor.visit_node(& $($mutability)? member_expression.node);
+
+                match & $($mutability)? member_expression.object {
+                    JSXMemberExpressionObject::Identifier(jsx_identifier) => {
+                        visitor.visit_node(& $($mutability)? jsx_identifier.node)
+                    }
+                    JSXMemberExpressionObject::MemberExpression(member_expression) => {
+                        walk_jsx_member_expression(visitor, member_expression)
+                    }
+                }
+
+                visitor.visit_node(& $($mutability)? member_expression.property.node);
+            }
+
+            fn walk_jsx_namespaced_name<'a, V: $visitor<'a> + ?Sized>(
+                visitor: &mut V,
+                namespaced_name: &'a $($mutability)? JSXNamespacedName,
+            ) {
+                visitor.visit_node(& $($mutability)? namespaced_name.node);
+                visitor.visit_node(& $($mutability)? namespaced_name.namespace.node);
+                visitor.visit_node(& $($mutability)? namespaced_name.name.node);
+            }
+
+            fn module_export_name_node(module_export_name: &ModuleExportName) -> &Node {
+                match module_export_name {
+                    ModuleExportName::Identifier(identifier) => &identifier.node,
+                    ModuleExportName::Literal(literal) => &literal.node,
+                }
+            }
+
+            fn same_span(a: &Node, b: &Node) -> bool {
+                a.start == b.start && a.end == b.end
+            }
+        }
+    };
+}
+
+define_visitor!(Visit, walk,);
+define_visitor!(VisitMut, walk_mut, mut);
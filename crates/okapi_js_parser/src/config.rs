@@ -1,6 +1,12 @@
-#[allow(dead_code)]
-#[derive(Debug)]
-enum ECMAVersion {
+use crate::{ast::ProgramSource, ParserError, ParserErrorKind};
+
+// The ECMAScript edition to parse, the same as Acorn's `ecmaVersion` option.
+// Editions can be converted from either their number or their year, e.g. `6` or `2015`, apart from ES3 and ES5, which
+// were only numbered.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ECMAVersion {
+    Three,
+    Five,
     SixOrTwentyFifteen,
     TwentySixteen,
     TwentySeventeen,
@@ -10,10 +16,243 @@ enum ECMAVersion {
     TwentyTwentyOne,
     TwentyTwentyTwo,
     TwentyTwentyThree,
+    TwentyTwentyFour,
+    TwentyTwentyFive,
+    TwentyTwentySix,
+    // The newest edition supported by the parser.
+    #[default]
+    Latest,
+}
+
+impl TryFrom<u16> for ECMAVersion {
+    type Error = ParserError;
+
+    fn try_from(version: u16) -> Result<Self, Self::Error> {
+        match version {
+            3 => Ok(ECMAVersion::Three),
+            5 => Ok(ECMAVersion::Five),
+            6 | 2015 => Ok(ECMAVersion::SixOrTwentyFifteen),
+            7 | 2016 => Ok(ECMAVersion::TwentySixteen),
+            8 | 2017 => Ok(ECMAVersion::TwentySeventeen),
+            9 | 2018 => Ok(ECMAVersion::TwentyEighteen),
+            10 | 2019 => Ok(ECMAVersion::TwentyNineteen),
+            11 | 2020 => Ok(ECMAVersion::TwentyTwenty),
+            12 | 2021 => Ok(ECMAVersion::TwentyTwentyOne),
+            13 | 2022 => Ok(ECMAVersion::TwentyTwentyTwo),
+            14 | 2023 => Ok(ECMAVersion::TwentyTwentyThree),
+            15 | 2024 => Ok(ECMAVersion::TwentyTwentyFour),
+            16 | 2025 => Ok(ECMAVersion::TwentyTwentyFive),
+            17 | 2026 => Ok(ECMAVersion::TwentyTwentySix),
+            // Options are not part of the source, so the error has an empty span at the start of it.
+            _ => Err(ParserError::new(
+                ParserErrorKind::UnsupportedECMAVersion(version),
+                0,
+                0,
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for ECMAVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            ECMAVersion::Three => write!(f, "ES3"),
+            ECMAVersion::Five => write!(f, "ES5"),
+            ECMAVersion::SixOrTwentyFifteen => write!(f, "ES2015"),
+            ECMAVersion::TwentySixteen => write!(f, "ES2016"),
+            ECMAVersion::TwentySeventeen => write!(f, "ES2017"),
+            ECMAVersion::TwentyEighteen => write!(f, "ES2018"),
+            ECMAVersion::TwentyNineteen => write!(f, "ES2019"),
+            ECMAVersion::TwentyTwenty => write!(f, "ES2020"),
+            ECMAVersion::TwentyTwentyOne => write!(f, "ES2021"),
+            ECMAVersion::TwentyTwentyTwo => write!(f, "ES2022"),
+            ECMAVersion::TwentyTwentyThree => write!(f, "ES2023"),
+            ECMAVersion::TwentyTwentyFour => write!(f, "ES2024"),
+            ECMAVersion::TwentyTwentyFive => write!(f, "ES2025"),
+            ECMAVersion::TwentyTwentySix => write!(f, "ES2026"),
+            ECMAVersion::Latest => write!(f, "latest"),
+        }
+    }
+}
+
+// Options passed to `Parser::new`, which mirror Acorn's options and their defaults.
+// https://github.com/acornjs/acorn/tree/master/acorn#interface
+#[derive(Clone, Debug, Default)]
+pub struct ParserOptions {
+    pub(crate) ecma_version: ECMAVersion,
+    pub(crate) source_type: ProgramSource,
+    pub(crate) allow_return_outside_function: bool,
+    pub(crate) allow_import_export_everywhere: bool,
+    pub(crate) allow_await_outside_function: bool,
+    // Defaults to whether the ECMAScript version is 2023 or later, when hashbang comments were standardised.
+    pub(crate) allow_hash_bang: Option<bool>,
+    pub(crate) locations: bool,
+    pub(crate) ranges: bool,
+    pub(crate) source_file: Option<String>,
+    pub(crate) preserve_parens: bool,
+    pub(crate) comments: bool,
+    pub(crate) attach_comments: bool,
+    pub(crate) tokens: bool,
+    pub(crate) code_frame: bool,
+    pub(crate) offset_encoding: OffsetEncoding,
+    pub(crate) extensions: Extensions,
+}
+
+impl ParserOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ecma_version(mut self, ecma_version: ECMAVersion) -> Self {
+        self.ecma_version = ecma_version;
+
+        self
+    }
+
+    // Whether `Parser::parse` parses a script or a module.
+    pub fn source_type(mut self, source_type: ProgramSource) -> Self {
+        self.source_type = source_type;
+
+        self
+    }
+
+    // Allow `return` statements at the top level.
+    pub fn allow_return_outside_function(mut self, value: bool) -> Self {
+        self.allow_return_outside_function = value;
+
+        self
+    }
+
+    // Allow import and export declarations anywhere a statement is allowed in a module, not only at the top level.
+    pub fn allow_import_export_everywhere(mut self, value: bool) -> Self {
+        self.allow_import_export_everywhere = value;
+
+        self
+    }
+
+    // Allow `await` expressions at the top level of scripts, as they already are in modules.
+    pub fn allow_await_outside_function(mut self, value: bool) -> Self {
+        self.allow_await_outside_function = value;
+
+        self
+    }
+
+    // Allow a `#!` comment at the start of the source.
+    pub fn allow_hash_bang(mut self, value: bool) -> Self {
+        self.allow_hash_bang = Some(value);
+
+        self
+    }
+
+    // Add ESTree `loc` line and column positions to every node.
+    pub fn locations(mut self, value: bool) -> Self {
+        self.locations = value;
+
+        self
+    }
+
+    // Add a `range` array of the start and end offsets to every node.
+    pub fn ranges(mut self, value: bool) -> Self {
+        self.ranges = value;
+
+        self
+    }
+
+    // The source file name which is added to each `loc`, when locations are enabled.
+    pub fn source_file(mut self, source_file: &str) -> Self {
+        self.source_file = Some(source_file.to_string());
+
+        self
+    }
+
+    // Add a `ParenthesizedExpression` node for each parenthesized expression, rather than dropping the parentheses.
+    pub fn preserve_parens(mut self, value: bool) -> Self {
+        self.preserve_parens = value;
+
+        self
+    }
+
+    // Add every comment to a `comments` array on the program, the same as Espree's `comment` option.
+    pub fn comments(mut self, value: bool) -> Self {
+        self.comments = value;
+
+        self
+    }
+
+    // Add `leadingComments` and `trailingComments` to the nodes before and after each comment.
+    pub fn attach_comments(mut self, value: bool) -> Self {
+        self.attach_comments = value;
+
+        self
+    }
+
+    // Add every token to a `tokens` array on the program, in the same format as Espree's `tokens` option.
+    pub fn tokens(mut self, value: bool) -> Self {
+        self.tokens = value;
+
+        self
+    }
+
+    // Render the source lines around each error with a caret under it, in the `codeFrame` of each `ParserErrorReport`.
+    pub fn code_frame(mut self, value: bool) -> Self {
+        self.code_frame = value;
+
+        self
+    }
+
+    // Set the unit used for node and error offsets and columns, which are UTF-8 byte offsets by default.
+    pub fn offset_encoding(mut self, offset_encoding: OffsetEncoding) -> Self {
+        self.offset_encoding = offset_encoding;
+
+        self
+    }
+
+    // Parse stage 3 decorators on classes and class elements, and `accessor` class fields.
+    pub fn decorators(mut self, value: bool) -> Self {
+        self.extensions.decorators = value;
+
+        self
+    }
+
+    // Parse JSX elements and fragments, where a `<` at the start of an expression opens an element.
+    pub fn jsx(mut self, value: bool) -> Self {
+        self.extensions.jsx = value;
+
+        self
+    }
+
+    // Parse TypeScript syntax, where type annotations and declarations are added to the tree as `TS*` nodes.
+    pub fn typescript(mut self, value: bool) -> Self {
+        self.extensions.typescript = value;
+
+        self
+    }
+}
+
+// The unit used for node, token and error offsets, including the offsets of regular expression pattern nodes, which
+// are relative to the start of the pattern. The `Lexer` always uses UTF-8 byte offsets, and a `Tokenizer` only
+// converts them when it is given an encoding.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum OffsetEncoding {
+    // Byte offsets into the UTF-8 source, which can be used to slice the source `&str` directly.
+    #[default]
+    Utf8,
+    // UTF-16 code unit offsets, which are the same as JavaScript string indices.
+    Utf16,
+    // Unicode code point offsets.
+    CodePoint,
 }
 
-#[allow(dead_code)]
-#[derive(Debug)]
-pub struct Config {
-    ecma_version: ECMAVersion,
+// Syntax which is not part of the ECMAScript specification, and which has to be opted in to.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Extensions {
+    // Stage 3 decorators and `accessor` class fields.
+    // https://github.com/tc39/proposal-decorators
+    pub(crate) decorators: bool,
+    // JSX elements and fragments in expression position.
+    // https://facebook.github.io/jsx/
+    pub(crate) jsx: bool,
+    // TypeScript type annotations and declarations, which are parsed into typescript-estree `TS*` nodes.
+    // https://typescript-eslint.io/packages/typescript-estree/
+    pub(crate) typescript: bool,
 }
@@ -1,7 +1,43 @@
-use crate::{TokenKind, TokenValue};
+use serde::Serialize;
 
-#[derive(Debug, PartialEq)]
-pub enum ParserError {
+use crate::{
+    ast::{Node, Position, Span},
+    Feature, TokenKind,
+};
+
+// An error and the span of the source it was found in, which is in the offset encoding of the parser, the same as
+// nodes. Errors returned by the `Lexer` are in UTF-8 byte offsets instead, and errors returned by a `Tokenizer` are in
+// the same offset encoding as its tokens.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParserError {
+    pub kind: ParserErrorKind,
+    pub span: Span,
+}
+
+impl ParserError {
+    pub fn new(kind: ParserErrorKind, start: usize, end: usize) -> Self {
+        Self {
+            kind,
+            span: Span { start, end },
+        }
+    }
+
+    pub(crate) fn from_node(kind: ParserErrorKind, node: &Node) -> Self {
+        Self {
+            kind,
+            span: Span::from(node),
+        }
+    }
+}
+
+impl std::fmt::Display for ParserError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.kind.fmt(f)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParserErrorKind {
     SyntaxError,
 
     // Identifiers
@@ -15,6 +51,7 @@ pub enum ParserError {
     InvalidUnicodeCodePointEscapeSequence,
     InvalidLegacyOctalEscapeSequence,
     InvalidLegacyOctalEscapeSequenceNotAllowedInStrictMode,
+    UnterminatedStringLiteral,
 
     // Number literals
     InvalidDecimalLiteral,
@@ -37,6 +74,31 @@ pub enum ParserError {
     InvalidRegexBackslashSequence,
     InvalidRegexExpressionClass,
 
+    // Regex patterns, with the offset of the error in the pattern
+    InvalidRegexNothingToRepeat(usize),
+    InvalidRegexLoneBracket(usize),
+    InvalidRegexUnmatchedParenthesis(usize),
+    UnterminatedRegexGroup(usize),
+    InvalidRegexGroup(usize),
+    InvalidRegexGroupName(usize),
+    UnterminatedRegexCharacterClass(usize),
+    InvalidRegexEscape(usize),
+    InvalidRegexUnicodeProperty(usize),
+    DuplicateRegexGroupName(usize),
+    InvalidRegexBackreference(usize),
+    InvalidRegexIndexBackreference(usize),
+    InvalidRegexQuantifierRange(usize),
+    InvalidRegexCharacterClassRange(usize),
+    InvalidRegexClassSetOperation(usize),
+    InvalidRegexClassSetCharacter(usize),
+    InvalidRegexNegatedClassOfStrings(usize),
+    InvalidRegexModifiers(usize),
+
+    // Regex flags, with the offset of the error in the flags
+    InvalidRegexFlag(usize),
+    DuplicateRegexFlag(usize),
+    InvalidRegexFlagCombination(usize),
+
     // Object expressions
     InvalidPropertyKey,
     InvalidPropertyValue,
@@ -50,6 +112,34 @@ pub enum ParserError {
     // For statement
     InvalidAwaitForInStatement,
 
+    // Return statement
+    InvalidReturnStatement,
+
+    // Hashbang comment
+    UnexpectedHashbang,
+
+    // Import attributes
+    DuplicateImportAttributeKey,
+
+    // Using declarations
+    InvalidUsingDeclarationBinding,
+    InvalidUsingDeclarationInitializer,
+    InvalidUsingDeclarationPosition,
+
+    // Decorators
+    InvalidDecoratorPosition,
+
+    // JSX
+    UnterminatedJSXStringLiteral,
+    InvalidJSXTextCharacter,
+    UnterminatedJSXContents,
+    MismatchedJSXClosingTag,
+
+    // TypeScript
+    InvalidTypeScriptModifier,
+    InvalidParameterPropertyPosition,
+    InvalidParameterPropertyBinding,
+
     // Conversion error
     InvalidExpressionToAssignmentExpressionConversion,
     InvalidExpressionToChainElementConversion,
@@ -57,106 +147,473 @@ pub enum ParserError {
     InvalidExpressionToPatternConversion,
     InvalidExpressionToArrayPatternElementConversion,
     InvalidExpressionToLiteralConversion,
+    InvalidFunctionParameterToPatternConversion,
 
-    UnexpectedToken(TokenKind, usize, usize),
-    UnexpectedTokenValue(TokenKind, TokenValue, usize, usize),
+    UnexpectedToken(TokenKind),
+    // The source text of the unexpected token.
+    UnexpectedTokenValue(TokenKind, String),
     UnexpectedLineTerminator,
+
+    // JSON output
+    SerializationError,
+
+    // Parser options
+    UnsupportedECMAVersion(u16),
+    UnsupportedFeature(Feature),
 }
 
-impl std::fmt::Display for ParserError {
+// Errors are displayed as a human-readable message in the style of V8 and Acorn, without a position, as the line and
+// column of the span are only known to the parser, see `ParserErrorReport`.
+impl std::fmt::Display for ParserErrorKind {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
-            ParserError::UnexpectedToken(token_kind, line, column) => {
+            ParserErrorKind::SyntaxError => write!(f, "Syntax error"),
+            ParserErrorKind::InvalidIdentifierCharacter => {
+                write!(f, "Invalid character in identifier")
+            }
+            ParserErrorKind::InvalidEscapeSequence
+            | ParserErrorKind::InvalidEscapeSequenceCannotBeFormatted => {
+                write!(f, "Invalid escape sequence")
+            }
+            ParserErrorKind::InvalidHexadecimalEscapeSequence => {
+                write!(f, "Invalid hexadecimal escape sequence")
+            }
+            ParserErrorKind::InvalidUnicodeEscapeSequence => {
+                write!(f, "Invalid Unicode escape sequence")
+            }
+            ParserErrorKind::InvalidUnicodeCodePointEscapeSequence => {
+                write!(f, "Undefined Unicode code point")
+            }
+            ParserErrorKind::InvalidLegacyOctalEscapeSequence => {
+                write!(f, "Invalid octal escape sequence")
+            }
+            ParserErrorKind::InvalidLegacyOctalEscapeSequenceNotAllowedInStrictMode => {
+                write!(f, "Octal escape sequences are not allowed in strict mode")
+            }
+            ParserErrorKind::UnterminatedStringLiteral => write!(f, "Unterminated string constant"),
+            ParserErrorKind::InvalidDecimalLiteral => write!(f, "Invalid number"),
+            ParserErrorKind::InvalidNonDecimalBinaryNumberLiteral => {
+                write!(f, "Invalid binary number")
+            }
+            ParserErrorKind::InvalidNonDecimalOctalNumberLiteral => {
+                write!(f, "Invalid octal number")
+            }
+            ParserErrorKind::InvalidNonDecimalHexadecimalNumberLiteral => {
+                write!(f, "Invalid hexadecimal number")
+            }
+            ParserErrorKind::InvalidLegacyOctalNumberLiteral => {
+                write!(f, "Invalid legacy octal number")
+            }
+            ParserErrorKind::InvalidLegacyOctalNumberLiteralNotAllowedInStrictMode => {
+                write!(f, "Octal literals are not allowed in strict mode")
+            }
+            ParserErrorKind::InvalidExponentPartNumberLiteral => {
+                write!(f, "Invalid exponent in number")
+            }
+            ParserErrorKind::InvalidNumericSeparatorAtSibling => {
+                write!(f, "Only one underscore is allowed as numeric separator")
+            }
+            ParserErrorKind::InvalidNumericSeparatorAtEnd => {
                 write!(
                     f,
-                    "UnexpectedToken: `{:?}` at {:?}:{:?}",
-                    token_kind, line, column
+                    "Numeric separators are not allowed at the end of numeric literals"
                 )
             }
-            ParserError::UnexpectedTokenValue(token_kind, token_value, line, column) => {
+            ParserErrorKind::InvalidDecimalBigIntegerLiteral => write!(f, "Invalid BigInt literal"),
+            ParserErrorKind::UnterminatedRegExLiteral => {
+                write!(f, "Unterminated regular expression")
+            }
+            ParserErrorKind::InvalidRegexLiteralFirstChar => {
+                write!(f, "Invalid regular expression: invalid first character")
+            }
+            ParserErrorKind::InvalidRegexBackslashSequence => {
+                write!(f, "Invalid regular expression: \\ at end of pattern")
+            }
+            ParserErrorKind::InvalidRegexExpressionClass => {
                 write!(
                     f,
-                    "UnexpectedTokenValue: `{:?}` of kind `{:?}` at {:?}:{:?}",
-                    token_value, token_kind, line, column
+                    "Invalid regular expression: unterminated character class"
                 )
             }
-            ParserError::SyntaxError => write!(f, "SyntaxError"),
-            ParserError::InvalidIdentifierCharacter => write!(f, "InvalidIdentifierCharacter"),
-            ParserError::InvalidEscapeSequence => write!(f, "InvalidEscapeSequence"),
-            ParserError::InvalidEscapeSequenceCannotBeFormatted => {
-                write!(f, "InvalidEscapeSequenceCannotBeFormatted")
+            ParserErrorKind::InvalidRegexNothingToRepeat(offset) => {
+                write_regex_error(f, "nothing to repeat", offset)
+            }
+            ParserErrorKind::InvalidRegexLoneBracket(offset) => {
+                write_regex_error(f, "lone quantifier brackets", offset)
+            }
+            ParserErrorKind::InvalidRegexUnmatchedParenthesis(offset) => {
+                write_regex_error(f, "unmatched ')'", offset)
             }
-            ParserError::InvalidHexadecimalEscapeSequence => {
-                write!(f, "InvalidHexadecimalEscapeSequence")
+            ParserErrorKind::UnterminatedRegexGroup(offset) => {
+                write_regex_error(f, "unterminated group", offset)
             }
-            ParserError::InvalidUnicodeEscapeSequence => write!(f, "InvalidUnicodeEscapeSequence"),
-            ParserError::InvalidUnicodeCodePointEscapeSequence => {
-                write!(f, "InvalidUnicodeCodePointEscapeSequence")
+            ParserErrorKind::InvalidRegexGroup(offset) => {
+                write_regex_error(f, "invalid group", offset)
             }
-            ParserError::InvalidLegacyOctalEscapeSequence => {
-                write!(f, "InvalidLegacyOctalEscapeSequence")
+            ParserErrorKind::InvalidRegexGroupName(offset) => {
+                write_regex_error(f, "invalid capture group name", offset)
             }
-            ParserError::InvalidLegacyOctalEscapeSequenceNotAllowedInStrictMode => {
-                write!(f, "InvalidLegacyOctalEscapeSequenceNotAllowedInStrictMode")
+            ParserErrorKind::UnterminatedRegexCharacterClass(offset) => {
+                write_regex_error(f, "unterminated character class", offset)
             }
-            ParserError::InvalidDecimalLiteral => write!(f, "InvalidDecimalLiteral"),
-            ParserError::InvalidNonDecimalBinaryNumberLiteral => {
-                write!(f, "InvalidNonDecimalBinaryNumberLiteral")
+            ParserErrorKind::InvalidRegexEscape(offset) => {
+                write_regex_error(f, "invalid escape", offset)
             }
-            ParserError::InvalidNonDecimalOctalNumberLiteral => {
-                write!(f, "InvalidNonDecimalOctalNumberLiteral")
+            ParserErrorKind::InvalidRegexUnicodeProperty(offset) => {
+                write_regex_error(f, "invalid property name", offset)
             }
-            ParserError::InvalidNonDecimalHexadecimalNumberLiteral => {
-                write!(f, "InvalidNonDecimalHexadecimalNumberLiteral")
+            ParserErrorKind::DuplicateRegexGroupName(offset) => {
+                write_regex_error(f, "duplicate capture group name", offset)
             }
-            ParserError::InvalidLegacyOctalNumberLiteral => {
-                write!(f, "InvalidLegacyOctalNumberLiteral")
+            ParserErrorKind::InvalidRegexBackreference(offset) => {
+                write_regex_error(f, "invalid named reference", offset)
             }
-            ParserError::InvalidLegacyOctalNumberLiteralNotAllowedInStrictMode => {
-                write!(f, "InvalidLegacyOctalNumberLiteralNotAllowedInStrictMode")
+            ParserErrorKind::InvalidRegexIndexBackreference(offset) => {
+                write_regex_error(f, "reference to a nonexistent group", offset)
             }
-            ParserError::InvalidExponentPartNumberLiteral => {
-                write!(f, "InvalidExponentPartNumberLiteral")
+            ParserErrorKind::InvalidRegexQuantifierRange(offset) => {
+                write_regex_error(f, "numbers out of order in {} quantifier", offset)
             }
-            ParserError::InvalidNumericSeparatorAtSibling => {
-                write!(f, "InvalidNumericSeparatorAtSibling")
+            ParserErrorKind::InvalidRegexCharacterClassRange(offset) => {
+                write_regex_error(f, "range out of order in character class", offset)
             }
-            ParserError::InvalidNumericSeparatorAtEnd => write!(f, "InvalidNumericSeparatorAtEnd"),
-            ParserError::InvalidDecimalBigIntegerLiteral => {
-                write!(f, "InvalidDecimalBigIntegerLiteral")
+            ParserErrorKind::InvalidRegexClassSetOperation(offset) => {
+                write_regex_error(f, "invalid set operation in character class", offset)
             }
-            ParserError::UnterminatedRegExLiteral => write!(f, "UnterminatedRegExLiteral"),
-            ParserError::InvalidRegexLiteralFirstChar => write!(f, "InvalidRegexLiteralFirstChar"),
-            ParserError::InvalidRegexBackslashSequence => {
-                write!(f, "InvalidRegexBackslashSequence")
+            ParserErrorKind::InvalidRegexClassSetCharacter(offset) => {
+                write_regex_error(f, "invalid character in character class", offset)
             }
-            ParserError::InvalidRegexExpressionClass => write!(f, "InvalidRegexExpressionClass"),
-            ParserError::InvalidPropertyKey => write!(f, "InvalidPropertyKey"),
-            ParserError::InvalidPropertyValue => write!(f, "InvalidPropertyValue"),
-            ParserError::InvalidYieldExpression => write!(f, "InvalidYieldExpression"),
-            ParserError::InvalidLeftHandSideExpression => {
-                write!(f, "InvalidLeftHandSideExpression")
+            ParserErrorKind::InvalidRegexNegatedClassOfStrings(offset) => {
+                write_regex_error(f, "negated character class may contain strings", offset)
             }
-            ParserError::InvalidAwaitForInStatement => write!(f, "InvalidAwaitForInStatement"),
-            ParserError::InvalidExpressionToAssignmentExpressionConversion => {
-                write!(f, "InvalidExpressionToAssignmentExpressionConversion")
+            ParserErrorKind::InvalidRegexModifiers(offset) => {
+                write_regex_error(f, "invalid group modifiers", offset)
             }
-            ParserError::InvalidExpressionToChainElementConversion => {
-                write!(f, "InvalidExpressionToChainElementConversion")
+            ParserErrorKind::InvalidRegexFlag(offset) => {
+                write!(
+                    f,
+                    "Invalid regular expression flag at flag offset {}",
+                    offset
+                )
             }
-            ParserError::InvalidMethodDefinitionToPropertyConversion => {
-                write!(f, "InvalidMethodDefinitionToPropertyConversion")
+            ParserErrorKind::DuplicateRegexFlag(offset) => {
+                write!(
+                    f,
+                    "Duplicate regular expression flag at flag offset {}",
+                    offset
+                )
             }
-            ParserError::InvalidExpressionToPatternConversion => {
-                write!(f, "InvalidExpressionToPatternConversion")
+            ParserErrorKind::InvalidRegexFlagCombination(offset) => {
+                write!(
+                    f,
+                    "Incompatible regular expression flags at flag offset {}",
+                    offset
+                )
+            }
+            ParserErrorKind::InvalidPropertyKey => write!(f, "Invalid property key"),
+            ParserErrorKind::InvalidPropertyValue => write!(f, "Invalid property value"),
+            ParserErrorKind::InvalidYieldExpression => {
+                write!(f, "Yield expression not allowed in formal parameter")
+            }
+            ParserErrorKind::InvalidLeftHandSideExpression => {
+                write!(f, "Invalid left-hand side in assignment")
+            }
+            ParserErrorKind::InvalidAwaitForInStatement => {
+                write!(f, "for await loops can only be used with 'of'")
             }
-            ParserError::InvalidExpressionToArrayPatternElementConversion => {
-                write!(f, "InvalidExpressionToArrayPatternElementConversion")
+            ParserErrorKind::InvalidReturnStatement => write!(f, "Illegal return statement"),
+            ParserErrorKind::UnexpectedHashbang => {
+                write!(
+                    f,
+                    "Hashbang comments are only allowed at the start of the source"
+                )
             }
-            ParserError::InvalidExpressionToLiteralConversion => {
-                write!(f, "InvalidExpressionToLiteralConversion")
+            ParserErrorKind::DuplicateImportAttributeKey => {
+                write!(f, "Duplicate import attribute key")
             }
-            ParserError::UnexpectedLineTerminator => write!(f, "UnexpectedLineTerminator"),
+            ParserErrorKind::InvalidUsingDeclarationBinding => {
+                write!(f, "Using declarations can only bind identifiers")
+            }
+            ParserErrorKind::InvalidUsingDeclarationInitializer => {
+                write!(f, "Missing initializer in using declaration")
+            }
+            ParserErrorKind::InvalidUsingDeclarationPosition => {
+                write!(f, "Using declarations are not allowed here")
+            }
+            ParserErrorKind::InvalidDecoratorPosition => write!(f, "Decorators are not valid here"),
+            ParserErrorKind::UnterminatedJSXStringLiteral => write!(f, "Unterminated JSX string"),
+            ParserErrorKind::InvalidJSXTextCharacter => write!(f, "Invalid character in JSX text"),
+            ParserErrorKind::UnterminatedJSXContents => write!(f, "Unterminated JSX contents"),
+            ParserErrorKind::MismatchedJSXClosingTag => {
+                write!(f, "Expected corresponding JSX closing tag")
+            }
+            ParserErrorKind::InvalidTypeScriptModifier => write!(f, "Invalid TypeScript modifier"),
+            ParserErrorKind::InvalidParameterPropertyPosition => {
+                write!(
+                    f,
+                    "A parameter property is only allowed in a constructor implementation"
+                )
+            }
+            ParserErrorKind::InvalidParameterPropertyBinding => {
+                write!(
+                    f,
+                    "A parameter property may not be declared using a binding pattern"
+                )
+            }
+            ParserErrorKind::InvalidExpressionToAssignmentExpressionConversion => {
+                write!(f, "Invalid assignment target")
+            }
+            ParserErrorKind::InvalidExpressionToChainElementConversion => {
+                write!(f, "Invalid optional chain")
+            }
+            ParserErrorKind::InvalidMethodDefinitionToPropertyConversion => {
+                write!(f, "Invalid shorthand method in pattern")
+            }
+            ParserErrorKind::InvalidExpressionToPatternConversion
+            | ParserErrorKind::InvalidExpressionToArrayPatternElementConversion => {
+                write!(f, "Invalid destructuring assignment target")
+            }
+            ParserErrorKind::InvalidExpressionToLiteralConversion => write!(f, "Invalid literal"),
+            ParserErrorKind::InvalidFunctionParameterToPatternConversion => {
+                write!(f, "Invalid function parameter")
+            }
+            ParserErrorKind::UnexpectedToken(token_kind, ..) => {
+                write_unexpected_token(f, token_kind)
+            }
+            ParserErrorKind::UnexpectedTokenValue(TokenKind::Identifier, value) => {
+                write!(f, "Unexpected identifier '{}'", value)
+            }
+            ParserErrorKind::UnexpectedTokenValue(token_kind, ..) => {
+                write_unexpected_token(f, token_kind)
+            }
+            ParserErrorKind::UnexpectedLineTerminator => write!(f, "Unexpected line terminator"),
+            ParserErrorKind::SerializationError => write!(f, "The program could not be serialized"),
+            ParserErrorKind::UnsupportedECMAVersion(version) => {
+                write!(f, "Unsupported ECMAScript version {}", version)
+            }
+            ParserErrorKind::UnsupportedFeature(feature) => {
+                write!(f, "`{:?}` requires {}", feature, feature.ecma_version())
+            }
+        }
+    }
+}
+
+fn write_regex_error(
+    f: &mut std::fmt::Formatter,
+    message: &str,
+    offset: &usize,
+) -> std::fmt::Result {
+    write!(
+        f,
+        "Invalid regular expression: {} at pattern offset {}",
+        message, offset
+    )
+}
+
+// The same messages as V8 for each kind of token.
+fn write_unexpected_token(f: &mut std::fmt::Formatter, token_kind: &TokenKind) -> std::fmt::Result {
+    match token_kind {
+        TokenKind::EOF => write!(f, "Unexpected end of input"),
+        TokenKind::Identifier | TokenKind::PrivateIdentifier | TokenKind::JSXIdentifier => {
+            write!(f, "Unexpected identifier")
+        }
+        TokenKind::StringLiteral => write!(f, "Unexpected string"),
+        TokenKind::NumberLiteral | TokenKind::BigIntLiteral => write!(f, "Unexpected number"),
+        TokenKind::RegularExpressionLiteral => write!(f, "Unexpected regular expression"),
+        TokenKind::TemplateNoSubstitution
+        | TokenKind::TemplateHead
+        | TokenKind::TemplateMiddle
+        | TokenKind::TemplateTail => write!(f, "Unexpected template string"),
+        TokenKind::JSXText => write!(f, "Unexpected JSX text"),
+        TokenKind::Keyword(keyword) => write!(f, "Unexpected token '{}'", keyword),
+        token_kind => match token_kind.punctuator() {
+            Some(punctuator) => write!(f, "Unexpected token '{}'", punctuator),
+            None => write!(f, "Invalid or unexpected token"),
+        },
+    }
+}
+
+impl ParserErrorKind {
+    // The name of the variant, without any of its data.
+    pub fn name(&self) -> String {
+        let debug = format!("{:?}", self);
+
+        match debug.split_once('(') {
+            Some((kind, _)) => kind.to_string(),
+            None => debug,
+        }
+    }
+
+    // A stable code for each error, which is never changed or reused once it has been released, unlike the message.
+    // Codes are grouped by where the error is found: `E0` for unexpected tokens, `E1` for lexical errors, `E2` for
+    // regular expressions, `E3` for syntactic errors, `E4` for extensions, `E5` for invalid conversions between nodes,
+    // and `E9` for parser options and output.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ParserErrorKind::SyntaxError => "E0000",
+            ParserErrorKind::UnexpectedToken(..) => "E0001",
+            ParserErrorKind::UnexpectedTokenValue(..) => "E0002",
+            ParserErrorKind::UnexpectedLineTerminator => "E0003",
+            ParserErrorKind::InvalidIdentifierCharacter => "E1001",
+            ParserErrorKind::InvalidEscapeSequence => "E1010",
+            ParserErrorKind::InvalidEscapeSequenceCannotBeFormatted => "E1011",
+            ParserErrorKind::InvalidHexadecimalEscapeSequence => "E1012",
+            ParserErrorKind::InvalidUnicodeEscapeSequence => "E1013",
+            ParserErrorKind::InvalidUnicodeCodePointEscapeSequence => "E1014",
+            ParserErrorKind::InvalidLegacyOctalEscapeSequence => "E1015",
+            ParserErrorKind::InvalidLegacyOctalEscapeSequenceNotAllowedInStrictMode => "E1016",
+            ParserErrorKind::UnterminatedStringLiteral => "E1017",
+            ParserErrorKind::InvalidDecimalLiteral => "E1020",
+            ParserErrorKind::InvalidNonDecimalBinaryNumberLiteral => "E1021",
+            ParserErrorKind::InvalidNonDecimalOctalNumberLiteral => "E1022",
+            ParserErrorKind::InvalidNonDecimalHexadecimalNumberLiteral => "E1023",
+            ParserErrorKind::InvalidLegacyOctalNumberLiteral => "E1024",
+            ParserErrorKind::InvalidLegacyOctalNumberLiteralNotAllowedInStrictMode => "E1025",
+            ParserErrorKind::InvalidExponentPartNumberLiteral => "E1026",
+            ParserErrorKind::InvalidNumericSeparatorAtSibling => "E1027",
+            ParserErrorKind::InvalidNumericSeparatorAtEnd => "E1028",
+            ParserErrorKind::InvalidDecimalBigIntegerLiteral => "E1030",
+            ParserErrorKind::UnterminatedRegExLiteral => "E1040",
+            ParserErrorKind::InvalidRegexLiteralFirstChar => "E1041",
+            ParserErrorKind::InvalidRegexBackslashSequence => "E1042",
+            ParserErrorKind::InvalidRegexExpressionClass => "E1043",
+            ParserErrorKind::InvalidRegexNothingToRepeat(..) => "E2001",
+            ParserErrorKind::InvalidRegexLoneBracket(..) => "E2002",
+            ParserErrorKind::InvalidRegexUnmatchedParenthesis(..) => "E2003",
+            ParserErrorKind::UnterminatedRegexGroup(..) => "E2004",
+            ParserErrorKind::InvalidRegexGroup(..) => "E2005",
+            ParserErrorKind::InvalidRegexGroupName(..) => "E2006",
+            ParserErrorKind::UnterminatedRegexCharacterClass(..) => "E2007",
+            ParserErrorKind::InvalidRegexEscape(..) => "E2008",
+            ParserErrorKind::InvalidRegexUnicodeProperty(..) => "E2009",
+            ParserErrorKind::DuplicateRegexGroupName(..) => "E2010",
+            ParserErrorKind::InvalidRegexBackreference(..) => "E2011",
+            ParserErrorKind::InvalidRegexQuantifierRange(..) => "E2012",
+            ParserErrorKind::InvalidRegexCharacterClassRange(..) => "E2013",
+            ParserErrorKind::InvalidRegexClassSetOperation(..) => "E2014",
+            ParserErrorKind::InvalidRegexClassSetCharacter(..) => "E2015",
+            ParserErrorKind::InvalidRegexNegatedClassOfStrings(..) => "E2016",
+            ParserErrorKind::InvalidRegexIndexBackreference(..) => "E2017",
+            ParserErrorKind::InvalidRegexModifiers(..) => "E2018",
+            ParserErrorKind::InvalidRegexFlag(..) => "E2101",
+            ParserErrorKind::DuplicateRegexFlag(..) => "E2102",
+            ParserErrorKind::InvalidRegexFlagCombination(..) => "E2103",
+            ParserErrorKind::InvalidPropertyKey => "E3001",
+            ParserErrorKind::InvalidPropertyValue => "E3002",
+            ParserErrorKind::InvalidYieldExpression => "E3010",
+            ParserErrorKind::InvalidLeftHandSideExpression => "E3020",
+            ParserErrorKind::InvalidAwaitForInStatement => "E3030",
+            ParserErrorKind::InvalidReturnStatement => "E3040",
+            ParserErrorKind::UnexpectedHashbang => "E3050",
+            ParserErrorKind::DuplicateImportAttributeKey => "E3060",
+            ParserErrorKind::InvalidUsingDeclarationBinding => "E3070",
+            ParserErrorKind::InvalidUsingDeclarationInitializer => "E3071",
+            ParserErrorKind::InvalidUsingDeclarationPosition => "E3072",
+            ParserErrorKind::InvalidDecoratorPosition => "E4001",
+            ParserErrorKind::UnterminatedJSXStringLiteral => "E4101",
+            ParserErrorKind::InvalidJSXTextCharacter => "E4102",
+            ParserErrorKind::UnterminatedJSXContents => "E4103",
+            ParserErrorKind::MismatchedJSXClosingTag => "E4104",
+            ParserErrorKind::InvalidTypeScriptModifier => "E4201",
+            ParserErrorKind::InvalidParameterPropertyPosition => "E4202",
+            ParserErrorKind::InvalidParameterPropertyBinding => "E4203",
+            ParserErrorKind::InvalidExpressionToAssignmentExpressionConversion => "E5001",
+            ParserErrorKind::InvalidExpressionToChainElementConversion => "E5002",
+            ParserErrorKind::InvalidMethodDefinitionToPropertyConversion => "E5003",
+            ParserErrorKind::InvalidExpressionToPatternConversion => "E5004",
+            ParserErrorKind::InvalidExpressionToArrayPatternElementConversion => "E5005",
+            ParserErrorKind::InvalidExpressionToLiteralConversion => "E5006",
+            ParserErrorKind::InvalidFunctionParameterToPatternConversion => "E5007",
+            ParserErrorKind::SerializationError => "E9001",
+            ParserErrorKind::UnsupportedECMAVersion(..) => "E9101",
+            ParserErrorKind::UnsupportedFeature(..) => "E9102",
+        }
+    }
+
+    // The offset of a regular expression pattern error from the start of the pattern.
+    pub(crate) fn regex_pattern_offset(&self) -> Option<usize> {
+        match self {
+            ParserErrorKind::InvalidRegexNothingToRepeat(offset)
+            | ParserErrorKind::InvalidRegexLoneBracket(offset)
+            | ParserErrorKind::InvalidRegexUnmatchedParenthesis(offset)
+            | ParserErrorKind::UnterminatedRegexGroup(offset)
+            | ParserErrorKind::InvalidRegexGroup(offset)
+            | ParserErrorKind::InvalidRegexGroupName(offset)
+            | ParserErrorKind::UnterminatedRegexCharacterClass(offset)
+            | ParserErrorKind::InvalidRegexEscape(offset)
+            | ParserErrorKind::InvalidRegexUnicodeProperty(offset)
+            | ParserErrorKind::DuplicateRegexGroupName(offset)
+            | ParserErrorKind::InvalidRegexBackreference(offset)
+            | ParserErrorKind::InvalidRegexIndexBackreference(offset)
+            | ParserErrorKind::InvalidRegexQuantifierRange(offset)
+            | ParserErrorKind::InvalidRegexCharacterClassRange(offset)
+            | ParserErrorKind::InvalidRegexClassSetOperation(offset)
+            | ParserErrorKind::InvalidRegexClassSetCharacter(offset)
+            | ParserErrorKind::InvalidRegexNegatedClassOfStrings(offset)
+            | ParserErrorKind::InvalidRegexModifiers(offset) => Some(*offset),
+            _ => None,
+        }
+    }
+
+    // The offset of a regular expression flag error from the start of the flags.
+    pub(crate) fn regex_flag_offset(&self) -> Option<usize> {
+        match self {
+            ParserErrorKind::InvalidRegexFlag(offset)
+            | ParserErrorKind::DuplicateRegexFlag(offset)
+            | ParserErrorKind::InvalidRegexFlagCombination(offset) => Some(*offset),
+            _ => None,
         }
     }
 }
+
+// Serializable form of a `ParserError`, located at the start of its span.
+// The column is zero-based so that it lines up with ESTree and Acorn locations, and uses the same unit as the offset.
+// The span of the error is from `offset` to `end`, and the message ends with the position in the same format as Acorn,
+// e.g. "Unexpected token '}' (3:14)".
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", rename = "ParserError")]
+pub struct ParserErrorReport {
+    pub kind: String,
+    pub code: String,
+    pub message: String,
+    pub line: usize,
+    pub column: usize,
+    pub offset: usize,
+    pub end: usize,
+    // The source lines around the error with a caret under it, which is only rendered with the `code_frame` option.
+    #[serde(rename = "codeFrame", skip_serializing_if = "Option::is_none")]
+    pub code_frame: Option<String>,
+}
+
+impl ParserErrorReport {
+    pub fn new(error: &ParserError, position: Position) -> Self {
+        Self {
+            kind: error.kind.name(),
+            code: error.kind.code().to_string(),
+            message: format!("{} ({}:{})", error, position.line, position.column),
+            line: position.line,
+            column: position.column,
+            offset: error.span.start,
+            end: error.span.end,
+            code_frame: None,
+        }
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+// Every error is also displayed with its code and position, e.g. "E0001: Unexpected token '}' (3:14)", followed by
+// the code frame if it was rendered.
+impl std::fmt::Display for ParserErrorReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}: {}", self.code, self.message)?;
+
+        if let Some(code_frame) = &self.code_frame {
+            write!(f, "\n{}", code_frame)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,195 @@
+use crate::ECMAVersion;
+
+pub use usage::{FeatureReport, FeatureUsage};
+
+pub(crate) use regexp::pattern_features;
+
+mod regexp;
+mod usage;
+
+// Syntax which was added in ES5 or later. Syntax is rejected when the `ecmaVersion` option is older than the edition
+// the syntax was added in. Trailing commas in functions have no node of their own, so they are not reported by the
+// `FeatureReport`.
+// https://github.com/tc39/proposals/blob/main/finished-proposals.md
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Feature {
+    // ES5
+    PropertyAccessors,
+    ReservedWordPropertyNames,
+    // ES2015
+    ArrowFunctions,
+    BinaryAndOctalLiterals,
+    Classes,
+    ComputedProperties,
+    DefaultParameters,
+    Destructuring,
+    ForOf,
+    Generators,
+    LexicalDeclarations,
+    Modules,
+    NewTarget,
+    RegExpStickyFlag,
+    RegExpUnicodeFlag,
+    RestParameters,
+    ShorthandProperties,
+    SpreadElements,
+    TemplateLiterals,
+    UnicodeCodePointEscapes,
+    // ES2016
+    ExponentiationOperator,
+    // ES2017
+    AsyncFunctions,
+    FunctionTrailingCommas,
+    // ES2018
+    AsyncIteration,
+    ObjectRestSpread,
+    RegExpDotAllFlag,
+    RegExpLookbehindAssertions,
+    RegExpNamedCaptureGroups,
+    RegExpUnicodePropertyEscapes,
+    // ES2019
+    OptionalCatchBinding,
+    // ES2020
+    BigInt,
+    DynamicImport,
+    ExportNamespaceFrom,
+    ImportMeta,
+    NullishCoalescing,
+    OptionalChaining,
+    // ES2021
+    LogicalAssignment,
+    NumericSeparators,
+    // ES2022
+    ArbitraryModuleNamespaceNames,
+    ClassFields,
+    ClassPrivateMethods,
+    ClassStaticBlock,
+    ErgonomicBrandChecks,
+    PrivateNames,
+    RegExpMatchIndicesFlag,
+    TopLevelAwait,
+    // ES2023
+    Hashbang,
+    // ES2024
+    RegExpUnicodeSetsFlag,
+    // ES2025
+    ImportAttributes,
+    RegExpModifiers,
+    // ES2026
+    UsingDeclarations,
+}
+
+impl Feature {
+    // The first edition of ECMAScript which includes the feature.
+    pub fn ecma_version(&self) -> ECMAVersion {
+        match self {
+            Feature::PropertyAccessors | Feature::ReservedWordPropertyNames => ECMAVersion::Five,
+            Feature::ArrowFunctions
+            | Feature::BinaryAndOctalLiterals
+            | Feature::Classes
+            | Feature::ComputedProperties
+            | Feature::DefaultParameters
+            | Feature::Destructuring
+            | Feature::ForOf
+            | Feature::Generators
+            | Feature::LexicalDeclarations
+            | Feature::Modules
+            | Feature::NewTarget
+            | Feature::RegExpStickyFlag
+            | Feature::RegExpUnicodeFlag
+            | Feature::RestParameters
+            | Feature::ShorthandProperties
+            | Feature::SpreadElements
+            | Feature::TemplateLiterals
+            | Feature::UnicodeCodePointEscapes => ECMAVersion::SixOrTwentyFifteen,
+            Feature::ExponentiationOperator => ECMAVersion::TwentySixteen,
+            Feature::AsyncFunctions | Feature::FunctionTrailingCommas => {
+                ECMAVersion::TwentySeventeen
+            }
+            Feature::AsyncIteration
+            | Feature::ObjectRestSpread
+            | Feature::RegExpDotAllFlag
+            | Feature::RegExpLookbehindAssertions
+            | Feature::RegExpNamedCaptureGroups
+            | Feature::RegExpUnicodePropertyEscapes => ECMAVersion::TwentyEighteen,
+            Feature::OptionalCatchBinding => ECMAVersion::TwentyNineteen,
+            Feature::BigInt
+            | Feature::DynamicImport
+            | Feature::ExportNamespaceFrom
+            | Feature::ImportMeta
+            | Feature::NullishCoalescing
+            | Feature::OptionalChaining => ECMAVersion::TwentyTwenty,
+            Feature::LogicalAssignment | Feature::NumericSeparators => ECMAVersion::TwentyTwentyOne,
+            Feature::ArbitraryModuleNamespaceNames
+            | Feature::ClassFields
+            | Feature::ClassPrivateMethods
+            | Feature::ClassStaticBlock
+            | Feature::ErgonomicBrandChecks
+            | Feature::PrivateNames
+            | Feature::RegExpMatchIndicesFlag
+            | Feature::TopLevelAwait => ECMAVersion::TwentyTwentyTwo,
+            Feature::Hashbang => ECMAVersion::TwentyTwentyThree,
+            Feature::RegExpUnicodeSetsFlag => ECMAVersion::TwentyTwentyFour,
+            Feature::ImportAttributes | Feature::RegExpModifiers => ECMAVersion::TwentyTwentyFive,
+            Feature::UsingDeclarations => ECMAVersion::TwentyTwentySix,
+        }
+    }
+}
+
+// Reserved words were only allowed as property names from ES5, e.g. `a.class` or `{ default: a }`.
+// https://tc39.es/ecma262/#prod-ReservedWord
+pub(crate) fn is_reserved_word(name: &str) -> bool {
+    matches!(
+        name,
+        "break"
+            | "case"
+            | "catch"
+            | "class"
+            | "const"
+            | "continue"
+            | "debugger"
+            | "default"
+            | "delete"
+            | "do"
+            | "else"
+            | "enum"
+            | "export"
+            | "extends"
+            | "false"
+            | "finally"
+            | "for"
+            | "function"
+            | "if"
+            | "import"
+            | "in"
+            | "instanceof"
+            | "new"
+            | "null"
+            | "return"
+            | "super"
+            | "switch"
+            | "this"
+            | "throw"
+            | "true"
+            | "try"
+            | "typeof"
+            | "var"
+            | "void"
+            | "while"
+            | "with"
+    )
+}
+
+// Whether the raw source of a string literal contains a `\u{...}` escape, skipping escaped backslashes such as in
+// `"\\u{"`.
+pub(crate) fn has_unicode_code_point_escape(raw: &str) -> bool {
+    let mut chars = raw.chars();
+
+    while let Some(ch) = chars.next() {
+        if ch == '\\' && chars.next() == Some('u') && chars.clone().next() == Some('{') {
+            return true;
+        }
+    }
+
+    false
+}
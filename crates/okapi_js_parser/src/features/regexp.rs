@@ -0,0 +1,92 @@
+use crate::{
+    ast::regexp::{
+        Alternative, CharacterClassElement, CharacterSetKind, Element, LookaroundAssertionKind,
+        Pattern,
+    },
+    Feature,
+};
+
+// The features used by a regular expression pattern, in the order they are found. Shared by the `FeatureReport` and
+// the parser, so that both agree on which patterns use which feature.
+pub(crate) fn pattern_features(pattern: &Pattern) -> Vec<Feature> {
+    let mut features = vec![];
+
+    add_alternatives(&pattern.alternatives, &mut features);
+
+    features
+}
+
+fn add_alternatives(alternatives: &[Alternative], features: &mut Vec<Feature>) {
+    for alternative in alternatives {
+        for element in &alternative.elements {
+            add_element(element, features);
+        }
+    }
+}
+
+fn add_element(element: &Element, features: &mut Vec<Feature>) {
+    match element {
+        Element::LookaroundAssertion(lookaround_assertion) => {
+            if matches!(
+                lookaround_assertion.kind,
+                LookaroundAssertionKind::Lookbehind
+            ) {
+                features.push(Feature::RegExpLookbehindAssertions);
+            }
+
+            add_alternatives(&lookaround_assertion.alternatives, features);
+        }
+        Element::Quantifier(quantifier) => add_element(&quantifier.element, features),
+        Element::Group(group) => {
+            if group.modifiers.is_some() {
+                features.push(Feature::RegExpModifiers);
+            }
+
+            add_alternatives(&group.alternatives, features);
+        }
+        Element::CapturingGroup(capturing_group) => {
+            if capturing_group.name.is_some() {
+                features.push(Feature::RegExpNamedCaptureGroups);
+            }
+
+            add_alternatives(&capturing_group.alternatives, features);
+        }
+        Element::CharacterSet(character_set) => {
+            if matches!(character_set.kind, CharacterSetKind::Property) {
+                features.push(Feature::RegExpUnicodePropertyEscapes);
+            }
+        }
+        Element::CharacterClass(character_class) => {
+            for class_element in &character_class.elements {
+                add_class_element(class_element, features);
+            }
+        }
+        Element::BoundaryAssertion(_) | Element::Character(_) | Element::Backreference(_) => {}
+    }
+}
+
+fn add_class_element(class_element: &CharacterClassElement, features: &mut Vec<Feature>) {
+    match class_element {
+        CharacterClassElement::CharacterSet(character_set) => {
+            if matches!(character_set.kind, CharacterSetKind::Property) {
+                features.push(Feature::RegExpUnicodePropertyEscapes);
+            }
+        }
+        CharacterClassElement::CharacterClass(character_class) => {
+            for class_element in &character_class.elements {
+                add_class_element(class_element, features);
+            }
+        }
+        CharacterClassElement::ClassIntersection(class_intersection) => {
+            add_class_element(&class_intersection.left, features);
+            add_class_element(&class_intersection.right, features);
+        }
+        CharacterClassElement::ClassSubtraction(class_subtraction) => {
+            add_class_element(&class_subtraction.left, features);
+            add_class_element(&class_subtraction.right, features);
+        }
+        CharacterClassElement::Character(_)
+        | CharacterClassElement::CharacterClassRange(_)
+        | CharacterClassElement::ClassStringDisjunction(_) => {}
+    }
+}
@@ -0,0 +1,589 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    ast::{
+        visit::{walk, Visit},
+        ArrayExpressionElement, ArrayPattern, ArrowFunctionExpressionBody, AssignmentOperator,
+        BinaryExpressionLeft, BinaryOperator, BlockStatement, CallExpression,
+        CallExpressionArgument, CallExpressionCallee, ClassBodyBody, ClassDeclaration,
+        ExportDeclaration, Expression, FunctionDeclaration, FunctionExpression, FunctionParameter,
+        ImportDeclaration, ImportExpression, LiteralValue, LogicalOperator,
+        MemberExpressionProperty, ModuleExportName, NewExpressionArguments, Node,
+        ObjectExpressionProperty, ObjectPattern, ObjectPatternProperty, Pattern, PrivateIdentifier,
+        Program, Property, PropertyDefinitionKey, PropertyKind, RegExpLiteral, Span, Statement,
+        TSParameterPropertyParameter, TemplateLiteral, VariableDeclaration, VariableKind,
+    },
+    ECMAVersion, Feature,
+};
+
+use super::{has_unicode_code_point_escape, is_reserved_word, regexp::pattern_features};
+
+// Every use of a feature within a program.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FeatureUsage {
+    pub feature: Feature,
+    pub ecma_version: ECMAVersion,
+    // In source order, using the offset encoding the program was parsed with.
+    pub spans: Vec<Span>,
+}
+
+// The ECMAScript features used by a parsed program, ordered by the edition they were added in.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FeatureReport {
+    pub usages: Vec<FeatureUsage>,
+}
+
+impl FeatureReport {
+    pub fn new(program: &Program) -> Self {
+        let mut visitor = FeatureVisitor::default();
+
+        visitor.visit_program(program);
+
+        let usages = visitor
+            .features
+            .into_iter()
+            .map(|(feature, mut spans)| {
+                // Nodes add features for some of their children before they are walked, e.g. the spread elements of
+                // an array, so spans are only in source order once sorted.
+                spans.sort_by_key(|span| span.start);
+                spans.dedup();
+
+                FeatureUsage {
+                    feature,
+                    ecma_version: feature.ecma_version(),
+                    spans,
+                }
+            })
+            .collect();
+
+        Self { usages }
+    }
+
+    pub fn get(&self, feature: Feature) -> Option<&FeatureUsage> {
+        self.usages.iter().find(|usage| usage.feature == feature)
+    }
+
+    // The oldest edition which supports every feature used by the program. A program without any of the features is
+    // ES5, as only some of the features of ES5 itself are tracked.
+    pub fn ecma_version(&self) -> ECMAVersion {
+        self.usages
+            .iter()
+            .map(|usage| usage.ecma_version)
+            .max()
+            .unwrap_or(ECMAVersion::Five)
+    }
+}
+
+#[derive(Default)]
+struct FeatureVisitor {
+    features: BTreeMap<Feature, Vec<Span>>,
+    // Used to tell top-level `await` apart from `await` within async functions.
+    function_depth: usize,
+}
+
+impl FeatureVisitor {
+    fn add(&mut self, feature: Feature, node: &Node) {
+        let spans = self.features.entry(feature).or_default();
+
+        let span = Span::from(node);
+
+        // A node can use the same feature more than once, e.g. a regular expression with several named groups.
+        if spans.last() != Some(&span) {
+            spans.push(span);
+        }
+    }
+
+    fn add_function(&mut self, node: &Node, is_async: bool, is_generator: bool) {
+        match (is_async, is_generator) {
+            (true, true) => self.add(Feature::AsyncIteration, node),
+            (true, false) => self.add(Feature::AsyncFunctions, node),
+            (false, true) => self.add(Feature::Generators, node),
+            (false, false) => {}
+        }
+    }
+
+    fn add_class_element_key(
+        &mut self,
+        key: Option<&PropertyDefinitionKey>,
+        computed: bool,
+        node: &Node,
+    ) {
+        if computed && matches!(key, Some(PropertyDefinitionKey::Expression(_))) {
+            self.add(Feature::ComputedProperties, node);
+        }
+    }
+
+    fn add_property_name(&mut self, key: &Expression, node: &Node) {
+        if let Expression::Identifier(identifier) = key {
+            if is_reserved_word(&identifier.name) {
+                self.add(Feature::ReservedWordPropertyNames, node);
+            }
+        }
+    }
+
+    fn add_import_expression(&mut self, import_expression: &ImportExpression) {
+        self.add(Feature::DynamicImport, &import_expression.node);
+
+        if import_expression.options.is_some() {
+            self.add(Feature::ImportAttributes, &import_expression.node);
+        }
+    }
+
+    fn visit_numeric_literal(&mut self, raw: &str, node: &Node) {
+        if ["0b", "0B", "0o", "0O"]
+            .iter()
+            .any(|prefix| raw.starts_with(prefix))
+        {
+            self.add(Feature::BinaryAndOctalLiterals, node);
+        }
+
+        if raw.contains('_') {
+            self.add(Feature::NumericSeparators, node);
+        }
+    }
+
+    // Regular expression pattern features are reported for the whole literal, as pattern offsets are relative to
+    // the start of the pattern.
+    fn visit_regexp_literal(&mut self, regexp_literal: &RegExpLiteral) {
+        let node = &regexp_literal.node;
+        let flags = &regexp_literal.regexp.flags;
+
+        let flag_features = [
+            (flags.sticky, Feature::RegExpStickyFlag),
+            (flags.unicode, Feature::RegExpUnicodeFlag),
+            (flags.dot_all, Feature::RegExpDotAllFlag),
+            (flags.has_indices, Feature::RegExpMatchIndicesFlag),
+            (flags.unicode_sets, Feature::RegExpUnicodeSetsFlag),
+        ];
+
+        for (has_flag, feature) in flag_features {
+            if has_flag {
+                self.add(feature, node);
+            }
+        }
+
+        for feature in pattern_features(&regexp_literal.regexp.pattern) {
+            self.add(feature, node);
+        }
+    }
+}
+
+// Each node adds its features before its children are walked.
+impl<'a> Visit<'a> for FeatureVisitor {
+    fn visit_program(&mut self, program: &'a Program) {
+        // The hashbang has no node of its own, so it is reported at the start of the program.
+        if program.hashbang.is_some() {
+            let node = Node::new(program.node.start, program.node.start);
+
+            self.add(Feature::Hashbang, &node);
+        }
+
+        walk::walk_program(self, program);
+    }
+
+    // Scripts and modules
+
+    fn visit_import_declaration(&mut self, import_declaration: &'a ImportDeclaration) {
+        self.add(Feature::Modules, &import_declaration.node);
+
+        for specifier in &import_declaration.specifiers {
+            if let Some(ModuleExportName::Literal(_)) = specifier.imported {
+                self.add(Feature::ArbitraryModuleNamespaceNames, &specifier.node);
+            }
+        }
+
+        if !import_declaration.attributes.is_empty() {
+            self.add(Feature::ImportAttributes, &import_declaration.node);
+        }
+
+        walk::walk_import_declaration(self, import_declaration);
+    }
+
+    fn visit_export_declaration(&mut self, export_declaration: &'a ExportDeclaration) {
+        match export_declaration {
+            ExportDeclaration::All(export_all_declaration) => {
+                self.add(Feature::Modules, &export_all_declaration.node);
+
+                match &export_all_declaration.exported {
+                    Some(ModuleExportName::Identifier(_)) => {
+                        self.add(Feature::ExportNamespaceFrom, &export_all_declaration.node);
+                    }
+                    Some(ModuleExportName::Literal(_)) => {
+                        self.add(Feature::ExportNamespaceFrom, &export_all_declaration.node);
+                        self.add(
+                            Feature::ArbitraryModuleNamespaceNames,
+                            &export_all_declaration.node,
+                        );
+                    }
+                    None => {}
+                }
+
+                if !export_all_declaration.attributes.is_empty() {
+                    self.add(Feature::ImportAttributes, &export_all_declaration.node);
+                }
+            }
+            ExportDeclaration::Default(export_default_declaration) => {
+                self.add(Feature::Modules, &export_default_declaration.node);
+            }
+            ExportDeclaration::Named(export_named_declaration) => {
+                self.add(Feature::Modules, &export_named_declaration.node);
+
+                for specifier in &export_named_declaration.specifiers {
+                    if matches!(*specifier.local, ModuleExportName::Literal(_))
+                        || matches!(*specifier.exported, ModuleExportName::Literal(_))
+                    {
+                        self.add(Feature::ArbitraryModuleNamespaceNames, &specifier.node);
+                    }
+                }
+
+                if !export_named_declaration.attributes.is_empty() {
+                    self.add(Feature::ImportAttributes, &export_named_declaration.node);
+                }
+            }
+            ExportDeclaration::TSAssignment(_) => {}
+        }
+
+        walk::walk_export_declaration(self, export_declaration);
+    }
+
+    // Statements and declarations
+
+    fn visit_variable_declaration(&mut self, variable_declaration: &'a VariableDeclaration) {
+        match variable_declaration.kind {
+            VariableKind::Var => {}
+            VariableKind::Let | VariableKind::Const => {
+                self.add(Feature::LexicalDeclarations, &variable_declaration.node);
+            }
+            VariableKind::Using => {
+                self.add(Feature::UsingDeclarations, &variable_declaration.node);
+            }
+            VariableKind::AwaitUsing => {
+                self.add(Feature::UsingDeclarations, &variable_declaration.node);
+
+                if self.function_depth == 0 {
+                    self.add(Feature::TopLevelAwait, &variable_declaration.node);
+                }
+            }
+        }
+
+        walk::walk_variable_declaration(self, variable_declaration);
+    }
+
+    fn visit_statement(&mut self, statement: &'a Statement) {
+        match statement {
+            Statement::ForOf(for_of_statement) => {
+                self.add(Feature::ForOf, &for_of_statement.node);
+
+                if for_of_statement.awaiting {
+                    self.add(Feature::AsyncIteration, &for_of_statement.node);
+
+                    if self.function_depth == 0 {
+                        self.add(Feature::TopLevelAwait, &for_of_statement.node);
+                    }
+                }
+            }
+            Statement::StaticBlock(static_block) => {
+                self.add(Feature::ClassStaticBlock, &static_block.node);
+            }
+            Statement::Try(try_statement) => {
+                if let Some(handler) = &try_statement.handler {
+                    if handler.param.is_none() {
+                        self.add(Feature::OptionalCatchBinding, &handler.node);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        walk::walk_statement(self, statement);
+    }
+
+    // Functions and classes
+
+    fn visit_function_declaration(&mut self, function_declaration: &'a FunctionDeclaration) {
+        self.add_function(
+            &function_declaration.node,
+            function_declaration.asynchronous,
+            function_declaration.generator,
+        );
+
+        walk::walk_function_declaration(self, function_declaration);
+    }
+
+    fn visit_function_expression(&mut self, function_expression: &'a FunctionExpression) {
+        self.add_function(
+            &function_expression.node,
+            function_expression.is_async,
+            function_expression.generator,
+        );
+
+        walk::walk_function_expression(self, function_expression);
+    }
+
+    fn visit_function_parameter(&mut self, param: &'a FunctionParameter) {
+        match param {
+            FunctionParameter::Assignment(assignment_pattern) => {
+                self.add(Feature::DefaultParameters, &assignment_pattern.node);
+            }
+            FunctionParameter::RestElement(rest_element) => {
+                self.add(Feature::RestParameters, &rest_element.node);
+            }
+            FunctionParameter::TSParameterProperty(parameter_property) => {
+                if let TSParameterPropertyParameter::Assignment(assignment_pattern) =
+                    &parameter_property.parameter
+                {
+                    self.add(Feature::DefaultParameters, &assignment_pattern.node);
+                }
+            }
+            _ => {}
+        }
+
+        walk::walk_function_parameter(self, param);
+    }
+
+    fn visit_function_body(&mut self, body: &'a BlockStatement) {
+        self.function_depth += 1;
+
+        self.visit_block_statement(body);
+
+        self.function_depth -= 1;
+    }
+
+    fn visit_class_declaration(&mut self, class_declaration: &'a ClassDeclaration) {
+        self.add(Feature::Classes, &class_declaration.node);
+
+        walk::walk_class_declaration(self, class_declaration);
+    }
+
+    fn visit_class_element(&mut self, class_element: &'a ClassBodyBody) {
+        match class_element {
+            ClassBodyBody::MethodDefinition(method_definition) => {
+                if let Some(PropertyDefinitionKey::PrivateIdentifier(_)) = method_definition.key {
+                    self.add(Feature::ClassPrivateMethods, &method_definition.node);
+                }
+
+                self.add_class_element_key(
+                    method_definition.key.as_ref(),
+                    method_definition.computed,
+                    &method_definition.node,
+                );
+            }
+            ClassBodyBody::PropertyDefinition(property_definition) => {
+                self.add(Feature::ClassFields, &property_definition.node);
+                self.add_class_element_key(
+                    property_definition.key.as_ref(),
+                    property_definition.computed,
+                    &property_definition.node,
+                );
+            }
+            ClassBodyBody::AccessorProperty(accessor_property) => self.add_class_element_key(
+                Some(&accessor_property.key),
+                accessor_property.computed,
+                &accessor_property.node,
+            ),
+            ClassBodyBody::StaticBlock(static_block) => {
+                self.add(Feature::ClassStaticBlock, &static_block.node);
+            }
+            ClassBodyBody::TSAbstractMethodDefinition(method_definition) => self
+                .add_class_element_key(
+                    method_definition.key.as_ref(),
+                    method_definition.computed,
+                    &method_definition.node,
+                ),
+            ClassBodyBody::TSAbstractPropertyDefinition(property_definition) => self
+                .add_class_element_key(
+                    property_definition.key.as_ref(),
+                    property_definition.computed,
+                    &property_definition.node,
+                ),
+            ClassBodyBody::TSIndexSignature(_) => {}
+        }
+
+        walk::walk_class_element(self, class_element);
+    }
+
+    // Patterns
+
+    fn visit_object_pattern(&mut self, object_pattern: &'a ObjectPattern) {
+        self.add(Feature::Destructuring, &object_pattern.node);
+
+        for property in &object_pattern.properties {
+            if let ObjectPatternProperty::Rest(rest_element) = property {
+                self.add(Feature::ObjectRestSpread, &rest_element.node);
+            }
+        }
+
+        walk::walk_object_pattern(self, object_pattern);
+    }
+
+    fn visit_array_pattern(&mut self, array_pattern: &'a ArrayPattern) {
+        self.add(Feature::Destructuring, &array_pattern.node);
+
+        walk::walk_array_pattern(self, array_pattern);
+    }
+
+    // Expressions
+
+    fn visit_expression(&mut self, expression: &'a Expression) {
+        match expression {
+            Expression::Array(array_expression) => {
+                for element in array_expression.elements.iter().flatten() {
+                    if let ArrayExpressionElement::SpreadElement(spread_element) = element {
+                        self.add(Feature::SpreadElements, &spread_element.node);
+                    }
+                }
+            }
+            Expression::ArrowFunction(arrow_function_expression) => {
+                self.add(Feature::ArrowFunctions, &arrow_function_expression.node);
+
+                if arrow_function_expression.is_async {
+                    self.add(Feature::AsyncFunctions, &arrow_function_expression.node);
+                }
+
+                for param in &arrow_function_expression.params {
+                    match param {
+                        Pattern::Assignment(assignment_pattern) => {
+                            self.add(Feature::DefaultParameters, &assignment_pattern.node);
+                        }
+                        Pattern::RestElement(rest_element) => {
+                            self.add(Feature::RestParameters, &rest_element.node);
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            Expression::Assignment(assignment_expression) => match assignment_expression.operator {
+                AssignmentOperator::ExponentiationAssignment => {
+                    self.add(Feature::ExponentiationOperator, &assignment_expression.node)
+                }
+                AssignmentOperator::LogicalOrAssignment
+                | AssignmentOperator::LogicalAndAssignment
+                | AssignmentOperator::NullishCoalescingAssignment => {
+                    self.add(Feature::LogicalAssignment, &assignment_expression.node)
+                }
+                _ => {}
+            },
+            Expression::Await(await_expression) if self.function_depth == 0 => {
+                self.add(Feature::TopLevelAwait, &await_expression.node);
+            }
+            Expression::Binary(binary_expression) => {
+                if matches!(binary_expression.operator, BinaryOperator::StarStar) {
+                    self.add(Feature::ExponentiationOperator, &binary_expression.node);
+                }
+
+                if let BinaryExpressionLeft::PrivateIdentifier(_) = binary_expression.left {
+                    self.add(Feature::ErgonomicBrandChecks, &binary_expression.node);
+                }
+            }
+            Expression::Chain(chain_expression) => {
+                self.add(Feature::OptionalChaining, &chain_expression.node);
+            }
+            Expression::Class(class_expression) => {
+                self.add(Feature::Classes, &class_expression.node);
+            }
+            Expression::Import(import_expression) => self.add_import_expression(import_expression),
+            Expression::Literal(literal) => match literal.value {
+                LiteralValue::Number(_) => self.visit_numeric_literal(&literal.raw, &literal.node),
+                LiteralValue::String(_) if has_unicode_code_point_escape(&literal.raw) => {
+                    self.add(Feature::UnicodeCodePointEscapes, &literal.node);
+                }
+                _ => {}
+            },
+            Expression::RegExpLiteral(regexp_literal) => self.visit_regexp_literal(regexp_literal),
+            Expression::BigIntLiteral(big_int_literal) => {
+                self.add(Feature::BigInt, &big_int_literal.node);
+
+                self.visit_numeric_literal(&big_int_literal.raw, &big_int_literal.node);
+            }
+            Expression::Logical(logical_expression) => {
+                if matches!(
+                    logical_expression.operator,
+                    LogicalOperator::NullishCoalescing
+                ) {
+                    self.add(Feature::NullishCoalescing, &logical_expression.node);
+                }
+            }
+            Expression::Member(member_expression) if !member_expression.computed => {
+                if let MemberExpressionProperty::Expression(property) = &member_expression.property
+                {
+                    self.add_property_name(property, &member_expression.node);
+                }
+            }
+            Expression::MetaProperty(meta_property) => match meta_property.meta.name.as_str() {
+                "new" => self.add(Feature::NewTarget, &meta_property.node),
+                "import" => self.add(Feature::ImportMeta, &meta_property.node),
+                _ => {}
+            },
+            Expression::New(new_expression) => {
+                for argument in &new_expression.arguments {
+                    if let NewExpressionArguments::SpreadElement(spread_element) = argument {
+                        self.add(Feature::SpreadElements, &spread_element.node);
+                    }
+                }
+            }
+            Expression::Object(object_expression) => {
+                for property in &object_expression.properties {
+                    if let ObjectExpressionProperty::SpreadElement(spread_element) = property {
+                        self.add(Feature::ObjectRestSpread, &spread_element.node);
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        walk::walk_expression(self, expression);
+    }
+
+    fn visit_arrow_function_body(&mut self, body: &'a ArrowFunctionExpressionBody) {
+        self.function_depth += 1;
+
+        walk::walk_arrow_function_body(self, body);
+
+        self.function_depth -= 1;
+    }
+
+    fn visit_call_expression(&mut self, call_expression: &'a CallExpression) {
+        if let CallExpressionCallee::Import(import_expression) = &call_expression.callee {
+            self.add_import_expression(import_expression);
+        }
+
+        for argument in &call_expression.arguments {
+            if let CallExpressionArgument::SpreadElement(spread_element) = argument {
+                self.add(Feature::SpreadElements, &spread_element.node);
+            }
+        }
+
+        walk::walk_call_expression(self, call_expression);
+    }
+
+    // Shared by object literals and object patterns.
+    fn visit_property(&mut self, property: &'a Property) {
+        if property.computed {
+            self.add(Feature::ComputedProperties, &property.node);
+        }
+
+        if property.shorthand || property.method {
+            self.add(Feature::ShorthandProperties, &property.node);
+        }
+
+        if matches!(property.kind, PropertyKind::Get | PropertyKind::Set) {
+            self.add(Feature::PropertyAccessors, &property.node);
+        }
+
+        if !property.computed {
+            self.add_property_name(&property.key, &property.node);
+        }
+
+        walk::walk_property(self, property);
+    }
+
+    fn visit_template_literal(&mut self, template_literal: &'a TemplateLiteral) {
+        self.add(Feature::TemplateLiterals, &template_literal.node);
+
+        walk::walk_template_literal(self, template_literal);
+    }
+
+    fn visit_private_identifier(&mut self, private_identifier: &'a PrivateIdentifier) {
+        self.add(Feature::PrivateNames, &private_identifier.node);
+    }
+}
@@ -89,6 +89,7 @@ impl LexerChar for char {
                 | &':'
                 | &'/'
                 | &'}'
+                | &'@'
         )
     }
 
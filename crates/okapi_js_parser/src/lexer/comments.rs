@@ -1,27 +1,61 @@
-use crate::Lexer;
+use crate::{ast::CommentKind, Lexer};
 
 use super::char::LexerChar;
 
+// The byte range of a comment, including its delimiters, which the parser turns into a `Comment` node.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct ScannedComment {
+    pub kind: CommentKind,
+    pub start: usize,
+    pub end: usize,
+}
+
 // 12.4 Comments
 // https://tc39.es/ecma262/#sec-comments
-impl Lexer {
+impl<'a> Lexer<'a> {
+    // A hashbang comment is only allowed at the very start of the source text, and runs up to the first line terminator.
+    // https://tc39.es/ecma262/#sec-hashbang
+    pub fn hashbang(&self) -> Option<&'a str> {
+        let comment = self.source.strip_prefix("#!")?;
+
+        let end = comment
+            .find(|ch: char| ch.is_line_terminator())
+            .unwrap_or(comment.len());
+
+        Some(&comment[..end])
+    }
+
+    pub(crate) fn skip_hashbang_comment(&mut self) {
+        self.read_char(); // Eat '#' char.
+        self.read_char(); // Eat '!' char.
+
+        // The line terminator is left for `skip_comment_or_whitespace`, in the same way as it is for whitespace.
+        while !self.is_end_of_file() && !self.current_char().is_line_terminator() {
+            self.read_char();
+        }
+    }
+
     pub(crate) fn skip_single_line_comment(&mut self) {
+        let start_index = self.read_index;
+
         self.read_char(); // Eat '/' char.
         self.read_char(); // Eat '/' char.
 
-        while !self.is_end_of_file() {
-            match self.current_char() {
-                ch if ch.is_line_terminator() => {
-                    self.read_char(); // Eat line terminator.
-
-                    break;
-                }
-                _ => self.read_char(),
-            }
+        // The line terminator is not part of the comment, and is left for `skip_comment_or_whitespace`.
+        while !self.is_end_of_file() && !self.current_char().is_line_terminator() {
+            self.read_char();
         }
+
+        self.push_comment(CommentKind::Line, start_index);
     }
 
-    pub(crate) fn skip_multi_line_comment(&mut self) {
+    // A multi-line comment which contains a line terminator is treated as a line terminator by the syntactic grammar.
+    // https://tc39.es/ecma262/#sec-comments
+    pub(crate) fn skip_multi_line_comment(&mut self) -> bool {
+        let start_index = self.read_index;
+
+        let mut has_line_terminator = false;
+
         self.read_char(); // Eat '/' char.
         self.read_char(); // Eat '*' char.
 
@@ -33,8 +67,45 @@ impl Lexer {
 
                     break;
                 }
-                _ => self.read_char(),
+                (ch, _) => {
+                    has_line_terminator |= ch.is_line_terminator();
+
+                    self.read_char();
+                }
             }
         }
+
+        self.push_comment(CommentKind::Block, start_index);
+
+        has_line_terminator
+    }
+
+    // Comments can be skipped more than once when tokens are scanned again, so only comments after the last one are kept.
+    fn push_comment(&mut self, kind: CommentKind, start_index: usize) {
+        if self
+            .comments
+            .last()
+            .is_some_and(|comment| comment.end > start_index)
+        {
+            return;
+        }
+
+        self.comments.push(ScannedComment {
+            kind,
+            start: start_index,
+            end: self.read_index,
+        });
+    }
+
+    // Forget comments which were skipped after the given position, as the source after it is about to be scanned again
+    // and might not contain the same comments, e.g. a `//` within a template literal or JSX text.
+    pub(crate) fn discard_comments_from(&mut self, index: usize) {
+        while self
+            .comments
+            .last()
+            .is_some_and(|comment| comment.start >= index)
+        {
+            self.comments.pop();
+        }
     }
 }
@@ -1,93 +1,75 @@
+use std::borrow::Cow;
+
 use okapi_unicode::is_unicode_id_start;
 
 use super::char::LexerChar;
-use crate::{KeywordKind, Lexer, ParserError, Token, TokenKind, TokenValue};
+use crate::{KeywordKind, Lexer, ParserErrorKind, Token, TokenKind, TokenValue};
 
 // 12.7 Names and Keywords
 // https://tc39.es/ecma262/#sec-names-and-keywords
-impl Lexer {
+impl<'a> Lexer<'a> {
     // https://tc39.es/ecma262/#sec-names-and-keywords
-    pub(crate) fn scan_identifier_name_or_keyword(&mut self) -> Result<Token, ParserError> {
+    pub(crate) fn scan_identifier_name_or_keyword(&mut self) -> Result<Token<'a>, ParserErrorKind> {
         let start_index = self.read_index;
 
         self.read_identifier_start()?;
 
-        let keyword_or_identifer_name = &self.chars[start_index..self.read_index]
-            .iter()
-            .collect::<String>();
+        let keyword_or_identifer_name = self.source_slice(start_index..self.read_index);
 
         match self.match_reserved_keyword(keyword_or_identifer_name) {
-            Some(keyword_token) => {
-                let keyword_or_identifier_name_str = keyword_or_identifer_name.to_string();
-
-                Ok(Token::new(
-                    keyword_token,
-                    start_index,
-                    self.read_index,
-                    self.line,
-                    self.column,
-                    TokenValue::String {
-                        raw: keyword_or_identifier_name_str.clone(),
-                        value: keyword_or_identifier_name_str,
-                    },
-                ))
-            }
-            None => {
-                let keyword_or_identifier_name_str = keyword_or_identifer_name.to_string();
-
-                Ok(Token::new(
-                    TokenKind::Identifier,
-                    start_index,
-                    self.read_index,
-                    self.line,
-                    self.column,
-                    TokenValue::String {
-                        raw: keyword_or_identifier_name_str.clone(),
-                        value: keyword_or_identifier_name_str,
-                    },
-                ))
-            }
+            Some(keyword_token) => Ok(Token::new(
+                keyword_token,
+                start_index,
+                self.read_index,
+                self.line,
+                self.column,
+                TokenValue::String {
+                    raw: keyword_or_identifer_name,
+                    value: Cow::Borrowed(keyword_or_identifer_name),
+                },
+            )),
+            None => Ok(Token::new(
+                TokenKind::Identifier,
+                start_index,
+                self.read_index,
+                self.line,
+                self.column,
+                TokenValue::String {
+                    raw: keyword_or_identifer_name,
+                    value: Cow::Borrowed(keyword_or_identifer_name),
+                },
+            )),
         }
     }
 
-    fn read_identifier_start(&mut self) -> Result<(), ParserError> {
+    fn read_identifier_start(&mut self) -> Result<(), ParserErrorKind> {
         match self.current_char() {
             '$' | '_' => self.read_char(),
             ch if ch.is_ascii_alphabetic() => self.read_char(),
             '\\' => {
                 if self.peek_char() != 'u' {
-                    return Err(ParserError::InvalidIdentifierCharacter);
+                    return Err(ParserErrorKind::InvalidIdentifierCharacter);
                 }
 
-                let unicode_escape_sequence_u32 = self.read_unicode_escape_sequence();
-
-                match unicode_escape_sequence_u32.is_err() {
-                    true => return Err(unicode_escape_sequence_u32.unwrap_err()),
-                    false => (),
-                }
+                self.read_unicode_escape_sequence()?;
             }
             ch if is_unicode_id_start(&ch) => self.read_char(),
             _ => {
-                return Err(ParserError::InvalidIdentifierCharacter);
+                return Err(ParserErrorKind::InvalidIdentifierCharacter);
             }
         };
 
         self.read_identifier_part()
     }
 
-    fn read_identifier_part(&mut self) -> Result<(), ParserError> {
+    fn read_identifier_part(&mut self) -> Result<(), ParserErrorKind> {
         while self.current_char().is_identifier_part() || self.current_char() == '\\' {
             if self.current_char() == '\\' {
                 if self.peek_char() != 'u' {
-                    return Err(ParserError::InvalidIdentifierCharacter);
+                    return Err(ParserErrorKind::InvalidIdentifierCharacter);
                 }
 
-                let unicode_escape_sequence_u32 = self.read_unicode_escape_sequence();
-
-                match unicode_escape_sequence_u32.is_err() {
-                    true => return Err(unicode_escape_sequence_u32.unwrap_err()),
-                    false => (),
-                }
+                self.read_unicode_escape_sequence()?;
             }
 
             self.read_char();
@@ -157,12 +139,30 @@ impl Lexer {
             "of" => Some(TokenKind::Keyword(KeywordKind::Of)),
             "set" => Some(TokenKind::Keyword(KeywordKind::Set)),
             "target" => Some(TokenKind::Keyword(KeywordKind::Target)),
+            "using" => Some(TokenKind::Keyword(KeywordKind::Using)),
+            "accessor" => Some(TokenKind::Keyword(KeywordKind::Accessor)),
+
+            // TypeScript contextual keywords.
+            "abstract" => Some(TokenKind::Keyword(KeywordKind::Abstract)),
+            "asserts" => Some(TokenKind::Keyword(KeywordKind::Asserts)),
+            "declare" => Some(TokenKind::Keyword(KeywordKind::Declare)),
+            "global" => Some(TokenKind::Keyword(KeywordKind::Global)),
+            "infer" => Some(TokenKind::Keyword(KeywordKind::Infer)),
+            "is" => Some(TokenKind::Keyword(KeywordKind::Is)),
+            "keyof" => Some(TokenKind::Keyword(KeywordKind::Keyof)),
+            "module" => Some(TokenKind::Keyword(KeywordKind::Module)),
+            "namespace" => Some(TokenKind::Keyword(KeywordKind::Namespace)),
+            "override" => Some(TokenKind::Keyword(KeywordKind::Override)),
+            "readonly" => Some(TokenKind::Keyword(KeywordKind::Readonly)),
+            "satisfies" => Some(TokenKind::Keyword(KeywordKind::Satisfies)),
+            "type" => Some(TokenKind::Keyword(KeywordKind::Type)),
+            "unique" => Some(TokenKind::Keyword(KeywordKind::Unique)),
             _ => None,
         }
     }
 
     // https://tc39.es/ecma262/#prod-PrivateIdentifier
-    pub(crate) fn scan_private_identifier(&mut self) -> Result<Token, ParserError> {
+    pub(crate) fn scan_private_identifier(&mut self) -> Result<Token<'a>, ParserErrorKind> {
         let start_index = self.read_index;
 
         self.read_char(); // Eat the '#' char.
@@ -170,26 +170,20 @@ impl Lexer {
         let identifier = self.read_identifier_start();
 
         // Omit the '#' char.
-        let identifer_name = &self.chars[start_index + 1..self.read_index]
-            .iter()
-            .collect::<String>();
+        let identifer_name = self.source_slice(start_index + 1..self.read_index);
 
         match identifier {
-            Ok(_) => {
-                let identifer_name_str = identifer_name.to_string();
-
-                Ok(Token::new(
-                    TokenKind::PrivateIdentifier,
-                    start_index,
-                    self.read_index,
-                    self.line,
-                    self.column,
-                    TokenValue::String {
-                        raw: identifer_name_str.clone(),
-                        value: identifer_name_str,
-                    },
-                ))
-            }
+            Ok(_) => Ok(Token::new(
+                TokenKind::PrivateIdentifier,
+                start_index,
+                self.read_index,
+                self.line,
+                self.column,
+                TokenValue::String {
+                    raw: identifer_name,
+                    value: Cow::Borrowed(identifer_name),
+                },
+            )),
             Err(error) => Err(error),
         }
     }
@@ -0,0 +1,236 @@
+use std::borrow::Cow;
+
+use crate::{Lexer, ParserErrorKind, Token, TokenKind, TokenValue};
+
+use super::{char::LexerChar, jsx_entities::xhtml_entity};
+
+// JSX
+// https://facebook.github.io/jsx/
+impl<'a> Lexer<'a> {
+    // Tokens between the `<` and `>` of a tag, where `>` is never part of a longer punctuator such as `>>`.
+    pub(crate) fn scan_jsx_tag_token(&mut self) -> Result<Token<'a>, ParserErrorKind> {
+        let start_index = self.read_index;
+
+        let token_kind = match self.current_char() {
+            ch if ch.is_identifier_start() => return self.scan_jsx_identifier(),
+            '"' | '\'' => return self.scan_jsx_string_literal(),
+            '<' => TokenKind::LessThan,
+            '>' => TokenKind::GreaterThan,
+            '/' => TokenKind::Division,
+            '=' => TokenKind::Assignment,
+            ':' => TokenKind::Colon,
+            '.' => TokenKind::Dot,
+            '{' => TokenKind::LeftCurlyBrace,
+            '}' => TokenKind::RightCurlyBrace,
+            _ => return Err(ParserErrorKind::SyntaxError),
+        };
+
+        self.read_char();
+
+        Ok(Token::new(
+            token_kind,
+            start_index,
+            self.read_index,
+            self.line,
+            self.column,
+            TokenValue::Null,
+        ))
+    }
+
+    // https://facebook.github.io/jsx/#prod-JSXIdentifier
+    fn scan_jsx_identifier(&mut self) -> Result<Token<'a>, ParserErrorKind> {
+        let start_index = self.read_index;
+
+        self.read_char(); // Eat identifier start char.
+
+        while self.current_char().is_identifier_part() || self.current_char() == '-' {
+            self.read_char();
+        }
+
+        let identifier = self.source_slice(start_index..self.read_index);
+
+        Ok(Token::new(
+            TokenKind::JSXIdentifier,
+            start_index,
+            self.read_index,
+            self.line,
+            self.column,
+            TokenValue::String {
+                raw: identifier,
+                value: Cow::Borrowed(identifier),
+            },
+        ))
+    }
+
+    // https://facebook.github.io/jsx/#prod-JSXDoubleStringCharacters
+    // Unlike ECMAScript strings, JSX strings have no escape sequences and can span multiple lines, but can contain HTML entities.
+    fn scan_jsx_string_literal(&mut self) -> Result<Token<'a>, ParserErrorKind> {
+        let start_index = self.read_index;
+
+        let start_quote_character = self.current_char(); // '\'' | '"'
+
+        self.read_char(); // Eat start quote char.
+
+        let value_start_index = self.read_index;
+
+        let mut cooked_value: Option<String> = None;
+
+        loop {
+            match self.current_char() {
+                _ if self.is_end_of_file() => {
+                    return Err(ParserErrorKind::UnterminatedJSXStringLiteral);
+                }
+                ch if ch == start_quote_character => break,
+                '&' => self.read_cooked_jsx_entity(&mut cooked_value, value_start_index),
+                ch => {
+                    if let Some(string_literal) = &mut cooked_value {
+                        string_literal.push(ch);
+                    }
+
+                    self.read_char();
+                }
+            }
+        }
+
+        let string_literal = self.cooked_jsx_value(cooked_value, value_start_index);
+
+        self.read_char(); // Eat end quote char.
+
+        Ok(Token::new(
+            TokenKind::StringLiteral,
+            start_index,
+            self.read_index,
+            self.line,
+            self.column,
+            TokenValue::String {
+                raw: self.source_slice(start_index..self.read_index),
+                value: string_literal,
+            },
+        ))
+    }
+
+    // https://facebook.github.io/jsx/#prod-JSXChild
+    pub(crate) fn scan_jsx_child(&mut self) -> Result<Token<'a>, ParserErrorKind> {
+        let start_index = self.read_index;
+
+        match self.current_char() {
+            '<' | '{' => {
+                let token_kind = if self.current_char() == '<' {
+                    TokenKind::LessThan
+                } else {
+                    TokenKind::LeftCurlyBrace
+                };
+
+                self.read_char();
+
+                Ok(Token::new(
+                    token_kind,
+                    start_index,
+                    self.read_index,
+                    self.line,
+                    self.column,
+                    TokenValue::Null,
+                ))
+            }
+            _ => self.scan_jsx_text(),
+        }
+    }
+
+    // https://facebook.github.io/jsx/#prod-JSXText
+    fn scan_jsx_text(&mut self) -> Result<Token<'a>, ParserErrorKind> {
+        let start_index = self.read_index;
+
+        let mut cooked_value: Option<String> = None;
+
+        while !self.is_end_of_file() {
+            match self.current_char() {
+                '<' | '{' => break,
+                // `>` and `}` are not allowed in JSX text, and have to be written as `{'>'}` and `{'}'}` or as entities.
+                '>' | '}' => return Err(ParserErrorKind::InvalidJSXTextCharacter),
+                '&' => self.read_cooked_jsx_entity(&mut cooked_value, start_index),
+                ch => {
+                    if let Some(text) = &mut cooked_value {
+                        text.push(ch);
+                    }
+
+                    self.read_char();
+                }
+            }
+        }
+
+        let text = self.cooked_jsx_value(cooked_value, start_index);
+
+        Ok(Token::new(
+            TokenKind::JSXText,
+            start_index,
+            self.read_index,
+            self.line,
+            self.column,
+            TokenValue::String {
+                raw: self.source_slice(start_index..self.read_index),
+                value: text,
+            },
+        ))
+    }
+
+    // The value of JSX text or a JSX string is only copied from the source text once it differs from it, at the first
+    // character reference.
+    fn read_cooked_jsx_entity(&mut self, cooked_value: &mut Option<String>, start_index: usize) {
+        let cooked_value = cooked_value
+            .get_or_insert_with(|| self.source_slice(start_index..self.read_index).to_string());
+
+        cooked_value.push_str(&self.read_jsx_entity());
+    }
+
+    fn cooked_jsx_value(&self, cooked_value: Option<String>, start_index: usize) -> Cow<'a, str> {
+        match cooked_value {
+            Some(cooked_value) => Cow::Owned(cooked_value),
+            None => Cow::Borrowed(self.source_slice(start_index..self.read_index)),
+        }
+    }
+
+    // HTML character references, e.g. `&amp;`, `&#123;` and `&#x7B;`.
+    // An `&` which does not start a valid reference is read as a literal `&`, the same as Babel and Acorn.
+    fn read_jsx_entity(&mut self) -> String {
+        let start_index = self.read_index;
+
+        let state = self.state();
+
+        self.read_char(); // Eat '&' char.
+
+        // Character references are at most 10 characters long between the `&` and `;`, the same as Acorn.
+        let mut name_length = 0;
+
+        while self.current_char() != ';' && !self.is_end_of_file() && name_length < 10 {
+            self.read_char();
+
+            name_length += 1;
+        }
+
+        if self.current_char() == ';' {
+            let name = self.source_slice(start_index + 1..self.read_index);
+
+            let ch = if let Some(hexadecimal) = name.strip_prefix("#x") {
+                u32::from_str_radix(hexadecimal, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+            } else if let Some(decimal) = name.strip_prefix('#') {
+                decimal.parse::<u32>().ok().and_then(char::from_u32)
+            } else {
+                xhtml_entity(name)
+            };
+
+            if let Some(ch) = ch {
+                self.read_char(); // Eat ';' char.
+
+                return ch.to_string();
+            }
+        }
+
+        self.restore_state(state);
+
+        self.read_char(); // Eat '&' char.
+
+        "&".to_string()
+    }
+}
@@ -1,66 +1,91 @@
-use crate::{parser::Context, ParserError, Token, TokenKind, TokenValue};
+use std::ops::Range;
 
-use char::LexerChar;
+use crate::{parser::Context, ParserError, ParserErrorKind, Token, TokenKind, TokenValue};
+
+pub(crate) use char::LexerChar;
+pub(crate) use comments::ScannedComment;
+pub use tokenizer::Tokenizer;
 
 mod char;
 mod comments;
 mod identifiers;
+mod jsx;
+mod jsx_entities;
 mod numbers;
 mod punctuators;
 mod regular_expression;
 mod strings;
 mod template_literal;
+mod tokenizer;
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum GoalSymbol {
     InputElementRegExp,
     InputElementRegExpOrTemplateTail,
     InputElementDiv,
+    // The names, attributes and punctuators between the `<` and `>` of a JSX tag.
+    JSXTag,
+    // The text, elements and expression containers between a JSX opening and closing tag.
+    JSXChild,
 }
 
+// The lexer scans the UTF-8 source directly, so `read_index` and all token positions are byte offsets into the source.
 #[derive(Clone, Debug)]
-pub struct Lexer {
+pub struct Lexer<'a> {
     pub context: Context,
     pub read_index: usize,
     pub line: usize,
     pub column: usize,
-    pub chars: Vec<char>,
+    pub source: &'a str,
     pub goal_symbol: GoalSymbol,
     pub template_literal_depth: usize,
+    // Every comment skipped so far, in source order.
+    pub(crate) comments: Vec<ScannedComment>,
 }
 
-impl Lexer {
-    pub fn new(input: &str) -> Self {
-        let mut lexer = Self::default();
-
-        lexer.chars = input.chars().collect();
-
-        Self::skip_comment_or_whitespace(&mut lexer, &mut false);
-
-        lexer
-    }
+// Snapshot of everything the lexer needs to resume scanning from a given position, without copying the source.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct LexerState {
+    pub read_index: usize,
+    pub line: usize,
+    pub column: usize,
+    pub goal_symbol: GoalSymbol,
+    pub template_literal_depth: usize,
+}
 
-    fn default() -> Self {
-        Self {
+impl<'a> Lexer<'a> {
+    pub fn new(input: &'a str) -> Self {
+        let mut lexer = Self {
             context: Context::default(),
             read_index: 0,
             line: 1,
             column: 1,
-            chars: Vec::new(),
+            source: input,
             goal_symbol: GoalSymbol::InputElementDiv,
             template_literal_depth: 0,
+            comments: Vec::new(),
+        };
+
+        if lexer.hashbang().is_some() {
+            lexer.skip_hashbang_comment();
         }
+
+        Self::skip_comment_or_whitespace(&mut lexer, &mut false);
+
+        lexer
     }
 
     pub fn read_char(&mut self) {
-        if self.current_char().is_line_terminator() {
+        let ch = self.current_char();
+
+        if ch.is_line_terminator() {
             self.line += 1;
             self.column = 1;
         } else {
             self.column += 1;
         }
 
-        self.read_index += 1;
+        self.read_index += ch.len_utf8();
     }
 
     pub fn read_char_nth(&mut self, offset: usize) {
@@ -70,10 +95,14 @@ impl Lexer {
     }
 
     pub fn current_char(&mut self) -> char {
-        if !self.is_end_of_file() {
-            self.chars[self.read_index]
-        } else {
-            '\0'
+        match self.source.as_bytes().get(self.read_index) {
+            // Fast path for ASCII chars, which are always a single byte.
+            Some(byte) if byte.is_ascii() => *byte as char,
+            Some(_) => self.source[self.read_index..]
+                .chars()
+                .next()
+                .unwrap_or('\0'),
+            None => '\0',
         }
     }
 
@@ -82,26 +111,66 @@ impl Lexer {
     }
 
     pub fn peek_char_nth(&mut self, offset: usize) -> char {
-        if self.read_index + offset < self.len() {
-            self.chars[self.read_index + offset]
-        } else {
-            '\0'
+        let bytes = self.source.as_bytes();
+
+        // Fast path for when all of the chars up to the offset are ASCII, and so are a single byte each.
+        if let Some(ascii_bytes) = bytes.get(self.read_index..=self.read_index + offset) {
+            if ascii_bytes.is_ascii() {
+                return ascii_bytes[offset] as char;
+            }
+        }
+
+        match self.source.get(self.read_index..) {
+            Some(remaining_source) => remaining_source.chars().nth(offset).unwrap_or('\0'),
+            None => '\0',
         }
     }
 
+    // Slice of the source between two byte offsets, which borrows from the source instead of the lexer.
+    pub(crate) fn source_slice(&self, range: Range<usize>) -> &'a str {
+        &self.source[range]
+    }
+
     pub fn len(&self) -> usize {
-        self.chars.len()
+        self.source.len()
     }
 
     pub fn is_empty(&self) -> bool {
-        self.chars.is_empty()
+        self.source.is_empty()
     }
 
     pub fn is_end_of_file(&mut self) -> bool {
         self.read_index >= self.len()
     }
 
-    pub fn rewind_token(&mut self, read_index: usize, line: usize, column: usize) -> Token {
+    pub(crate) fn state(&self) -> LexerState {
+        LexerState {
+            read_index: self.read_index,
+            line: self.line,
+            column: self.column,
+            goal_symbol: self.goal_symbol.clone(),
+            template_literal_depth: self.template_literal_depth,
+        }
+    }
+
+    pub(crate) fn restore_state(&mut self, state: LexerState) {
+        self.discard_comments_from(state.read_index);
+
+        self.read_index = state.read_index;
+        self.line = state.line;
+        self.column = state.column;
+        self.goal_symbol = state.goal_symbol;
+        self.template_literal_depth = state.template_literal_depth;
+    }
+
+    pub fn rewind_token(
+        &mut self,
+        read_index: usize,
+        line: usize,
+        column: usize,
+    ) -> Result<Token<'a>, ParserError> {
+        self.discard_comments_from(read_index);
+
         self.read_index = read_index;
         self.line = line;
         self.column = column;
@@ -109,16 +178,24 @@ impl Lexer {
         self.scan_token()
     }
 
-    pub fn next_token(&mut self) -> Token {
+    pub fn next_token(&mut self) -> Result<Token<'a>, ParserError> {
         self.scan_token()
     }
 
-    fn scan_token(&mut self) -> Token {
+    // An invalid token is returned as an error which spans the part of the token scanned before the error was found,
+    // and scanning continues after it.
+    fn scan_token(&mut self) -> Result<Token<'a>, ParserError> {
         let start_index = self.read_index;
         let start_line = self.line;
         let start_column = self.column;
 
-        let mut token = match self.current_char() {
+        let token = match self.current_char() {
+            _ if self.goal_symbol == GoalSymbol::JSXChild && !self.is_end_of_file() => {
+                self.scan_jsx_child()
+            }
+            _ if self.goal_symbol == GoalSymbol::JSXTag && !self.is_end_of_file() => {
+                self.scan_jsx_tag_token()
+            }
             '#' => self.scan_private_identifier(),
             '0'..='9' => self.scan_number_literal(),
             '.' if self.peek_char().is_ascii_digit() => self.scan_number_literal(),
@@ -133,40 +210,38 @@ impl Lexer {
             ch if ch.is_punctuator_start() => self.scan_punctuator(),
             ch if ch.is_identifier_start() => self.scan_identifier_name_or_keyword(),
             _ if self.is_end_of_file() => {
-                return Token::new(
+                return Ok(Token::new(
                     TokenKind::EOF,
                     start_index,
                     self.read_index,
                     self.line,
                     self.column,
                     TokenValue::Null,
-                );
+                ));
             }
-            _ => Err(ParserError::SyntaxError),
-        }
-        .unwrap_or_else(|err| {
-            Token::new(
-                TokenKind::Illegal,
-                start_index,
-                self.read_index,
-                self.line,
-                self.column,
-                TokenValue::String {
-                    raw: err.to_string(),
-                    value: err.to_string(),
-                },
-            )
-        });
+            // The invalid char is included in the error, so that the error spans it.
+            _ => {
+                self.read_char();
+
+                Err(ParserErrorKind::SyntaxError)
+            }
+        };
+
+        let end_index = self.read_index;
+
+        let mut line_terminator = false;
+
+        self.skip_comment_or_whitespace(&mut line_terminator);
+
+        let mut token = token.map_err(|kind| ParserError::new(kind, start_index, end_index))?;
 
         token.start = start_index;
-        token.end = self.read_index;
+        token.end = end_index;
         token.line = start_line;
         token.column = start_column;
-        token.line_terminator = false;
-
-        self.skip_comment_or_whitespace(&mut token.line_terminator);
+        token.line_terminator = line_terminator;
 
-        token
+        Ok(token)
     }
 
     fn skip_comment_or_whitespace(&mut self, has_line_terminator: &mut bool) {
@@ -174,7 +249,7 @@ impl Lexer {
             match self.current_char() {
                 '/' => match self.peek_char() {
                     '/' => self.skip_single_line_comment(),
-                    '*' => self.skip_multi_line_comment(),
+                    '*' => *has_line_terminator |= self.skip_multi_line_comment(),
                     _ => break,
                 },
                 ch if ch.is_js_whitespace() => {
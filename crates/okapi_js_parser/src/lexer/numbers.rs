@@ -1,6 +1,6 @@
 use super::char::LexerChar;
 
-use crate::{Lexer, ParserError, Token, TokenKind, TokenValue};
+use crate::{Lexer, ParserErrorKind, Token, TokenKind, TokenValue};
 
 const NUMERIC_LITERAL_SEPARATOR: char = '_';
 const DECIMAL: char = '.';
@@ -27,7 +27,7 @@ fn is_decimal_literal_char(ch: char) -> bool {
 }
 
 fn is_non_decimal_literal_char(ch: char, radix: u32) -> bool {
-    matches!(ch, NUMERIC_LITERAL_SEPARATOR | _ if ch.is_digit(radix))
+    ch == NUMERIC_LITERAL_SEPARATOR || ch.is_digit(radix)
 }
 
 fn match_num_kind_to_start_index_offset(num_kind: &NumKind) -> usize {
@@ -46,25 +46,25 @@ fn match_num_kind_to_radix(num_kind: &NumKind) -> u32 {
     }
 }
 
-fn match_num_kind_to_parse_error(num_kind: &NumKind) -> ParserError {
+fn match_num_kind_to_parse_error(num_kind: &NumKind) -> ParserErrorKind {
     match num_kind {
-        NumKind::Int | NumKind::Decimal => ParserError::InvalidDecimalLiteral,
-        NumKind::Binary => ParserError::InvalidNonDecimalBinaryNumberLiteral,
-        NumKind::Octal => ParserError::InvalidNonDecimalOctalNumberLiteral,
-        NumKind::Hexadecimal => ParserError::InvalidNonDecimalHexadecimalNumberLiteral,
-        NumKind::LegacyOctal => ParserError::InvalidLegacyOctalNumberLiteral,
+        NumKind::Int | NumKind::Decimal => ParserErrorKind::InvalidDecimalLiteral,
+        NumKind::Binary => ParserErrorKind::InvalidNonDecimalBinaryNumberLiteral,
+        NumKind::Octal => ParserErrorKind::InvalidNonDecimalOctalNumberLiteral,
+        NumKind::Hexadecimal => ParserErrorKind::InvalidNonDecimalHexadecimalNumberLiteral,
+        NumKind::LegacyOctal => ParserErrorKind::InvalidLegacyOctalNumberLiteral,
         NumKind::PositiveExponent | NumKind::NegativeExponent => {
-            ParserError::InvalidExponentPartNumberLiteral
+            ParserErrorKind::InvalidExponentPartNumberLiteral
         }
-        NumKind::BigInt => ParserError::InvalidDecimalBigIntegerLiteral,
+        NumKind::BigInt => ParserErrorKind::InvalidDecimalBigIntegerLiteral,
     }
 }
 
 // 12.9.3 Numeric Literals
 // https://tc39.es/ecma262/#sec-literals-numeric-literals
-impl Lexer {
+impl<'a> Lexer<'a> {
     // https://tc39.es/ecma262/#prod-NumericLiteral
-    pub(crate) fn scan_number_literal(&mut self) -> Result<Token, ParserError> {
+    pub(crate) fn scan_number_literal(&mut self) -> Result<Token<'a>, ParserErrorKind> {
         let start_index: usize = self.read_index;
 
         let num_kind = match (self.current_char(), self.peek_char()) {
@@ -77,7 +77,7 @@ impl Lexer {
                 self.read_legacy_octal_integer_literal()
             }
             ('0', _) => self.read_decimal_literal(),
-            (_, _) => Err(ParserError::SyntaxError),
+            (_, _) => Err(ParserErrorKind::SyntaxError),
         }?;
 
         match num_kind {
@@ -87,11 +87,7 @@ impl Lexer {
                 self.read_index,
                 self.line,
                 self.column,
-                TokenValue::BigInt(
-                    self.chars[start_index..self.read_index]
-                        .iter()
-                        .collect::<String>(),
-                ),
+                TokenValue::BigInt(self.source_slice(start_index..self.read_index)),
             )),
             _ => {
                 let number_literal_f64 = self.parse_num_str_to_f64(
@@ -106,9 +102,7 @@ impl Lexer {
                     self.line,
                     self.column,
                     TokenValue::Number {
-                        raw: self.chars[start_index..self.read_index]
-                            .iter()
-                            .collect::<String>(),
+                        raw: self.source_slice(start_index..self.read_index),
                         value: number_literal_f64,
                     },
                 ))
@@ -117,7 +111,9 @@ impl Lexer {
     }
 
     // https://tc39.es/ecma262/#prod-DecimalLiteral
-    fn read_decimal_literal(&mut self) -> Result<NumKind, ParserError> {
+    fn read_decimal_literal(&mut self) -> Result<NumKind, ParserErrorKind> {
+        let start_index = self.read_index;
+
         let mut num_kind = NumKind::Int;
 
         while is_decimal_literal_char(self.current_char()) && !self.is_end_of_file() {
@@ -127,10 +123,10 @@ impl Lexer {
 
                     num_kind = match self.current_char() {
                         ch if !ch.is_ascii_digit() => {
-                            return Err(ParserError::InvalidDecimalLiteral);
+                            return Err(ParserErrorKind::InvalidDecimalLiteral);
                         }
                         NUMERIC_LITERAL_SEPARATOR => {
-                            return Err(ParserError::InvalidNumericSeparatorAtSibling);
+                            return Err(ParserErrorKind::InvalidNumericSeparatorAtSibling);
                         }
                         _ => NumKind::Decimal,
                     }
@@ -140,11 +136,11 @@ impl Lexer {
 
                     match self.current_char() {
                         NUMERIC_LITERAL_SEPARATOR | DECIMAL => {
-                            return Err(ParserError::InvalidNumericSeparatorAtSibling);
+                            return Err(ParserErrorKind::InvalidNumericSeparatorAtSibling);
                         }
 
                         ch if !ch.is_ascii_digit() => {
-                            return Err(ParserError::InvalidNumericSeparatorAtEnd);
+                            return Err(ParserErrorKind::InvalidNumericSeparatorAtEnd);
                         }
                         _ => {}
                     }
@@ -157,7 +153,7 @@ impl Lexer {
                         '-' => NumKind::NegativeExponent,
                         ch if ch.is_ascii_digit() => NumKind::PositiveExponent,
                         _ => {
-                            return Err(ParserError::InvalidExponentPartNumberLiteral);
+                            return Err(ParserErrorKind::InvalidExponentPartNumberLiteral);
                         }
                     }
                 }
@@ -167,9 +163,17 @@ impl Lexer {
             self.read_char();
         }
 
-        if self.current_char() == BIG_INT_SUFFIX && num_kind == NumKind::Int {
+        if self.current_char() == BIG_INT_SUFFIX {
+            // https://tc39.es/ecma262/#prod-DecimalBigIntegerLiteral
+            let digits = self.source_slice(start_index..self.read_index);
+            let has_leading_zero = digits.len() > 1 && digits.starts_with('0');
+
             self.read_char(); // Eat 'n' char.
 
+            if num_kind != NumKind::Int || has_leading_zero {
+                return Err(ParserErrorKind::InvalidDecimalBigIntegerLiteral);
+            }
+
             return Ok(NumKind::BigInt);
         }
 
@@ -180,7 +184,7 @@ impl Lexer {
     fn read_non_decimal_integer_literal(
         &mut self,
         num_kind: NumKind,
-    ) -> Result<NumKind, ParserError> {
+    ) -> Result<NumKind, ParserErrorKind> {
         self.read_char(); // Eat '0' char.
         self.read_char(); // Eat 'b', 'o' or 'x' char.
 
@@ -190,11 +194,11 @@ impl Lexer {
 
                 match self.current_char() {
                     NUMERIC_LITERAL_SEPARATOR => {
-                        return Err(ParserError::InvalidNumericSeparatorAtSibling);
+                        return Err(ParserErrorKind::InvalidNumericSeparatorAtSibling);
                     }
 
-                    ch if !ch.is_ascii_digit() => {
-                        return Err(ParserError::InvalidNumericSeparatorAtEnd);
+                    ch if !ch.is_digit(match_num_kind_to_radix(&num_kind)) => {
+                        return Err(ParserErrorKind::InvalidNumericSeparatorAtEnd);
                     }
                     _ => {}
                 }
@@ -203,7 +207,7 @@ impl Lexer {
             self.read_char();
         }
 
-        if self.current_char() == BIG_INT_SUFFIX && num_kind == NumKind::Int {
+        if self.current_char() == BIG_INT_SUFFIX {
             self.read_char(); // Eat 'n' char.
 
             return Ok(NumKind::BigInt);
@@ -213,9 +217,9 @@ impl Lexer {
     }
 
     // https://tc39.es/ecma262/#prod-LegacyOctalIntegerLiteral
-    fn read_legacy_octal_integer_literal(&mut self) -> Result<NumKind, ParserError> {
+    fn read_legacy_octal_integer_literal(&mut self) -> Result<NumKind, ParserErrorKind> {
         if self.context.strict_mode {
-            return Err(ParserError::InvalidLegacyOctalNumberLiteralNotAllowedInStrictMode);
+            return Err(ParserErrorKind::InvalidLegacyOctalNumberLiteralNotAllowedInStrictMode);
         }
 
         self.read_char(); // Eat '0' char.
@@ -224,6 +228,12 @@ impl Lexer {
             self.read_char();
         }
 
+        if self.current_char() == BIG_INT_SUFFIX {
+            self.read_char(); // Eat 'n' char.
+
+            return Err(ParserErrorKind::InvalidDecimalBigIntegerLiteral);
+        }
+
         Ok(NumKind::LegacyOctal)
     }
 
@@ -231,10 +241,9 @@ impl Lexer {
         &mut self,
         num_kind: &NumKind,
         start_index: usize,
-    ) -> Result<f64, ParserError> {
-        let number_literal_str = &self.chars[start_index..self.read_index]
-            .iter()
-            .collect::<String>()
+    ) -> Result<f64, ParserErrorKind> {
+        let number_literal_str = &self
+            .source_slice(start_index..self.read_index)
             .replace(NUMERIC_LITERAL_SEPARATOR, "");
 
         let radix = match_num_kind_to_radix(num_kind);
@@ -1,10 +1,10 @@
-use crate::{Lexer, ParserError, Token, TokenKind, TokenValue};
+use crate::{Lexer, ParserErrorKind, Token, TokenKind, TokenValue};
 
 // 12.8 Punctuators
 // https://tc39.es/ecma262/#sec-punctuators
-impl Lexer {
+impl<'a> Lexer<'a> {
     // https://tc39.es/ecma262/#prod-Punctuator
-    pub(crate) fn scan_punctuator(&mut self) -> Result<Token, ParserError> {
+    pub(crate) fn scan_punctuator(&mut self) -> Result<Token<'a>, ParserErrorKind> {
         let start_index = self.read_index;
 
         let token_kind = match self.current_char() {
@@ -228,6 +228,7 @@ impl Lexer {
                 }
             }
             '~' => TokenKind::BitwiseNot,
+            '@' => TokenKind::At,
             '?' => {
                 let peek_char = self.peek_char();
                 let peek_char_1 = self.peek_char_nth(2);
@@ -249,7 +250,7 @@ impl Lexer {
                 }
             }
             ':' => TokenKind::Colon,
-            _ => return Err(ParserError::SyntaxError),
+            _ => return Err(ParserErrorKind::SyntaxError),
         };
 
         self.read_char();
@@ -1,11 +1,11 @@
 use super::char::LexerChar;
-use crate::{Lexer, ParserError, Token, TokenKind, TokenValue};
+use crate::{Lexer, ParserErrorKind, Token, TokenKind, TokenValue};
 
 // 12.9.5 Regular Expression Literals
 // https://tc39.es/ecma262/#sec-literals-regular-expression-literals
-impl Lexer {
+impl<'a> Lexer<'a> {
     // https://tc39.es/ecma262/#prod-RegularExpressionLiteral
-    pub(crate) fn scan_regular_expression_literal(&mut self) -> Result<Token, ParserError> {
+    pub(crate) fn scan_regular_expression_literal(&mut self) -> Result<Token<'a>, ParserErrorKind> {
         let start_index = self.read_index;
 
         self.read_char(); // Eat '/' char.
@@ -23,22 +23,24 @@ impl Lexer {
             self.line,
             self.column,
             TokenValue::RegularExpression {
-                pattern: regular_expression_body.to_string(),
-                flags: regular_expression_flags.to_string(),
+                pattern: regular_expression_body,
+                flags: regular_expression_flags,
             },
         ))
     }
 
     // https://tc39.es/ecma262/#prod-RegularExpressionBody
-    fn read_regular_expression_body(&mut self) -> Result<String, ParserError> {
+    fn read_regular_expression_body(&mut self) -> Result<&'a str, ParserErrorKind> {
         let start_index = self.read_index;
 
         let mut in_class = false;
         let mut in_backslash_sequence = false;
 
         match self.current_char() {
-            '*' | '/' => return Err(ParserError::InvalidRegexLiteralFirstChar),
-            ch if ch.is_line_terminator() => return Err(ParserError::InvalidRegexLiteralFirstChar),
+            '*' | '/' => return Err(ParserErrorKind::InvalidRegexLiteralFirstChar),
+            ch if ch.is_line_terminator() => {
+                return Err(ParserErrorKind::InvalidRegexLiteralFirstChar)
+            }
             '\\' => in_backslash_sequence = true,
             '[' => in_class = true,
             _ => {}
@@ -49,18 +51,25 @@ impl Lexer {
         while self.current_char() != '/' || in_class || in_backslash_sequence {
             if self.current_char().is_line_terminator() {
                 if in_class {
-                    return Err(ParserError::InvalidRegexExpressionClass);
+                    return Err(ParserErrorKind::InvalidRegexExpressionClass);
                 } else if in_backslash_sequence {
-                    return Err(ParserError::InvalidRegexBackslashSequence);
+                    return Err(ParserErrorKind::InvalidRegexBackslashSequence);
                 }
             }
 
             match self.current_char() {
-                ch if ch.is_line_terminator() => return Err(ParserError::UnterminatedRegExLiteral),
+                _ if self.is_end_of_file() => {
+                    return Err(ParserErrorKind::UnterminatedRegExLiteral)
+                }
+                ch if ch.is_line_terminator() => {
+                    return Err(ParserErrorKind::UnterminatedRegExLiteral)
+                }
                 '\\' => in_backslash_sequence = !in_backslash_sequence,
                 '[' if !in_backslash_sequence => in_class = true,
                 ']' if !in_backslash_sequence => in_class = false,
-                ch if ch.is_line_terminator() => return Err(ParserError::UnterminatedRegExLiteral),
+                ch if ch.is_line_terminator() => {
+                    return Err(ParserErrorKind::UnterminatedRegExLiteral)
+                }
                 _ => in_backslash_sequence = false,
             };
 
@@ -68,24 +77,21 @@ impl Lexer {
         }
 
         if in_class || in_backslash_sequence {
-            return Err(ParserError::UnterminatedRegExLiteral);
+            return Err(ParserErrorKind::UnterminatedRegExLiteral);
         }
 
-        Ok(self.chars[start_index..self.read_index]
-            .iter()
-            .collect::<String>())
+        Ok(self.source_slice(start_index..self.read_index))
     }
 
+    // Any identifier part is scanned as a flag, and unknown or duplicate flags are reported by the parser as early errors.
     // https://tc39.es/ecma262/#prod-RegularExpressionFlags
-    fn read_regular_expression_flags(&mut self) -> String {
+    fn read_regular_expression_flags(&mut self) -> &'a str {
         let start_index = self.read_index;
 
-        while matches!(self.current_char(), 'd' | 'g' | 'i' | 'm' | 's' | 'u' | 'y') {
+        while !self.is_end_of_file() && self.current_char().is_identifier_part() {
             self.read_char();
         }
 
-        self.chars[start_index..self.read_index]
-            .iter()
-            .collect::<String>()
+        self.source_slice(start_index..self.read_index)
     }
 }
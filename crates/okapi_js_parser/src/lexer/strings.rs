@@ -1,4 +1,6 @@
-use crate::{Lexer, ParserError, Token, TokenKind, TokenValue};
+use std::borrow::Cow;
+
+use crate::{Lexer, ParserErrorKind, Token, TokenKind, TokenValue};
 
 use super::char::LexerChar;
 
@@ -23,21 +25,56 @@ fn format_invalid_code_point_string(code_point_u32: u32) -> String {
 
 // 12.9.4 String Literals
 // https://tc39.es/ecma262/#sec-literals-string-literals
-impl Lexer {
+impl<'a> Lexer<'a> {
+    // An invalid string literal is skipped up to its end quote, so that scanning continues after the whole literal
+    // rather than from within it.
+    pub(crate) fn scan_string_literal(&mut self) -> Result<Token<'a>, ParserErrorKind> {
+        let start_quote_character = self.current_char(); // '\'' | '"'
+
+        let string_literal = self.read_string_literal();
+
+        if string_literal.is_err() {
+            while !self.is_end_of_file() && self.current_char() != start_quote_character {
+                if self.current_char() == '\\' {
+                    self.read_char(); // Eat '\' char, so that an escaped quote is skipped.
+                }
+
+                self.read_char();
+            }
+
+            if !self.is_end_of_file() {
+                self.read_char(); // Eat end quote char.
+            }
+        }
+
+        string_literal
+    }
+
     // https://tc39.es/ecma262/#prod-StringLiteral
-    pub(crate) fn scan_string_literal(&mut self) -> Result<Token, ParserError> {
+    fn read_string_literal(&mut self) -> Result<Token<'a>, ParserErrorKind> {
         let start_index = self.read_index;
 
         let start_quote_character = self.current_char(); // '\'' | '"'
 
-        let mut string_literal = String::new();
+        // The value is only copied from the source text once it differs from it, at the first escape sequence.
+        let mut cooked_value: Option<String> = None;
 
         self.read_char(); // Eat start quote char.
 
+        let value_start_index = self.read_index;
+
         while self.current_char() != start_quote_character {
             match self.current_char() {
+                _ if self.is_end_of_file() => {
+                    return Err(ParserErrorKind::UnterminatedStringLiteral)
+                }
                 ch if ch == start_quote_character => break,
                 '\\' => {
+                    let string_literal = cooked_value.get_or_insert_with(|| {
+                        self.source_slice(value_start_index..self.read_index)
+                            .to_string()
+                    });
+
                     self.read_char(); // Eat '\' char.
 
                     match self.current_char() {
@@ -62,7 +99,7 @@ impl Lexer {
 
                                     continue;
                                 }
-                                _ => return Err(ParserError::InvalidHexadecimalEscapeSequence),
+                                _ => return Err(ParserErrorKind::InvalidHexadecimalEscapeSequence),
                             }
                         }
                         'u' => {
@@ -92,11 +129,9 @@ impl Lexer {
 
                                             continue;
                                         }
-                                        _ => {
-                                            return Err(
-                                                ParserError::InvalidUnicodeCodePointEscapeSequence,
-                                            )
-                                        }
+                                        _ => return Err(
+                                            ParserErrorKind::InvalidUnicodeCodePointEscapeSequence,
+                                        ),
                                     }
                                 }
                                 SurrogatePair::LeadingValidTrailingInvalid(
@@ -130,25 +165,37 @@ impl Lexer {
 
                                     continue;
                                 }
-                                _ => return Err(ParserError::InvalidLegacyOctalEscapeSequence),
+                                _ => return Err(ParserErrorKind::InvalidLegacyOctalEscapeSequence),
                             }
                         }
                         _ => string_literal.push(self.current_char()),
                     }
                 }
                 // Invalid line terminator chars.
-                token_kind if token_kind.is_line_terminator() => {}
-                _ => string_literal.push(self.current_char()),
+                token_kind if token_kind.is_line_terminator() => {
+                    cooked_value.get_or_insert_with(|| {
+                        self.source_slice(value_start_index..self.read_index)
+                            .to_string()
+                    });
+                }
+                ch => {
+                    if let Some(string_literal) = &mut cooked_value {
+                        string_literal.push(ch);
+                    }
+                }
             }
 
             self.read_char();
         }
 
+        let string_literal = match cooked_value {
+            Some(string_literal) => Cow::Owned(string_literal),
+            None => Cow::Borrowed(self.source_slice(value_start_index..self.read_index)),
+        };
+
         self.read_char(); // Eat end quote char.
 
-        let raw_string_literal = self.chars[start_index..self.read_index]
-            .iter()
-            .collect::<String>();
+        let raw_string_literal = self.source_slice(start_index..self.read_index);
 
         Ok(Token::new(
             TokenKind::StringLiteral,
@@ -164,24 +211,22 @@ impl Lexer {
     }
 
     // https://tc39.es/ecma262/#prod-HexEscapeSequence
-    pub(crate) fn read_hexadecimal_escape_sequence_u32(&mut self) -> Result<u32, ParserError> {
+    pub(crate) fn read_hexadecimal_escape_sequence_u32(&mut self) -> Result<u32, ParserErrorKind> {
         let start_index = self.read_index;
 
         for _ in 0..2 {
             if !self.current_char().is_ascii_hexdigit() {
-                return Err(ParserError::InvalidHexadecimalEscapeSequence);
+                return Err(ParserErrorKind::InvalidHexadecimalEscapeSequence);
             }
 
             self.read_char();
         }
 
-        let hex_str = &self.chars[start_index..self.read_index]
-            .iter()
-            .collect::<String>();
+        let hex_str = self.source_slice(start_index..self.read_index);
 
         match u32::from_str_radix(hex_str, 16) {
             Ok(hex_u32) => Ok(hex_u32),
-            Err(_) => Err(ParserError::InvalidHexadecimalEscapeSequence),
+            Err(_) => Err(ParserErrorKind::InvalidHexadecimalEscapeSequence),
         }
     }
 
@@ -192,7 +237,7 @@ impl Lexer {
     // as a code point with the value (c1 - 0xD800) × 0x400 + (c2 - 0xDC00) + 0x10000."
     fn read_potential_unicode_or_code_point_surrogate_pairs(
         &mut self,
-    ) -> Result<SurrogatePair, ParserError> {
+    ) -> Result<SurrogatePair, ParserErrorKind> {
         let leading_surrogate = match self.current_char() {
             '{' => self.read_code_point_escape_sequence(),
             _ => self.read_unicode_escape_sequence(),
@@ -230,23 +275,21 @@ impl Lexer {
     }
 
     // https://tc39.es/ecma262/#prod-UnicodeEscapeSequence
-    pub fn read_unicode_escape_sequence(&mut self) -> Result<u32, ParserError> {
+    pub fn read_unicode_escape_sequence(&mut self) -> Result<u32, ParserErrorKind> {
         let start_index = self.read_index;
 
         for _ in 0..4 {
             if !self.current_char().is_ascii_hexdigit() {
-                return Err(ParserError::InvalidUnicodeEscapeSequence);
+                return Err(ParserErrorKind::InvalidUnicodeEscapeSequence);
             }
 
             self.read_char();
         }
 
-        let unicode_str = &self.chars[start_index..self.read_index]
-            .iter()
-            .collect::<String>();
+        let unicode_str = self.source_slice(start_index..self.read_index);
 
         if unicode_str.len() < 4 {
-            return Err(ParserError::InvalidUnicodeEscapeSequence);
+            return Err(ParserErrorKind::InvalidUnicodeEscapeSequence);
         }
 
         if let Ok(unicode_u32) = u32::from_str_radix(unicode_str, 16) {
@@ -256,29 +299,27 @@ impl Lexer {
             }
         }
 
-        Err(ParserError::InvalidUnicodeEscapeSequence)
+        Err(ParserErrorKind::InvalidUnicodeEscapeSequence)
     }
 
     // https://tc39.es/ecma262/#prod-UnicodeEscapeSequence
-    fn read_code_point_escape_sequence(&mut self) -> Result<u32, ParserError> {
+    fn read_code_point_escape_sequence(&mut self) -> Result<u32, ParserErrorKind> {
         self.read_char(); // Eat '{' char.
 
         let start_index = self.read_index;
 
         while self.current_char() != '}' {
             if !self.current_char().is_ascii_hexdigit() {
-                return Err(ParserError::InvalidUnicodeCodePointEscapeSequence);
+                return Err(ParserErrorKind::InvalidUnicodeCodePointEscapeSequence);
             }
 
             self.read_char();
         }
 
-        let code_point_str = &self.chars[start_index..self.read_index]
-            .iter()
-            .collect::<String>();
+        let code_point_str = self.source_slice(start_index..self.read_index);
 
         if code_point_str.len() < 4 {
-            return Err(ParserError::InvalidUnicodeEscapeSequence);
+            return Err(ParserErrorKind::InvalidUnicodeEscapeSequence);
         }
 
         if let Ok(code_point_value_u32) = u32::from_str_radix(code_point_str, 16) {
@@ -289,13 +330,13 @@ impl Lexer {
             }
         }
 
-        Err(ParserError::InvalidUnicodeCodePointEscapeSequence)
+        Err(ParserErrorKind::InvalidUnicodeCodePointEscapeSequence)
     }
 
     // https://tc39.es/ecma262/#prod-LegacyOctalEscapeSequence
-    fn read_octal_escape_sequence(&mut self) -> Result<u32, ParserError> {
+    fn read_octal_escape_sequence(&mut self) -> Result<u32, ParserErrorKind> {
         if self.context.strict_mode {
-            return Err(ParserError::InvalidLegacyOctalEscapeSequenceNotAllowedInStrictMode);
+            return Err(ParserErrorKind::InvalidLegacyOctalEscapeSequenceNotAllowedInStrictMode);
         }
 
         let start_index = self.read_index;
@@ -311,16 +352,14 @@ impl Lexer {
                 }
             }
             '4'..='7' if self.peek_char().is_ascii_octaldigit() => self.read_char(),
-            _ => return Err(ParserError::InvalidLegacyOctalEscapeSequence),
+            _ => return Err(ParserErrorKind::InvalidLegacyOctalEscapeSequence),
         }
 
-        let octal_str = &self.chars[start_index..self.read_index]
-            .iter()
-            .collect::<String>();
+        let octal_str = self.source_slice(start_index..self.read_index);
 
         match u32::from_str_radix(octal_str, 8) {
             Ok(octal_u32) => Ok(octal_u32),
-            Err(_) => Err(ParserError::InvalidLegacyOctalEscapeSequence),
+            Err(_) => Err(ParserErrorKind::InvalidLegacyOctalEscapeSequence),
         }
     }
 }
@@ -1,12 +1,14 @@
-use crate::{GoalSymbol, Lexer, ParserError, Token, TokenKind, TokenValue};
+use std::borrow::Cow;
+
+use crate::{GoalSymbol, Lexer, ParserErrorKind, Token, TokenKind, TokenValue};
 
 use super::char::LexerChar;
 
 // 12.9.6 Template Literal Lexical Components
 // https://tc39.es/ecma262/#sec-template-literal-lexical-components
-impl Lexer {
+impl<'a> Lexer<'a> {
     // https://tc39.es/ecma262/#prod-Template
-    pub(crate) fn scan_template_literal(&mut self) -> Result<Token, ParserError> {
+    pub(crate) fn scan_template_literal(&mut self) -> Result<Token<'a>, ParserErrorKind> {
         let is_head = self.current_char() == '`';
         let mut is_tail = false;
 
@@ -19,7 +21,8 @@ impl Lexer {
         let start_index = self.read_index;
         let mut end_index = self.read_index;
 
-        let mut cooked_string_literal = String::new();
+        // The cooked value is only copied from the source text once it differs from it, at the first escape sequence.
+        let mut cooked_value: Option<String> = None;
 
         while self.current_char() != '\0' {
             match self.current_char() {
@@ -45,6 +48,10 @@ impl Lexer {
                     break;
                 }
                 '\\' => {
+                    let cooked_string_literal = cooked_value.get_or_insert_with(|| {
+                        self.source_slice(start_index..self.read_index).to_string()
+                    });
+
                     self.read_char(); // Eat '\' char.
 
                     match self.current_char() {
@@ -72,7 +79,9 @@ impl Lexer {
 
                                     continue;
                                 }
-                                None => return Err(ParserError::InvalidHexadecimalEscapeSequence),
+                                None => {
+                                    return Err(ParserErrorKind::InvalidHexadecimalEscapeSequence)
+                                }
                             }
                         }
                         ch if ch.is_line_terminator() => {
@@ -83,17 +92,22 @@ impl Lexer {
                         ch => cooked_string_literal.push(ch),
                     }
                 }
-                _ => {
-                    cooked_string_literal.push(self.current_char());
+                ch => {
+                    if let Some(cooked_string_literal) = &mut cooked_value {
+                        cooked_string_literal.push(ch);
+                    }
 
                     self.read_char()
                 }
             };
         }
 
-        let raw_string_literal = self.chars[start_index..end_index]
-            .iter()
-            .collect::<String>();
+        let raw_string_literal = self.source_slice(start_index..end_index);
+
+        let cooked_string_literal = match cooked_value {
+            Some(cooked_string_literal) => Cow::Owned(cooked_string_literal),
+            None => Cow::Borrowed(raw_string_literal),
+        };
 
         let token_kind = match (is_head, is_tail) {
             (true, true) => TokenKind::TemplateNoSubstitution,
@@ -0,0 +1,222 @@
+use crate::{
+    parser::Locations, GoalSymbol, KeywordKind, Lexer, OffsetEncoding, ParserError, Token,
+    TokenKind,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Brace {
+    // A block, function body or class body, which is followed by the start of a statement.
+    Block,
+    // An object literal or pattern, which is followed by an operator.
+    Expression,
+    // A template substitution, which is closed by the `}` at the start of the next template part.
+    Template,
+}
+
+// Scans a source text into tokens without parsing it, choosing the goal symbol for each token from the tokens before
+// it, rather than from the syntactic context. A `/` or `/=` is a regular expression wherever an expression can start,
+// and a `}` continues a template literal when it closes a template substitution.
+// https://tc39.es/ecma262/#sec-ecmascript-language-lexical-grammar
+//
+// Like other tokenizers which don't parse, the goal symbol can only be guessed where the grammar is ambiguous without
+// more context, e.g. a `/` after the `}` of a function expression is scanned as a regular expression. JSX and
+// TypeScript syntax are not supported.
+#[derive(Clone, Debug)]
+pub struct Tokenizer<'a> {
+    lexer: Lexer<'a>,
+    // Converts the lexer's byte offsets into the selected offset encoding.
+    locations: Locations<'a>,
+    braces: Vec<Brace>,
+    // Whether each open parenthesis holds the condition of an `if`, `for`, `while` or `with` statement, which is
+    // followed by the start of a statement rather than an operator.
+    parentheses: Vec<bool>,
+    previous_token: Option<Token<'a>>,
+    // Whether a regular expression can start after the previous token.
+    regular_expression_allowed: bool,
+    done: bool,
+}
+
+impl<'a> Tokenizer<'a> {
+    pub fn new(source: &'a str) -> Self {
+        Self {
+            lexer: Lexer::new(source),
+            locations: Locations::new(source),
+            braces: Vec::new(),
+            parentheses: Vec::new(),
+            previous_token: None,
+            regular_expression_allowed: true,
+            done: false,
+        }
+    }
+
+    // Token and error offsets are UTF-8 byte offsets by default, the same as the `Lexer`.
+    pub fn offset_encoding(mut self, offset_encoding: OffsetEncoding) -> Self {
+        self.locations.set_offset_encoding(offset_encoding);
+
+        self
+    }
+
+    fn goal_symbol(&mut self) -> GoalSymbol {
+        if self.lexer.current_char() == '}' && self.braces.last() == Some(&Brace::Template) {
+            GoalSymbol::InputElementRegExpOrTemplateTail
+        } else if self.regular_expression_allowed {
+            GoalSymbol::InputElementRegExp
+        } else {
+            GoalSymbol::InputElementDiv
+        }
+    }
+
+    fn update_context(&mut self, token: &Token<'a>) {
+        let mut token = token.clone();
+
+        // A keyword after `.` or `?.` is a property name, e.g. `a.default / 2`, so it is treated as an identifier.
+        if matches!(token.kind, TokenKind::Keyword(_))
+            && matches!(
+                self.previous_token.as_ref().map(|token| &token.kind),
+                Some(TokenKind::Dot | TokenKind::OptionalChaining)
+            )
+        {
+            token.kind = TokenKind::Identifier;
+        }
+
+        self.regular_expression_allowed = match &token.kind {
+            TokenKind::LeftCurlyBrace => {
+                let brace = self.brace_kind();
+
+                self.braces.push(brace);
+
+                true
+            }
+            TokenKind::RightCurlyBrace => self.braces.pop() != Some(Brace::Expression),
+            TokenKind::TemplateHead => {
+                self.braces.push(Brace::Template);
+
+                true
+            }
+            TokenKind::TemplateMiddle => true,
+            TokenKind::TemplateTail => {
+                self.braces.pop();
+
+                false
+            }
+            TokenKind::LeftParenthesis => {
+                let is_statement_condition = matches!(
+                    self.previous_token.as_ref().map(|token| &token.kind),
+                    Some(TokenKind::Keyword(
+                        KeywordKind::If | KeywordKind::For | KeywordKind::While | KeywordKind::With
+                    ))
+                );
+
+                self.parentheses.push(is_statement_condition);
+
+                true
+            }
+            TokenKind::RightParenthesis => self.parentheses.pop().unwrap_or(false),
+            TokenKind::Keyword(
+                KeywordKind::This
+                | KeywordKind::Super
+                | KeywordKind::Null
+                | KeywordKind::True
+                | KeywordKind::False,
+            ) => false,
+            // Contextual keywords can be used as identifiers, e.g. `of / 2`.
+            kind @ TokenKind::Keyword(_) => kind.is_reserved_keyword(),
+            TokenKind::Identifier
+            | TokenKind::PrivateIdentifier
+            | TokenKind::StringLiteral
+            | TokenKind::NumberLiteral
+            | TokenKind::BigIntLiteral
+            | TokenKind::RegularExpressionLiteral
+            | TokenKind::TemplateNoSubstitution
+            | TokenKind::RightSquareBracket
+            | TokenKind::Increment
+            | TokenKind::Decrement => false,
+            _ => true,
+        };
+
+        self.previous_token = Some(token);
+    }
+
+    // Whether a `{` opens an object literal, based on the token before it, similarly to Acorn's `braceIsBlock`.
+    // https://github.com/acornjs/acorn/blob/master/acorn/src/tokencontext.js
+    fn brace_kind(&self) -> Brace {
+        let Some(previous_token) = &self.previous_token else {
+            return Brace::Block;
+        };
+
+        match &previous_token.kind {
+            // A line terminator after `return` ends the statement, so the `{` opens a block.
+            TokenKind::Keyword(KeywordKind::Return) if previous_token.line_terminator => {
+                Brace::Block
+            }
+            TokenKind::Colon if self.braces.last() == Some(&Brace::Expression) => Brace::Expression,
+            TokenKind::Colon
+            | TokenKind::Semicolon
+            | TokenKind::LeftCurlyBrace
+            | TokenKind::RightCurlyBrace
+            | TokenKind::RightParenthesis
+            | TokenKind::ArrowFunction
+            | TokenKind::Identifier
+            | TokenKind::Keyword(_)
+                if !matches!(
+                    previous_token.kind,
+                    TokenKind::Keyword(
+                        KeywordKind::Return
+                            | KeywordKind::Typeof
+                            | KeywordKind::Void
+                            | KeywordKind::Delete
+                            | KeywordKind::In
+                            | KeywordKind::Instanceof
+                            | KeywordKind::New
+                            | KeywordKind::Yield
+                            | KeywordKind::Await
+                            | KeywordKind::Case
+                            | KeywordKind::Of
+                    )
+                ) =>
+            {
+                Brace::Block
+            }
+            _ => Brace::Expression,
+        }
+    }
+}
+
+impl<'a> Iterator for Tokenizer<'a> {
+    type Item = Result<Token<'a>, ParserError>;
+
+    // The end of the source is not returned as a token, and an invalid token ends the iteration after its error, which
+    // spans the invalid token in the same offset encoding as the tokens.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        self.lexer.goal_symbol = self.goal_symbol();
+
+        let mut token = match self.lexer.next_token() {
+            Ok(token) => token,
+            Err(mut error) => {
+                self.done = true;
+
+                error.span.start = self.locations.offset(error.span.start);
+                error.span.end = self.locations.offset(error.span.end);
+
+                return Some(Err(error));
+            }
+        };
+
+        if token.kind == TokenKind::EOF {
+            self.done = true;
+
+            return None;
+        }
+
+        self.update_context(&token);
+
+        token.start = self.locations.offset(token.start);
+        token.end = self.locations.offset(token.end);
+
+        Some(Ok(token))
+    }
+}
@@ -1,12 +1,17 @@
-pub use errors::ParserError;
+pub use config::{ECMAVersion, OffsetEncoding, ParserOptions};
+pub use errors::{ParserError, ParserErrorKind, ParserErrorReport};
+pub use features::{Feature, FeatureReport, FeatureUsage};
 pub use lexer::GoalSymbol;
 pub use lexer::Lexer;
+pub use lexer::Tokenizer;
 pub use parser::{Cursor, Params, Parser};
 pub use tokens::{KeywordKind, Token, TokenKind, TokenValue};
 
-mod ast;
+pub mod ast;
 mod config;
 mod errors;
+mod features;
 mod lexer;
 mod parser;
+mod regexp;
 mod tokens;
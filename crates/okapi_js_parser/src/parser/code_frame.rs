@@ -0,0 +1,67 @@
+use crate::Parser;
+
+// The number of lines shown before and after the line of the error, the same as Babel's code frames.
+// https://github.com/babel/babel/tree/main/packages/babel-code-frame
+const LINES_ABOVE: usize = 2;
+const LINES_BELOW: usize = 3;
+
+impl Parser<'_> {
+    // Renders the source lines around an error, with a `>` before the line of the error and carets under its span, e.g.
+    //
+    //   1 | let a = 1;
+    // > 2 | let b = ;
+    //     |         ^
+    pub(crate) fn code_frame(&self, start_index: usize, end_index: usize) -> String {
+        let error_line = self.locations.position(start_index).line;
+
+        let first_line = error_line.saturating_sub(LINES_ABOVE).max(1);
+        let last_line = (error_line + LINES_BELOW).min(self.locations.line_count());
+
+        let gutter_width = last_line.to_string().len();
+
+        let mut lines = Vec::new();
+
+        for line in first_line..=last_line {
+            let line_range = self.locations.line_range(line);
+            let line_source = self.cursor.lexer.source_slice(line_range.clone());
+
+            let gutter = if line == error_line { '>' } else { ' ' };
+
+            lines.push(
+                format!("{} {:>gutter_width$} | {}", gutter, line, line_source)
+                    .trim_end()
+                    .to_string(),
+            );
+
+            if line != error_line {
+                continue;
+            }
+
+            // Tabs are kept in the marker, so that the carets line up however tabs are displayed.
+            let marker_indent: String = self
+                .cursor
+                .lexer
+                .source_slice(line_range.start..start_index)
+                .chars()
+                .map(|ch| if ch == '\t' { '\t' } else { ' ' })
+                .collect();
+
+            let marker_width = self
+                .cursor
+                .lexer
+                .source_slice(start_index..end_index.clamp(start_index, line_range.end))
+                .chars()
+                .count()
+                .max(1);
+
+            lines.push(format!(
+                "  {:>gutter_width$} | {}{}",
+                "",
+                marker_indent,
+                "^".repeat(marker_width)
+            ));
+        }
+
+        lines.join("\n")
+    }
+}
@@ -0,0 +1,139 @@
+use crate::{
+    ast::{Comment, CommentKind, Node, Program},
+    lexer::ScannedComment,
+    Parser,
+};
+
+use nodes::NodeCollector;
+
+mod nodes;
+
+// A node which is still open while the nodes are visited in source order, and the last of its children visited so far.
+struct OpenNode {
+    start: usize,
+    end: usize,
+    last_child: Option<usize>,
+}
+
+impl OpenNode {
+    fn contains(&self, start: usize, end: usize) -> bool {
+        self.start <= start && end <= self.end
+    }
+}
+
+impl Parser<'_> {
+    pub(crate) fn add_comments(&self, program: &mut Program) {
+        if !self.options.comments && !self.options.attach_comments {
+            return;
+        }
+
+        let comments = self
+            .cursor
+            .lexer
+            .comments
+            .iter()
+            .map(|scanned_comment| self.create_comment(scanned_comment))
+            .collect::<Vec<Comment>>();
+
+        if self.options.attach_comments {
+            attach_comments(program, &comments);
+        }
+
+        if self.options.comments {
+            program.comments = Some(comments);
+        }
+    }
+
+    fn create_comment(&self, scanned_comment: &ScannedComment) -> Comment {
+        let text = &self.cursor.lexer.source[scanned_comment.start..scanned_comment.end];
+
+        // An unterminated multi-line comment runs to the end of the source, so it has no closing `*/`.
+        let value = match scanned_comment.kind {
+            CommentKind::Line => &text[2..],
+            CommentKind::Block => text[2..].strip_suffix("*/").unwrap_or(&text[2..]),
+        };
+
+        Comment {
+            kind: scanned_comment.kind,
+            value: value.to_string(),
+            node: self.create_node(scanned_comment.start, scanned_comment.end),
+        }
+    }
+}
+
+// Each comment is added to the `leadingComments` of the node which follows it, and to the `trailingComments` of the node
+// which precedes it, where both nodes are children of the innermost node containing the comment. Where several nodes
+// start or end at the same position, the outermost one is used, e.g. the statement rather than its expression.
+fn attach_comments(program: &mut Program, comments: &[Comment]) {
+    let root = OpenNode {
+        start: program.node.start,
+        end: program.node.end,
+        last_child: None,
+    };
+
+    let mut nodes = NodeCollector::new(program).nodes;
+
+    let mut leading_comments = vec![Vec::new(); nodes.len()];
+    let mut trailing_comments = vec![Vec::new(); nodes.len()];
+
+    let mut open_nodes = vec![root];
+    let mut comments = comments.iter().peekable();
+
+    for (index, node) in nodes.iter().enumerate() {
+        while let Some(comment) = comments.next_if(|comment| comment.node.end <= node.start) {
+            let parent = enclosing_node(&mut open_nodes, &comment.node);
+
+            if let Some(last_child) = parent.last_child {
+                trailing_comments[last_child].push(comment.clone());
+            }
+
+            // Otherwise the node is after the end of the innermost node containing the comment.
+            if parent.contains(node.start, node.end) {
+                leading_comments[index].push(comment.clone());
+            }
+        }
+
+        let parent = enclosing_node(&mut open_nodes, node);
+
+        parent.last_child = Some(index);
+
+        open_nodes.push(OpenNode {
+            start: node.start,
+            end: node.end,
+            last_child: None,
+        });
+    }
+
+    for comment in comments {
+        let parent = enclosing_node(&mut open_nodes, &comment.node);
+
+        if let Some(last_child) = parent.last_child {
+            trailing_comments[last_child].push(comment.clone());
+        }
+    }
+
+    for ((node, leading_comments), trailing_comments) in nodes
+        .iter_mut()
+        .zip(leading_comments)
+        .zip(trailing_comments)
+    {
+        if leading_comments.is_empty() && trailing_comments.is_empty() {
+            continue;
+        }
+
+        let details = node.details_mut();
+
+        details.leading_comments = leading_comments;
+        details.trailing_comments = trailing_comments;
+    }
+}
+
+// Close the open nodes which end before the given node, leaving the innermost node which contains it. The program
+// contains every node and comment, so it is never closed.
+fn enclosing_node<'a>(open_nodes: &'a mut Vec<OpenNode>, node: &Node) -> &'a mut OpenNode {
+    while open_nodes.len() > 1 && !open_nodes[open_nodes.len() - 1].contains(node.start, node.end) {
+        open_nodes.pop();
+    }
+
+    open_nodes.last_mut().unwrap()
+}
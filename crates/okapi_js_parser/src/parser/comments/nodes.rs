@@ -0,0 +1,24 @@
+use crate::ast::visit::VisitMut;
+use crate::ast::{Node, Program};
+
+// Collects every node of a program in source order, with each node before its children. Type annotations and other
+// TypeScript types are skipped, as comments are only attached to the nodes of the JavaScript syntax.
+pub(crate) struct NodeCollector<'a> {
+    pub(crate) nodes: Vec<&'a mut Node>,
+}
+
+impl<'a> NodeCollector<'a> {
+    pub(crate) fn new(program: &'a mut Program) -> Self {
+        let mut collector = Self { nodes: Vec::new() };
+
+        collector.visit_program(program);
+
+        collector
+    }
+}
+
+impl<'a> VisitMut<'a> for NodeCollector<'a> {
+    fn visit_node(&mut self, node: &'a mut Node) {
+        self.nodes.push(node);
+    }
+}
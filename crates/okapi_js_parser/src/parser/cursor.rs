@@ -1,63 +1,262 @@
-use crate::{Lexer, Token, TokenKind, TokenValue};
+use std::collections::VecDeque;
 
+use crate::{lexer::LexerState, GoalSymbol, Lexer, ParserError, Token, TokenKind, TokenValue};
+
+// The parser never needs to look further ahead than two tokens past the current token.
+const LOOKAHEAD_CAPACITY: usize = 4;
+
+#[derive(Clone, Debug)]
+struct LookaheadToken<'a> {
+    token: Result<Token<'a>, ParserError>,
+    // Lexer state immediately after scanning the token.
+    state: LexerState,
+}
+
+// Saved cursor position used to backtrack after speculatively parsing ahead.
 #[derive(Clone, Debug)]
-pub struct Cursor {
-    pub(crate) current_token: Token,
-    pub(crate) lexer: Lexer,
-    pub(crate) next_token: Token,
-    pub(crate) previous_token: Token,
+pub(crate) struct CursorCheckpoint<'a> {
+    current_token: Token<'a>,
+    previous_token: Token<'a>,
+    state: LexerState,
 }
 
-impl Cursor {
-    pub fn new(lexer: Lexer, current_token: Token, next_token: Token) -> Self {
+// The cursor owns the lexer and a ring buffer of tokens which have been scanned ahead of the current token.
+// Tokens in the buffer are only valid for the goal symbol they were scanned with, so changing the goal symbol
+// or rewinding discards the buffer and the lexer re-scans from the end of the current token.
+// An invalid token is only returned as an error once the cursor advances to it, as it may be scanned again with a
+// different goal symbol before then. Until then, it is peeked as an `Illegal` token.
+#[derive(Clone, Debug)]
+pub struct Cursor<'a> {
+    pub(crate) current_token: Token<'a>,
+    pub(crate) lexer: Lexer<'a>,
+    pub(crate) previous_token: Token<'a>,
+    lookahead: VecDeque<LookaheadToken<'a>>,
+    // Lexer state immediately after the current token.
+    state: LexerState,
+    // Every token consumed so far, in source order, which is only recorded when the `tokens` option is enabled.
+    pub(crate) tokens: Option<Vec<Token<'a>>>,
+}
+
+impl<'a> Cursor<'a> {
+    // The first token is only scanned by `start`, so that an error in it is returned from parsing.
+    pub fn new(lexer: Lexer<'a>) -> Self {
+        let state = lexer.state();
+        let start_token = Token::new(
+            TokenKind::Illegal,
+            state.read_index,
+            state.read_index,
+            state.line,
+            state.column,
+            TokenValue::Null,
+        );
+
         Self {
-            current_token: current_token.clone(),
+            current_token: start_token.clone(),
             lexer,
-            next_token,
-            previous_token: current_token.clone(),
+            previous_token: start_token,
+            lookahead: VecDeque::with_capacity(LOOKAHEAD_CAPACITY),
+            state,
+            tokens: None,
         }
     }
 
+    pub(crate) fn start(&mut self) -> Result<(), ParserError> {
+        let (line, column) = (self.state.line, self.state.column);
+
+        let token = self.lexer.next_token();
+
+        self.state = self.lexer.state();
+
+        let (token, result) = token_or_illegal(token, line, column);
+
+        self.current_token = token.clone();
+        self.previous_token = token;
+
+        result
+    }
+
+    pub(crate) fn record_tokens(&mut self) {
+        self.tokens = Some(Vec::new());
+    }
+
     pub(crate) fn current_token_kind(&self) -> TokenKind {
         self.current_token.kind.clone()
     }
 
-    pub(crate) fn current_token_value(&self) -> TokenValue {
+    pub(crate) fn current_token_value(&self) -> TokenValue<'a> {
         self.current_token.value.clone()
     }
 
-    pub(crate) fn peek_token_kind(&self) -> TokenKind {
-        self.next_token.kind.clone()
+    pub(crate) fn peek_token_kind(&mut self) -> TokenKind {
+        self.peek_nth_kind(1)
     }
 
-    pub(crate) fn peek_nth_kind(&self, i: i32) -> TokenKind {
+    pub(crate) fn peek_nth_kind(&mut self, i: usize) -> TokenKind {
         match i {
             0 => self.current_token_kind(),
-            1 => self.peek_token_kind(),
-            _ => {
-                let mut lexer = self.lexer.clone();
+            _ => match self.peek_nth(i) {
+                Ok(token) => token.kind.clone(),
+                Err(_) => TokenKind::Illegal,
+            },
+        }
+    }
+
+    pub(crate) fn has_peek_token_line_terminator(&mut self) -> bool {
+        self.peek_nth(1)
+            .as_ref()
+            .is_ok_and(|token| token.line_terminator)
+    }
+
+    fn peek_nth(&mut self, i: usize) -> &Result<Token<'a>, ParserError> {
+        while self.lookahead.len() < i {
+            let token = self.lexer.next_token();
+
+            self.lookahead.push_back(LookaheadToken {
+                token,
+                state: self.lexer.state(),
+            });
+        }
+
+        &self.lookahead[i - 1].token
+    }
+
+    pub(crate) fn goal_symbol(&self) -> GoalSymbol {
+        self.state.goal_symbol.clone()
+    }
+
+    // Any tokens scanned ahead with a different goal symbol may have been scanned incorrectly, e.g. a `/` as a division punctuator instead of a regular expression, so they need to be scanned again.
+    pub(crate) fn set_goal_symbol(&mut self, goal_symbol: GoalSymbol) {
+        if self.state.goal_symbol == goal_symbol {
+            return;
+        }
+
+        self.state.goal_symbol = goal_symbol;
 
-                for _ in 0..i - 1 {
-                    lexer.next_token();
-                }
+        self.reset_lookahead();
+    }
+
+    // JSX children are scanned from the end of the current `>` or `}` token, rather than from after the whitespace which
+    // was skipped following it, as whitespace is part of JSX text.
+    pub(crate) fn set_jsx_child_goal_symbol(&mut self) {
+        self.state.goal_symbol = GoalSymbol::JSXChild;
+
+        // Both tokens are a single character, so the position after them is always on the same line.
+        self.state.read_index = self.current_token.end;
+        self.state.line = self.current_token.line;
+        self.state.column = self.current_token.column + 1;
+
+        self.reset_lookahead();
+    }
+
+    // Split a punctuator which starts with `>`, such as `>>` or `>=`, so that its first character can close a list of
+    // TypeScript type arguments or parameters, and the rest of it is scanned again as the next token.
+    pub(crate) fn split_greater_than(&mut self) {
+        self.current_token.kind = TokenKind::GreaterThan;
+        self.current_token.end = self.current_token.start + 1;
+        self.current_token.line_terminator = false;
+
+        self.state.read_index = self.current_token.end;
+        self.state.line = self.current_token.line;
+        self.state.column = self.current_token.column + 1;
+
+        self.reset_lookahead();
+    }
 
-                lexer.next_token().kind
+    pub(crate) fn checkpoint(&self) -> CursorCheckpoint<'a> {
+        CursorCheckpoint {
+            current_token: self.current_token.clone(),
+            previous_token: self.previous_token.clone(),
+            state: self.state.clone(),
+        }
+    }
+
+    pub(crate) fn restore(&mut self, checkpoint: CursorCheckpoint<'a>) {
+        self.current_token = checkpoint.current_token;
+        self.previous_token = checkpoint.previous_token;
+        self.state = checkpoint.state;
+
+        // Tokens consumed after the checkpoint are consumed again, and possibly scanned differently.
+        if let Some(tokens) = &mut self.tokens {
+            while tokens
+                .last()
+                .is_some_and(|token| token.start >= self.current_token.start)
+            {
+                tokens.pop();
             }
         }
+
+        self.reset_lookahead();
     }
 
-    pub(crate) fn rewind(&mut self) {
-        self.current_token = self.lexer.rewind_token(
-            self.current_token.start,
-            self.current_token.line,
-            self.current_token.column,
-        );
-        self.next_token = self.lexer.clone().next_token();
+    // Re-scan the current token with the current goal symbol.
+    pub(crate) fn rewind(&mut self) -> Result<(), ParserError> {
+        self.lookahead.clear();
+
+        self.lexer.restore_state(self.state.clone());
+
+        let (line, column) = (self.current_token.line, self.current_token.column);
+
+        let token = self
+            .lexer
+            .rewind_token(self.current_token.start, line, column);
+
+        self.state = self.lexer.state();
+
+        let (token, result) = token_or_illegal(token, line, column);
+
+        self.current_token = token;
+
+        result
+    }
+
+    pub(crate) fn advance(&mut self) -> Result<(), ParserError> {
+        let next = match self.lookahead.pop_front() {
+            Some(lookahead_token) => lookahead_token,
+            None => LookaheadToken {
+                token: self.lexer.next_token(),
+                state: self.lexer.state(),
+            },
+        };
+
+        if let Some(tokens) = &mut self.tokens {
+            tokens.push(self.current_token.clone());
+        }
+
+        // The next token starts where the lexer stopped after the current token.
+        let (token, result) = token_or_illegal(next.token, self.state.line, self.state.column);
+
+        self.previous_token = std::mem::replace(&mut self.current_token, token);
+        self.state = next.state;
+
+        result
     }
 
-    pub(crate) fn advance(&mut self) {
-        self.previous_token = self.current_token.clone();
-        self.current_token = self.lexer.next_token();
-        self.next_token = self.lexer.clone().next_token();
+    fn reset_lookahead(&mut self) {
+        self.lookahead.clear();
+
+        self.lexer.restore_state(self.state.clone());
+    }
+}
+
+// An invalid token is replaced with an `Illegal` token which spans it, so that parsing can continue after it when
+// recovering from errors.
+fn token_or_illegal(
+    token: Result<Token<'_>, ParserError>,
+    line: usize,
+    column: usize,
+) -> (Token<'_>, Result<(), ParserError>) {
+    match token {
+        Ok(token) => (token, Ok(())),
+        Err(error) => (
+            Token::new(
+                TokenKind::Illegal,
+                error.span.start,
+                error.span.end,
+                line,
+                column,
+                TokenValue::Null,
+            ),
+            Err(error),
+        ),
     }
 }
@@ -8,7 +8,7 @@ use crate::{
 
 // 11 ECMAScript Language: Source Text
 // https://tc39.es/ecma262/#sec-ecmascript-language-source-code
-impl Parser {
+impl Parser<'_> {
     // 11.2.1 Directive Prologues and the Use Strict Directive
     // https://tc39.es/ecma262/#sec-directive-prologues-and-the-use-strict-directive
     pub(crate) fn parse_directive_prologue(
@@ -30,7 +30,7 @@ impl Parser {
 
         let expression = self.parse_expression()?;
 
-        self.expect_optional_semicolon_and_advance();
+        self.expect_optional_semicolon_and_advance()?;
 
         if let Expression::Literal(Literal {
             value: LiteralValue::String(value),
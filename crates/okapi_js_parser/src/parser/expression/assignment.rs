@@ -1,5 +1,5 @@
 use crate::ast::*;
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{Feature, KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind};
 
 fn match_token_kind_to_assignment_operator(token_kind: &TokenKind) -> Option<AssignmentOperator> {
     match token_kind {
@@ -27,12 +27,37 @@ fn match_token_kind_to_assignment_operator(token_kind: &TokenKind) -> Option<Ass
     }
 }
 
+// It is a Syntax Error if the AssignmentTargetType of LeftHandSideExpression is not simple.
+// https://tc39.es/ecma262/#sec-static-semantics-assignmenttargettype
+fn is_simple_assignment_target(expression: &Expression) -> bool {
+    match expression {
+        Expression::Identifier(_) | Expression::Member(_) => true,
+        Expression::Parenthesized(parenthesized_expression) => {
+            is_simple_assignment_target(&parenthesized_expression.expression)
+        }
+        // TypeScript assertions are removed when compiled, so they are the same target as their expression.
+        Expression::TSAs(as_expression) => is_simple_assignment_target(&as_expression.expression),
+        Expression::TSNonNull(non_null_expression) => {
+            is_simple_assignment_target(&non_null_expression.expression)
+        }
+        Expression::TSSatisfies(satisfies_expression) => {
+            is_simple_assignment_target(&satisfies_expression.expression)
+        }
+        Expression::TSTypeAssertion(type_assertion) => {
+            is_simple_assignment_target(&type_assertion.expression)
+        }
+        _ => false,
+    }
+}
+
 // 13 ECMAScript Language: Expressions
 // https://tc39.es/ecma262/#sec-ecmascript-language-expressions
-impl Parser {
+impl Parser<'_> {
     // 13.15 Assignment Operators
     // https://tc39.es/ecma262/#prod-AssignmentExpression
     pub(crate) fn parse_assignment_expression(&mut self) -> Result<Expression, ParserError> {
+        let allow_arrow_return_type = !std::mem::take(&mut self.context.disallow_arrow_return_type);
+
         if self.params.has_allow_yield()
             && self.token_kind() == TokenKind::Keyword(KeywordKind::Yield)
         {
@@ -45,11 +70,17 @@ impl Parser {
         let is_maybe_parenthesized_arrow_function = self.token_kind() == TokenKind::LeftParenthesis;
         let is_maybe_async_parenthesized_arrow_function = self.token_kind()
             == TokenKind::Keyword(KeywordKind::Async)
-            && self.peek_token_kind() == TokenKind::LeftParenthesis;
+            && (self.peek_token_kind() == TokenKind::LeftParenthesis
+                || (self.options.extensions.typescript
+                    && self.peek_token_kind() == TokenKind::LessThan));
+        // `< TypeParameters > ( ... ) =>` in TypeScript, which is a JSX element when JSX is enabled.
+        let is_maybe_generic_arrow_function = self.options.extensions.typescript
+            && !self.options.extensions.jsx
+            && self.token_kind() == TokenKind::LessThan;
 
         let start_index = self.start_node();
 
-        let previous_cursor = self.cursor.clone();
+        let previous_cursor = self.cursor.checkpoint();
         let previous_context = self.context.clone();
 
         // Short circuit simple arrow functions.
@@ -82,7 +113,15 @@ impl Parser {
             return self.parse_async_arrow_function_declaration();
         }
 
-        let is_arrow_function = if is_maybe_parenthesized_arrow_function
+        let is_arrow_function = if self.options.extensions.typescript
+            && (is_maybe_parenthesized_arrow_function
+                || is_maybe_async_parenthesized_arrow_function
+                || is_maybe_generic_arrow_function)
+        {
+            // Parameters and return types may contain any type, so TypeScript arrow functions are found by parsing the
+            // signature up to the `=>` token.
+            self.is_typescript_arrow_function_start(allow_arrow_return_type)
+        } else if is_maybe_parenthesized_arrow_function
             || is_maybe_async_parenthesized_arrow_function
         {
             if is_maybe_async_parenthesized_arrow_function {
@@ -92,7 +131,7 @@ impl Parser {
 
             let is_arrow_function = self.maybe_arrow_function();
 
-            self.cursor = previous_cursor.clone();
+            self.cursor.restore(previous_cursor.clone());
             self.context = previous_context.clone();
 
             is_arrow_function
@@ -114,7 +153,7 @@ impl Parser {
             TokenKind::Assignment if is_maybe_assignment_pattern => {
                 // If LeftHandSideExpression is either an ObjectLiteral or an ArrayLiteral, it must be reparsed as an AssignmentPattern.
                 // https://tc39.es/ecma262/#sec-assignment-operators-static-semantics-early-errors
-                self.cursor = previous_cursor;
+                self.cursor.restore(previous_cursor);
                 self.context = previous_context;
 
                 let left_pattern = self.parse_assignment_pattern()?;
@@ -133,14 +172,20 @@ impl Parser {
             token_kind if token_kind.is_assignment_operator() => {
                 let operator = match_token_kind_to_assignment_operator(&self.token_kind()).unwrap();
 
-                self.advance_any(); // Eat assignment operator token.
+                self.expect_operator_feature(&token_kind)?;
+
+                let left_expression = left_expression?;
+
+                Self::expect_simple_assignment_target(&left_expression)?;
+
+                self.advance_any()?; // Eat assignment operator token.
 
                 let right = self.parse_assignment_expression()?;
 
                 return Ok(Expression::Assignment(AssignmentExpression {
                     node: self.end_node(start_index)?,
                     operator,
-                    left: Box::new(AssignmentExpressionLeft::Expression(left_expression?)),
+                    left: Box::new(AssignmentExpressionLeft::Expression(left_expression)),
                     right: Box::new(right),
                 }));
             }
@@ -152,6 +197,19 @@ impl Parser {
         left_expression
     }
 
+    // Kept out of `parse_assignment_expression`, so that it doesn't add to the size of its recursive stack frame.
+    #[inline(never)]
+    fn expect_simple_assignment_target(expression: &Expression) -> Result<(), ParserError> {
+        if is_simple_assignment_target(expression) {
+            return Ok(());
+        }
+
+        Err(ParserError::from_node(
+            ParserErrorKind::InvalidLeftHandSideExpression,
+            expression.node(),
+        ))
+    }
+
     fn maybe_arrow_function(&mut self) -> bool {
         let mut parentheses_depth = 0;
 
@@ -168,7 +226,9 @@ impl Parser {
                 _ => {}
             }
 
-            self.advance_any();
+            if self.advance_any().is_err() {
+                return false;
+            }
         }
 
         false
@@ -178,6 +238,8 @@ impl Parser {
     // Supplemental Syntax
     // https://tc39.es/ecma262/#prod-AssignmentPattern
     pub(crate) fn parse_assignment_pattern(&mut self) -> Result<Pattern, ParserError> {
+        self.expect_feature(Feature::Destructuring)?;
+
         let assignment_pattern = match self.token_kind() {
             TokenKind::LeftCurlyBrace => Pattern::Object(self.parse_object_assignment_pattern()?),
             TokenKind::LeftSquareBracket => Pattern::Array(self.parse_array_assignment_pattern()?),
@@ -204,11 +266,14 @@ impl Parser {
         Ok(ObjectPattern {
             node: self.end_node(start_index)?,
             properties,
+            type_annotation: None,
         })
     }
 
     // https://tc39.es/ecma262/#prod-AssignmentRestProperty
     fn parse_assignment_rest_property(&mut self) -> Result<ObjectPatternProperty, ParserError> {
+        self.expect_feature(Feature::ObjectRestSpread)?;
+
         let start_index = self.start_node();
 
         self.expect_and_advance(TokenKind::Ellipsis)?; // Eat '...' token.
@@ -220,6 +285,7 @@ impl Parser {
         Ok(ObjectPatternProperty::Rest(RestElement {
             node: self.end_node(start_index)?,
             argument: Box::new(pattern),
+            type_annotation: None,
         }))
     }
 
@@ -240,6 +306,7 @@ impl Parser {
         Ok(ArrayPattern {
             node: self.end_node(start_index)?,
             elements,
+            type_annotation: None,
         })
     }
 
@@ -257,7 +324,7 @@ impl Parser {
             properties.push(self.parse_assignment_property()?);
 
             if self.token_kind() == TokenKind::Comma {
-                self.advance_any(); // Eat ',' token.
+                self.advance_any()?; // Eat ',' token.
             }
         }
 
@@ -276,7 +343,7 @@ impl Parser {
             }
 
             if self.token_kind() == TokenKind::Comma {
-                self.advance_any(); // Eat ellision token.
+                self.advance_any()?; // Eat ellision token.
 
                 elements.push(None);
 
@@ -286,7 +353,7 @@ impl Parser {
             elements.push(Some(self.parse_assignment_element()?));
 
             if self.token_kind() == TokenKind::Comma {
-                self.advance_any(); // Eat ',' token.
+                self.advance_any()?; // Eat ',' token.
             }
         }
 
@@ -330,7 +397,7 @@ impl Parser {
                 }))
             }
             TokenKind::Assignment => {
-                self.advance_any(); // Eat '=' token.
+                self.advance_any()?; // Eat '=' token.
 
                 let assignment_expression = self.with_params(
                     self.params.clone().add_allow_in(false),
@@ -377,7 +444,7 @@ impl Parser {
             };
 
         let assignment_element = if self.token_kind() == TokenKind::Assignment {
-            self.advance_any(); // Eat '=' token.
+            self.advance_any()?; // Eat '=' token.
 
             let assignment_expression = self.with_params(
                 self.params.clone().add_allow_in(false),
@@ -409,6 +476,7 @@ impl Parser {
         Ok(ArrayPatternElement::RestElement(RestElement {
             node: self.end_node(start_index)?,
             argument: Box::new(Pattern::try_from(left_hand_side_expression)?),
+            type_annotation: None,
         }))
     }
 }
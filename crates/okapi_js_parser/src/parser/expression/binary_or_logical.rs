@@ -69,7 +69,7 @@ fn match_token_kind_to_logical_operator(token_kind: &TokenKind) -> Option<Logica
 
 // 13 ECMAScript Language: Expressions
 // https://tc39.es/ecma262/#sec-ecmascript-language-expressions
-impl Parser {
+impl Parser<'_> {
     // 13.6 Exponentiation Operators
     // https://tc39.es/ecma262/#prod-ExponentiationExpression
 
@@ -112,7 +112,7 @@ impl Parser {
 
             let unary_expression = self.parse_unary_expression()?;
 
-            let node = Node::new(start_token.start, self.cursor.previous_token.end);
+            let node = self.create_node(start_token.start, self.cursor.previous_token.end);
 
             return Ok(Expression::Binary(BinaryExpression {
                 node,
@@ -136,6 +136,48 @@ impl Parser {
         while self.token_kind() != TokenKind::EOF {
             let token_kind = self.token_kind();
 
+            // `Expression [no LineTerminator here] as Type` and `Expression [no LineTerminator here] satisfies Type` in
+            // TypeScript, which have the same precedence as the relational operators.
+            if self.options.extensions.typescript
+                && matches!(
+                    token_kind,
+                    TokenKind::Keyword(KeywordKind::As | KeywordKind::Satisfies)
+                )
+                && !self.has_previous_token_line_terminator()
+            {
+                if minimum_precedence > 14 {
+                    break;
+                }
+
+                self.advance_any()?; // Eat 'as' or 'satisfies' token.
+
+                let type_annotation = if token_kind == TokenKind::Keyword(KeywordKind::As) {
+                    self.parse_assertion_type()?
+                } else {
+                    self.parse_type()?
+                };
+
+                let node = self.create_node(left_start_token.start, self.cursor.previous_token.end);
+
+                let expression = Box::new(left_expression);
+
+                left_expression = if token_kind == TokenKind::Keyword(KeywordKind::As) {
+                    Expression::TSAs(Box::new(TSAsExpression {
+                        node,
+                        expression,
+                        type_annotation,
+                    }))
+                } else {
+                    Expression::TSSatisfies(Box::new(TSSatisfiesExpression {
+                        node,
+                        expression,
+                        type_annotation,
+                    }))
+                };
+
+                continue;
+            }
+
             let Some((left_precedence, right_precedence)) =
                 match_token_kind_to_operator_precedence(&self.token_kind())
             else {
@@ -154,11 +196,13 @@ impl Parser {
                 break;
             }
 
-            self.advance_any(); // Eat operator token.
+            self.expect_operator_feature(&token_kind)?;
+
+            self.advance_any()?; // Eat operator token.
 
             let right_expression = self.parse_binary_expression(right_precedence)?;
 
-            let node = Node::new(left_start_token.start, self.cursor.previous_token.end);
+            let node = self.create_node(left_start_token.start, self.cursor.previous_token.end);
 
             if token_kind.is_logical_operator() {
                 let Some(operator) = match_token_kind_to_logical_operator(&token_kind) else {
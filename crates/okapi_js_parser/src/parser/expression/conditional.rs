@@ -3,7 +3,7 @@ use crate::{Parser, ParserError, TokenKind};
 
 // 13 ECMAScript Language: Expressions
 // https://tc39.es/ecma262/#sec-ecmascript-language-expressions
-impl Parser {
+impl Parser<'_> {
     // 13.14 Conditional Operator ( ? : )
     // https://tc39.es/ecma262/#prod-ConditionalExpression
     pub(crate) fn parse_conditional_expression(&mut self) -> Result<Expression, ParserError> {
@@ -12,12 +12,9 @@ impl Parser {
         let short_circuit_expression = self.parse_binary_expression(0)?;
 
         if self.token_kind() == TokenKind::QuestionMark {
-            self.advance_any(); // Eat '?' token.
+            self.advance_any()?; // Eat '?' token.
 
-            let consequent = self.with_params(
-                self.params.clone().add_allow_in(false),
-                Self::parse_assignment_expression,
-            )?;
+            let consequent = self.parse_conditional_consequent()?;
 
             self.expect_and_advance(TokenKind::Colon)?;
 
@@ -35,4 +32,37 @@ impl Parser {
 
         Ok(short_circuit_expression)
     }
+
+    // In TypeScript, `a ? (b) : c => d` is ambiguous, as `(b) : c => d` is also an arrow function with a return type.
+    // As in TypeScript and Babel, the arrow function is only kept if the `:` of the conditional expression follows it,
+    // and otherwise the consequent is reparsed without the return type.
+    #[inline(never)]
+    fn parse_conditional_consequent(&mut self) -> Result<Expression, ParserError> {
+        let params = self.params.clone().add_allow_in(false);
+
+        if !self.options.extensions.typescript {
+            return self.with_params(params, Self::parse_assignment_expression);
+        }
+
+        let previous_cursor = self.cursor.checkpoint();
+        let previous_context = self.context.clone();
+
+        let consequent = self.with_params(params, Self::parse_assignment_expression)?;
+
+        let is_arrow_function_with_return_type = matches!(
+            &consequent,
+            Expression::ArrowFunction(arrow_function_expression)
+                if arrow_function_expression.return_type.is_some()
+        );
+
+        if !is_arrow_function_with_return_type || self.token_kind() == TokenKind::Colon {
+            return Ok(consequent);
+        }
+
+        self.cursor.restore(previous_cursor);
+        self.context = previous_context;
+        self.context.disallow_arrow_return_type = true;
+
+        self.with_params(params, Self::parse_assignment_expression)
+    }
 }
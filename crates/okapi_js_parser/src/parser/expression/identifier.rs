@@ -1,9 +1,9 @@
 use crate::ast::*;
-use crate::{Parser, ParserError};
+use crate::{Feature, KeywordKind, Parser, ParserError, TokenKind};
 
 // 13 ECMAScript Language: Expressions
 // https://tc39.es/ecma262/#sec-ecmascript-language-expressions
-impl Parser {
+impl Parser<'_> {
     // 13.1 Identifiers
     // https://tc39.es/ecma262/#prod-IdentifierReference
     pub(crate) fn parse_identifier_reference(&mut self) -> Result<Identifier, ParserError> {
@@ -11,8 +11,10 @@ impl Parser {
 
         let identifier_reference = String::from(self.token_value());
 
+        self.check_await_identifier()?;
+
         if self.token_kind().is_identifier_reference() {
-            self.advance_any(); // Eat identifier reference token.
+            self.advance_any()?; // Eat identifier reference token.
         } else {
             return Err(self.unexpected_current_token_kind());
         }
@@ -20,6 +22,8 @@ impl Parser {
         Ok(Identifier {
             node: self.end_node(start_index)?,
             name: identifier_reference,
+            type_annotation: None,
+            optional: false,
         })
     }
 
@@ -28,8 +32,10 @@ impl Parser {
         let start_index = self.start_node();
         let binding_identifier = String::from(self.token_value());
 
+        self.check_await_identifier()?;
+
         if self.token_kind().is_binding_identifier() {
-            self.advance_any(); // Eat binding identifier token.
+            self.advance_any()?; // Eat binding identifier token.
         } else {
             return Err(self.unexpected_current_token_kind());
         }
@@ -37,9 +43,23 @@ impl Parser {
         Ok(Identifier {
             node: self.end_node(start_index)?,
             name: binding_identifier,
+            type_annotation: None,
+            optional: false,
         })
     }
 
+    // It is a Syntax Error if `await` is used as an identifier in an async function or in module code.
+    // https://tc39.es/ecma262/#sec-identifiers-static-semantics-early-errors
+    fn check_await_identifier(&mut self) -> Result<(), ParserError> {
+        if self.token_kind() == TokenKind::Keyword(KeywordKind::Await)
+            && (self.params.has_allow_await() || self.context.in_module)
+        {
+            return Err(self.unexpected_current_token_kind());
+        }
+
+        Ok(())
+    }
+
     // https://tc39.es/ecma262/#prod-LabelIdentifier
     pub(crate) fn parse_label_identifier(&mut self) -> Result<Identifier, ParserError> {
         self.parse_binding_identifier()
@@ -48,12 +68,14 @@ impl Parser {
     // 12.7 Names and Keywords
     // https://tc39.es/ecma262/#prod-PrivateIdentifier
     pub(crate) fn parse_private_identifier(&mut self) -> Result<PrivateIdentifier, ParserError> {
+        self.expect_feature(Feature::PrivateNames)?;
+
         let start_index = self.start_node();
 
         let private_idententifier = String::from(self.token_value());
 
         if self.token_kind().is_private_identifier() {
-            self.advance_any(); // Eat private identifier token.
+            self.advance_any()?; // Eat private identifier token.
         } else {
             return Err(self.unexpected_current_token_kind());
         }
@@ -71,7 +93,7 @@ impl Parser {
         let identifier_name = String::from(self.token_value());
 
         if self.token_kind().is_identifier_name() {
-            self.advance_any(); // Eat identifier or reserved keyword token.
+            self.advance_any()?; // Eat identifier or reserved keyword token.
         } else {
             return Err(self.unexpected_current_token_kind());
         }
@@ -79,6 +101,8 @@ impl Parser {
         Ok(Identifier {
             node: self.end_node(start_index)?,
             name: identifier_name,
+            type_annotation: None,
+            optional: false,
         })
     }
 }
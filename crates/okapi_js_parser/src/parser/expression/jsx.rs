@@ -0,0 +1,461 @@
+use crate::ast::*;
+use crate::{GoalSymbol, Parser, ParserError, ParserErrorKind, TokenKind, TokenValue};
+
+// JSX
+// https://facebook.github.io/jsx/
+impl Parser<'_> {
+    // JSX is only parsed when it has been opted in to, as otherwise `<` is always a relational operator.
+    pub(crate) fn is_jsx_start(&self) -> bool {
+        self.options.extensions.jsx && self.token_kind() == TokenKind::LessThan
+    }
+
+    // https://facebook.github.io/jsx/#prod-PrimaryExpression
+    pub(crate) fn parse_jsx_element_or_fragment(&mut self) -> Result<Expression, ParserError> {
+        // The tokens after the element are scanned with the same goal symbol as the tokens before it.
+        let goal_symbol = self.cursor.goal_symbol();
+
+        self.cursor.set_goal_symbol(GoalSymbol::JSXTag);
+
+        if self.peek_token_kind() == TokenKind::GreaterThan {
+            Ok(Expression::JSXFragment(Box::new(
+                self.parse_jsx_fragment(goal_symbol)?,
+            )))
+        } else {
+            Ok(Expression::JSXElement(Box::new(
+                self.parse_jsx_element(goal_symbol)?,
+            )))
+        }
+    }
+
+    // Advance past the `>` or `}` token which ends a part of a JSX element, scanning the next token with the goal
+    // symbol of whatever follows it.
+    fn expect_and_advance_with_goal_symbol(
+        &mut self,
+        token_kind: TokenKind,
+        goal_symbol: GoalSymbol,
+    ) -> Result<(), ParserError> {
+        self.expect(token_kind)?;
+
+        if goal_symbol == GoalSymbol::JSXChild {
+            self.cursor.set_jsx_child_goal_symbol();
+        } else {
+            self.cursor.set_goal_symbol(goal_symbol);
+        }
+
+        self.advance_any()?;
+
+        Ok(())
+    }
+
+    // https://facebook.github.io/jsx/#prod-JSXElement
+    fn parse_jsx_element(&mut self, goal_symbol: GoalSymbol) -> Result<JSXElement, ParserError> {
+        let start_index = self.start_node();
+
+        self.expect_and_advance(TokenKind::LessThan)?;
+
+        let name = self.parse_jsx_element_name()?;
+
+        let attributes = self.parse_jsx_attributes()?;
+
+        // `JSXSelfClosingElement`.
+        if self.token_kind() == TokenKind::Division {
+            self.advance_any()?; // Eat '/' token.
+
+            self.expect_and_advance_with_goal_symbol(TokenKind::GreaterThan, goal_symbol)?;
+
+            let node = self.end_node(start_index)?;
+
+            return Ok(JSXElement {
+                node: node.clone(),
+                opening_element: JSXOpeningElement {
+                    node,
+                    attributes,
+                    name,
+                    self_closing: true,
+                },
+                closing_element: None,
+                children: vec![],
+            });
+        }
+
+        self.expect_and_advance_with_goal_symbol(TokenKind::GreaterThan, GoalSymbol::JSXChild)?;
+
+        let opening_element = JSXOpeningElement {
+            node: self.end_node(start_index)?,
+            attributes,
+            name,
+            self_closing: false,
+        };
+
+        let children = self.parse_jsx_children()?;
+
+        let closing_element_start_index = self.start_node();
+
+        self.expect_and_advance(TokenKind::LessThan)?;
+        self.expect_and_advance(TokenKind::Division)?;
+
+        let closing_name_start_index = self.start_node();
+
+        let closing_name = self.parse_jsx_element_name()?;
+
+        if jsx_element_name_to_string(&closing_name)
+            != jsx_element_name_to_string(&opening_element.name)
+        {
+            return Err(self.error(
+                ParserErrorKind::MismatchedJSXClosingTag,
+                closing_name_start_index,
+                self.cursor.previous_token.end,
+            ));
+        }
+
+        self.expect_and_advance_with_goal_symbol(TokenKind::GreaterThan, goal_symbol)?;
+
+        let closing_element = JSXClosingElement {
+            node: self.end_node(closing_element_start_index)?,
+            name: closing_name,
+        };
+
+        Ok(JSXElement {
+            node: self.end_node(start_index)?,
+            opening_element,
+            closing_element: Some(closing_element),
+            children,
+        })
+    }
+
+    // https://facebook.github.io/jsx/#prod-JSXFragment
+    fn parse_jsx_fragment(&mut self, goal_symbol: GoalSymbol) -> Result<JSXFragment, ParserError> {
+        let start_index = self.start_node();
+
+        self.expect_and_advance(TokenKind::LessThan)?;
+
+        self.expect_and_advance_with_goal_symbol(TokenKind::GreaterThan, GoalSymbol::JSXChild)?;
+
+        let opening_fragment = JSXOpeningFragment {
+            node: self.end_node(start_index)?,
+        };
+
+        let children = self.parse_jsx_children()?;
+
+        let closing_fragment_start_index = self.start_node();
+
+        self.expect_and_advance(TokenKind::LessThan)?;
+        self.expect_and_advance(TokenKind::Division)?;
+
+        self.expect_and_advance_with_goal_symbol(TokenKind::GreaterThan, goal_symbol)?;
+
+        let closing_fragment = JSXClosingFragment {
+            node: self.end_node(closing_fragment_start_index)?,
+        };
+
+        Ok(JSXFragment {
+            node: self.end_node(start_index)?,
+            opening_fragment,
+            closing_fragment,
+            children,
+        })
+    }
+
+    // https://facebook.github.io/jsx/#prod-JSXElementName
+    fn parse_jsx_element_name(&mut self) -> Result<JSXElementName, ParserError> {
+        let start_index = self.start_node();
+
+        let identifier = self.parse_jsx_identifier()?;
+
+        match self.token_kind() {
+            // `JSXNamespacedName`.
+            TokenKind::Colon => {
+                self.advance_any()?; // Eat ':' token.
+
+                let name = self.parse_jsx_identifier()?;
+
+                Ok(JSXElementName::NamespacedName(JSXNamespacedName {
+                    node: self.end_node(start_index)?,
+                    namespace: identifier,
+                    name,
+                }))
+            }
+            // `JSXMemberExpression`.
+            TokenKind::Dot => {
+                let mut object = JSXMemberExpressionObject::Identifier(identifier);
+
+                loop {
+                    self.advance_any()?; // Eat '.' token.
+
+                    let member_expression = JSXMemberExpression {
+                        property: self.parse_jsx_identifier()?,
+                        node: self.end_node(start_index)?,
+                        object,
+                    };
+
+                    if self.token_kind() != TokenKind::Dot {
+                        return Ok(JSXElementName::MemberExpression(member_expression));
+                    }
+
+                    object =
+                        JSXMemberExpressionObject::MemberExpression(Box::new(member_expression));
+                }
+            }
+            _ => Ok(JSXElementName::Identifier(identifier)),
+        }
+    }
+
+    // https://facebook.github.io/jsx/#prod-JSXIdentifier
+    fn parse_jsx_identifier(&mut self) -> Result<JSXIdentifier, ParserError> {
+        let start_index = self.start_node();
+
+        let name = String::from(self.token_value());
+
+        self.expect_and_advance(TokenKind::JSXIdentifier)?;
+
+        Ok(JSXIdentifier {
+            node: self.end_node(start_index)?,
+            name,
+        })
+    }
+
+    // https://facebook.github.io/jsx/#prod-JSXAttributes
+    fn parse_jsx_attributes(&mut self) -> Result<Vec<JSXAttributeItem>, ParserError> {
+        let mut attributes = vec![];
+
+        loop {
+            match self.token_kind() {
+                TokenKind::LeftCurlyBrace => {
+                    attributes.push(JSXAttributeItem::SpreadAttribute(
+                        self.parse_jsx_spread_attribute()?,
+                    ));
+                }
+                TokenKind::JSXIdentifier => {
+                    attributes.push(JSXAttributeItem::Attribute(self.parse_jsx_attribute()?));
+                }
+                _ => return Ok(attributes),
+            }
+        }
+    }
+
+    // https://facebook.github.io/jsx/#prod-JSXSpreadAttribute
+    fn parse_jsx_spread_attribute(&mut self) -> Result<JSXSpreadAttribute, ParserError> {
+        let start_index = self.start_node();
+
+        self.expect_and_advance_with_goal_symbol(
+            TokenKind::LeftCurlyBrace,
+            GoalSymbol::InputElementDiv,
+        )?;
+
+        self.expect_and_advance(TokenKind::Ellipsis)?;
+
+        let argument = self.with_params(
+            self.params.clone().add_allow_in(false),
+            Self::parse_assignment_expression,
+        )?;
+
+        self.expect_and_advance_with_goal_symbol(TokenKind::RightCurlyBrace, GoalSymbol::JSXTag)?;
+
+        Ok(JSXSpreadAttribute {
+            node: self.end_node(start_index)?,
+            argument,
+        })
+    }
+
+    // https://facebook.github.io/jsx/#prod-JSXAttribute
+    fn parse_jsx_attribute(&mut self) -> Result<JSXAttribute, ParserError> {
+        let start_index = self.start_node();
+
+        let identifier = self.parse_jsx_identifier()?;
+
+        let name = if self.token_kind() == TokenKind::Colon {
+            self.advance_any()?; // Eat ':' token.
+
+            let name = self.parse_jsx_identifier()?;
+
+            JSXAttributeName::NamespacedName(JSXNamespacedName {
+                node: self.end_node(start_index)?,
+                namespace: identifier,
+                name,
+            })
+        } else {
+            JSXAttributeName::Identifier(identifier)
+        };
+
+        let value = if self.token_kind() == TokenKind::Assignment {
+            self.advance_any()?; // Eat '=' token.
+
+            Some(self.parse_jsx_attribute_value()?)
+        } else {
+            None
+        };
+
+        Ok(JSXAttribute {
+            node: self.end_node(start_index)?,
+            name,
+            value,
+        })
+    }
+
+    // https://facebook.github.io/jsx/#prod-JSXAttributeValue
+    fn parse_jsx_attribute_value(&mut self) -> Result<JSXAttributeValue, ParserError> {
+        match self.token_kind() {
+            TokenKind::StringLiteral => Ok(JSXAttributeValue::Literal(self.parse_literal()?)),
+            TokenKind::LeftCurlyBrace => {
+                let start_index = self.start_node();
+
+                self.expect_and_advance_with_goal_symbol(
+                    TokenKind::LeftCurlyBrace,
+                    GoalSymbol::InputElementDiv,
+                )?;
+
+                let assignment_expression = self.with_params(
+                    self.params.clone().add_allow_in(false),
+                    Self::parse_assignment_expression,
+                )?;
+
+                self.expect_and_advance_with_goal_symbol(
+                    TokenKind::RightCurlyBrace,
+                    GoalSymbol::JSXTag,
+                )?;
+
+                Ok(JSXAttributeValue::ExpressionContainer(
+                    JSXExpressionContainer {
+                        node: self.end_node(start_index)?,
+                        expression: JSXExpression::Expression(assignment_expression),
+                    },
+                ))
+            }
+            TokenKind::LessThan if self.peek_token_kind() == TokenKind::GreaterThan => Ok(
+                JSXAttributeValue::Fragment(Box::new(self.parse_jsx_fragment(GoalSymbol::JSXTag)?)),
+            ),
+            TokenKind::LessThan => Ok(JSXAttributeValue::Element(Box::new(
+                self.parse_jsx_element(GoalSymbol::JSXTag)?,
+            ))),
+            _ => Err(self.unexpected_current_token_kind()),
+        }
+    }
+
+    // https://facebook.github.io/jsx/#prod-JSXChildren
+    // The children end at the `<` of the closing tag, which is left as the current token.
+    fn parse_jsx_children(&mut self) -> Result<Vec<JSXChild>, ParserError> {
+        let mut children = vec![];
+
+        loop {
+            match self.token_kind() {
+                TokenKind::JSXText => children.push(JSXChild::Text(self.parse_jsx_text()?)),
+                TokenKind::LeftCurlyBrace => {
+                    children.push(self.parse_jsx_child_expression_container()?);
+                }
+                TokenKind::LessThan => {
+                    self.cursor.set_goal_symbol(GoalSymbol::JSXTag);
+
+                    match self.peek_token_kind() {
+                        TokenKind::Division => return Ok(children),
+                        TokenKind::GreaterThan => children.push(JSXChild::Fragment(
+                            self.parse_jsx_fragment(GoalSymbol::JSXChild)?,
+                        )),
+                        _ => children.push(JSXChild::Element(
+                            self.parse_jsx_element(GoalSymbol::JSXChild)?,
+                        )),
+                    }
+                }
+                TokenKind::EOF => {
+                    return Err(self.current_token_error(ParserErrorKind::UnterminatedJSXContents))
+                }
+                _ => return Err(self.unexpected_current_token_kind()),
+            }
+        }
+    }
+
+    // https://facebook.github.io/jsx/#prod-JSXText
+    fn parse_jsx_text(&mut self) -> Result<JSXText, ParserError> {
+        let start_index = self.start_node();
+
+        let TokenValue::String { raw, value } = self.token_value() else {
+            return Err(self.unexpected_current_token_value());
+        };
+
+        self.expect_and_advance(TokenKind::JSXText)?;
+
+        Ok(JSXText {
+            node: self.end_node(start_index)?,
+            value: value.into_owned(),
+            raw: raw.to_string(),
+        })
+    }
+
+    // `{ JSXChildExpression }`.
+    // https://facebook.github.io/jsx/#prod-JSXChild
+    fn parse_jsx_child_expression_container(&mut self) -> Result<JSXChild, ParserError> {
+        let start_index = self.start_node();
+
+        self.expect_and_advance_with_goal_symbol(
+            TokenKind::LeftCurlyBrace,
+            GoalSymbol::InputElementDiv,
+        )?;
+
+        let expression = match self.token_kind() {
+            // `JSXEmptyExpression`, which spans the contents of the braces, including any comments.
+            TokenKind::RightCurlyBrace => {
+                let end_index = self.start_node();
+
+                JSXExpression::Empty(JSXEmptyExpression {
+                    node: self.create_node(self.cursor.previous_token.end, end_index),
+                })
+            }
+            // `JSXSpreadChild`.
+            TokenKind::Ellipsis => {
+                self.advance_any()?; // Eat '...' token.
+
+                let expression = self.with_params(
+                    self.params.clone().add_allow_in(false),
+                    Self::parse_expression,
+                )?;
+
+                self.expect_and_advance_with_goal_symbol(
+                    TokenKind::RightCurlyBrace,
+                    GoalSymbol::JSXChild,
+                )?;
+
+                return Ok(JSXChild::SpreadChild(JSXSpreadChild {
+                    node: self.end_node(start_index)?,
+                    expression,
+                }));
+            }
+            _ => JSXExpression::Expression(self.with_params(
+                self.params.clone().add_allow_in(false),
+                Self::parse_expression,
+            )?),
+        };
+
+        self.expect_and_advance_with_goal_symbol(TokenKind::RightCurlyBrace, GoalSymbol::JSXChild)?;
+
+        Ok(JSXChild::ExpressionContainer(JSXExpressionContainer {
+            node: self.end_node(start_index)?,
+            expression,
+        }))
+    }
+}
+
+// The qualified name of an element, used to check that the closing tag matches the opening tag, e.g. `a.b.c` or `a:b`.
+fn jsx_element_name_to_string(name: &JSXElementName) -> String {
+    match name {
+        JSXElementName::Identifier(identifier) => identifier.name.clone(),
+        JSXElementName::NamespacedName(namespaced_name) => {
+            format!(
+                "{}:{}",
+                namespaced_name.namespace.name, namespaced_name.name.name
+            )
+        }
+        JSXElementName::MemberExpression(member_expression) => {
+            jsx_member_expression_to_string(member_expression)
+        }
+    }
+}
+
+fn jsx_member_expression_to_string(member_expression: &JSXMemberExpression) -> String {
+    let object = match &member_expression.object {
+        JSXMemberExpressionObject::Identifier(identifier) => identifier.name.clone(),
+        JSXMemberExpressionObject::MemberExpression(member_expression) => {
+            jsx_member_expression_to_string(member_expression)
+        }
+    };
+
+    format!("{}.{}", object, member_expression.property.name)
+}
@@ -1,9 +1,9 @@
 use crate::ast::*;
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{Feature, KeywordKind, Parser, ParserError, TokenKind};
 
 // 13 ECMAScript Language: Expressions
 // https://tc39.es/ecma262/#sec-ecmascript-language-expressions
-impl Parser {
+impl Parser<'_> {
     // 13.3 Left-Hand-Side Expressions
     // https://tc39.es/ecma262/#sec-left-hand-side-expressions
 
@@ -16,18 +16,22 @@ impl Parser {
         let next_member_expression = match member_expression {
             _ if self.token_kind() == TokenKind::Keyword(KeywordKind::New) => {
                 // `new MemberExpression[?Yield, ?Await] Arguments[?Yield, ?Await]`.
-                self.advance_any(); // Eat 'new' token.
+                self.advance_any()?; // Eat 'new' token.
 
                 let callee_start_index = self.start_node();
 
                 let member_expression = self.parse_member_expression(None, callee_start_index)?;
 
+                // `new MemberExpression TypeArguments Arguments` in TypeScript.
+                let type_arguments = self.try_parse_type_arguments_in_expression();
+
                 let arguments = self.parse_new_expression_arguments()?;
 
                 Expression::New(NewExpression {
                     node: self.end_node(start_index)?,
                     callee: Box::new(member_expression),
                     arguments,
+                    type_arguments: type_arguments.map(Box::new),
                 })
             }
             Some(left_hand_side_expression) => left_hand_side_expression,
@@ -61,6 +65,18 @@ impl Parser {
         }
 
         let member_expression_property = match self.token_kind() {
+            // `MemberExpression [no LineTerminator here] !` in TypeScript.
+            TokenKind::LogicalNot
+                if self.options.extensions.typescript
+                    && !self.has_previous_token_line_terminator() =>
+            {
+                self.advance_any()?; // Eat '!' token.
+
+                return Ok(Some(Expression::TSNonNull(TSNonNullExpression {
+                    node: self.end_node(start_index)?,
+                    expression: Box::new(member_expression.clone()),
+                })));
+            }
             TokenKind::OptionalChaining => {
                 // `OptionalExpression > MemberExpression OptionalChain`.
                 return Ok(Some(
@@ -130,11 +146,15 @@ impl Parser {
         // TODO Throw error if not in a function body or class.
         let meta_property_start_index = self.start_node();
 
+        self.expect_feature(Feature::NewTarget)?;
+
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::New))?;
 
         let meta_identifier = Identifier {
             node: self.end_node(meta_property_start_index)?,
             name: String::from("new"),
+            type_annotation: None,
+            optional: false,
         };
 
         self.expect_and_advance(TokenKind::Dot)?;
@@ -146,6 +166,8 @@ impl Parser {
         let property_identifier = Identifier {
             node: self.end_node(start_index)?,
             name: String::from("target"),
+            type_annotation: None,
+            optional: false,
         };
 
         Ok(Expression::MetaProperty(MetaProperty {
@@ -158,6 +180,8 @@ impl Parser {
     // https://tc39.es/ecma262/#prod-ImportMeta
     fn parse_import_meta(&mut self) -> Result<Expression, ParserError> {
         // TODO Throw error if not in a module.
+        self.expect_feature(Feature::ImportMeta)?;
+
         let meta_property_start_index = self.start_node();
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Import))?;
@@ -165,6 +189,8 @@ impl Parser {
         let meta_identifier = Identifier {
             node: self.end_node(meta_property_start_index)?,
             name: String::from("import"),
+            type_annotation: None,
+            optional: false,
         };
 
         self.expect_and_advance(TokenKind::Dot)?;
@@ -176,6 +202,8 @@ impl Parser {
         let property_identifier = Identifier {
             node: self.end_node(start_index)?,
             name: String::from("meta"),
+            type_annotation: None,
+            optional: false,
         };
 
         Ok(Expression::MetaProperty(MetaProperty {
@@ -230,6 +258,7 @@ impl Parser {
                 callee: CallExpressionCallee::Expression(Box::new(left_hand_side_expression)),
                 arguments: optional_arguments,
                 optional: self.context.in_optional_chain,
+                type_arguments: None,
             });
 
             left_hand_side_expression =
@@ -300,6 +329,7 @@ impl Parser {
                 callee: CallExpressionCallee::Expression(Box::new(next_call_expression)),
                 arguments: optional_arguments,
                 optional: self.context.in_optional_chain,
+                type_arguments: None,
             })
         } else {
             next_call_expression
@@ -314,6 +344,24 @@ impl Parser {
                 // `CallExpression Arguments`.
                 self.parse_call_expression_tail(Some(next_call_expression), start_index)
             }
+            TokenKind::LessThan if self.options.extensions.typescript => {
+                // `CallExpression TypeArguments Arguments` in TypeScript, where `<` is otherwise a relational operator.
+                let Some(type_arguments) = self.try_parse_type_arguments_in_expression() else {
+                    return Ok(next_call_expression);
+                };
+
+                let arguments = self.parse_arguments()?;
+
+                let call_expression = Expression::Call(CallExpression {
+                    node: self.end_node(start_index)?,
+                    callee: CallExpressionCallee::Expression(Box::new(next_call_expression)),
+                    arguments,
+                    optional: self.context.in_optional_chain,
+                    type_arguments: Some(Box::new(type_arguments)),
+                });
+
+                self.parse_call_expression_tail(Some(call_expression), start_index)
+            }
             _ => match self.parse_member_expression_tail(&next_call_expression, start_index)? {
                 Some(member_expression) => {
                     // `CallExpression . IdentifierName`.
@@ -345,11 +393,14 @@ impl Parser {
             callee: CallExpressionCallee::Expression(Box::new(Expression::Super(super_identifier))),
             arguments,
             optional: false,
+            type_arguments: None,
         }))
     }
 
     // https://tc39.es/ecma262/#prod-ImportCall
     fn parse_import_call(&mut self) -> Result<Expression, ParserError> {
+        self.expect_feature(Feature::DynamicImport)?;
+
         let start_index = self.start_node();
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Import))?;
@@ -361,17 +412,36 @@ impl Parser {
             Self::parse_assignment_expression,
         )?;
 
+        let mut options = None;
+
+        // `import ( AssignmentExpression , AssignmentExpression ,opt )`
+        if self.token_kind() == TokenKind::Comma {
+            self.advance_any()?; // Eat ',' token.
+
+            if self.token_kind() != TokenKind::RightParenthesis {
+                options = Some(Box::new(self.with_params(
+                    self.params.clone().add_allow_in(false),
+                    Self::parse_assignment_expression,
+                )?));
+
+                if self.token_kind() == TokenKind::Comma {
+                    self.advance_any()?; // Eat ',' token.
+                }
+            }
+        }
+
         self.expect_and_advance(TokenKind::RightParenthesis)?;
 
         Ok(Expression::Import(ImportExpression {
             node: self.end_node(start_index)?,
             source: Box::new(assignment_expression),
+            options,
         }))
     }
 
     // https://tc39.es/ecma262/#prod-Arguments
     // https://tc39.es/ecma262/#prod-ArgumentList
-    fn parse_arguments(&mut self) -> Result<Vec<CallExpressionArgument>, ParserError> {
+    pub(crate) fn parse_arguments(&mut self) -> Result<Vec<CallExpressionArgument>, ParserError> {
         self.expect_and_advance(TokenKind::LeftParenthesis)?;
 
         let mut arguments_list = vec![];
@@ -380,7 +450,9 @@ impl Parser {
             let start_index = self.start_node();
 
             let is_spread = if self.token_kind() == TokenKind::Ellipsis {
-                self.advance_any(); // Eat '...' token.
+                self.expect_feature(Feature::SpreadElements)?;
+
+                self.advance_any()?; // Eat '...' token.
 
                 true
             } else {
@@ -409,7 +481,11 @@ impl Parser {
                 break;
             }
 
-            self.advance_any();
+            if self.peek_token_kind() == TokenKind::RightParenthesis {
+                self.expect_feature(Feature::FunctionTrailingCommas)?;
+            }
+
+            self.advance_any()?;
         }
 
         self.expect_and_advance(TokenKind::RightParenthesis)?;
@@ -428,7 +504,9 @@ impl Parser {
         let mut is_computed = false;
 
         let is_current_token_optional = if self.token_kind() == TokenKind::OptionalChaining {
-            self.advance_any(); // Eat '?' token.
+            self.expect_feature(Feature::OptionalChaining)?;
+
+            self.advance_any()?; // Eat '?' token.
 
             self.context.in_optional_chain = true;
 
@@ -447,6 +525,7 @@ impl Parser {
                     callee: CallExpressionCallee::Expression(Box::new(optional_expression.clone())),
                     arguments,
                     optional: is_current_token_optional,
+                    type_arguments: None,
                 })
             } else {
                 let member_expression_property = match self.token_kind() {
@@ -496,8 +575,12 @@ impl Parser {
 
         match self.token_kind() {
             token_kind if token_kind.is_identifier_name() => {
+                let identifier = self.parse_identifier_name()?;
+
+                self.expect_property_name_features(&identifier.name, &identifier.node)?;
+
                 Ok(MemberExpressionProperty::Expression(Box::new(
-                    Expression::Identifier(self.parse_identifier_name()?),
+                    Expression::Identifier(identifier),
                 )))
             }
             token_kind if token_kind.is_private_identifier() => Ok(
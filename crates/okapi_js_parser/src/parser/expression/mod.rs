@@ -2,6 +2,7 @@ mod assignment;
 mod binary_or_logical;
 mod conditional;
 mod identifier;
+mod jsx;
 mod left_hand_side;
 mod primary;
 mod unary;
@@ -12,7 +13,7 @@ use crate::{Parser, ParserError, TokenKind};
 
 // 13 ECMAScript Language: Expressions
 // https://tc39.es/ecma262/#sec-ecmascript-language-expressions
-impl Parser {
+impl Parser<'_> {
     // https://tc39.es/ecma262/#prod-Expression
     pub(crate) fn parse_expression(&mut self) -> Result<Expression, ParserError> {
         let start_index = self.start_node();
@@ -22,7 +23,7 @@ impl Parser {
         let mut expressions = vec![assignment_expression.clone()];
 
         while self.token_kind() == TokenKind::Comma {
-            self.advance_any(); // Eat ',' token.
+            self.advance_any()?; // Eat ',' token.
 
             let expression = self.parse_assignment_expression()?;
 
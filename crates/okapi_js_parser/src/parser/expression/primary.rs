@@ -1,10 +1,15 @@
-use crate::{ast::*, GoalSymbol};
+use crate::{
+    ast::*,
+    features::{has_unicode_code_point_escape, is_reserved_word, pattern_features},
+    regexp::{convert_offsets, parse_regular_expression},
+    GoalSymbol,
+};
 
-use crate::{KeywordKind, Parser, ParserError, TokenKind, TokenValue};
+use crate::{Feature, KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind, TokenValue};
 
 // 13 ECMAScript Language: Expressions
 // https://tc39.es/ecma262/#sec-ecmascript-language-expressions
-impl Parser {
+impl Parser<'_> {
     // 13.2 Primary Expression
     // https://tc39.es/ecma262/#prod-PrimaryExpression
     pub(crate) fn parse_primary_expression(&mut self) -> Result<Expression, ParserError> {
@@ -33,6 +38,9 @@ impl Parser {
             | TokenKind::Keyword(KeywordKind::False) => {
                 Ok(Expression::Literal(self.parse_literal()?))
             }
+            TokenKind::BigIntLiteral => {
+                Ok(Expression::BigIntLiteral(self.parse_big_int_literal()?))
+            }
             TokenKind::LeftSquareBracket => Ok(Expression::Array(self.parse_array_literal()?)),
             TokenKind::LeftCurlyBrace => Ok(Expression::Object(self.parse_object_literal()?)),
             TokenKind::Keyword(KeywordKind::Function) => {
@@ -45,6 +53,10 @@ impl Parser {
             TokenKind::Keyword(KeywordKind::Class) => {
                 Ok(Expression::Class(self.parse_class_expression()?))
             }
+            TokenKind::At if self.is_decorator_start() => {
+                Ok(Expression::Class(self.parse_decorated_class_expression()?))
+            }
+            TokenKind::LessThan if self.is_jsx_start() => self.parse_jsx_element_or_fragment(),
             TokenKind::LeftParenthesis => self.parse_cover_parenthesized_expression(),
             TokenKind::Division | TokenKind::DivisionAssignment => Ok(Expression::RegExpLiteral(
                 self.parse_regular_expression_literal()?,
@@ -61,6 +73,8 @@ impl Parser {
     pub(crate) fn parse_cover_parenthesized_expression(
         &mut self,
     ) -> Result<Expression, ParserError> {
+        let start_index = self.start_node();
+
         self.expect_and_advance(TokenKind::LeftParenthesis)?;
 
         let expression = self.with_params(
@@ -70,6 +84,13 @@ impl Parser {
 
         self.expect_and_advance(TokenKind::RightParenthesis)?;
 
+        if self.options.preserve_parens {
+            return Ok(Expression::Parenthesized(ParenthesizedExpression {
+                node: self.end_node(start_index)?,
+                expression: Box::new(expression),
+            }));
+        }
+
         Ok(expression)
     }
 
@@ -137,10 +158,57 @@ impl Parser {
             _ => return Err(self.unexpected_current_token_value()),
         };
 
+        self.expect_number_features(raw, &node)?;
+
         Ok(Literal {
             node,
             value: LiteralValue::Number(value),
-            raw,
+            raw: raw.to_string(),
+        })
+    }
+
+    fn expect_number_features(&self, raw: &str, node: &Node) -> Result<(), ParserError> {
+        if matches!(raw.get(..2), Some("0b" | "0B" | "0o" | "0O")) {
+            self.expect_node_feature(Feature::BinaryAndOctalLiterals, node)?;
+        }
+
+        if raw.contains('_') {
+            self.expect_node_feature(Feature::NumericSeparators, node)?;
+        }
+
+        Ok(())
+    }
+
+    // https://tc39.es/ecma262/#prod-NumericLiteral
+    pub(crate) fn parse_big_int_literal(&mut self) -> Result<BigIntLiteral, ParserError> {
+        self.expect_feature(Feature::BigInt)?;
+
+        let start_index = self.start_node();
+
+        let token_value = self.token_value();
+
+        self.expect_and_advance(TokenKind::BigIntLiteral)?;
+
+        let raw = match token_value {
+            TokenValue::BigInt(raw) => raw,
+            _ => return Err(self.unexpected_current_token_value()),
+        };
+
+        let node = self.end_node(start_index)?;
+
+        if raw.contains('_') {
+            self.expect_node_feature(Feature::NumericSeparators, &node)?;
+        }
+
+        // The bigint property is the source text without the 'n' suffix or any numeric separators.
+        // https://github.com/estree/estree/blob/master/es2020.md#bigintliteral
+        let bigint = raw.trim_end_matches('n').replace('_', "");
+
+        Ok(BigIntLiteral {
+            node,
+            value: LiteralValue::BigInt,
+            raw: raw.to_string(),
+            bigint,
         })
     }
 
@@ -155,13 +223,23 @@ impl Parser {
             _ => return Err(self.unexpected_current_token_value()),
         };
 
+        self.expect_string_features(raw, &node)?;
+
         Ok(Literal {
             node,
-            value: LiteralValue::String(value),
-            raw,
+            value: LiteralValue::String(value.into_owned()),
+            raw: raw.to_string(),
         })
     }
 
+    fn expect_string_features(&self, raw: &str, node: &Node) -> Result<(), ParserError> {
+        if has_unicode_code_point_escape(raw) {
+            self.expect_node_feature(Feature::UnicodeCodePointEscapes, node)?;
+        }
+
+        Ok(())
+    }
+
     // 13.2.4 Array Initializer
     // https://tc39.es/ecma262/#prod-ArrayLiteral
     fn parse_array_literal(&mut self) -> Result<ArrayExpression, ParserError> {
@@ -186,16 +264,18 @@ impl Parser {
         while self.token_kind() != TokenKind::RightSquareBracket {
             match self.token_kind() {
                 TokenKind::Comma => {
-                    self.advance_any(); // Eat ',' token.
+                    self.advance_any()?; // Eat ',' token.
 
                     elements.push(None);
 
                     continue;
                 }
                 TokenKind::Ellipsis => {
+                    self.expect_feature(Feature::SpreadElements)?;
+
                     let start_index = self.start_node();
 
-                    self.advance_any(); // Eat '...' token.
+                    self.advance_any()?; // Eat '...' token.
 
                     let assignment_expression = self.with_params(
                         self.params.clone().add_allow_in(false),
@@ -235,14 +315,14 @@ impl Parser {
         let start_index = self.start_node();
 
         // Template literal middles or tails are not permitted within an object literal.
-        let previous_goal_symbol = self.cursor.lexer.goal_symbol.clone();
-        self.cursor.lexer.goal_symbol = GoalSymbol::InputElementDiv;
+        let previous_goal_symbol = self.cursor.goal_symbol();
+        self.cursor.set_goal_symbol(GoalSymbol::InputElementDiv);
 
         self.expect_and_advance(TokenKind::LeftCurlyBrace)?;
 
         let properties = self.parse_property_definition_list()?;
 
-        self.cursor.lexer.goal_symbol = previous_goal_symbol;
+        self.cursor.set_goal_symbol(previous_goal_symbol);
 
         self.expect_and_advance(TokenKind::RightCurlyBrace)?;
 
@@ -290,7 +370,9 @@ impl Parser {
         match (self.token_kind(), self.peek_token_kind()) {
             // `... AssignmentExpression`.
             (TokenKind::Ellipsis, _) => {
-                self.advance_any(); // Eat '...' token.
+                self.expect_feature(Feature::ObjectRestSpread)?;
+
+                self.advance_any()?; // Eat '...' token.
 
                 let assignment_expression = self.with_params(
                     self.params.clone().add_allow_in(false),
@@ -306,10 +388,16 @@ impl Parser {
             (TokenKind::Keyword(KeywordKind::Get), peek_token_kind)
                 if peek_token_kind.is_class_element_name() =>
             {
-                self.advance_any(); // Eat 'get' token.
+                self.expect_feature(Feature::PropertyAccessors)?;
+
+                self.advance_any()?; // Eat 'get' token.
 
                 is_computed = self.token_kind() == TokenKind::LeftSquareBracket;
 
+                if is_computed {
+                    self.expect_feature(Feature::ComputedProperties)?;
+                }
+
                 let method_definition_key = self.parse_class_element_name()?;
 
                 let function_expression = self.parse_method_definition_getter_body()?;
@@ -331,10 +419,16 @@ impl Parser {
             (TokenKind::Keyword(KeywordKind::Set), peek_token_kind)
                 if peek_token_kind.is_class_element_name() =>
             {
-                self.advance_any(); // Eat 'set' token.
+                self.expect_feature(Feature::PropertyAccessors)?;
+
+                self.advance_any()?; // Eat 'set' token.
 
                 is_computed = self.token_kind() == TokenKind::LeftSquareBracket;
 
+                if is_computed {
+                    self.expect_feature(Feature::ComputedProperties)?;
+                }
+
                 let method_definition_key = self.parse_class_element_name()?;
 
                 let function_expression = self.parse_method_definition_setter_body()?;
@@ -392,7 +486,7 @@ impl Parser {
             (TokenKind::Keyword(KeywordKind::Async), TokenKind::Multiplication)
                 if !self.has_current_token_line_terminator() =>
             {
-                self.advance_any(); // Eat 'async' token.
+                self.advance_any()?; // Eat 'async' token.
 
                 is_computed = self.peek_token_kind() == TokenKind::LeftSquareBracket;
 
@@ -410,6 +504,8 @@ impl Parser {
             }
             // `CoverInitializedName > IdentifierReference Initializer`.
             (token_kind, TokenKind::Assignment) if token_kind.is_identifier_reference() => {
+                self.expect_feature(Feature::ShorthandProperties)?;
+
                 property_definition_key =
                     Some(Expression::Identifier(self.parse_identifier_reference()?));
 
@@ -438,6 +534,8 @@ impl Parser {
 
             // `(PropertyName > ComputedPropertyName) :`.
             (TokenKind::LeftSquareBracket, _) => {
+                self.expect_feature(Feature::ComputedProperties)?;
+
                 is_computed = true;
 
                 let property_name = self.parse_computed_property_name()?;
@@ -459,7 +557,8 @@ impl Parser {
                     }
                     // `MethodDefinition > ClassElementName > ComputedPropertyName ( UniqueFormalParameters )`.
                     TokenKind::LeftParenthesis => {
-                        let function_expression = self.parse_method_definition_method_body()?;
+                        let function_expression =
+                            self.parse_method_definition_method_body(false)?;
 
                         let method_definition = self.parse_method_definition(
                             start_index,
@@ -480,8 +579,12 @@ impl Parser {
             // `IdentifierReference`.
             (token_kind, peek_token_kind)
                 if token_kind.is_identifier_reference()
-                    && peek_token_kind != TokenKind::LeftParenthesis =>
+                    && peek_token_kind != TokenKind::LeftParenthesis
+                    && !(self.options.extensions.typescript
+                        && peek_token_kind == TokenKind::LessThan) =>
             {
+                self.expect_feature(Feature::ShorthandProperties)?;
+
                 is_shorthand = true;
 
                 let identifier_reference =
@@ -493,11 +596,13 @@ impl Parser {
             }
             // `MethodDefinition > ClassElementName ( UniqueFormalParameters )`.
             (token_kind, _) if token_kind.is_class_element_name() => {
+                self.expect_feature(Feature::ShorthandProperties)?;
+
                 is_computed = token_kind == TokenKind::LeftSquareBracket;
 
                 let method_definition_key = self.parse_class_element_name()?;
 
-                let function_expression = self.parse_method_definition_method_body()?;
+                let function_expression = self.parse_method_definition_method_body(false)?;
 
                 let method_definition = self.parse_method_definition(
                     start_index,
@@ -516,11 +621,19 @@ impl Parser {
         };
 
         let Some(property_definition_key) = property_definition_key else {
-            return Err(ParserError::InvalidPropertyKey);
+            return Err(self.error(
+                ParserErrorKind::InvalidPropertyKey,
+                start_index,
+                self.cursor.previous_token.end,
+            ));
         };
 
         let Some(property_definition_value) = property_definition_value else {
-            return Err(ParserError::InvalidPropertyValue);
+            return Err(self.error(
+                ParserErrorKind::InvalidPropertyValue,
+                start_index,
+                self.cursor.previous_token.end,
+            ));
         };
 
         Ok(ObjectExpressionProperty::Property(Property {
@@ -542,52 +655,82 @@ impl Parser {
             token_kind if token_kind.is_identifier_name() => {
                 let start_index = self.start_node();
 
-                self.advance_any(); // Eat identifier token.
+                self.advance_any()?; // Eat identifier token.
 
                 let name = String::from(token_value);
 
+                let node = self.end_node(start_index)?;
+
+                self.expect_property_name_features(&name, &node)?;
+
                 Ok(Expression::Identifier(Identifier {
-                    node: self.end_node(start_index)?,
+                    node,
                     name,
+                    type_annotation: None,
+                    optional: false,
                 }))
             }
             TokenKind::StringLiteral => {
                 let start_index = self.start_node();
 
-                self.advance_any(); // Eat string literal token.
+                self.advance_any()?; // Eat string literal token.
 
                 let (raw, value) = match token_value {
                     TokenValue::String { raw, value } => (raw, value),
                     _ => return Err(self.unexpected_current_token_value()),
                 };
 
+                let node = self.end_node(start_index)?;
+
+                self.expect_string_features(raw, &node)?;
+
                 Ok(Expression::Literal(Literal {
-                    node: self.end_node(start_index)?,
-                    value: LiteralValue::String(value),
-                    raw,
+                    node,
+                    value: LiteralValue::String(value.into_owned()),
+                    raw: raw.to_string(),
                 }))
             }
             TokenKind::NumberLiteral => {
                 let start_index = self.start_node();
 
-                self.advance_any(); // Eat number literal token.
+                self.advance_any()?; // Eat number literal token.
 
                 let (raw, value) = match token_value {
                     TokenValue::Number { raw, value } => (raw, value),
                     _ => return Err(self.unexpected_current_token_value()),
                 };
 
+                let node = self.end_node(start_index)?;
+
+                self.expect_number_features(raw, &node)?;
+
                 Ok(Expression::Literal(Literal {
-                    node: self.end_node(start_index)?,
+                    node,
                     value: LiteralValue::Number(value),
-                    raw,
+                    raw: raw.to_string(),
                 }))
             }
+            TokenKind::BigIntLiteral => {
+                Ok(Expression::BigIntLiteral(self.parse_big_int_literal()?))
+            }
             TokenKind::LeftSquareBracket => self.parse_computed_property_name(),
             _ => Err(self.unexpected_current_token_kind()),
         }
     }
 
+    // Shared by property keys and member expressions, e.g. `{ class: a }` and `a.class`.
+    pub(crate) fn expect_property_name_features(
+        &self,
+        name: &str,
+        node: &Node,
+    ) -> Result<(), ParserError> {
+        if is_reserved_word(name) {
+            self.expect_node_feature(Feature::ReservedWordPropertyNames, node)?;
+        }
+
+        Ok(())
+    }
+
     // https://tc39.es/ecma262/#prod-ComputedPropertyName
     pub(crate) fn parse_computed_property_name(&mut self) -> Result<Expression, ParserError> {
         self.expect_and_advance(TokenKind::LeftSquareBracket)?;
@@ -609,10 +752,10 @@ impl Parser {
         // https://tc39.es/ecma262/#prod-InputElementRegExpOrTemplateTail
         let start_index = self.start_node();
 
-        let previous_goal_symbol = self.cursor.lexer.goal_symbol.clone();
-        self.cursor.lexer.goal_symbol = GoalSymbol::InputElementRegExp;
+        let previous_goal_symbol = self.cursor.goal_symbol();
+        self.cursor.set_goal_symbol(GoalSymbol::InputElementRegExp);
 
-        self.cursor.rewind();
+        self.rewind()?;
 
         let TokenValue::RegularExpression { pattern, flags } = self.token_value() else {
             return Err(self.unexpected_current_token_value());
@@ -622,21 +765,86 @@ impl Parser {
 
         let node = self.end_node(start_index)?;
 
-        self.cursor.lexer.goal_symbol = previous_goal_symbol;
+        self.cursor.set_goal_symbol(previous_goal_symbol);
 
         let raw_value = format!("/{:}/{:}", pattern, flags);
 
+        // Each flag is located by itself, after the pattern and its enclosing slashes.
+        let flags_index = start_index + pattern.len() + 2;
+
+        for (index, flag) in flags.char_indices() {
+            let feature = match flag {
+                'y' => Feature::RegExpStickyFlag,
+                'u' => Feature::RegExpUnicodeFlag,
+                's' => Feature::RegExpDotAllFlag,
+                'd' => Feature::RegExpMatchIndicesFlag,
+                'v' => Feature::RegExpUnicodeSetsFlag,
+                _ => continue,
+            };
+
+            self.expect_feature_in(feature, flags_index + index, flags_index + index + 1)?;
+        }
+
+        let mut regexp = parse_regular_expression(pattern, flags)
+            .map_err(|kind| self.regular_expression_error(kind, start_index, pattern))?;
+
+        // Pattern features are located at the whole literal, the same as in the `FeatureReport`.
+        for feature in pattern_features(&regexp.pattern) {
+            self.expect_node_feature(feature, &node)?;
+        }
+
+        // The pattern's node offsets stay relative to the start of the pattern, in the selected offset encoding.
+        if !pattern.is_ascii() {
+            let pattern_index = start_index + 1;
+            let pattern_offset = self.locations.offset(pattern_index);
+
+            convert_offsets(&mut regexp, &|index| {
+                self.locations.offset(pattern_index + index) - pattern_offset
+            });
+        }
+
         Ok(RegExpLiteral {
             node,
-            regex: Regex { pattern, flags },
+            regex: Regex {
+                pattern: pattern.to_string(),
+                flags: flags.to_string(),
+            },
             value: LiteralValue::Regex {},
             raw: raw_value,
+            regexp: Box::new(regexp),
         })
     }
 
+    // Pattern errors are located at the character they refer to, after the opening `/`, and flag errors at the flag,
+    // after the closing `/`.
+    #[inline(never)]
+    fn regular_expression_error(
+        &self,
+        kind: ParserErrorKind,
+        start_index: usize,
+        pattern: &str,
+    ) -> ParserError {
+        let pattern_start_index = start_index + "/".len();
+
+        let error_index = match (kind.regex_pattern_offset(), kind.regex_flag_offset()) {
+            (Some(offset), _) => pattern_start_index + offset,
+            (_, Some(offset)) => pattern_start_index + pattern.len() + "/".len() + offset,
+            _ => start_index,
+        };
+
+        let char_len = self.cursor.lexer.source[error_index..]
+            .chars()
+            .next()
+            .map_or(0, char::len_utf8);
+
+        self.error(kind, error_index, error_index + char_len)
+    }
+
     // 13.2.7 Template Literals
     // https://tc39.es/ecma262/#sec-template-literals
     pub(crate) fn parse_template_literal(&mut self) -> Result<TemplateLiteral, ParserError> {
+        self.expect_feature(Feature::TemplateLiterals)?;
+
         let start_index = self.start_node();
 
         let mut expressions = vec![];
@@ -686,7 +894,7 @@ impl Parser {
         })
     }
 
-    fn parse_template_element(
+    pub(crate) fn parse_template_element(
         &mut self,
         has_tail: bool,
         start_offset: usize,
@@ -705,14 +913,17 @@ impl Parser {
             TokenKind::TemplateTail,
         ])?;
 
-        let mut node = self.end_node(start_index)?;
-
-        node.loc.start += start_offset;
-        node.loc.end -= end_offset;
+        let node = self.create_node(
+            start_index + start_offset,
+            self.cursor.previous_token.end - end_offset,
+        );
 
         Ok(TemplateElement {
             node,
-            value: TemplateElementValue { cooked, raw },
+            value: TemplateElementValue {
+                cooked: cooked.into_owned(),
+                raw: raw.to_string(),
+            },
             tail: has_tail,
         })
     }
@@ -18,7 +18,7 @@ fn match_token_kind_to_unary_operator(token_kind: &TokenKind) -> Option<UnaryOpe
 
 // 13 ECMAScript Language: Expressions
 // https://tc39.es/ecma262/#sec-ecmascript-language-expressions
-impl Parser {
+impl Parser<'_> {
     // 13.5 Unary Operators
     // https://tc39.es/ecma262/#prod-UnaryExpression
     pub(crate) fn parse_unary_expression(&mut self) -> Result<Expression, ParserError> {
@@ -36,7 +36,7 @@ impl Parser {
                     return Err(self.unexpected_current_token_kind());
                 };
 
-                self.advance_any(); // Eat delete or void or typeof or + or - or ~ or ! token.
+                self.advance_any()?; // Eat delete or void or typeof or + or - or ~ or ! token.
 
                 let unary_argument = self.parse_unary_expression()?;
 
@@ -48,19 +48,46 @@ impl Parser {
                 }))
             }
             TokenKind::Keyword(KeywordKind::Await) if self.params.has_allow_await() => {
+                self.parse_await_expression()
+            }
+            // `< Type > UnaryExpression` in TypeScript, which is a JSX element when JSX is enabled.
+            TokenKind::LessThan
+                if self.options.extensions.typescript && !self.options.extensions.jsx =>
+            {
                 let start_index = self.start_node();
 
-                self.advance_any(); // Eat `await` token.
+                self.advance_any()?; // Eat '<' token.
+
+                let type_annotation = self.parse_assertion_type()?;
+
+                self.expect_type_list_end_and_advance()?;
 
                 let unary_expression = self.parse_unary_expression()?;
 
-                // TODO check if is supported by ECMA script version.
-                Ok(Expression::Await(AwaitExpression {
+                Ok(Expression::TSTypeAssertion(Box::new(TSTypeAssertion {
                     node: self.end_node(start_index)?,
-                    argument: Box::new(unary_expression),
-                }))
+                    type_annotation,
+                    expression: Box::new(unary_expression),
+                })))
             }
             _ => self.parse_update_expression(),
         }
     }
+
+    // 15.8 Async Function Definitions
+    // https://tc39.es/ecma262/#prod-AwaitExpression
+    fn parse_await_expression(&mut self) -> Result<Expression, ParserError> {
+        self.expect_await_feature()?;
+
+        let start_index = self.start_node();
+
+        self.advance_any()?; // Eat `await` token.
+
+        let unary_expression = self.parse_unary_expression()?;
+
+        Ok(Expression::Await(AwaitExpression {
+            node: self.end_node(start_index)?,
+            argument: Box::new(unary_expression),
+        }))
+    }
 }
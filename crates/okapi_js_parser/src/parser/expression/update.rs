@@ -11,7 +11,7 @@ fn match_token_kind_to_update_operator(token_kind: &TokenKind) -> Option<UpdateO
 
 // 13 ECMAScript Language: Expressions
 // https://tc39.es/ecma262/#sec-ecmascript-language-expressions
-impl Parser {
+impl Parser<'_> {
     // 13.4 Update Expressions
     // https://tc39.es/ecma262/#prod-UpdateExpression
     pub(crate) fn parse_update_expression(&mut self) -> Result<Expression, ParserError> {
@@ -20,7 +20,7 @@ impl Parser {
         let mut operator_token_kind = self.token_kind();
 
         if operator_token_kind.is_update_operator() {
-            self.advance_any(); // Eat '++' or '--' token.
+            self.advance_any()?; // Eat '++' or '--' token.
 
             let operator = match_token_kind_to_update_operator(&operator_token_kind).unwrap();
 
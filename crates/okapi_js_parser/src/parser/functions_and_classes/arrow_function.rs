@@ -1,38 +1,61 @@
 use std::vec;
 
 use crate::ast::*;
-use crate::{Parser, ParserError, TokenKind};
+use crate::{Feature, Parser, ParserError, ParserErrorKind, TokenKind};
 
 // 15 ECMAScript Language: Functions and Classes
 // https://tc39.es/ecma262/#sec-ecmascript-language-functions-and-classes
-impl Parser {
+impl Parser<'_> {
     // 15.3 Arrow Function Definitions
     // https://tc39.es/ecma262/#prod-ArrowFunction
     // https://tc39.es/ecma262/#prod-ArrowFormalParameters
     pub(crate) fn parse_arrow_function(&mut self) -> Result<Expression, ParserError> {
         let start_index = self.start_node();
 
-        let parameters = if self.token_kind().is_binding_identifier() {
-            vec![Pattern::Identifier(self.parse_binding_identifier()?)]
-        } else {
-            self.parse_parenthesized_formal_parameters()?
-                .into_iter()
-                .map(Pattern::try_from)
-                .collect::<Result<Vec<Pattern>, ParserError>>()?
-        };
+        let (type_parameters, parameters, return_type) =
+            if self.token_kind().is_binding_identifier() {
+                (
+                    None,
+                    vec![Pattern::Identifier(self.parse_binding_identifier()?)],
+                    None,
+                )
+            } else {
+                let type_parameters = self.parse_optional_type_parameters()?;
+
+                let parameters = self
+                    .parse_parenthesized_formal_parameters()?
+                    .into_iter()
+                    .map(Pattern::try_from)
+                    .collect::<Result<Vec<Pattern>, ParserError>>()?;
+
+                (
+                    type_parameters,
+                    parameters,
+                    self.parse_optional_return_type()?,
+                )
+            };
 
         if self.has_previous_token_line_terminator() {
-            return Err(ParserError::UnexpectedLineTerminator);
+            return Err(self.current_token_error(ParserErrorKind::UnexpectedLineTerminator));
         }
 
+        self.expect_feature(Feature::ArrowFunctions)?;
+
         self.expect_and_advance(TokenKind::ArrowFunction)?;
 
         let is_expression = self.token_kind() != TokenKind::LeftCurlyBrace;
 
+        // `await` is not an operator in the body of a non-async arrow function, even within an async function or module.
+        let body_params = self.params.clone().add_allow_await(false);
+
         let arrow_function_body = if self.token_kind() == TokenKind::LeftCurlyBrace {
-            ArrowFunctionExpressionBody::BlockStatement(self.parse_function_body()?)
+            ArrowFunctionExpressionBody::BlockStatement(
+                self.with_params(body_params, Self::parse_function_body)?,
+            )
         } else {
-            ArrowFunctionExpressionBody::Expression(Box::new(self.parse_assignment_expression()?))
+            ArrowFunctionExpressionBody::Expression(Box::new(
+                self.with_params(body_params, Self::parse_assignment_expression)?,
+            ))
         };
 
         Ok(Expression::ArrowFunction(ArrowFunctionExpression {
@@ -43,6 +66,8 @@ impl Parser {
             expression: is_expression,
             generator: false,
             is_async: false,
+            type_parameters: type_parameters.map(Box::new),
+            return_type: return_type.map(Box::new),
         }))
     }
 }
@@ -1,43 +1,71 @@
 use crate::ast::*;
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{Feature, KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind};
 
 // 15 ECMAScript Language: Functions and Classes
 // https://tc39.es/ecma262/#sec-ecmascript-language-functions-and-classes
-impl Parser {
+impl Parser<'_> {
     // 15.9 Async Arrow Function Definitions
     // https://tc39.es/ecma262/#prod-AsyncArrowFunction
     pub(crate) fn parse_async_arrow_function_declaration(
         &mut self,
     ) -> Result<Expression, ParserError> {
+        self.expect_feature(Feature::AsyncFunctions)?;
+
         let start_index = self.start_node();
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Async))?;
 
         if self.has_previous_token_line_terminator() {
-            return Err(ParserError::UnexpectedLineTerminator);
+            return Err(self.current_token_error(ParserErrorKind::UnexpectedLineTerminator));
         }
 
-        let parameters = if self.token_kind().is_binding_identifier() {
-            vec![Pattern::Identifier(self.parse_binding_identifier()?)]
-        } else {
-            self.parse_parenthesized_formal_parameters()?
-                .into_iter()
-                .map(Pattern::try_from)
-                .collect::<Result<Vec<Pattern>, ParserError>>()?
-        };
+        let (type_parameters, parameters, return_type) =
+            if self.token_kind().is_binding_identifier() {
+                (
+                    None,
+                    vec![Pattern::Identifier(self.parse_binding_identifier()?)],
+                    None,
+                )
+            } else {
+                let type_parameters = self.parse_optional_type_parameters()?;
+
+                let parameters = self
+                    .parse_parenthesized_formal_parameters()?
+                    .into_iter()
+                    .map(Pattern::try_from)
+                    .collect::<Result<Vec<Pattern>, ParserError>>()?;
+
+                (
+                    type_parameters,
+                    parameters,
+                    self.parse_optional_return_type()?,
+                )
+            };
 
         if self.has_previous_token_line_terminator() {
-            return Err(ParserError::UnexpectedLineTerminator);
+            return Err(self.current_token_error(ParserErrorKind::UnexpectedLineTerminator));
         }
 
         self.expect_and_advance(TokenKind::ArrowFunction)?;
 
         let is_expression = self.token_kind() != TokenKind::LeftCurlyBrace;
 
+        let body_params = self.params.clone().add_allow_await(true);
+
         let arrow_function_body = if self.token_kind() == TokenKind::LeftCurlyBrace {
-            ArrowFunctionExpressionBody::BlockStatement(self.parse_function_body()?)
+            ArrowFunctionExpressionBody::BlockStatement(
+                self.with_params(body_params, Self::parse_function_body)?,
+            )
         } else {
-            ArrowFunctionExpressionBody::Expression(Box::new(self.parse_assignment_expression()?))
+            // A concise body is not a function body, but `await` within it still belongs to the arrow function.
+            let previous_in_function = self.context.in_function;
+            self.context.in_function = true;
+
+            let expression = self.with_params(body_params, Self::parse_assignment_expression)?;
+
+            self.context.in_function = previous_in_function;
+
+            ArrowFunctionExpressionBody::Expression(Box::new(expression))
         };
 
         Ok(Expression::ArrowFunction(ArrowFunctionExpression {
@@ -48,6 +76,8 @@ impl Parser {
             expression: is_expression,
             generator: false,
             is_async: true,
+            type_parameters: type_parameters.map(Box::new),
+            return_type: return_type.map(Box::new),
         }))
     }
 }
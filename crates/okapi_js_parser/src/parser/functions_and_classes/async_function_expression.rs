@@ -1,20 +1,22 @@
 use crate::{ast::*, Params};
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{Feature, KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind};
 
 // 15 ECMAScript Language: Functions and Classes
 // https://tc39.es/ecma262/#sec-ecmascript-language-functions-and-classes
-impl Parser {
+impl Parser<'_> {
     // 15.8 Async Function Definitions
     // https://tc39.es/ecma262/#prod-AsyncFunctionDeclaration
     pub(crate) fn parse_async_function_declaration(
         &mut self,
     ) -> Result<FunctionDeclaration, ParserError> {
+        self.expect_feature(Feature::AsyncFunctions)?;
+
         let start_index = self.start_node();
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Async))?;
 
         if self.has_previous_token_line_terminator() {
-            return Err(ParserError::UnexpectedLineTerminator);
+            return Err(self.current_token_error(ParserErrorKind::UnexpectedLineTerminator));
         }
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Function))?;
@@ -25,8 +27,12 @@ impl Parser {
             None
         };
 
+        let type_parameters = self.parse_optional_type_parameters()?;
+
         let formal_parameters = self.parse_parenthesized_formal_parameters()?;
 
+        let return_type = self.parse_optional_return_type()?;
+
         let body = self.with_params(
             Params::default().add_allow_await(true),
             Self::parse_function_body,
@@ -40,6 +46,8 @@ impl Parser {
             generator: false,
             asynchronous: true,
             expression: false,
+            type_parameters: type_parameters.map(Box::new),
+            return_type: return_type.map(Box::new),
         })
     }
 
@@ -47,6 +55,8 @@ impl Parser {
     pub(crate) fn parse_async_function_expression(
         &mut self,
     ) -> Result<FunctionExpression, ParserError> {
+        self.expect_feature(Feature::AsyncFunctions)?;
+
         let start_index = self.start_node();
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Async))?;
@@ -59,8 +69,12 @@ impl Parser {
             None
         };
 
+        let type_parameters = self.parse_optional_type_parameters()?;
+
         let formal_parameters = self.parse_parenthesized_formal_parameters()?;
 
+        let return_type = self.parse_optional_return_type()?;
+
         let body = self.with_params(
             Params::default().add_allow_await(true),
             Self::parse_function_body,
@@ -74,6 +88,8 @@ impl Parser {
             expression: false,
             generator: false,
             is_async: true,
+            type_parameters: type_parameters.map(Box::new),
+            return_type: return_type.map(Box::new),
         })
     }
 }
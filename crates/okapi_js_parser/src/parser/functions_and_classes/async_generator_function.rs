@@ -1,20 +1,22 @@
-use crate::{ast::*, KeywordKind, Params, TokenKind};
-use crate::{Parser, ParserError};
+use crate::{ast::*, Feature, KeywordKind, Params, TokenKind};
+use crate::{Parser, ParserError, ParserErrorKind};
 
 // 15 ECMAScript Language: Functions and Classes
 // https://tc39.es/ecma262/#sec-ecmascript-language-functions-and-classes
-impl Parser {
+impl Parser<'_> {
     // 15.6 Async Generator Function Definitions
     // https://tc39.es/ecma262/#prod-AsyncGeneratorDeclaration
     pub(crate) fn parse_async_generator_declaration(
         &mut self,
     ) -> Result<FunctionDeclaration, ParserError> {
+        self.expect_feature(Feature::AsyncIteration)?;
+
         let start_index = self.start_node();
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Async))?;
 
         if self.has_previous_token_line_terminator() {
-            return Err(ParserError::UnexpectedLineTerminator);
+            return Err(self.current_token_error(ParserErrorKind::UnexpectedLineTerminator));
         }
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Function))?;
@@ -27,8 +29,12 @@ impl Parser {
             None
         };
 
+        let type_parameters = self.parse_optional_type_parameters()?;
+
         let formal_parameters = self.parse_parenthesized_formal_parameters()?;
 
+        let return_type = self.parse_optional_return_type()?;
+
         let generator_body = self.with_params(
             Params::default()
                 .add_allow_yield(true)
@@ -44,6 +50,8 @@ impl Parser {
             generator: true,
             asynchronous: true,
             expression: false,
+            type_parameters: type_parameters.map(Box::new),
+            return_type: return_type.map(Box::new),
         })
     }
 
@@ -51,12 +59,14 @@ impl Parser {
     pub(crate) fn parse_async_generator_expression(
         &mut self,
     ) -> Result<FunctionExpression, ParserError> {
+        self.expect_feature(Feature::AsyncIteration)?;
+
         let start_index = self.start_node();
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Async))?;
 
         if self.has_previous_token_line_terminator() {
-            return Err(ParserError::UnexpectedLineTerminator);
+            return Err(self.current_token_error(ParserErrorKind::UnexpectedLineTerminator));
         }
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Function))?;
@@ -69,8 +79,12 @@ impl Parser {
             None
         };
 
+        let type_parameters = self.parse_optional_type_parameters()?;
+
         let formal_parameters = self.parse_parenthesized_formal_parameters()?;
 
+        let return_type = self.parse_optional_return_type()?;
+
         let generator_body = self.with_params(
             Params::default()
                 .add_allow_yield(true)
@@ -86,6 +100,8 @@ impl Parser {
             is_async: true,
             params: formal_parameters,
             body: generator_body,
+            type_parameters: type_parameters.map(Box::new),
+            return_type: return_type.map(Box::new),
         })
     }
 }
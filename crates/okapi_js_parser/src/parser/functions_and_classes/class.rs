@@ -1,9 +1,11 @@
+use std::borrow::Cow;
+
 use crate::{ast::*, TokenValue};
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{Feature, KeywordKind, Parser, ParserError, TokenKind};
 
 // 15 ECMAScript Language: Functions and Classes
 // https://tc39.es/ecma262/#sec-ecmascript-language-functions-and-classes
-impl Parser {
+impl Parser<'_> {
     // 15.7 Class Definitions
     // https://tc39.es/ecma262/#prod-ClassExpression
     pub(crate) fn parse_class_declaration(&mut self) -> Result<ClassDeclaration, ParserError> {
@@ -14,6 +16,8 @@ impl Parser {
 
         let start_index = self.start_node();
 
+        self.expect_feature(Feature::Classes)?;
+
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Class))?;
 
         let optional_binding_identifier = match self.token_kind() {
@@ -24,11 +28,19 @@ impl Parser {
             _ => return Err(self.unexpected_current_token_kind()),
         };
 
-        let super_class = if self.token_kind() == TokenKind::Keyword(KeywordKind::Extends) {
-            Some(self.parse_class_heritage()?)
-        } else {
-            None
-        };
+        let type_parameters = self.parse_optional_type_parameters()?;
+
+        let (super_class, super_type_arguments) =
+            if self.token_kind() == TokenKind::Keyword(KeywordKind::Extends) {
+                (
+                    Some(self.parse_class_heritage()?),
+                    self.parse_optional_heritage_type_arguments()?,
+                )
+            } else {
+                (None, None)
+            };
+
+        let implements = self.parse_optional_class_implements()?;
 
         let class_tail = self.parse_class_tail()?;
 
@@ -39,6 +51,12 @@ impl Parser {
             id: optional_binding_identifier,
             super_class,
             body: class_tail,
+            decorators: vec![],
+            type_parameters: type_parameters.map(Box::new),
+            super_type_arguments: super_type_arguments.map(Box::new),
+            implements,
+            is_abstract: false,
+            declare: false,
         })
     }
 
@@ -51,6 +69,8 @@ impl Parser {
 
         let start_index = self.start_node();
 
+        self.expect_feature(Feature::Classes)?;
+
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Class))?;
 
         let binding_identifier = if self.token_kind().is_binding_identifier() {
@@ -59,11 +79,19 @@ impl Parser {
             None
         };
 
-        let super_class = if self.token_kind() == TokenKind::Keyword(KeywordKind::Extends) {
-            Some(Box::new(self.parse_class_heritage()?))
-        } else {
-            None
-        };
+        let type_parameters = self.parse_optional_type_parameters()?;
+
+        let (super_class, super_type_arguments) =
+            if self.token_kind() == TokenKind::Keyword(KeywordKind::Extends) {
+                (
+                    Some(Box::new(self.parse_class_heritage()?)),
+                    self.parse_optional_heritage_type_arguments()?,
+                )
+            } else {
+                (None, None)
+            };
+
+        let implements = self.parse_optional_class_implements()?;
 
         let class_tail = self.parse_class_tail()?;
 
@@ -74,6 +102,12 @@ impl Parser {
             id: binding_identifier,
             super_class,
             body: class_tail,
+            decorators: vec![],
+            type_parameters: type_parameters.map(Box::new),
+            super_type_arguments: super_type_arguments.map(Box::new),
+            implements,
+            is_abstract: false,
+            declare: false,
         })
     }
 
@@ -105,10 +139,66 @@ impl Parser {
         let mut class_element_list = vec![];
 
         while self.token_kind() != TokenKind::RightCurlyBrace {
-            let Some(class_element) = self.parse_class_element(false, None)? else {
+            let start_index = self.start_node();
+
+            let decorators = self.parse_decorator_list()?;
+
+            let modifiers = self.parse_class_element_modifiers()?;
+
+            // `[ BindingIdentifier : Type ] : Type ;`
+            if self.options.extensions.typescript && self.is_index_signature_start() {
+                if !decorators.is_empty() {
+                    return Err(self.invalid_decorator_position(&decorators));
+                }
+
+                class_element_list.push(ClassBodyBody::TSIndexSignature(
+                    self.parse_index_signature(
+                        start_index,
+                        modifiers.readonly,
+                        modifiers.is_static,
+                        modifiers.accessibility,
+                    )?,
+                ));
+
+                continue;
+            }
+
+            let is_static = modifiers.is_static;
+
+            let Some(class_element) = self.parse_class_element(is_static, Some(start_index))?
+            else {
+                if !decorators.is_empty() {
+                    return Err(self.invalid_decorator_position(&decorators));
+                }
+
                 continue;
             };
 
+            let mut class_element = Self::apply_class_element_modifiers(class_element, modifiers)?;
+
+            if !decorators.is_empty() {
+                match &mut class_element {
+                    ClassBodyBody::MethodDefinition(method_definition) => {
+                        method_definition.decorators = decorators;
+                    }
+                    ClassBodyBody::PropertyDefinition(property_definition) => {
+                        property_definition.decorators = decorators;
+                    }
+                    ClassBodyBody::AccessorProperty(accessor_property) => {
+                        accessor_property.decorators = decorators;
+                    }
+                    ClassBodyBody::TSAbstractMethodDefinition(method_definition) => {
+                        method_definition.decorators = decorators;
+                    }
+                    ClassBodyBody::TSAbstractPropertyDefinition(property_definition) => {
+                        property_definition.decorators = decorators;
+                    }
+                    ClassBodyBody::StaticBlock(_) | ClassBodyBody::TSIndexSignature(_) => {
+                        return Err(self.invalid_decorator_position(&decorators));
+                    }
+                }
+            }
+
             class_element_list.push(class_element);
         }
 
@@ -126,7 +216,7 @@ impl Parser {
         match (self.token_kind(), self.peek_token_kind()) {
             // `;`.
             (TokenKind::Semicolon, _) => {
-                self.advance_any(); // Eat ';' token.
+                self.advance_any()?; // Eat ';' token.
 
                 Ok(None)
             }
@@ -145,15 +235,27 @@ impl Parser {
             (TokenKind::Keyword(KeywordKind::Static), peek_token_kind)
                 if !matches!(peek_token_kind, TokenKind::LeftParenthesis) =>
             {
-                self.advance_any(); // Eat 'static' token.
+                self.advance_any()?; // Eat 'static' token.
 
                 self.parse_class_element(true, Some(start_index))
             }
+            // `accessor [no LineTerminator here] ClassElementName Initializer ;`.
+            (TokenKind::Keyword(KeywordKind::Accessor), peek_token_kind)
+                if self.options.extensions.decorators
+                    && !self.has_current_token_line_terminator()
+                    && peek_token_kind.is_class_element_name() =>
+            {
+                self.advance_any()?; // Eat 'accessor' token.
+
+                Ok(Some(ClassBodyBody::AccessorProperty(
+                    self.parse_accessor_property(start_index, is_static)?,
+                )))
+            }
             // `MethodDefinition > get ClassElementName ... ;`.
             (TokenKind::Keyword(KeywordKind::Get), peek_token_kind)
                 if peek_token_kind.is_class_element_name() =>
             {
-                self.advance_any(); // Eat 'get' token.
+                self.advance_any()?; // Eat 'get' token.
 
                 let is_computed = self.token_kind() == TokenKind::LeftSquareBracket;
 
@@ -176,7 +278,7 @@ impl Parser {
             (TokenKind::Keyword(KeywordKind::Set), peek_token_kind)
                 if peek_token_kind.is_class_element_name() =>
             {
-                self.advance_any(); // Eat 'set' token.
+                self.advance_any()?; // Eat 'set' token.
 
                 let is_computed = self.token_kind() == TokenKind::LeftSquareBracket;
 
@@ -235,7 +337,7 @@ impl Parser {
             (TokenKind::Keyword(KeywordKind::Async), TokenKind::Multiplication)
                 if !self.has_current_token_line_terminator() =>
             {
-                self.advance_any(); // Eat 'async' token.
+                self.advance_any()?; // Eat 'async' token.
 
                 let is_computed = self.peek_token_kind() == TokenKind::LeftSquareBracket;
 
@@ -257,8 +359,8 @@ impl Parser {
                 let is_computed = token_kind == TokenKind::LeftSquareBracket;
 
                 let constructor_token_value = TokenValue::String {
-                    raw: "constructor".to_string(),
-                    value: "constructor".to_string(),
+                    raw: "constructor",
+                    value: Cow::Borrowed("constructor"),
                 };
 
                 let method_definition_kind = if self.token_value() == constructor_token_value {
@@ -269,33 +371,75 @@ impl Parser {
 
                 let method_definition_key = self.parse_class_element_name()?;
 
-                match self.token_kind() {
-                    TokenKind::LeftParenthesis => {
-                        let function_expression = self.parse_method_definition_method_body()?;
+                // `ClassElementName ?` and `ClassElementName !` in TypeScript.
+                let optional = self.options.extensions.typescript
+                    && self.token_kind() == TokenKind::QuestionMark;
 
-                        let method_definition = self.parse_method_definition(
-                            start_index,
-                            Some(method_definition_key),
-                            Some(function_expression),
-                            method_definition_kind,
-                            is_static,
-                            is_computed,
-                        )?;
+                if optional {
+                    self.advance_any()?; // Eat '?' token.
+                }
 
-                        Ok(Some(ClassBodyBody::MethodDefinition(method_definition)))
-                    }
-                    TokenKind::Assignment | TokenKind::Semicolon => {
-                        let field_definition = self.parse_field_definition(
-                            start_index,
-                            Some(method_definition_key),
-                            is_static,
-                            is_computed,
-                        )?;
-
-                        Ok(Some(ClassBodyBody::PropertyDefinition(field_definition)))
+                let definite = self.options.extensions.typescript
+                    && !optional
+                    && self.token_kind() == TokenKind::LogicalNot
+                    && !self.has_previous_token_line_terminator();
+
+                if definite {
+                    self.advance_any()?; // Eat '!' token.
+                }
+
+                let is_method = self.token_kind() == TokenKind::LeftParenthesis
+                    || (self.options.extensions.typescript
+                        && self.token_kind() == TokenKind::LessThan);
+
+                if !is_method {
+                    // A TypeScript field may have a type annotation, and its semicolon may be inserted.
+                    let is_field = matches!(
+                        self.token_kind(),
+                        TokenKind::Assignment | TokenKind::Semicolon
+                    ) || (self.options.extensions.typescript
+                        && (matches!(
+                            self.token_kind(),
+                            TokenKind::Colon | TokenKind::RightCurlyBrace
+                        ) || self.has_previous_token_line_terminator()));
+
+                    if !is_field {
+                        return Err(self.unexpected_current_token_kind());
                     }
-                    _ => Err(self.unexpected_current_token_kind()),
+
+                    let mut field_definition = self.parse_field_definition(
+                        start_index,
+                        Some(method_definition_key),
+                        is_static,
+                        is_computed,
+                    )?;
+
+                    field_definition.optional = optional;
+                    field_definition.definite = definite;
+
+                    return Ok(Some(ClassBodyBody::PropertyDefinition(field_definition)));
+                }
+
+                if definite {
+                    return Err(self.unexpected_current_token_kind());
                 }
+
+                let function_expression = self.parse_method_definition_method_body(
+                    method_definition_kind == MethodDefinitionKind::Constructor,
+                )?;
+
+                let mut method_definition = self.parse_method_definition(
+                    start_index,
+                    Some(method_definition_key),
+                    Some(function_expression),
+                    method_definition_kind,
+                    is_static,
+                    is_computed,
+                )?;
+
+                method_definition.optional = optional;
+
+                Ok(Some(ClassBodyBody::MethodDefinition(method_definition)))
             }
             _ => Err(self.unexpected_current_token_kind()),
         }
@@ -309,25 +453,18 @@ impl Parser {
         is_static: bool,
         is_computed: bool,
     ) -> Result<PropertyDefinition, ParserError> {
+        self.expect_feature(Feature::ClassFields)?;
+
         let class_element_name = match class_element_name {
             Some(class_element_name) => class_element_name,
             None => self.parse_class_element_name()?,
         };
 
-        let optional_assignment_expression = if self.token_kind() == TokenKind::Assignment {
-            self.advance_any(); // Eat  '=' token.
+        let type_annotation = self.parse_optional_type_annotation()?;
 
-            let assignment_expression = self.with_params(
-                self.params.clone().add_allow_in(false),
-                Self::parse_assignment_expression,
-            )?;
+        let optional_assignment_expression = self.parse_optional_field_initializer()?;
 
-            Some(assignment_expression)
-        } else {
-            None
-        };
-
-        self.expect_optional_semicolon_and_advance();
+        self.expect_optional_semicolon_and_advance()?;
 
         Ok(PropertyDefinition {
             node: self.end_node(start_index)?,
@@ -335,9 +472,57 @@ impl Parser {
             computed: is_computed,
             key: Some(class_element_name),
             value: optional_assignment_expression,
+            decorators: vec![],
+            type_annotation: type_annotation.map(Box::new),
+            accessibility: None,
+            readonly: false,
+            optional: false,
+            definite: false,
+            declare: false,
+            is_override: false,
+        })
+    }
+
+    // https://arai-a.github.io/ecma262-compare/?pr=2417#prod-FieldDefinition
+    fn parse_accessor_property(
+        &mut self,
+        start_index: usize,
+        is_static: bool,
+    ) -> Result<AccessorProperty, ParserError> {
+        let is_computed = self.token_kind() == TokenKind::LeftSquareBracket;
+
+        let class_element_name = self.parse_class_element_name()?;
+
+        let optional_assignment_expression = self.parse_optional_field_initializer()?;
+
+        self.expect_optional_semicolon_and_advance()?;
+
+        Ok(AccessorProperty {
+            node: self.end_node(start_index)?,
+            is_static,
+            computed: is_computed,
+            key: class_element_name,
+            value: optional_assignment_expression,
+            decorators: vec![],
         })
     }
 
+    // https://tc39.es/ecma262/#prod-Initializer
+    fn parse_optional_field_initializer(&mut self) -> Result<Option<Expression>, ParserError> {
+        if self.token_kind() != TokenKind::Assignment {
+            return Ok(None);
+        }
+
+        self.advance_any()?; // Eat  '=' token.
+
+        let assignment_expression = self.with_params(
+            self.params.clone().add_allow_in(false),
+            Self::parse_assignment_expression,
+        )?;
+
+        Ok(Some(assignment_expression))
+    }
+
     // https://tc39.es/ecma262/#prod-ClassElementName
     pub(crate) fn parse_class_element_name(
         &mut self,
@@ -359,13 +544,19 @@ impl Parser {
 
     // https://tc39.es/ecma262/#prod-ClassStaticBlock
     fn parse_static_block(&mut self) -> Result<StaticBlock, ParserError> {
+        self.expect_feature(Feature::ClassStaticBlock)?;
+
         let start_index = self.start_node();
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Static))?;
 
         self.expect_and_advance(TokenKind::LeftCurlyBrace)?;
 
-        let statement_list = self.parse_statement_list()?;
+        // https://tc39.es/ecma262/#prod-ClassStaticBlockStatementList
+        let statement_list = self.with_params(
+            self.params.clone().add_allow_return(false),
+            Self::parse_statement_list,
+        )?;
 
         self.expect_and_advance(TokenKind::RightCurlyBrace)?;
 
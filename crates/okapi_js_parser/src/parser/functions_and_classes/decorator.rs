@@ -0,0 +1,181 @@
+use crate::ast::*;
+use crate::{KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind};
+
+// Decorators
+// https://github.com/tc39/proposal-decorators
+impl Parser<'_> {
+    // Decorators are only parsed when they have been opted in to, as they are not yet part of the specification.
+    pub(crate) fn is_decorator_start(&self) -> bool {
+        self.options.extensions.decorators && self.token_kind() == TokenKind::At
+    }
+
+    // https://arai-a.github.io/ecma262-compare/?pr=2417#prod-DecoratorList
+    pub(crate) fn parse_decorator_list(&mut self) -> Result<Vec<Decorator>, ParserError> {
+        let mut decorators = vec![];
+
+        while self.is_decorator_start() {
+            decorators.push(self.parse_decorator()?);
+        }
+
+        Ok(decorators)
+    }
+
+    // https://arai-a.github.io/ecma262-compare/?pr=2417#prod-Decorator
+    fn parse_decorator(&mut self) -> Result<Decorator, ParserError> {
+        let start_index = self.start_node();
+
+        self.expect_and_advance(TokenKind::At)?;
+
+        let expression = match self.token_kind() {
+            // `@ DecoratorParenthesizedExpression`.
+            TokenKind::LeftParenthesis => self.parse_cover_parenthesized_expression()?,
+            // `@ DecoratorMemberExpression`.
+            // `@ DecoratorCallExpression`.
+            _ => self.parse_decorator_member_or_call_expression()?,
+        };
+
+        Ok(Decorator {
+            node: self.end_node(start_index)?,
+            expression,
+        })
+    }
+
+    // https://arai-a.github.io/ecma262-compare/?pr=2417#prod-DecoratorMemberExpression
+    // https://arai-a.github.io/ecma262-compare/?pr=2417#prod-DecoratorCallExpression
+    fn parse_decorator_member_or_call_expression(&mut self) -> Result<Expression, ParserError> {
+        let start_index = self.start_node();
+
+        let mut expression = Expression::Identifier(self.parse_identifier_reference()?);
+
+        while self.token_kind() == TokenKind::Dot {
+            self.advance_any()?; // Eat '.' token.
+
+            let property = if self.token_kind().is_private_identifier() {
+                MemberExpressionProperty::PrivateIdentifier(self.parse_private_identifier()?)
+            } else {
+                MemberExpressionProperty::Expression(Box::new(Expression::Identifier(
+                    self.parse_identifier_name()?,
+                )))
+            };
+
+            expression = Expression::Member(MemberExpression {
+                node: self.end_node(start_index)?,
+                object: Box::new(expression),
+                property,
+                computed: false,
+                optional: false,
+            });
+        }
+
+        // Only a single argument list is allowed, so `@a()()` has to be written as `@(a()())`.
+        if self.token_kind() == TokenKind::LeftParenthesis {
+            let arguments = self.parse_arguments()?;
+
+            expression = Expression::Call(CallExpression {
+                node: self.end_node(start_index)?,
+                callee: CallExpressionCallee::Expression(Box::new(expression)),
+                arguments,
+                optional: false,
+                type_arguments: None,
+            });
+        }
+
+        Ok(expression)
+    }
+
+    // Decorators which are not followed by a class or class element are reported from the first to the last decorator.
+    pub(crate) fn invalid_decorator_position(&self, decorators: &[Decorator]) -> ParserError {
+        match (decorators.first(), decorators.last()) {
+            (Some(first), Some(last)) => ParserError::new(
+                ParserErrorKind::InvalidDecoratorPosition,
+                first.node.start,
+                last.node.end,
+            ),
+            _ => self.current_token_error(ParserErrorKind::InvalidDecoratorPosition),
+        }
+    }
+
+    // A class declaration with a decorator list, where the class node starts at the first decorator.
+    pub(crate) fn parse_decorated_class_declaration(
+        &mut self,
+    ) -> Result<ClassDeclaration, ParserError> {
+        let start_index = self.start_node();
+
+        let decorators = self.parse_decorator_list()?;
+
+        self.parse_class_declaration_with_decorators(start_index, decorators)
+    }
+
+    pub(crate) fn parse_class_declaration_with_decorators(
+        &mut self,
+        start_index: usize,
+        decorators: Vec<Decorator>,
+    ) -> Result<ClassDeclaration, ParserError> {
+        if self.token_kind() != TokenKind::Keyword(KeywordKind::Class) {
+            return Err(self.invalid_decorator_position(&decorators));
+        }
+
+        let mut class_declaration = self.parse_class_declaration()?;
+
+        class_declaration.node = self.end_node(start_index)?;
+        class_declaration.decorators = decorators;
+
+        Ok(class_declaration)
+    }
+
+    // A class expression with a decorator list, where the class node starts at the first decorator.
+    pub(crate) fn parse_decorated_class_expression(
+        &mut self,
+    ) -> Result<ClassExpression, ParserError> {
+        let start_index = self.start_node();
+
+        let decorators = self.parse_decorator_list()?;
+
+        if self.token_kind() != TokenKind::Keyword(KeywordKind::Class) {
+            return Err(self.invalid_decorator_position(&decorators));
+        }
+
+        let mut class_expression = self.parse_class_expression()?;
+
+        class_expression.node = self.end_node(start_index)?;
+        class_expression.decorators = decorators;
+
+        Ok(class_expression)
+    }
+
+    // Decorators can come before the `export` keyword, e.g. `@decorator export class Foo {}`, but not on both sides of it.
+    // https://arai-a.github.io/ecma262-compare/?pr=2417#prod-ExportDeclaration
+    pub(crate) fn parse_export_declaration_with_decorators(
+        &mut self,
+        start_index: usize,
+        decorators: Vec<Decorator>,
+    ) -> Result<ExportDeclaration, ParserError> {
+        let mut export_declaration = self.parse_export_declaration()?;
+
+        let (export_node, class_declaration) = match &mut export_declaration {
+            ExportDeclaration::Named(ExportNamedDeclaration {
+                node,
+                declaration: Some(ExportNamedDeclarationDeclaration::Class(class_declaration)),
+                ..
+            }) => (node, class_declaration),
+            ExportDeclaration::Default(ExportDefaultDeclaration {
+                node,
+                declaration:
+                    ExportDefaultDeclarationDeclaration::ClassDeclaration(class_declaration),
+                ..
+            }) => (node, class_declaration),
+            _ => return Err(self.invalid_decorator_position(&decorators)),
+        };
+
+        if !class_declaration.decorators.is_empty() {
+            return Err(self.invalid_decorator_position(&class_declaration.decorators));
+        }
+
+        *export_node = self.end_node(start_index)?;
+
+        class_declaration.node = self.end_node(start_index)?;
+        class_declaration.decorators = decorators;
+
+        Ok(export_declaration)
+    }
+}
@@ -1,9 +1,9 @@
-use crate::{ast::*, GoalSymbol};
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{ast::*, GoalSymbol, Params};
+use crate::{KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind};
 
 // 13 ECMAScript Language: Expressions
 // https://tc39.es/ecma262/#sec-ecmascript-language-expressions
-impl Parser {
+impl Parser<'_> {
     // 15.2 Function Definitions
     // https://tc39.es/ecma262/#prod-FunctionDeclaration
     pub(crate) fn parse_function_declaration(
@@ -19,9 +19,13 @@ impl Parser {
             None
         };
 
+        let type_parameters = self.parse_optional_type_parameters()?;
+
         let formal_parameters = self.parse_parenthesized_formal_parameters()?;
 
-        let body = self.parse_function_body()?;
+        let return_type = self.parse_optional_return_type()?;
+
+        let body = self.with_params(Params::default(), Self::parse_function_body)?;
 
         Ok(FunctionDeclaration {
             node: self.end_node(start_index)?,
@@ -31,6 +35,8 @@ impl Parser {
             generator: false,
             asynchronous: false,
             expression: false,
+            type_parameters: type_parameters.map(Box::new),
+            return_type: return_type.map(Box::new),
         })
     }
 
@@ -46,9 +52,13 @@ impl Parser {
             None
         };
 
+        let type_parameters = self.parse_optional_type_parameters()?;
+
         let formal_parameters = self.parse_parenthesized_formal_parameters()?;
 
-        let body = self.parse_function_body()?;
+        let return_type = self.parse_optional_return_type()?;
+
+        let body = self.with_params(Params::default(), Self::parse_function_body)?;
 
         Ok(FunctionExpression {
             node: self.end_node(start_index)?,
@@ -58,6 +68,8 @@ impl Parser {
             expression: false,
             generator: false,
             is_async: false,
+            type_parameters: type_parameters.map(Box::new),
+            return_type: return_type.map(Box::new),
         })
     }
 
@@ -65,21 +77,33 @@ impl Parser {
     pub(crate) fn parse_function_body(&mut self) -> Result<BlockStatement, ParserError> {
         let start_index = self.start_node();
 
+        // Return statements are only allowed within function bodies.
+        // https://tc39.es/ecma262/#prod-FunctionStatementList
+        self.params.add_allow_return(true);
+
+        let previous_in_function = self.context.in_function;
+        self.context.in_function = true;
+
         // Template literal middles or tails are not permitted within an object literal.
-        let previous_goal_symbol = self.cursor.lexer.goal_symbol.clone();
-        self.cursor.lexer.goal_symbol = GoalSymbol::InputElementDiv;
+        let previous_goal_symbol = self.cursor.goal_symbol();
+        self.cursor.set_goal_symbol(GoalSymbol::InputElementDiv);
 
         self.expect_and_advance(TokenKind::LeftCurlyBrace)?;
 
         let mut body = self.parse_directive_prologue()?;
 
-        while self.token_kind() != TokenKind::RightCurlyBrace {
-            body.push(self.parse_statement_list_item()?);
+        while !matches!(
+            self.token_kind(),
+            TokenKind::RightCurlyBrace | TokenKind::EOF
+        ) {
+            body.push(self.parse_statement_list_item_or_recover()?);
         }
 
-        self.cursor.lexer.goal_symbol = previous_goal_symbol;
+        self.cursor.set_goal_symbol(previous_goal_symbol);
+
+        self.context.in_function = previous_in_function;
 
-        self.expect_and_advance(TokenKind::RightCurlyBrace)?;
+        self.expect_closing_brace_or_recover()?;
 
         Ok(BlockStatement {
             node: self.end_node(start_index)?,
@@ -101,23 +125,19 @@ impl Parser {
 
         let function_expression_start_index = self.start_node();
 
+        let type_parameters = self.parse_optional_type_parameters()?;
+
         let formal_parameters = self.parse_parenthesized_formal_parameters()?;
 
-        let function_body = self.with_params(
+        let function_expression = self.parse_method_function(
+            function_expression_start_index,
+            type_parameters,
+            formal_parameters,
+            true,
+            false,
             self.params.clone().add_allow_await(true),
-            Self::parse_function_body,
         )?;
 
-        let function_expression = FunctionExpression {
-            node: self.end_node(function_expression_start_index)?,
-            id: None,
-            expression: false,
-            generator: false,
-            is_async: true,
-            params: formal_parameters,
-            body: function_body,
-        };
-
         Ok(MethodDefinition {
             node: self.end_node(start_index)?, // End class element node.
             is_static,
@@ -125,19 +145,44 @@ impl Parser {
             key: Some(class_element_name),
             kind,
             value: Some(function_expression),
+            decorators: vec![],
+            accessibility: None,
+            optional: false,
+            is_override: false,
         })
     }
 
+    // TypeScript parameter properties are only allowed in the parameters of a class constructor.
     pub(crate) fn parse_parenthesized_formal_parameters(
         &mut self,
+    ) -> Result<Vec<FunctionParameter>, ParserError> {
+        let formal_parameters = self.parse_parenthesized_constructor_parameters()?;
+
+        if let Some(parameter_property) =
+            formal_parameters
+                .iter()
+                .find_map(|parameter| match parameter {
+                    FunctionParameter::TSParameterProperty(parameter_property) => {
+                        Some(parameter_property)
+                    }
+                    _ => None,
+                })
+        {
+            return Err(ParserError::from_node(
+                ParserErrorKind::InvalidParameterPropertyPosition,
+                &parameter_property.node,
+            ));
+        }
+
+        Ok(formal_parameters)
+    }
+
+    pub(crate) fn parse_parenthesized_constructor_parameters(
+        &mut self,
     ) -> Result<Vec<FunctionParameter>, ParserError> {
         self.expect_and_advance(TokenKind::LeftParenthesis)?;
 
-        let formal_parameters = self
-            .parse_formal_parameters()?
-            .into_iter()
-            .map(FunctionParameter::try_from)
-            .collect::<Result<Vec<FunctionParameter>, ParserError>>()?;
+        let formal_parameters = self.parse_formal_parameters()?;
 
         self.expect_and_advance(TokenKind::RightParenthesis)?;
 
@@ -1,9 +1,9 @@
 use crate::{ast::*, Params};
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{Feature, KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind};
 
 // 13 ECMAScript Language: Expressions
 // https://tc39.es/ecma262/#sec-ecmascript-language-expressions
-impl Parser {
+impl Parser<'_> {
     // 15.5 Generator Function Definitions
     // https://tc39.es/ecma262/#prod-GeneratorExpression
     pub(crate) fn parse_generator_declaration(
@@ -13,6 +13,8 @@ impl Parser {
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Function))?;
 
+        self.expect_feature(Feature::Generators)?;
+
         self.expect_and_advance(TokenKind::Multiplication)?;
 
         let optional_binding_identifier = if self.token_kind().is_binding_identifier() {
@@ -21,15 +23,11 @@ impl Parser {
             None
         };
 
-        self.expect_and_advance(TokenKind::LeftParenthesis)?;
+        let type_parameters = self.parse_optional_type_parameters()?;
 
-        let formal_parameters = self
-            .parse_formal_parameters()?
-            .into_iter()
-            .map(FunctionParameter::try_from)
-            .collect::<Result<Vec<FunctionParameter>, ParserError>>()?;
+        let formal_parameters = self.parse_parenthesized_formal_parameters()?;
 
-        self.expect_and_advance(TokenKind::RightParenthesis)?;
+        let return_type = self.parse_optional_return_type()?;
 
         let generator_body = self.with_params(
             Params::default().add_allow_yield(true),
@@ -44,6 +42,8 @@ impl Parser {
             generator: true,
             asynchronous: false,
             expression: false,
+            type_parameters: type_parameters.map(Box::new),
+            return_type: return_type.map(Box::new),
         })
     }
 
@@ -53,6 +53,8 @@ impl Parser {
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Function))?;
 
+        self.expect_feature(Feature::Generators)?;
+
         self.expect_and_advance(TokenKind::Multiplication)?;
 
         let optional_binding_identifier = if self.token_kind().is_binding_identifier() {
@@ -61,8 +63,12 @@ impl Parser {
             None
         };
 
+        let type_parameters = self.parse_optional_type_parameters()?;
+
         let formal_parameters = self.parse_parenthesized_formal_parameters()?;
 
+        let return_type = self.parse_optional_return_type()?;
+
         let generator_body = self.with_params(
             Params::default().add_allow_yield(true),
             Self::parse_function_body,
@@ -76,6 +82,8 @@ impl Parser {
             generator: true,
             expression: false,
             is_async: false,
+            type_parameters: type_parameters.map(Box::new),
+            return_type: return_type.map(Box::new),
         })
     }
 
@@ -88,6 +96,8 @@ impl Parser {
         is_static: bool,
         kind: MethodDefinitionKind,
     ) -> Result<MethodDefinition, ParserError> {
+        self.expect_feature(Feature::Generators)?;
+
         self.expect_and_advance(TokenKind::Multiplication)?;
 
         // Handle computed generator methods like `*[foo]() {}`.
@@ -97,31 +107,19 @@ impl Parser {
 
         let function_expression_start_index = self.start_node();
 
-        self.expect_and_advance(TokenKind::LeftParenthesis)?;
-
-        let formal_parameters = self
-            .parse_formal_parameters()?
-            .into_iter()
-            .map(FunctionParameter::try_from)
-            .collect::<Result<Vec<FunctionParameter>, ParserError>>()?;
+        let type_parameters = self.parse_optional_type_parameters()?;
 
-        self.expect_and_advance(TokenKind::RightParenthesis)?;
+        let formal_parameters = self.parse_parenthesized_formal_parameters()?;
 
-        let generator_body = self.with_params(
+        let function_expression = self.parse_method_function(
+            function_expression_start_index,
+            type_parameters,
+            formal_parameters,
+            is_async,
+            true,
             Params::default().add_allow_yield(true),
-            Self::parse_function_body,
         )?;
 
-        let function_expression = FunctionExpression {
-            node: self.end_node(function_expression_start_index)?,
-            id: None,
-            expression: false,
-            generator: true,
-            is_async,
-            params: formal_parameters,
-            body: generator_body,
-        };
-
         Ok(MethodDefinition {
             node: self.end_node(start_index)?, // End class element node.
             is_static,
@@ -129,6 +127,10 @@ impl Parser {
             key: Some(class_element_name),
             kind,
             value: Some(function_expression),
+            decorators: vec![],
+            accessibility: None,
+            optional: false,
+            is_override: false,
         })
     }
 
@@ -139,7 +141,11 @@ impl Parser {
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Yield))?;
 
         if !self.params.has_allow_yield() {
-            return Err(ParserError::InvalidYieldExpression);
+            return Err(self.error(
+                ParserErrorKind::InvalidYieldExpression,
+                start_index,
+                self.cursor.previous_token.end,
+            ));
         }
 
         let invalid_assignment_expression_start = matches!(
@@ -157,11 +163,11 @@ impl Parser {
 
         if !invalid_assignment_expression_start {
             if self.has_previous_token_line_terminator() {
-                return Err(ParserError::UnexpectedLineTerminator);
+                return Err(self.current_token_error(ParserErrorKind::UnexpectedLineTerminator));
             }
 
             is_generator = if self.token_kind() == TokenKind::Multiplication {
-                self.advance_any(); // Eat '*' token.
+                self.advance_any()?; // Eat '*' token.
 
                 true
             } else {
@@ -176,7 +182,7 @@ impl Parser {
 
         let node = self.end_node(start_index)?;
 
-        self.expect_optional_semicolon_and_advance(); // Eat ';' token.
+        self.expect_optional_semicolon_and_advance()?; // Eat ';' token.
 
         Ok(YieldExpression {
             node,
@@ -1,16 +1,16 @@
-use crate::ast::*;
+use crate::{ast::*, Feature, Params};
 use crate::{Parser, ParserError, TokenKind};
 
 // 15 ECMAScript Language: Functions and Classes
 // https://tc39.es/ecma262/#sec-ecmascript-language-functions-and-classes
-impl Parser {
+impl Parser<'_> {
     // 15.4 Method Definitions
     // https://tc39.es/ecma262/#prod-MethodDefinition
     pub(crate) fn parse_method_definition(
         &mut self,
         start_index: usize,
         key: Option<PropertyDefinitionKey>,
-        value: Option<FunctionExpression>,
+        value: Option<MethodDefinitionValue>,
         method_definition_kind: MethodDefinitionKind,
         is_static: bool,
         is_computed: bool,
@@ -22,54 +22,52 @@ impl Parser {
             is_static,
             computed: is_computed,
             key,
+            decorators: vec![],
+            accessibility: None,
+            optional: false,
+            is_override: false,
         })
     }
 
     pub(crate) fn parse_method_definition_method_body(
         &mut self,
-    ) -> Result<FunctionExpression, ParserError> {
+        is_constructor: bool,
+    ) -> Result<MethodDefinitionValue, ParserError> {
         let start_index = self.start_node();
 
-        let formal_parameters = self.parse_parenthesized_formal_parameters()?;
+        let type_parameters = self.parse_optional_type_parameters()?;
 
-        let function_body = self.parse_function_body()?;
+        let formal_parameters = if is_constructor {
+            self.parse_parenthesized_constructor_parameters()?
+        } else {
+            self.parse_parenthesized_formal_parameters()?
+        };
 
-        Ok(FunctionExpression {
-            node: self.end_node(start_index)?,
-            id: None,
-            expression: false,
-            generator: false,
-            is_async: false,
-            params: formal_parameters,
-            body: function_body,
-        })
+        self.parse_method_function(
+            start_index,
+            type_parameters,
+            formal_parameters,
+            false,
+            false,
+            Params::default(),
+        )
     }
 
     pub(crate) fn parse_method_definition_getter_body(
         &mut self,
-    ) -> Result<FunctionExpression, ParserError> {
+    ) -> Result<MethodDefinitionValue, ParserError> {
         let start_index = self.start_node();
 
         self.expect_and_advance(TokenKind::LeftParenthesis)?;
 
         self.expect_and_advance(TokenKind::RightParenthesis)?;
 
-        let function_body = self.parse_function_body()?;
-
-        Ok(FunctionExpression {
-            node: self.end_node(start_index)?,
-            id: None,
-            expression: false,
-            generator: false,
-            is_async: false,
-            params: vec![],
-            body: function_body,
-        })
+        self.parse_method_function(start_index, None, vec![], false, false, Params::default())
     }
 
     pub(crate) fn parse_method_definition_setter_body(
         &mut self,
-    ) -> Result<FunctionExpression, ParserError> {
+    ) -> Result<MethodDefinitionValue, ParserError> {
         let start_index = self.start_node();
 
         let formal_parameters = self.parse_parenthesized_formal_parameters()?;
@@ -78,16 +76,67 @@ impl Parser {
             return Err(self.unexpected_current_token_kind());
         }
 
-        let function_body = self.parse_function_body()?;
+        self.parse_method_function(
+            start_index,
+            None,
+            formal_parameters,
+            false,
+            false,
+            Params::default(),
+        )
+    }
 
-        Ok(FunctionExpression {
-            node: self.end_node(start_index)?,
-            id: None,
-            expression: false,
-            generator: false,
-            is_async: false,
-            params: formal_parameters,
-            body: function_body,
-        })
+    // The function after the parameters of a method. In TypeScript, a method without a body is an overload signature
+    // or an abstract method.
+    pub(crate) fn parse_method_function(
+        &mut self,
+        start_index: usize,
+        type_parameters: Option<TSTypeParameterDeclaration>,
+        formal_parameters: Vec<FunctionParameter>,
+        is_async: bool,
+        is_generator: bool,
+        body_params: Params,
+    ) -> Result<MethodDefinitionValue, ParserError> {
+        match (is_async, is_generator) {
+            (true, true) => self.expect_feature(Feature::AsyncIteration)?,
+            (true, false) => self.expect_feature(Feature::AsyncFunctions)?,
+            _ => {}
+        }
+
+        let return_type = self.parse_optional_return_type()?;
+
+        if self.options.extensions.typescript && self.token_kind() != TokenKind::LeftCurlyBrace {
+            self.expect_optional_semicolon_and_advance()?;
+
+            return Ok(MethodDefinitionValue::TSEmptyBodyFunctionExpression(
+                TSEmptyBodyFunctionExpression {
+                    node: self.end_node(start_index)?,
+                    id: None,
+                    expression: false,
+                    generator: is_generator,
+                    is_async,
+                    type_parameters,
+                    params: formal_parameters,
+                    return_type,
+                    body: None,
+                },
+            ));
+        }
+
+        let function_body = self.with_params(body_params, Self::parse_function_body)?;
+
+        Ok(MethodDefinitionValue::FunctionExpression(
+            FunctionExpression {
+                node: self.end_node(start_index)?,
+                id: None,
+                expression: false,
+                generator: is_generator,
+                is_async,
+                params: formal_parameters,
+                body: function_body,
+                type_parameters: type_parameters.map(Box::new),
+                return_type: return_type.map(Box::new),
+            },
+        ))
     }
 }
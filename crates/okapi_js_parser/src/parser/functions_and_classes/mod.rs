@@ -3,6 +3,7 @@ mod async_arrow_function;
 mod async_function_expression;
 mod async_generator_function;
 mod class;
+mod decorator;
 mod function;
 mod generator;
 mod method_definition;
@@ -1,14 +1,14 @@
 use crate::ast::*;
-use crate::{Parser, ParserError, TokenKind};
+use crate::{Feature, KeywordKind, Parser, ParserError, TokenKind};
 
 // 15 ECMAScript Language: Functions and Classes
 // https://tc39.es/ecma262/#sec-ecmascript-language-functions-and-classes
-impl Parser {
+impl Parser<'_> {
     // 15.1 Parameter Lists
     // https://tc39.es/ecma262/#prod-FormalParameters
     pub(crate) fn parse_formal_parameters(
         &mut self,
-    ) -> Result<Vec<ArrayPatternElement>, ParserError> {
+    ) -> Result<Vec<FunctionParameter>, ParserError> {
         let mut parameter_list = vec![];
 
         if self.token_kind() == TokenKind::Ellipsis {
@@ -24,23 +24,38 @@ impl Parser {
         }
 
         if self.token_kind() == TokenKind::Comma {
-            self.advance_any(); // Eat ',' token.
+            self.expect_trailing_comma_feature()?;
+
+            self.advance_any()?; // Eat ',' token.
         }
 
         Ok(parameter_list)
     }
 
+    fn expect_trailing_comma_feature(&mut self) -> Result<(), ParserError> {
+        if self.peek_token_kind() == TokenKind::RightParenthesis {
+            self.expect_feature(Feature::FunctionTrailingCommas)?;
+        }
+
+        Ok(())
+    }
+
     // https://tc39.es/ecma262/#prod-FormalParameterList
-    fn parse_formal_parameter_list(&mut self) -> Result<Vec<ArrayPatternElement>, ParserError> {
+    fn parse_formal_parameter_list(&mut self) -> Result<Vec<FunctionParameter>, ParserError> {
         let mut parameter_list = vec![];
 
-        while self.token_kind().is_lexical_binding_start() {
+        while self.token_kind().is_lexical_binding_start()
+            || (self.options.extensions.typescript
+                && self.token_kind() == TokenKind::Keyword(KeywordKind::This))
+        {
             let formal_parameter = self.parse_formal_parameter()?;
 
             parameter_list.push(formal_parameter);
 
             if self.token_kind() == TokenKind::Comma {
-                self.advance_any(); // Eat ',' token.
+                self.expect_trailing_comma_feature()?;
+
+                self.advance_any()?; // Eat ',' token.
             } else {
                 break;
             }
@@ -50,12 +65,44 @@ impl Parser {
     }
 
     // https://tc39.es/ecma262/#prod-FunctionRestParameter
-    fn parse_function_rest_parameter(&mut self) -> Result<ArrayPatternElement, ParserError> {
-        self.parse_binding_rest_element()
+    fn parse_function_rest_parameter(&mut self) -> Result<FunctionParameter, ParserError> {
+        self.expect_feature(Feature::RestParameters)?;
+
+        let start_index = self.start_node();
+
+        let mut binding_rest_element = self.parse_binding_rest_element()?;
+
+        if let ArrayPatternElement::RestElement(rest_element) = &mut binding_rest_element {
+            if let Some(type_annotation) = self.parse_optional_type_annotation()? {
+                rest_element.node = self.end_node(start_index)?;
+                rest_element.type_annotation = Some(Box::new(type_annotation));
+            }
+        }
+
+        FunctionParameter::try_from(binding_rest_element)
     }
 
     // https://tc39.es/ecma262/#prod-FormalParameter
-    pub(crate) fn parse_formal_parameter(&mut self) -> Result<ArrayPatternElement, ParserError> {
-        self.parse_binding_element()
+    pub(crate) fn parse_formal_parameter(&mut self) -> Result<FunctionParameter, ParserError> {
+        let formal_parameter = if self.options.extensions.typescript {
+            self.parse_typescript_formal_parameter()?
+        } else {
+            FunctionParameter::try_from(self.parse_binding_element()?)?
+        };
+
+        let default_parameter = match &formal_parameter {
+            FunctionParameter::Assignment(assignment_pattern) => Some(assignment_pattern),
+            FunctionParameter::TSParameterProperty(TSParameterProperty {
+                parameter: TSParameterPropertyParameter::Assignment(assignment_pattern),
+                ..
+            }) => Some(assignment_pattern),
+            _ => None,
+        };
+
+        if let Some(assignment_pattern) = default_parameter {
+            self.expect_node_feature(Feature::DefaultParameters, &assignment_pattern.node)?;
+        }
+
+        Ok(formal_parameter)
     }
 }
@@ -1,9 +1,9 @@
 use crate::{ast::*, Params};
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{Feature, KeywordKind, Parser, ParserError, TokenKind};
 
 // 16 ECMAScript Language: Scripts and Modules
 // https://tc39.es/ecma262/#sec-ecmascript-language-scripts-and-modules
-impl Parser {
+impl Parser<'_> {
     // 16.2.2 Imports
     // https://tc39.es/ecma262/#prod-ExportDeclaration
     pub(crate) fn parse_export_declaration(&mut self) -> Result<ExportDeclaration, ParserError> {
@@ -11,6 +11,29 @@ impl Parser {
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Export))?;
 
+        // TypeScript `export = Expression;`
+        if self.options.extensions.typescript && self.token_kind() == TokenKind::Assignment {
+            self.advance_any()?; // Eat '=' token.
+
+            let expression = self.parse_assignment_expression()?;
+
+            self.expect_optional_semicolon_and_advance()?;
+
+            return Ok(ExportDeclaration::TSAssignment(TSExportAssignment {
+                node: self.end_node(start_index)?,
+                expression,
+            }));
+        }
+
+        // Only checked after `export =`, which is TypeScript syntax rather than a module export.
+        self.expect_feature_in(
+            Feature::Modules,
+            start_index,
+            self.cursor.previous_token.end,
+        )?;
+
+        let export_kind = self.parse_export_kind()?;
+
         match self.token_kind() {
             // `export ExportFromClause FromClause ;`
             TokenKind::Multiplication => {
@@ -18,12 +41,16 @@ impl Parser {
 
                 let from_clause = self.parse_from_clause()?;
 
-                self.expect_optional_semicolon_and_advance();
+                let attributes = self.parse_optional_with_clause()?;
+
+                self.expect_optional_semicolon_and_advance()?;
 
                 return Ok(ExportDeclaration::All(ExportAllDeclaration {
                     node: self.end_node(start_index)?,
                     source: from_clause,
                     exported: export_from_clause,
+                    attributes,
+                    export_kind,
                 }));
             }
             // `export NamedExports ;`
@@ -32,23 +59,36 @@ impl Parser {
 
                 let mut from_clause = None;
 
+                let mut attributes = vec![];
+
                 if self.token_kind() == TokenKind::Keyword(KeywordKind::From) {
                     from_clause = Some(self.parse_from_clause()?);
+
+                    attributes = self.parse_optional_with_clause()?;
                 }
 
-                self.expect_optional_semicolon_and_advance();
+                self.expect_optional_semicolon_and_advance()?;
 
                 return Ok(ExportDeclaration::Named(ExportNamedDeclaration {
                     node: self.end_node(start_index)?,
                     declaration: None,
                     specifiers: named_exports,
                     source: from_clause,
+                    attributes,
+                    export_kind,
                 }));
             }
+            _ if export_kind == Some(TSImportExportKind::Type) => {
+                return Err(self.unexpected_current_token_kind())
+            }
             _ => {}
         }
 
         let optional_declaration = match self.token_kind() {
+            // `export` TypeScript declarations, e.g. `export interface I {}`.
+            _ if self.is_typescript_declaration_start() => Some(
+                ExportNamedDeclarationDeclaration::from(self.parse_typescript_declaration()?),
+            ),
             // `export VariableStatement`
             TokenKind::Keyword(KeywordKind::Var) => Some(
                 ExportNamedDeclarationDeclaration::Variable(self.parse_variable_statement(true)?),
@@ -61,6 +101,10 @@ impl Parser {
             TokenKind::Keyword(KeywordKind::Class) => Some(
                 ExportNamedDeclarationDeclaration::Class(self.parse_class_declaration()?),
             ),
+            // `export DecoratorList ClassDeclaration`
+            TokenKind::At if self.is_decorator_start() => Some(
+                ExportNamedDeclarationDeclaration::Class(self.parse_decorated_class_declaration()?),
+            ),
             // `export Declaration > LexicalDeclaration`
             token_kind if token_kind.is_lexical_declaration_start() => Some(
                 ExportNamedDeclarationDeclaration::Variable(self.parse_lexical_declaration(true)?),
@@ -68,12 +112,16 @@ impl Parser {
             _ => None,
         };
 
-        if optional_declaration.is_some() {
+        if let Some(declaration) = optional_declaration {
+            let export_kind = self.declaration_export_kind(&declaration);
+
             return Ok(ExportDeclaration::Named(ExportNamedDeclaration {
                 node: self.end_node(start_index)?,
-                declaration: optional_declaration,
+                declaration: Some(declaration),
                 specifiers: vec![],
                 source: None,
+                attributes: vec![],
+                export_kind,
             }));
         };
 
@@ -105,6 +153,34 @@ impl Parser {
 
                 ExportDefaultDeclarationDeclaration::ClassDeclaration(class_declaration)
             }
+            // `export default DecoratorList ClassDeclaration`
+            TokenKind::At if self.is_decorator_start() => {
+                let class_declaration = self.with_params(
+                    Params::default().add_allow_default(true),
+                    Self::parse_decorated_class_declaration,
+                )?;
+
+                ExportDefaultDeclarationDeclaration::ClassDeclaration(class_declaration)
+            }
+            // `export default interface` and `export default abstract class` in TypeScript.
+            TokenKind::Keyword(KeywordKind::Interface | KeywordKind::Abstract)
+                if self.is_typescript_declaration_start() =>
+            {
+                match self.with_params(
+                    Params::default().add_allow_default(true),
+                    Self::parse_typescript_declaration,
+                )? {
+                    Declaration::TSInterface(interface_declaration) => {
+                        ExportDefaultDeclarationDeclaration::TSInterfaceDeclaration(
+                            interface_declaration,
+                        )
+                    }
+                    Declaration::Class(class_declaration) => {
+                        ExportDefaultDeclarationDeclaration::ClassDeclaration(class_declaration)
+                    }
+                    _ => return Err(self.unexpected_current_token_kind()),
+                }
+            }
             // `export default HoistableDeclaration`
             token_kind if token_kind.is_hoistable_declaration_start() => {
                 ExportDefaultDeclarationDeclaration::FunctionDeclaration(
@@ -117,12 +193,70 @@ impl Parser {
             }
         };
 
+        // Only interfaces are exported as types, as an abstract class is also a value.
+        let export_kind = match export_declaration {
+            ExportDefaultDeclarationDeclaration::TSInterfaceDeclaration(_) => {
+                Some(TSImportExportKind::Type)
+            }
+            _ => self.value_kind(),
+        };
+
         Ok(ExportDeclaration::Default(ExportDefaultDeclaration {
             node: self.end_node(start_index)?,
             declaration: export_declaration,
+            export_kind,
         }))
     }
 
+    // TypeScript `export type { A }` and `export type * from "x"` declarations, which only export types.
+    fn parse_export_kind(&mut self) -> Result<Option<TSImportExportKind>, ParserError> {
+        if self.token_kind() != TokenKind::Keyword(KeywordKind::Type)
+            || !matches!(
+                self.peek_token_kind(),
+                TokenKind::LeftCurlyBrace | TokenKind::Multiplication
+            )
+            || !self.options.extensions.typescript
+        {
+            return Ok(self.value_kind());
+        }
+
+        self.advance_any()?; // Eat 'type' token.
+
+        Ok(Some(TSImportExportKind::Type))
+    }
+
+    // As per typescript-estree, exported interfaces, type aliases and declarations with `declare` are types.
+    fn declaration_export_kind(
+        &self,
+        declaration: &ExportNamedDeclarationDeclaration,
+    ) -> Option<TSImportExportKind> {
+        let is_type = match declaration {
+            ExportNamedDeclarationDeclaration::TSInterface(_)
+            | ExportNamedDeclarationDeclaration::TSTypeAlias(_) => true,
+            ExportNamedDeclarationDeclaration::Class(class_declaration) => {
+                class_declaration.declare
+            }
+            ExportNamedDeclarationDeclaration::Variable(variable_declaration) => {
+                variable_declaration.declare
+            }
+            ExportNamedDeclarationDeclaration::TSDeclareFunction(declare_function) => {
+                declare_function.declare
+            }
+            ExportNamedDeclarationDeclaration::TSEnum(enum_declaration) => enum_declaration.declare,
+            ExportNamedDeclarationDeclaration::TSModule(module_declaration) => {
+                module_declaration.declare
+            }
+            ExportNamedDeclarationDeclaration::Function(_)
+            | ExportNamedDeclarationDeclaration::TSImportEquals(_) => false,
+        };
+
+        if is_type {
+            Some(TSImportExportKind::Type)
+        } else {
+            self.value_kind()
+        }
+    }
+
     // https://tc39.es/ecma262/#prod-ExportFromClause
     fn parse_export_from_clause_export_all_declaration(
         &mut self,
@@ -130,7 +264,9 @@ impl Parser {
         self.expect_and_advance(TokenKind::Multiplication)?;
 
         if self.token_kind() == TokenKind::Keyword(KeywordKind::As) {
-            self.advance_any(); // Eat '*' token.
+            self.expect_feature(Feature::ExportNamespaceFrom)?;
+
+            self.advance_any()?; // Eat 'as' token.
 
             let module_export_name = self.parse_module_export_name()?;
 
@@ -146,7 +282,7 @@ impl Parser {
         let mut exports_list = vec![];
 
         if self.token_kind() == TokenKind::RightCurlyBrace {
-            self.advance_any();
+            self.advance_any()?;
 
             return Ok(exports_list);
         }
@@ -170,7 +306,7 @@ impl Parser {
             }
 
             if self.token_kind() == TokenKind::Comma {
-                self.advance_any();
+                self.advance_any()?;
             }
         }
 
@@ -179,10 +315,12 @@ impl Parser {
 
     // https://tc39.es/ecma262/#prod-ExportSpecifier
     fn parse_export_specifier(&mut self) -> Result<ExportSpecifier, ParserError> {
+        let start_index = self.start_node();
+
+        let export_kind = self.parse_specifier_kind()?;
+
         match self.peek_token_kind() {
             TokenKind::Keyword(KeywordKind::As) => {
-                let start_index = self.start_node();
-
                 let local_module_export_name = self.parse_module_export_name()?;
 
                 self.expect_and_advance(TokenKind::Keyword(KeywordKind::As))?;
@@ -193,17 +331,17 @@ impl Parser {
                     node: self.end_node(start_index)?,
                     local: Box::new(local_module_export_name),
                     exported: Box::new(export_module_export_name),
+                    export_kind,
                 })
             }
             _ => {
-                let start_index = self.start_node();
-
                 let module_export_name = self.parse_module_export_name()?;
 
                 Ok(ExportSpecifier {
                     node: self.end_node(start_index)?,
                     local: Box::new(module_export_name.clone()),
                     exported: Box::new(module_export_name),
+                    export_kind,
                 })
             }
         }
@@ -1,32 +1,40 @@
 use crate::ast::*;
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{Feature, KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind};
 
 // 16 ECMAScript Language: Scripts and Modules
 // https://tc39.es/ecma262/#sec-ecmascript-language-scripts-and-modules
-impl Parser {
+impl Parser<'_> {
     // 16.2.2 Imports
     // https://tc39.es/ecma262/#prod-ImportDeclaration
     pub(crate) fn parse_import_declaration(&mut self) -> Result<ImportDeclaration, ParserError> {
+        self.expect_feature(Feature::Modules)?;
+
         let start_index = self.start_node();
 
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Import))?;
 
+        let import_kind = self.parse_import_kind()?;
+
         if self.token_kind() == TokenKind::StringLiteral {
             let module_specifier = self.parse_module_specifier()?;
 
-            self.expect_optional_semicolon_and_advance();
+            let attributes = self.parse_optional_with_clause()?;
+
+            self.expect_optional_semicolon_and_advance()?;
 
             return Ok(ImportDeclaration {
                 node: self.end_node(start_index)?,
                 source: module_specifier,
                 specifiers: vec![],
+                attributes,
+                import_kind,
             });
         }
 
         let mut import_clause = self.parse_import_clause()?;
 
         if self.token_kind() == TokenKind::Comma {
-            self.advance_any(); // Eat comma token.
+            self.advance_any()?; // Eat comma token.
 
             if self.token_kind() == TokenKind::Multiplication {
                 let name_space_import = self.parse_name_space_import()?;
@@ -39,12 +47,16 @@ impl Parser {
 
         let module_specifier = self.parse_from_clause()?;
 
-        self.expect_optional_semicolon_and_advance();
+        let attributes = self.parse_optional_with_clause()?;
+
+        self.expect_optional_semicolon_and_advance()?;
 
         Ok(ImportDeclaration {
             node: self.end_node(start_index)?,
             source: module_specifier,
             specifiers: import_clause,
+            attributes,
+            import_kind,
         })
     }
 
@@ -57,6 +69,8 @@ impl Parser {
                 Ok(ModuleExportName::Identifier(self.parse_identifier_name()?))
             }
             TokenKind::StringLiteral => {
+                self.expect_feature(Feature::ArbitraryModuleNamespaceNames)?;
+
                 let current_token_value = self.token_value();
 
                 self.expect_and_advance(TokenKind::StringLiteral)?;
@@ -102,6 +116,7 @@ impl Parser {
             kind: ImportSpecifierKind::DefaultSpecifier,
             local: imported_binding_identifier,
             imported: None,
+            import_kind: None,
         })
     }
 
@@ -120,6 +135,7 @@ impl Parser {
             kind: ImportSpecifierKind::NamespaceSpecifier,
             local: imported_binding_identifier,
             imported: None,
+            import_kind: None,
         })
     }
 
@@ -130,7 +146,7 @@ impl Parser {
         let mut imports_list = vec![];
 
         if self.token_kind() == TokenKind::RightCurlyBrace {
-            self.advance_any();
+            self.advance_any()?;
 
             return Ok(imports_list);
         }
@@ -161,7 +177,7 @@ impl Parser {
             }
 
             if self.token_kind() == TokenKind::Comma {
-                self.advance_any();
+                self.advance_any()?;
             }
         }
 
@@ -170,10 +186,12 @@ impl Parser {
 
     // https://tc39.es/ecma262/#prod-ImportSpecifier
     fn parse_import_specifier(&mut self) -> Result<ImportSpecifier, ParserError> {
+        let start_index = self.start_node();
+
+        let import_kind = self.parse_specifier_kind()?;
+
         match self.peek_token_kind() {
             TokenKind::Keyword(KeywordKind::As) => {
-                let start_index = self.start_node();
-
                 let module_export_name = self.parse_module_export_name()?;
 
                 self.expect_and_advance(TokenKind::Keyword(KeywordKind::As))?;
@@ -185,11 +203,10 @@ impl Parser {
                     kind: ImportSpecifierKind::Specifier,
                     local: import_specifier,
                     imported: Some(module_export_name),
+                    import_kind,
                 })
             }
             _ => {
-                let start_index = self.start_node();
-
                 let import_specifier = self.parse_imported_binding()?;
 
                 let module_export_name = ModuleExportName::Identifier(import_specifier.clone());
@@ -199,11 +216,71 @@ impl Parser {
                     kind: ImportSpecifierKind::Specifier,
                     local: import_specifier.clone(),
                     imported: Some(module_export_name),
+                    import_kind,
                 })
             }
         }
     }
 
+    // TypeScript imports and exports are values unless they are marked with `type`, which is only added when parsing
+    // TypeScript.
+    pub(crate) fn value_kind(&self) -> Option<TSImportExportKind> {
+        self.options
+            .extensions
+            .typescript
+            .then_some(TSImportExportKind::Value)
+    }
+
+    // TypeScript `import type` declarations, which only import types. `type` is the name of a default import when it is
+    // followed by `from` or `,`, e.g. `import type from "x"`.
+    fn parse_import_kind(&mut self) -> Result<Option<TSImportExportKind>, ParserError> {
+        if !self.options.extensions.typescript {
+            return Ok(None);
+        }
+
+        let is_type_only = self.token_kind() == TokenKind::Keyword(KeywordKind::Type)
+            && match self.peek_token_kind() {
+                TokenKind::LeftCurlyBrace | TokenKind::Multiplication => true,
+                TokenKind::Keyword(KeywordKind::From) => {
+                    self.peek_nth_kind(2) == TokenKind::Keyword(KeywordKind::From)
+                }
+                token_kind => token_kind.is_binding_identifier(),
+            };
+
+        if !is_type_only {
+            return Ok(Some(TSImportExportKind::Value));
+        }
+
+        self.advance_any()?; // Eat 'type' token.
+
+        Ok(Some(TSImportExportKind::Type))
+    }
+
+    // TypeScript `type` modifiers of import and export specifiers, e.g. `import { type A }`. `type` is the imported or
+    // exported name when it is followed by `as`, `,` or `}`, e.g. `import { type as T }`.
+    pub(crate) fn parse_specifier_kind(
+        &mut self,
+    ) -> Result<Option<TSImportExportKind>, ParserError> {
+        if !self.options.extensions.typescript {
+            return Ok(None);
+        }
+
+        let peek_token_kind = self.peek_token_kind();
+
+        let is_type_only = self.token_kind() == TokenKind::Keyword(KeywordKind::Type)
+            && peek_token_kind != TokenKind::Keyword(KeywordKind::As)
+            && (peek_token_kind.is_identifier_name()
+                || peek_token_kind == TokenKind::StringLiteral);
+
+        if !is_type_only {
+            return Ok(Some(TSImportExportKind::Value));
+        }
+
+        self.advance_any()?; // Eat 'type' token.
+
+        Ok(Some(TSImportExportKind::Type))
+    }
+
     // https://tc39.es/ecma262/#prod-ModuleSpecifier
     fn parse_module_specifier(&mut self) -> Result<Literal, ParserError> {
         let source_literal = self.parse_literal()?;
@@ -211,8 +288,92 @@ impl Parser {
         Ok(source_literal)
     }
 
+    // https://tc39.es/ecma262/#prod-WithClause
+    pub(crate) fn parse_optional_with_clause(
+        &mut self,
+    ) -> Result<Vec<ImportAttribute>, ParserError> {
+        let mut attributes: Vec<ImportAttribute> = vec![];
+
+        if self.token_kind() != TokenKind::Keyword(KeywordKind::With) {
+            return Ok(attributes);
+        }
+
+        self.expect_feature(Feature::ImportAttributes)?;
+
+        self.advance_any()?; // Eat 'with' token.
+
+        self.expect_and_advance(TokenKind::LeftCurlyBrace)?;
+
+        while self.token_kind() != TokenKind::RightCurlyBrace {
+            let attribute = self.parse_import_attribute()?;
+
+            // It is a Syntax Error if WithClauseToAttributes of WithClause has two different entries a and b such that a.[[Key]] is b.[[Key]].
+            // https://tc39.es/ecma262/#sec-imports-static-semantics-early-errors
+            let key_name = import_attribute_key_name(&attribute.key);
+
+            if attributes
+                .iter()
+                .any(|other| import_attribute_key_name(&other.key) == key_name)
+            {
+                return Err(ParserError::from_node(
+                    ParserErrorKind::DuplicateImportAttributeKey,
+                    &attribute.node,
+                ));
+            }
+
+            attributes.push(attribute);
+
+            if self.token_kind() != TokenKind::RightCurlyBrace {
+                self.expect_and_advance(TokenKind::Comma)?;
+            }
+        }
+
+        self.expect_and_advance(TokenKind::RightCurlyBrace)?;
+
+        Ok(attributes)
+    }
+
+    // https://tc39.es/ecma262/#prod-AttributeEntry
+    fn parse_import_attribute(&mut self) -> Result<ImportAttribute, ParserError> {
+        let start_index = self.start_node();
+
+        // https://tc39.es/ecma262/#prod-AttributeKey
+        let key = match self.token_kind() {
+            TokenKind::StringLiteral => ImportAttributeKey::Literal(self.parse_literal()?),
+            token_kind if token_kind.is_identifier_name() => {
+                ImportAttributeKey::Identifier(self.parse_identifier_name()?)
+            }
+            _ => return Err(self.unexpected_current_token_kind()),
+        };
+
+        self.expect_and_advance(TokenKind::Colon)?;
+
+        if self.token_kind() != TokenKind::StringLiteral {
+            return Err(self.unexpected_current_token_kind());
+        }
+
+        let value = self.parse_literal()?;
+
+        Ok(ImportAttribute {
+            node: self.end_node(start_index)?,
+            key,
+            value,
+        })
+    }
+
     // https://tc39.es/ecma262/#prod-ImportedBinding
     pub(crate) fn parse_imported_binding(&mut self) -> Result<Identifier, ParserError> {
         self.parse_binding_identifier()
     }
 }
+
+fn import_attribute_key_name(key: &ImportAttributeKey) -> &str {
+    match key {
+        ImportAttributeKey::Identifier(identifier) => &identifier.name,
+        ImportAttributeKey::Literal(Literal {
+            value: LiteralValue::String(value),
+            ..
+        }) => value,
+        ImportAttributeKey::Literal(literal) => &literal.raw,
+    }
+}
@@ -4,21 +4,35 @@ mod module;
 mod script;
 
 use crate::ast::*;
-use crate::{Parser, ParserError};
+use crate::{Feature, Parser, ParserError, ParserErrorKind};
 
 // 16 ECMAScript Language: Scripts and Modules
 // https://tc39.es/ecma262/#sec-ecmascript-language-scripts-and-modules
-impl Parser {
+impl Parser<'_> {
     // 16.1 Scripts
     // https://tc39.es/ecma262/#prod-Script
     pub fn parse_script(&mut self) -> Result<Program, ParserError> {
+        self.start()?;
+        self.apply_program_options()?;
+
+        self.params
+            .add_allow_await(self.options.allow_await_outside_function);
+
         let program_body = self.parse_script_body()?;
 
-        Ok(Program {
+        let mut program = Program {
             body: program_body,
             source_type: ProgramSource::Script,
-            node: Node::new(0, self.cursor.lexer.len()),
-        })
+            hashbang: self.cursor.lexer.hashbang().map(String::from),
+            node: self.create_node(0, self.cursor.lexer.len()),
+            comments: None,
+            tokens: None,
+        };
+
+        self.add_comments(&mut program);
+        self.add_tokens(&mut program);
+
+        Ok(program)
     }
 
     // 16.2 Modules
@@ -28,12 +42,54 @@ impl Parser {
         // https://tc39.es/ecma262/#sec-strict-mode-code
         self.context.strict_mode = true;
 
+        self.context.in_module = true;
+
+        // Modules allow `await` at the top level, outside of any function.
+        // https://tc39.es/ecma262/#prod-ModuleItem
+        self.params.add_allow_await(true);
+
+        self.start()?;
+        self.apply_program_options()?;
+
         let program_body = self.parse_module_body()?;
 
-        Ok(Program {
+        let mut program = Program {
             body: program_body,
             source_type: ProgramSource::Module,
-            node: Node::new(0, self.cursor.lexer.len()),
-        })
+            hashbang: self.cursor.lexer.hashbang().map(String::from),
+            node: self.create_node(0, self.cursor.lexer.len()),
+            comments: None,
+            tokens: None,
+        };
+
+        self.add_comments(&mut program);
+        self.add_tokens(&mut program);
+
+        Ok(program)
+    }
+
+    fn apply_program_options(&mut self) -> Result<(), ParserError> {
+        // Hashbang comments were added in ES2023, so they can be disallowed for older environments.
+        // https://tc39.es/ecma262/#sec-hashbang
+        if let Some(hashbang) = self.cursor.lexer.hashbang() {
+            let hashbang_end_index = "#!".len() + hashbang.len();
+
+            match self.options.allow_hash_bang {
+                Some(true) => {}
+                Some(false) => {
+                    return Err(self.error(
+                        ParserErrorKind::UnexpectedHashbang,
+                        0,
+                        hashbang_end_index,
+                    ))
+                }
+                None => self.expect_feature_in(Feature::Hashbang, 0, hashbang_end_index)?,
+            }
+        }
+
+        self.params
+            .add_allow_return(self.options.allow_return_outside_function);
+
+        Ok(())
     }
 }
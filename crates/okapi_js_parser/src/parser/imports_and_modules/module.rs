@@ -3,7 +3,7 @@ use crate::{KeywordKind, Parser, ParserError, TokenKind};
 
 // 16 ECMAScript Language: Scripts and Modules
 // https://tc39.es/ecma262/#sec-ecmascript-language-scripts-and-modules
-impl Parser {
+impl Parser<'_> {
     // 16.2 Module
     // https://tc39.es/ecma262/#prod-ModuleBody
     pub(crate) fn parse_module_body(&mut self) -> Result<ProgramBody, ParserError> {
@@ -14,18 +14,48 @@ impl Parser {
             .collect::<Vec<ModuleItem>>();
 
         while self.token_kind() != TokenKind::EOF {
-            body.push(self.parse_module_item()?);
+            body.push(self.parse_module_item_or_recover()?);
         }
 
         Ok(ProgramBody::Module(body))
     }
 
     // https://tc39.es/ecma262/#prod-ModuleItem
-    fn parse_module_item(&mut self) -> Result<ModuleItem, ParserError> {
+    pub(crate) fn parse_module_item(&mut self) -> Result<ModuleItem, ParserError> {
         let module_item = match self.token_kind() {
-            TokenKind::Keyword(KeywordKind::Import) => {
+            // TypeScript `import x = require("x")` declarations.
+            TokenKind::Keyword(KeywordKind::Import) if self.is_typescript_declaration_start() => {
+                ModuleItem::StatementListItem(StatementListItem::Declaration(
+                    self.parse_typescript_declaration()?,
+                ))
+            }
+            // `import (` and `import .` start an expression statement rather than an import declaration.
+            TokenKind::Keyword(KeywordKind::Import)
+                if !matches!(
+                    self.peek_token_kind(),
+                    TokenKind::LeftParenthesis | TokenKind::Dot
+                ) =>
+            {
                 ModuleItem::ImportDeclaration(self.parse_import_declaration()?)
             }
+            // Decorators can be followed by either a class declaration or an exported class declaration.
+            TokenKind::At if self.is_decorator_start() => {
+                let start_index = self.start_node();
+
+                let decorators = self.parse_decorator_list()?;
+
+                if self.token_kind() == TokenKind::Keyword(KeywordKind::Export) {
+                    ModuleItem::ExportDeclaration(
+                        self.parse_export_declaration_with_decorators(start_index, decorators)?,
+                    )
+                } else {
+                    ModuleItem::StatementListItem(StatementListItem::Declaration(
+                        Declaration::Class(
+                            self.parse_class_declaration_with_decorators(start_index, decorators)?,
+                        ),
+                    ))
+                }
+            }
             TokenKind::Keyword(KeywordKind::Export) => {
                 ModuleItem::ExportDeclaration(self.parse_export_declaration()?)
             }
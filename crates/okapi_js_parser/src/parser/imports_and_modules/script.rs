@@ -1,17 +1,29 @@
 use crate::ast::*;
-use crate::{Parser, ParserError, TokenKind};
+use crate::{Parser, ParserError, ParserErrorKind, TokenKind};
 // 16 ECMAScript Language: Scripts and Modules
 // https://tc39.es/ecma262/#sec-ecmascript-language-scripts-and-modules
-impl Parser {
+impl Parser<'_> {
     // 16.1 Scripts
     // https://tc39.es/ecma262/#prod-Script
     pub(crate) fn parse_script_body(&mut self) -> Result<ProgramBody, ParserError> {
         let mut statement_list = self.parse_directive_prologue()?;
 
         while self.token_kind() != TokenKind::EOF {
-            statement_list.push(self.parse_statement_list_item()?);
+            statement_list.push(self.parse_script_statement_list_item_or_recover()?);
         }
 
         Ok(ProgramBody::StatementList(statement_list))
     }
+
+    pub(crate) fn parse_script_statement_list_item(
+        &mut self,
+    ) -> Result<StatementListItem, ParserError> {
+        // It is a Syntax Error if the goal symbol is Script and a using declaration is not contained within a block, function or class.
+        // https://tc39.es/proposal-explicit-resource-management/#sec-let-and-const-declarations-static-semantics-early-errors
+        if self.is_using_declaration_start() {
+            return Err(self.current_token_error(ParserErrorKind::InvalidUsingDeclarationPosition));
+        }
+
+        self.parse_statement_list_item()
+    }
 }
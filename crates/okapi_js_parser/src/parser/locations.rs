@@ -0,0 +1,177 @@
+use std::{cell::OnceCell, ops::Range, sync::Arc};
+
+use crate::{
+    ast::{Node, Position, SourceLocation},
+    OffsetEncoding,
+};
+
+const LF: u8 = b'\n';
+const CR: u8 = b'\r';
+
+// Converts the lexer's byte offsets into offsets of the selected encoding, and into ESTree line and column positions.
+// https://github.com/estree/estree/blob/master/es5.md#node-objects
+#[derive(Clone, Debug)]
+pub(crate) struct Locations<'a> {
+    source: &'a str,
+    offset_encoding: OffsetEncoding,
+    // Byte offset after each multi-byte char, and the total difference between byte and encoded offsets up to it.
+    offset_deltas: Vec<(usize, usize)>,
+    // Only built when line and column positions are first needed.
+    line_starts: OnceCell<Vec<usize>>,
+    // Whether to add `loc` to every node, the same as Acorn's `locations` option.
+    enabled: bool,
+    source_file: Option<Arc<str>>,
+    // Whether to add `range` to every node, the same as Acorn's `ranges` option.
+    ranges: bool,
+}
+
+impl<'a> Locations<'a> {
+    pub(crate) fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            offset_encoding: OffsetEncoding::Utf8,
+            offset_deltas: Vec::new(),
+            line_starts: OnceCell::new(),
+            enabled: false,
+            source_file: None,
+            ranges: false,
+        }
+    }
+
+    pub(crate) fn enable(&mut self, source_file: Option<&str>) {
+        self.enabled = true;
+        self.source_file = source_file.map(Arc::from);
+    }
+
+    pub(crate) fn enable_ranges(&mut self) {
+        self.ranges = true;
+    }
+
+    pub(crate) fn set_offset_encoding(&mut self, offset_encoding: OffsetEncoding) {
+        self.offset_encoding = offset_encoding;
+        self.offset_deltas = offset_deltas(self.source, offset_encoding);
+    }
+
+    pub(crate) fn node(&self, start_index: usize, end_index: usize) -> Node {
+        let mut node = Node::new(self.offset(start_index), self.offset(end_index));
+
+        if self.enabled {
+            node.details_mut().loc = Some(SourceLocation {
+                source: self.source_file.clone(),
+                start: self.position(start_index),
+                end: self.position(end_index),
+            });
+        }
+
+        if self.ranges {
+            node.details_mut().range = Some([node.start, node.end]);
+        }
+
+        node
+    }
+
+    pub(crate) fn offset(&self, index: usize) -> usize {
+        let delta_index = self
+            .offset_deltas
+            .partition_point(|(delta_start, _)| *delta_start <= index);
+
+        match delta_index {
+            0 => index,
+            _ => index - self.offset_deltas[delta_index - 1].1,
+        }
+    }
+
+    // The byte offset of an offset in the selected encoding, the reverse of `offset`.
+    pub(crate) fn index(&self, offset: usize) -> usize {
+        let delta_index = self
+            .offset_deltas
+            .partition_point(|(delta_start, delta)| delta_start - delta <= offset);
+
+        match delta_index {
+            0 => offset,
+            _ => offset + self.offset_deltas[delta_index - 1].1,
+        }
+    }
+
+    pub(crate) fn position(&self, index: usize) -> Position {
+        let line_starts = self.line_starts();
+
+        let line = line_starts.partition_point(|line_start| *line_start <= index);
+        let line_start = line_starts[line - 1];
+
+        Position {
+            line,
+            column: self.offset(index) - self.offset(line_start),
+        }
+    }
+
+    pub(crate) fn line_count(&self) -> usize {
+        self.line_starts().len()
+    }
+
+    // The byte range of a one-based line, without its line terminator.
+    pub(crate) fn line_range(&self, line: usize) -> Range<usize> {
+        let line_starts = self.line_starts();
+
+        let start = line_starts[line - 1];
+        let end = line_starts.get(line).copied().unwrap_or(self.source.len());
+
+        let line_source =
+            self.source[start..end].trim_end_matches(['\n', '\r', '\u{2028}', '\u{2029}']);
+
+        start..start + line_source.len()
+    }
+
+    fn line_starts(&self) -> &Vec<usize> {
+        self.line_starts.get_or_init(|| line_starts(self.source))
+    }
+}
+
+fn offset_deltas(source: &str, offset_encoding: OffsetEncoding) -> Vec<(usize, usize)> {
+    if offset_encoding == OffsetEncoding::Utf8 || source.is_ascii() {
+        return Vec::new();
+    }
+
+    let mut offset_deltas = Vec::new();
+    let mut delta = 0;
+
+    for (index, ch) in source.char_indices() {
+        let byte_len = ch.len_utf8();
+
+        if byte_len == 1 {
+            continue;
+        }
+
+        delta += match offset_encoding {
+            OffsetEncoding::Utf16 => byte_len - ch.len_utf16(),
+            _ => byte_len - 1,
+        };
+
+        offset_deltas.push((index + byte_len, delta));
+    }
+
+    offset_deltas
+}
+
+// 12.3 Line Terminators
+// https://tc39.es/ecma262/#sec-line-terminators
+// A `<CR><LF>` sequence is treated as a single line terminator.
+fn line_starts(source: &str) -> Vec<usize> {
+    let bytes = source.as_bytes();
+
+    let mut line_starts = vec![0];
+
+    for (index, byte) in bytes.iter().enumerate() {
+        match byte {
+            &LF => line_starts.push(index + 1),
+            &CR if bytes.get(index + 1) != Some(&LF) => line_starts.push(index + 1),
+            // <LS> and <PS> are encoded as `E2 80 A8` and `E2 80 A9`.
+            0xA8 | 0xA9 if index >= 2 && bytes[index - 2] == 0xE2 && bytes[index - 1] == 0x80 => {
+                line_starts.push(index + 1)
+            }
+            _ => {}
+        }
+    }
+
+    line_starts
+}
@@ -1,20 +1,37 @@
-pub(crate) use crate::{ast::*, Lexer, ParserError, TokenKind, TokenValue};
+pub(crate) use crate::{
+    ast::*, Feature, Lexer, ParserError, ParserErrorKind, ParserErrorReport, ParserOptions,
+    TokenKind, TokenValue,
+};
 
+mod code_frame;
+mod comments;
 mod cursor;
 mod directive;
 mod expression;
 mod functions_and_classes;
 mod imports_and_modules;
+mod locations;
 mod params;
+mod recovery;
 mod statement;
+mod tokens;
+mod typescript;
 
 pub use cursor::Cursor;
+pub(crate) use cursor::CursorCheckpoint;
 pub use params::Params;
 
+pub(crate) use locations::Locations;
+
 #[derive(Clone, Debug)]
 pub struct Context {
     pub in_optional_chain: bool,
     pub strict_mode: bool,
+    pub in_module: bool,
+    pub in_function: bool,
+    // Set when the consequent of a conditional expression is reparsed, so that its arrow function can't have a return
+    // type. Only applies to the next assignment expression, which clears it.
+    pub disallow_arrow_return_type: bool,
 }
 
 impl Context {
@@ -22,28 +39,53 @@ impl Context {
         Self {
             in_optional_chain: false,
             strict_mode: false,
+            in_module: false,
+            in_function: false,
+            disallow_arrow_return_type: false,
         }
     }
 }
 
 #[derive(Clone)]
-pub struct Parser {
-    pub cursor: Cursor,
-    pub context: Context,
-    pub params: Params,
+pub struct Parser<'a> {
+    pub cursor: Cursor<'a>,
+    pub(crate) context: Context,
+    pub(crate) params: Params,
+    pub(crate) options: ParserOptions,
+    pub(crate) locations: Locations<'a>,
+    // Every error which has been recovered from, which is only collected when parsing with error recovery.
+    pub(crate) diagnostics: Option<Vec<ParserErrorReport>>,
 }
 
-impl Parser {
-    pub fn new(input: &str) -> Self {
-        let mut lexer = Lexer::new(input);
+impl<'a> Parser<'a> {
+    pub fn new(input: &'a str, options: ParserOptions) -> Self {
+        let lexer = Lexer::new(input);
+
+        let mut locations = Locations::new(input);
+
+        if options.locations {
+            locations.enable(options.source_file.as_deref());
+        }
+
+        if options.ranges {
+            locations.enable_ranges();
+        }
+
+        locations.set_offset_encoding(options.offset_encoding);
 
-        let current_token = lexer.next_token();
-        let next_token = lexer.clone().next_token();
+        let mut cursor = Cursor::new(lexer);
+
+        if options.tokens {
+            cursor.record_tokens();
+        }
 
         Self {
-            cursor: Cursor::new(lexer, current_token, next_token),
+            cursor,
             context: Context::default(),
             params: Params::default(),
+            options,
+            locations,
+            diagnostics: None,
         }
     }
 
@@ -51,11 +93,11 @@ impl Parser {
         self.cursor.current_token_kind()
     }
 
-    pub(crate) fn token_value(&self) -> TokenValue {
+    pub(crate) fn token_value(&self) -> TokenValue<'a> {
         self.cursor.current_token_value()
     }
 
-    pub(crate) fn peek_token_kind(&self) -> TokenKind {
+    pub(crate) fn peek_token_kind(&mut self) -> TokenKind {
         self.cursor.peek_token_kind()
     }
 
@@ -63,7 +105,7 @@ impl Parser {
     //     self.cursor.peek_token_value()
     // }
 
-    pub(crate) fn peek_nth_kind(&self, i: i32) -> TokenKind {
+    pub(crate) fn peek_nth_kind(&mut self, i: usize) -> TokenKind {
         self.cursor.peek_nth_kind(i)
     }
 
@@ -75,37 +117,104 @@ impl Parser {
         self.cursor.current_token.line_terminator
     }
 
-    pub(crate) fn has_peek_token_line_terminator(&self) -> bool {
-        self.cursor.next_token.line_terminator
+    pub(crate) fn has_peek_token_line_terminator(&mut self) -> bool {
+        self.cursor.has_peek_token_line_terminator()
     }
 
-    pub(crate) fn unexpected_current_token_kind(&mut self) -> ParserError {
-        ParserError::UnexpectedToken(
-            self.token_kind(),
-            self.cursor.current_token.line,
-            self.cursor.current_token.column,
+    // An error spanning the source between two byte offsets, which are converted to the offset encoding of the parser.
+    pub(crate) fn error(
+        &self,
+        kind: ParserErrorKind,
+        start_index: usize,
+        end_index: usize,
+    ) -> ParserError {
+        ParserError::new(
+            kind,
+            self.locations.offset(start_index),
+            self.locations.offset(end_index),
         )
     }
 
+    pub(crate) fn current_token_error(&self, kind: ParserErrorKind) -> ParserError {
+        self.error(
+            kind,
+            self.cursor.current_token.start,
+            self.cursor.current_token.end,
+        )
+    }
+
+    pub(crate) fn unexpected_current_token_kind(&mut self) -> ParserError {
+        self.current_token_error(ParserErrorKind::UnexpectedToken(self.token_kind()))
+    }
+
     pub(crate) fn unexpected_current_token_value(&mut self) -> ParserError {
-        ParserError::UnexpectedTokenValue(
+        self.current_token_error(ParserErrorKind::UnexpectedTokenValue(
             self.token_kind(),
-            self.token_value(),
-            self.cursor.current_token.line,
-            self.cursor.current_token.column,
-        )
+            String::from(self.token_value()),
+        ))
     }
 
-    pub fn parse_script_json(&mut self) -> Result<String, serde_json::Error> {
+    // Parses either a script or a module, depending on the `sourceType` option.
+    pub fn parse(&mut self) -> Result<Program, ParserError> {
+        match self.options.source_type {
+            ProgramSource::Script => self.parse_script(),
+            ProgramSource::Module => self.parse_module(),
+        }
+    }
+
+    // Returns the ESTree program as JSON, or a `ParserErrorReport` if the source could not be parsed or serialized.
+    // The report is returned as is rather than boxed, as it is only created once per parse.
+    #[allow(clippy::result_large_err)]
+    pub fn parse_json(&mut self) -> Result<String, ParserErrorReport> {
+        let program = self.parse();
+
+        self.program_to_json(program)
+    }
+
+    // Returns the ESTree program as JSON, or a `ParserErrorReport` if the source could not be parsed or serialized.
+    #[allow(clippy::result_large_err)]
+    pub fn parse_script_json(&mut self) -> Result<String, ParserErrorReport> {
         let program = self.parse_script();
 
-        serde_json::to_string(&program.unwrap())
+        self.program_to_json(program)
     }
 
-    pub fn parse_module_json(&mut self) -> Result<String, serde_json::Error> {
+    // Returns the ESTree program as JSON, or a `ParserErrorReport` if the source could not be parsed or serialized.
+    #[allow(clippy::result_large_err)]
+    pub fn parse_module_json(&mut self) -> Result<String, ParserErrorReport> {
         let program = self.parse_module();
 
-        serde_json::to_string(&program.unwrap())
+        self.program_to_json(program)
+    }
+
+    #[allow(clippy::result_large_err)]
+    fn program_to_json(
+        &self,
+        program: Result<Program, ParserError>,
+    ) -> Result<String, ParserErrorReport> {
+        let error = match program {
+            Ok(program) => match serde_json::to_string(&program) {
+                Ok(program_json) => return Ok(program_json),
+                Err(_) => ParserError::new(ParserErrorKind::SerializationError, 0, 0),
+            },
+            Err(error) => error,
+        };
+
+        Err(self.error_report(&error))
+    }
+
+    // Errors are located at the start of their span, which is mapped back to byte offsets for the line and code frame.
+    pub(crate) fn error_report(&self, error: &ParserError) -> ParserErrorReport {
+        let start_index = self.locations.index(error.span.start);
+        let end_index = self.locations.index(error.span.end);
+
+        let mut report = ParserErrorReport::new(error, self.locations.position(start_index));
+
+        if self.options.code_frame {
+            report.code_frame = Some(self.code_frame(start_index, end_index));
+        }
+
+        report
     }
 
     pub(crate) fn start_node(&mut self) -> usize {
@@ -115,11 +224,44 @@ impl Parser {
     pub fn end_node(&mut self, start_index: usize) -> Result<Node, ParserError> {
         let end_index = self.cursor.previous_token.end;
 
-        Ok(Node::new(start_index, end_index))
+        Ok(self.create_node(start_index, end_index))
+    }
+
+    pub(crate) fn create_node(&self, start_index: usize, end_index: usize) -> Node {
+        self.locations.node(start_index, end_index)
+    }
+
+    // Lexer errors are returned from the parser once the invalid token is reached, in byte offsets which are converted
+    // to the offset encoding of the parser, the same as for nodes.
+    pub(crate) fn advance_any(&mut self) -> Result<(), ParserError> {
+        self.cursor
+            .advance()
+            .map_err(|error| self.lexer_error(error))
+    }
+
+    pub(crate) fn rewind(&mut self) -> Result<(), ParserError> {
+        self.cursor
+            .rewind()
+            .map_err(|error| self.lexer_error(error))
+    }
+
+    // The first token is scanned once parsing starts. When recovering, an error in it is reported and the first
+    // statement is parsed as invalid.
+    pub(crate) fn start(&mut self) -> Result<(), ParserError> {
+        match self.cursor.start() {
+            Err(error) if self.diagnostics.is_some() => {
+                let error = self.lexer_error(error);
+
+                self.add_diagnostic(&error);
+
+                Ok(())
+            }
+            result => result.map_err(|error| self.lexer_error(error)),
+        }
     }
 
-    pub(crate) fn advance_any(&mut self) {
-        self.cursor.advance();
+    fn lexer_error(&self, error: ParserError) -> ParserError {
+        self.error(error.kind, error.span.start, error.span.end)
     }
 
     fn expect(&mut self, token_kind: TokenKind) -> Result<(), ParserError> {
@@ -133,9 +275,7 @@ impl Parser {
     pub(crate) fn expect_and_advance(&mut self, token_kind: TokenKind) -> Result<(), ParserError> {
         self.expect(token_kind)?;
 
-        self.advance_any();
-
-        Ok(())
+        self.advance_any()
     }
 
     pub(crate) fn expect_one_of_and_advance(
@@ -144,7 +284,7 @@ impl Parser {
     ) -> Result<(), ParserError> {
         for token_kind in token_kinds {
             if self.token_kind() == token_kind {
-                self.advance_any();
+                self.advance_any()?;
 
                 return Ok(());
             }
@@ -153,10 +293,84 @@ impl Parser {
         Err(self.unexpected_current_token_kind())
     }
 
-    pub(crate) fn expect_optional_semicolon_and_advance(&mut self) {
+    // Syntax which was added after the ECMAScript version being parsed is rejected.
+    pub(crate) fn expect_feature(&self, feature: Feature) -> Result<(), ParserError> {
+        self.expect_feature_in(
+            feature,
+            self.cursor.current_token.start,
+            self.cursor.current_token.end,
+        )
+    }
+
+    // The same as `expect_feature`, for syntax which has already been parsed into a node, such as a literal whose
+    // token has been consumed.
+    pub(crate) fn expect_node_feature(
+        &self,
+        feature: Feature,
+        node: &Node,
+    ) -> Result<(), ParserError> {
+        if self.options.ecma_version < feature.ecma_version() {
+            return Err(ParserError::from_node(
+                ParserErrorKind::UnsupportedFeature(feature),
+                node,
+            ));
+        }
+
+        Ok(())
+    }
+
+    // The same as `expect_feature`, for syntax which is not at the current token.
+    pub(crate) fn expect_feature_in(
+        &self,
+        feature: Feature,
+        start_index: usize,
+        end_index: usize,
+    ) -> Result<(), ParserError> {
+        if self.options.ecma_version < feature.ecma_version() {
+            return Err(self.error(
+                ParserErrorKind::UnsupportedFeature(feature),
+                start_index,
+                end_index,
+            ));
+        }
+
+        Ok(())
+    }
+
+    // Binary and assignment operators which were added after ES2015.
+    pub(crate) fn expect_operator_feature(
+        &self,
+        token_kind: &TokenKind,
+    ) -> Result<(), ParserError> {
+        match token_kind {
+            TokenKind::Exponentiation | TokenKind::ExponentiationAssignment => {
+                self.expect_feature(Feature::ExponentiationOperator)
+            }
+            TokenKind::NullishCoalescing => self.expect_feature(Feature::NullishCoalescing),
+            TokenKind::LogicalOrAssignment
+            | TokenKind::LogicalAndAssignment
+            | TokenKind::NullishCoalescingAssignment => {
+                self.expect_feature(Feature::LogicalAssignment)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    // `await` outside of a function body is only allowed at the top level of a module, which was added in ES2022.
+    pub(crate) fn expect_await_feature(&self) -> Result<(), ParserError> {
+        if !self.context.in_function && !self.options.allow_await_outside_function {
+            self.expect_feature(Feature::TopLevelAwait)?;
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn expect_optional_semicolon_and_advance(&mut self) -> Result<(), ParserError> {
         if self.token_kind() == TokenKind::Semicolon {
-            self.advance_any();
+            self.advance_any()?;
         }
+
+        Ok(())
     }
 
     pub(crate) fn with_params<T, F>(
@@ -7,6 +7,7 @@ bitflags! {
         const ALLOW_YIELD = 1 << 1;
         const ALLOW_AWAIT = 1 << 2;
         const ALLOW_DEFAULT = 1 << 3;
+        const ALLOW_RETURN = 1 << 4;
     }
 }
 
@@ -49,6 +50,14 @@ impl Params {
         self.set_value(Self::ALLOW_DEFAULT, value)
     }
 
+    pub fn has_allow_return(self) -> bool {
+        self.contains(Self::ALLOW_RETURN)
+    }
+
+    pub fn add_allow_return(&mut self, value: bool) -> Self {
+        self.set_value(Self::ALLOW_RETURN, value)
+    }
+
     fn set_value(&mut self, flag: Self, value: bool) -> Self {
         if value {
             self.insert(flag);
@@ -0,0 +1,231 @@
+use crate::{ast::*, KeywordKind, Parser, ParserError, ParserErrorReport, TokenKind};
+
+impl Parser<'_> {
+    // Parses the program, recovering from each error by skipping to the next statement and adding an
+    // `InvalidStatement` in place of the statement which could not be parsed. The errors are returned in source order.
+    pub fn parse_with_recovery(&mut self) -> (Program, Vec<ParserErrorReport>) {
+        self.diagnostics = Some(Vec::new());
+
+        let program = self.parse();
+
+        let mut diagnostics = self.diagnostics.take().unwrap_or_default();
+
+        // Errors outside of a statement list, such as in the directive prologue, can't be recovered from.
+        let program = program.unwrap_or_else(|error| {
+            diagnostics.push(self.error_report(&error));
+
+            Program {
+                node: self.create_node(0, self.cursor.lexer.len()),
+                body: match self.options.source_type {
+                    ProgramSource::Script => ProgramBody::StatementList(vec![]),
+                    ProgramSource::Module => ProgramBody::Module(vec![]),
+                },
+                source_type: self.options.source_type,
+                hashbang: self.cursor.lexer.hashbang().map(String::from),
+                comments: None,
+                tokens: None,
+            }
+        });
+
+        (program, diagnostics)
+    }
+
+    // Statement list items are parsed in a separate function when recovering, so that the extra state it needs
+    // doesn't increase the stack size of every statement list.
+    #[inline]
+    pub(crate) fn parse_statement_list_item_or_recover(
+        &mut self,
+    ) -> Result<StatementListItem, ParserError> {
+        if self.diagnostics.is_none() {
+            return self.parse_statement_list_item();
+        }
+
+        self.parse_or_recover(Self::parse_statement_list_item, true, |invalid_statement| {
+            StatementListItem::Statement(Statement::Invalid(invalid_statement))
+        })
+    }
+
+    #[inline]
+    pub(crate) fn parse_script_statement_list_item_or_recover(
+        &mut self,
+    ) -> Result<StatementListItem, ParserError> {
+        if self.diagnostics.is_none() {
+            return self.parse_script_statement_list_item();
+        }
+
+        self.parse_or_recover(
+            Self::parse_script_statement_list_item,
+            false,
+            |invalid_statement| StatementListItem::Statement(Statement::Invalid(invalid_statement)),
+        )
+    }
+
+    #[inline]
+    pub(crate) fn parse_module_item_or_recover(&mut self) -> Result<ModuleItem, ParserError> {
+        if self.diagnostics.is_none() {
+            return self.parse_module_item();
+        }
+
+        self.parse_or_recover(Self::parse_module_item, false, |invalid_statement| {
+            ModuleItem::StatementListItem(StatementListItem::Statement(Statement::Invalid(
+                invalid_statement,
+            )))
+        })
+    }
+
+    // A missing `}` at the end of the source is reported rather than returned when recovering, so that the partial
+    // block or function body is kept.
+    pub(crate) fn expect_closing_brace_or_recover(&mut self) -> Result<(), ParserError> {
+        if self.diagnostics.is_some() && self.token_kind() == TokenKind::EOF {
+            let error = self.unexpected_current_token_kind();
+
+            self.add_diagnostic(&error);
+
+            return Ok(());
+        }
+
+        self.expect_and_advance(TokenKind::RightCurlyBrace)
+    }
+
+    // Statement list items within a block or function body are recovered from up to its closing `}`, whereas a `}` at
+    // the top level of the program is unmatched, e.g. left over from a broken expression, and is skipped.
+    #[inline(never)]
+    fn parse_or_recover<T>(
+        &mut self,
+        parse: fn(&mut Self) -> Result<T, ParserError>,
+        in_block: bool,
+        invalid: fn(InvalidStatement) -> T,
+    ) -> Result<T, ParserError> {
+        let start_index = self.start_node();
+
+        let previous_context = self.context.clone();
+        let previous_params = self.params;
+        let previous_goal_symbol = self.cursor.goal_symbol();
+
+        let error = match parse(self) {
+            Ok(item) => return Ok(item),
+            Err(error) => error,
+        };
+
+        if self.cursor.current_token.start == start_index && self.token_kind() == TokenKind::EOF {
+            return Err(error);
+        }
+
+        self.add_diagnostic(&error);
+
+        // The error may have been returned before the context was restored, e.g. from within a function body.
+        self.context = previous_context;
+        self.params = previous_params;
+
+        if self.cursor.goal_symbol() != previous_goal_symbol {
+            self.cursor.set_goal_symbol(previous_goal_symbol);
+            // An invalid token is skipped below as part of the invalid statement.
+            let _ = self.cursor.rewind();
+        }
+
+        self.synchronize(start_index, in_block);
+
+        let node = self.end_node(start_index)?;
+
+        Ok(invalid(InvalidStatement { node }))
+    }
+
+    // Skip to the start of the next statement, which is after a `;`, before the `}` of the enclosing block, or before
+    // a token which starts a statement on a new line. Errors in the skipped tokens are part of the invalid statement,
+    // so they are not reported.
+    fn synchronize(&mut self, start_index: usize, in_block: bool) {
+        // Always skip at least one token, so that the same error isn't returned again.
+        if self.cursor.current_token.start == start_index {
+            let _ = self.advance_any();
+        }
+
+        let mut depth = 0;
+
+        loop {
+            match self.token_kind() {
+                TokenKind::EOF => return,
+                TokenKind::Semicolon if depth == 0 => {
+                    let _ = self.advance_any();
+
+                    return;
+                }
+                TokenKind::RightCurlyBrace if depth == 0 && in_block => return,
+                TokenKind::RightCurlyBrace if depth == 0 => {}
+                TokenKind::RightCurlyBrace => depth -= 1,
+                TokenKind::LeftCurlyBrace => depth += 1,
+                token_kind
+                    if depth == 0
+                        && self.has_previous_token_line_terminator()
+                        && is_statement_start(&token_kind) =>
+                {
+                    return
+                }
+                _ => {}
+            }
+
+            let _ = self.advance_any();
+        }
+    }
+
+    // Errors from a speculative parse which was abandoned are discarded, as the same source is parsed again after
+    // them, so errors are always added in source order. Only the first error at an offset is kept, e.g. an invalid
+    // first token is reported by the lexer rather than as an unexpected token.
+    pub(crate) fn add_diagnostic(&mut self, error: &ParserError) {
+        let report = self.error_report(error);
+
+        let Some(diagnostics) = &mut self.diagnostics else {
+            return;
+        };
+
+        while diagnostics
+            .last()
+            .is_some_and(|diagnostic| diagnostic.offset > report.offset)
+        {
+            diagnostics.pop();
+        }
+
+        if diagnostics
+            .last()
+            .is_some_and(|diagnostic| diagnostic.offset == report.offset)
+        {
+            return;
+        }
+
+        diagnostics.push(report);
+    }
+}
+
+// Tokens which can start a statement on a new line. Keywords which only continue a statement, such as `else` or `in`,
+// are skipped along with the rest of the invalid statement.
+fn is_statement_start(token_kind: &TokenKind) -> bool {
+    match token_kind {
+        TokenKind::Keyword(keyword_kind) => !matches!(
+            keyword_kind,
+            KeywordKind::As
+                | KeywordKind::Case
+                | KeywordKind::Catch
+                | KeywordKind::Default
+                | KeywordKind::Else
+                | KeywordKind::Extends
+                | KeywordKind::Finally
+                | KeywordKind::From
+                | KeywordKind::In
+                | KeywordKind::Instanceof
+                | KeywordKind::Of
+        ),
+        token_kind => {
+            token_kind.is_template_start()
+                || matches!(
+                    token_kind,
+                    TokenKind::Identifier
+                        | TokenKind::StringLiteral
+                        | TokenKind::NumberLiteral
+                        | TokenKind::BigIntLiteral
+                        | TokenKind::LeftParenthesis
+                        | TokenKind::LeftSquareBracket
+                        | TokenKind::Increment
+                        | TokenKind::Decrement
+                )
+        }
+    }
+}
@@ -3,7 +3,7 @@ use crate::{KeywordKind, Parser, ParserError, TokenKind};
 
 // 14 ECMAScript Language: Statements and Declarations
 // https://tc39.es/ecma262/#prod-Statement
-impl Parser {
+impl Parser<'_> {
     // 14.2 Block
     // https://tc39.es/ecma262/#prod-BlockStatement
     pub(crate) fn parse_block_statement(&mut self) -> Result<BlockStatement, ParserError> {
@@ -13,7 +13,7 @@ impl Parser {
 
         let statement_list = self.parse_statement_list()?;
 
-        self.expect_and_advance(TokenKind::RightCurlyBrace)?;
+        self.expect_closing_brace_or_recover()?;
 
         Ok(BlockStatement {
             node: self.end_node(start_index)?,
@@ -25,8 +25,11 @@ impl Parser {
     pub(crate) fn parse_statement_list(&mut self) -> Result<Vec<StatementListItem>, ParserError> {
         let mut body = vec![];
 
-        while self.token_kind() != TokenKind::RightCurlyBrace {
-            body.push(StatementListItem::Statement(self.parse_statement()?));
+        while !matches!(
+            self.token_kind(),
+            TokenKind::RightCurlyBrace | TokenKind::EOF
+        ) {
+            body.push(self.parse_statement_list_item_or_recover()?);
         }
 
         Ok(body)
@@ -35,6 +38,25 @@ impl Parser {
     // https://tc39.es/ecma262/#prod-StatementListItem
     pub(crate) fn parse_statement_list_item(&mut self) -> Result<StatementListItem, ParserError> {
         match self.token_kind() {
+            // Import and export declarations are only allowed at the top level of a module, unless they are allowed
+            // everywhere by the `allowImportExportEverywhere` option.
+            TokenKind::Keyword(KeywordKind::Import | KeywordKind::Export)
+                if self.is_nested_module_item_start() =>
+            {
+                Ok(StatementListItem::from(self.parse_module_item()?))
+            }
+            // TypeScript declarations start with a contextual keyword, e.g. `interface`, `type` or `declare`.
+            _ if self.is_typescript_declaration_start() => Ok(StatementListItem::Declaration(
+                self.parse_typescript_declaration()?,
+            )),
+            // `using` and `await using` are only declarations when followed by a binding identifier on the same line.
+            _ if self.is_using_declaration_start() => Ok(StatementListItem::Declaration(
+                Declaration::Variable(self.parse_using_declaration(true)?),
+            )),
+            // Decorators can only be followed by a class declaration.
+            TokenKind::At if self.is_decorator_start() => Ok(StatementListItem::Declaration(
+                Declaration::Class(self.parse_decorated_class_declaration()?),
+            )),
             TokenKind::Keyword(KeywordKind::Let) => {
                 if self.peek_token_kind().is_lexical_declaration_start() {
                     Ok(StatementListItem::Declaration(self.parse_declaration()?))
@@ -48,4 +70,13 @@ impl Parser {
             _ => Ok(StatementListItem::Statement(self.parse_statement()?)),
         }
     }
+
+    fn is_nested_module_item_start(&mut self) -> bool {
+        self.context.in_module
+            && self.options.allow_import_export_everywhere
+            && !matches!(
+                self.peek_token_kind(),
+                TokenKind::LeftParenthesis | TokenKind::Dot
+            )
+    }
 }
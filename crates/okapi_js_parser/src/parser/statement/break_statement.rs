@@ -3,7 +3,7 @@ use crate::{KeywordKind, Parser, ParserError, TokenKind};
 
 // 14 ECMAScript Language: Statements and Declarations
 // https://tc39.es/ecma262/#prod-Statement
-impl Parser {
+impl Parser<'_> {
     // 14.9 The break Statement
     // https://tc39.es/ecma262/#prod-BreakStatement
     pub(crate) fn parse_break_statement(&mut self) -> Result<BreakStatement, ParserError> {
@@ -17,7 +17,7 @@ impl Parser {
             Some(self.parse_label_identifier()?)
         };
 
-        self.expect_optional_semicolon_and_advance();
+        self.expect_optional_semicolon_and_advance()?;
 
         Ok(BreakStatement {
             node: self.end_node(start_index)?,
@@ -1,9 +1,9 @@
 use crate::ast::*;
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind};
 
 // 14 ECMAScript Language: Statements and Declarations
 // https://tc39.es/ecma262/#prod-Statement
-impl Parser {
+impl Parser<'_> {
     // 14.8 The continue Statement
     // https://tc39.es/ecma262/#prod-ContinueStatement
     pub(crate) fn parse_continue_statement(&mut self) -> Result<ContinueStatement, ParserError> {
@@ -18,10 +18,10 @@ impl Parser {
         };
 
         if self.has_previous_token_line_terminator() {
-            return Err(ParserError::UnexpectedLineTerminator);
+            return Err(self.current_token_error(ParserErrorKind::UnexpectedLineTerminator));
         }
 
-        self.expect_optional_semicolon_and_advance();
+        self.expect_optional_semicolon_and_advance()?;
 
         Ok(ContinueStatement {
             node: self.end_node(start_index)?,
@@ -4,7 +4,7 @@ use crate::ast::DebuggerStatement;
 
 // 14 ECMAScript Language: Statements and Declarations
 // https://tc39.es/ecma262/#prod-Statement
-impl Parser {
+impl Parser<'_> {
     // 14.13 The debugger Statement
     // https://tc39.github.io/ecma262/#sec-debugger-statement
     pub(crate) fn parse_debugger_statement(&mut self) -> Result<DebuggerStatement, ParserError> {
@@ -1,9 +1,9 @@
 use crate::ast::*;
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{Feature, KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind};
 
 // 14.3 Declarations and the Variable Statement
 // https://tc39.es/ecma262/#sec-declarations-and-the-variable-statement
-impl Parser {
+impl Parser<'_> {
     // 14.3.1 Let and Const Declarations
     // https://tc39.es/ecma262/#prod-LexicalDeclaration
     pub(crate) fn parse_lexical_declaration(
@@ -18,12 +18,16 @@ impl Parser {
             _ => return Err(self.unexpected_current_token_kind()),
         };
 
+        self.expect_feature(Feature::LexicalDeclarations)?;
+
         match kind {
             VariableKind::Let => self.expect_and_advance(TokenKind::Keyword(KeywordKind::Let))?,
             VariableKind::Const => {
                 self.expect_and_advance(TokenKind::Keyword(KeywordKind::Const))?
             }
-            VariableKind::Var => return Err(self.unexpected_current_token_kind()),
+            VariableKind::Var | VariableKind::Using | VariableKind::AwaitUsing => {
+                return Err(self.unexpected_current_token_kind())
+            }
         };
 
         let binding_list = self.parse_binding_list()?;
@@ -33,16 +37,108 @@ impl Parser {
         }
 
         if include_optional_semicolon {
-            self.expect_optional_semicolon_and_advance();
+            self.expect_optional_semicolon_and_advance()?;
         }
 
         Ok(VariableDeclaration {
             node: self.end_node(start_index)?,
             declarations: binding_list,
             kind,
+            declare: false,
         })
     }
 
+    // Explicit Resource Management
+    // https://tc39.es/proposal-explicit-resource-management/#prod-UsingDeclaration
+    // https://tc39.es/proposal-explicit-resource-management/#prod-AwaitUsingDeclaration
+    pub(crate) fn is_using_declaration_start(&mut self) -> bool {
+        match self.token_kind() {
+            // `using [no LineTerminator here] BindingIdentifier`
+            TokenKind::Keyword(KeywordKind::Using) => {
+                !self.has_current_token_line_terminator()
+                    && self.peek_token_kind().is_binding_identifier()
+            }
+            // `await [no LineTerminator here] using [no LineTerminator here] BindingIdentifier`
+            TokenKind::Keyword(KeywordKind::Await) => {
+                self.params.has_allow_await()
+                    && !self.has_current_token_line_terminator()
+                    && self.peek_token_kind() == TokenKind::Keyword(KeywordKind::Using)
+                    && !self.has_peek_token_line_terminator()
+                    && self.peek_nth_kind(2).is_binding_identifier()
+            }
+            _ => false,
+        }
+    }
+
+    // The initializers of a using declaration are only checked for statements, as the head of a `for` statement can leave them out.
+    pub(crate) fn parse_using_declaration(
+        &mut self,
+        include_optional_semicolon: bool,
+    ) -> Result<VariableDeclaration, ParserError> {
+        self.expect_feature(Feature::UsingDeclarations)?;
+
+        let start_index = self.start_node();
+
+        let kind = if self.token_kind() == TokenKind::Keyword(KeywordKind::Await) {
+            self.expect_await_feature()?;
+
+            self.advance_any()?; // Eat 'await' token.
+
+            VariableKind::AwaitUsing
+        } else {
+            VariableKind::Using
+        };
+
+        self.expect_and_advance(TokenKind::Keyword(KeywordKind::Using))?;
+
+        let binding_list = self.parse_binding_list()?;
+
+        // It is a Syntax Error if the BoundNames of BindingList contains "let", and using declarations cannot bind patterns.
+        // https://tc39.es/proposal-explicit-resource-management/#sec-let-and-const-declarations-static-semantics-early-errors
+        for declarator in &binding_list {
+            match &declarator.id {
+                Pattern::Identifier(identifier) if identifier.name != "let" => {}
+                _ => {
+                    return Err(ParserError::from_node(
+                        ParserErrorKind::InvalidUsingDeclarationBinding,
+                        &declarator.node,
+                    ))
+                }
+            }
+        }
+
+        if include_optional_semicolon {
+            Self::check_using_declaration_initializers(&binding_list)?;
+
+            self.expect_optional_semicolon_and_advance()?;
+        }
+
+        Ok(VariableDeclaration {
+            node: self.end_node(start_index)?,
+            declarations: binding_list,
+            kind,
+            declare: false,
+        })
+    }
+
+    // It is a Syntax Error if Initializer is not present.
+    // https://tc39.es/proposal-explicit-resource-management/#sec-let-and-const-declarations-static-semantics-early-errors
+    pub(crate) fn check_using_declaration_initializers(
+        declarations: &[VariableDeclarator],
+    ) -> Result<(), ParserError> {
+        if let Some(declarator) = declarations
+            .iter()
+            .find(|declarator| declarator.init.is_none())
+        {
+            return Err(ParserError::from_node(
+                ParserErrorKind::InvalidUsingDeclarationInitializer,
+                &declarator.node,
+            ));
+        }
+
+        Ok(())
+    }
+
     // https://tc39.es/ecma262/#prod-BindingList
     pub(crate) fn parse_binding_list(&mut self) -> Result<Vec<VariableDeclarator>, ParserError> {
         let mut declarations = vec![self.parse_binding_identifier_or_binding_pattern()?];
@@ -70,13 +166,14 @@ impl Parser {
         let binding_list = self.parse_binding_list()?;
 
         if include_optional_semicolon {
-            self.expect_optional_semicolon_and_advance();
+            self.expect_optional_semicolon_and_advance()?;
         }
 
         Ok(VariableDeclaration {
             node: self.end_node(start_index)?,
             declarations: binding_list,
             kind: VariableKind::Var,
+            declare: false,
         })
     }
 
@@ -88,7 +185,7 @@ impl Parser {
     ) -> Result<VariableDeclarator, ParserError> {
         let start_index = self.start_node();
 
-        let binding_identifier = match self.token_kind() {
+        let mut binding_identifier = match self.token_kind() {
             token_kind if token_kind.is_binding_identifier() => {
                 Pattern::Identifier(self.parse_binding_identifier()?)
             }
@@ -97,8 +194,20 @@ impl Parser {
             _ => return Err(self.unexpected_current_token_kind()),
         };
 
+        // `BindingIdentifier [no LineTerminator here] ! : Type` asserts that the variable is definitely assigned.
+        let definite = self.options.extensions.typescript
+            && matches!(binding_identifier, Pattern::Identifier(_))
+            && self.token_kind() == TokenKind::LogicalNot
+            && !self.has_previous_token_line_terminator();
+
+        if definite {
+            self.advance_any()?; // Eat '!' token.
+        }
+
+        self.parse_pattern_type_annotation(start_index, &mut binding_identifier)?;
+
         let initializer = if self.token_kind() == TokenKind::Assignment {
-            self.advance_any(); // Eat '=' token.
+            self.advance_any()?; // Eat '=' token.
 
             let assignment_expression = self.with_params(
                 self.params.clone().add_allow_in(false),
@@ -114,11 +223,14 @@ impl Parser {
             node: self.end_node(start_index)?,
             id: binding_identifier,
             init: initializer,
+            definite,
         })
     }
 
     // https://tc39.es/ecma262/#prod-ObjectBindingPattern
     pub(crate) fn parse_object_binding_pattern(&mut self) -> Result<ObjectPattern, ParserError> {
+        self.expect_feature(Feature::Destructuring)?;
+
         let start_index = self.start_node();
 
         self.expect_and_advance(TokenKind::LeftCurlyBrace)?; // Eat '{' token.
@@ -134,11 +246,14 @@ impl Parser {
         Ok(ObjectPattern {
             node: self.end_node(start_index)?,
             properties,
+            type_annotation: None,
         })
     }
 
     // https://tc39.es/ecma262/#prod-ArrayBindingPattern
     pub(crate) fn parse_array_binding_pattern(&mut self) -> Result<ArrayPattern, ParserError> {
+        self.expect_feature(Feature::Destructuring)?;
+
         let start_index = self.start_node();
 
         self.expect_and_advance(TokenKind::LeftSquareBracket)?; // Eat '[' token.
@@ -154,11 +269,14 @@ impl Parser {
         Ok(ArrayPattern {
             node: self.end_node(start_index)?,
             elements,
+            type_annotation: None,
         })
     }
 
     // https://tc39.es/ecma262/#prod-BindingRestProperty
     fn parse_binding_rest_property(&mut self) -> Result<ObjectPatternProperty, ParserError> {
+        self.expect_feature(Feature::ObjectRestSpread)?;
+
         let start_index = self.start_node();
 
         self.expect_and_advance(TokenKind::Ellipsis)?; // Eat '...' token.
@@ -168,6 +286,7 @@ impl Parser {
         Ok(ObjectPatternProperty::Rest(RestElement {
             node: self.end_node(start_index)?,
             argument: Box::new(Pattern::Identifier(identifier)),
+            type_annotation: None,
         }))
     }
 
@@ -183,7 +302,7 @@ impl Parser {
             properties.push(self.parse_binding_property()?);
 
             if self.token_kind() == TokenKind::Comma {
-                self.advance_any(); // Eat ',' token.
+                self.advance_any()?; // Eat ',' token.
             } else {
                 break;
             }
@@ -204,7 +323,7 @@ impl Parser {
             }
 
             if self.token_kind() == TokenKind::Comma {
-                self.advance_any(); // Eat ellision token.
+                self.advance_any()?; // Eat ellision token.
 
                 elements.push(None);
 
@@ -214,7 +333,7 @@ impl Parser {
             elements.push(Some(self.parse_binding_element()?));
 
             if self.token_kind() == TokenKind::Comma {
-                self.advance_any(); // Eat ',' token.
+                self.advance_any()?; // Eat ',' token.
             } else {
                 break;
             }
@@ -256,7 +375,7 @@ impl Parser {
                 }))
             }
             TokenKind::Assignment => {
-                self.advance_any(); // Eat '=' token.
+                self.advance_any()?; // Eat '=' token.
 
                 let assignment_expression = self.with_params(
                     self.params.clone().add_allow_in(false),
@@ -309,7 +428,7 @@ impl Parser {
         };
 
         if self.token_kind() == TokenKind::Assignment {
-            self.advance_any(); // Eat '=' token.
+            self.advance_any()?; // Eat '=' token.
         } else {
             return Ok(left_hand_idenfitier_or_binding_pattern);
         }
@@ -348,6 +467,7 @@ impl Parser {
         Ok(ArrayPatternElement::RestElement(RestElement {
             node: self.end_node(start_index)?,
             argument: Box::new(binding_identifier_or_pattern),
+            type_annotation: None,
         }))
     }
 }
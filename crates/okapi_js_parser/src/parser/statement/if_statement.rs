@@ -3,7 +3,7 @@ use crate::{KeywordKind, Parser, ParserError, TokenKind};
 
 // 14 ECMAScript Language: Statements and Declarations
 // https://tc39.es/ecma262/#prod-Statement
-impl Parser {
+impl Parser<'_> {
     // 14.6 The if Statement
     // https://tc39.es/ecma262/#prod-IfStatement
     pub(crate) fn parse_if_statement(&mut self) -> Result<IfStatement, ParserError> {
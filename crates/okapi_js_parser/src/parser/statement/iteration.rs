@@ -1,5 +1,6 @@
 use crate::ast::*;
-use crate::{Cursor, KeywordKind, Parser, ParserError, TokenKind};
+use crate::parser::CursorCheckpoint;
+use crate::{Feature, KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind};
 pub(crate) enum ForStatementKind {
     Classic(ForStatement),
     In(ForInStatement),
@@ -8,7 +9,7 @@ pub(crate) enum ForStatementKind {
 
 // 14 ECMAScript Language: Statements and Declarations
 // https://tc39.es/ecma262/#prod-Statement
-impl Parser {
+impl<'a> Parser<'a> {
     // 14.7 Iteration Statements
     // https://tc39.es/ecma262/#sec-iteration-statements
     // 14.7.4 The for Statement
@@ -26,18 +27,28 @@ impl Parser {
         if self.params.has_allow_await()
             && self.token_kind() == TokenKind::Keyword(KeywordKind::Await)
         {
+            self.expect_feature(Feature::AsyncIteration)?;
+            self.expect_await_feature()?;
+
             is_async = true;
 
-            self.advance_any();
+            self.advance_any()?;
         }
 
         self.expect_and_advance(TokenKind::LeftParenthesis)?;
 
-        let previous_cursor = self.cursor.clone();
+        let previous_cursor = self.cursor.checkpoint();
 
         // `for ( LexicalDeclaration Expression`
         let is_head_lexical_declaration = self.token_kind().is_lexical_declaration_start();
 
+        // `for ( using ForBinding` or `for ( await using ForBinding`.
+        // `for ( using of` starts a `for-of` statement over an identifier named `using`, unless it is followed by another `of`.
+        let is_head_using_declaration = self.is_using_declaration_start()
+            && !(self.token_kind() == TokenKind::Keyword(KeywordKind::Using)
+                && self.peek_token_kind() == TokenKind::Keyword(KeywordKind::Of)
+                && self.peek_nth_kind(2) != TokenKind::Keyword(KeywordKind::Of));
+
         // Early check to see if we potentially have to reparse these as a `AssignmentPattern`.
         // `for ( {} in|of`
         // `for ( [] in|of`
@@ -45,7 +56,7 @@ impl Parser {
 
         // `for ( ;`
         if self.token_kind() == TokenKind::Semicolon {
-            self.advance_any(); // Eat `;` token.
+            self.advance_any()?; // Eat `;` token.
 
             return Ok(ForStatementKind::Classic(
                 self.parse_classic_for_statement(start_index, None)?,
@@ -70,6 +81,28 @@ impl Parser {
 
                 ForStatementInit::VariableDeclaration(lexical_declaration)
             }
+            _ if is_head_using_declaration => {
+                let using_declaration = self.parse_using_declaration(false)?;
+
+                match self.token_kind() {
+                    // It is a Syntax Error if the head of a `for-in` statement is a using declaration.
+                    // https://tc39.es/proposal-explicit-resource-management/#sec-for-in-and-for-of-statements-static-semantics-early-errors
+                    TokenKind::Keyword(KeywordKind::In) => {
+                        return Err(ParserError::from_node(
+                            ParserErrorKind::InvalidUsingDeclarationPosition,
+                            &using_declaration.node,
+                        ));
+                    }
+                    TokenKind::Semicolon => {
+                        Self::check_using_declaration_initializers(
+                            &using_declaration.declarations,
+                        )?;
+                    }
+                    _ => {}
+                }
+
+                ForStatementInit::VariableDeclaration(using_declaration)
+            }
             _ => {
                 if is_async {
                     // `for await ( LeftHandSideExpression`
@@ -102,7 +135,7 @@ impl Parser {
                     is_async,
                 ),
             TokenKind::Semicolon => {
-                self.advance_any(); // Eat `;` token.
+                self.advance_any()?; // Eat `;` token.
 
                 Ok(ForStatementKind::Classic(
                     self.parse_classic_for_statement(
@@ -170,7 +203,7 @@ impl Parser {
         start_index: usize,
         left_expression_init: ForStatementInit,
         is_left_assignment_pattern: bool,
-        previous_cursor: Cursor,
+        previous_cursor: CursorCheckpoint<'a>,
         is_async: bool,
     ) -> Result<ForStatementKind, ParserError> {
         let left_expression = match left_expression_init {
@@ -179,7 +212,7 @@ impl Parser {
             _ if is_left_assignment_pattern => {
                 // If LeftHandSideExpression is either an ObjectLiteral or an ArrayLiteral, LeftHandSideExpression must cover an AssignmentPattern.
                 // https://tc39.es/ecma262/#sec-for-in-and-for-of-statements-static-semantics-early-errors
-                self.cursor = previous_cursor;
+                self.cursor.restore(previous_cursor);
 
                 let assignment_pattern = self.parse_assignment_pattern()?;
 
@@ -193,12 +226,14 @@ impl Parser {
         let is_for_in = self.token_kind() == TokenKind::Keyword(KeywordKind::In);
 
         if is_for_in && is_async {
-            return Err(ParserError::InvalidAwaitForInStatement);
+            return Err(self.current_token_error(ParserErrorKind::InvalidAwaitForInStatement));
         }
 
         if is_for_in {
             self.expect_and_advance(TokenKind::Keyword(KeywordKind::In))?;
         } else {
+            self.expect_feature(Feature::ForOf)?;
+
             self.expect_and_advance(TokenKind::Keyword(KeywordKind::Of))?;
         };
 
@@ -260,7 +295,7 @@ impl Parser {
 
         self.expect_and_advance(TokenKind::RightParenthesis)?;
 
-        self.expect_optional_semicolon_and_advance();
+        self.expect_optional_semicolon_and_advance()?;
 
         Ok(DoWhileStatement {
             node: self.end_node(start_index)?,
@@ -3,7 +3,7 @@ use crate::{Parser, ParserError};
 
 // 14 ECMAScript Language: Statements and Declarations
 // https://tc39.es/ecma262/#prod-Statement
-impl Parser {
+impl Parser<'_> {
     // 14.13 Labelled Statements
     // https://tc39.es/ecma262/#prod-LabelledStatement
     pub(crate) fn parse_labeled_statement(&mut self) -> Result<LabeledStatement, ParserError> {
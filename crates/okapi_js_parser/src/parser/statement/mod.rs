@@ -20,7 +20,7 @@ use self::iteration::ForStatementKind;
 
 // 14 ECMAScript Language: Statements and Declarations
 // https://tc39.es/ecma262/#prod-Statement
-impl Parser {
+impl Parser<'_> {
     // https://tc39.es/ecma262/#prod-Statement
     pub(crate) fn parse_statement(&mut self) -> Result<Statement, ParserError> {
         match self.token_kind() {
@@ -171,7 +171,7 @@ impl Parser {
                 Self::parse_expression,
             )?;
 
-            self.expect_optional_semicolon_and_advance();
+            self.expect_optional_semicolon_and_advance()?;
 
             Ok(Statement::Expression(ExpressionStatement {
                 node: self.end_node(start_index)?,
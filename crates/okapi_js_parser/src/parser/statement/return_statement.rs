@@ -1,14 +1,20 @@
 use crate::ast::*;
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind};
 
 // 14 ECMAScript Language: Statements and Declarations
 // https://tc39.es/ecma262/#prod-Statement
-impl Parser {
+impl Parser<'_> {
     // 14.10 The return Statement
     // https://tc39.es/ecma262/#prod-ReturnStatement
     pub(crate) fn parse_return_statement(&mut self) -> Result<ReturnStatement, ParserError> {
         let start_index = self.start_node();
 
+        // It is a Syntax Error if a return statement is not within a function body.
+        // https://tc39.es/ecma262/#prod-ReturnStatement
+        if !self.params.has_allow_return() {
+            return Err(self.current_token_error(ParserErrorKind::InvalidReturnStatement));
+        }
+
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Return))?;
 
         let argument = if self.token_kind() == TokenKind::Semicolon {
@@ -22,7 +28,7 @@ impl Parser {
             Some(expression)
         };
 
-        self.expect_optional_semicolon_and_advance();
+        self.expect_optional_semicolon_and_advance()?;
 
         Ok(ReturnStatement {
             node: self.end_node(start_index)?,
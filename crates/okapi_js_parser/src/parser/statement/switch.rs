@@ -1,9 +1,9 @@
 use crate::ast::*;
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind};
 
 // 14 ECMAScript Language: Statements and Declarations
 // https://tc39.es/ecma262/#prod-Statement
-impl Parser {
+impl Parser<'_> {
     // 14.14 The switch Statement
     // https://tc39.es/ecma262/#prod-SwitchStatement
     pub(crate) fn parse_switch_statement(&mut self) -> Result<SwitchStatement, ParserError> {
@@ -51,7 +51,7 @@ impl Parser {
 
         let case_clause_expression = match self.token_kind() {
             TokenKind::Keyword(KeywordKind::Case) => {
-                self.advance_any(); // Eat 'case' token.
+                self.advance_any()?; // Eat 'case' token.
 
                 let expression = self.with_params(
                     self.params.clone().add_allow_in(false),
@@ -61,7 +61,7 @@ impl Parser {
                 Some(expression)
             }
             TokenKind::Keyword(KeywordKind::Default) => {
-                self.advance_any(); // Eat 'default' token.
+                self.advance_any()?; // Eat 'default' token.
 
                 None
             }
@@ -76,6 +76,14 @@ impl Parser {
             && self.token_kind() != TokenKind::Keyword(KeywordKind::Default)
             && self.token_kind() != TokenKind::RightCurlyBrace
         {
+            // It is a Syntax Error if a using declaration is directly contained in a case or default clause.
+            // https://tc39.es/proposal-explicit-resource-management/#sec-switch-statement-static-semantics-early-errors
+            if self.is_using_declaration_start() {
+                return Err(
+                    self.current_token_error(ParserErrorKind::InvalidUsingDeclarationPosition)
+                );
+            }
+
             statement_list.push(self.parse_statement()?);
         }
 
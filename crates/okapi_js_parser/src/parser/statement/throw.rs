@@ -1,9 +1,9 @@
 use crate::ast::*;
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind};
 
 // 14 ECMAScript Language: Statements and Declarations
 // https://tc39.es/ecma262/#prod-Statement
-impl Parser {
+impl Parser<'_> {
     // 14.11 The throw Statement
     // https://tc39.es/ecma262/#prod-ThrowStatement
     pub(crate) fn parse_throw_statement(&mut self) -> Result<ThrowStatement, ParserError> {
@@ -12,7 +12,7 @@ impl Parser {
         self.expect_and_advance(TokenKind::Keyword(KeywordKind::Throw))?;
 
         if self.has_previous_token_line_terminator() {
-            return Err(ParserError::UnexpectedLineTerminator);
+            return Err(self.current_token_error(ParserErrorKind::UnexpectedLineTerminator));
         }
 
         let expression = self.with_params(
@@ -20,7 +20,7 @@ impl Parser {
             Self::parse_expression,
         )?;
 
-        self.expect_optional_semicolon_and_advance();
+        self.expect_optional_semicolon_and_advance()?;
 
         Ok(ThrowStatement {
             node: self.end_node(start_index)?,
@@ -1,9 +1,9 @@
 use crate::ast::*;
-use crate::{KeywordKind, Parser, ParserError, TokenKind};
+use crate::{Feature, KeywordKind, Parser, ParserError, TokenKind};
 
 // 14 ECMAScript Language: Statements and Declarations
 // https://tc39.es/ecma262/#prod-Statement
-impl Parser {
+impl Parser<'_> {
     // 14.12 The try Statement
     // https://tc39.es/ecma262/#prod-TryStatement
     pub(crate) fn parse_try_statement(&mut self) -> Result<TryStatement, ParserError> {
@@ -41,7 +41,7 @@ impl Parser {
 
         let optional_binding_identifier_or_pattern = match self.token_kind() {
             TokenKind::LeftParenthesis => {
-                self.advance_any(); // Eat '(' token.
+                self.advance_any()?; // Eat '(' token.
 
                 let binding_idenfitier_or_pattern =
                     if self.token_kind() != TokenKind::RightParenthesis {
@@ -65,7 +65,11 @@ impl Parser {
 
                 binding_idenfitier_or_pattern
             }
-            _ => None,
+            _ => {
+                self.expect_feature(Feature::OptionalCatchBinding)?;
+
+                None
+            }
         };
 
         let body = self.parse_block_statement()?;
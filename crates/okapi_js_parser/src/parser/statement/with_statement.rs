@@ -3,7 +3,7 @@ use crate::{KeywordKind, Parser, ParserError, TokenKind};
 
 // 14 ECMAScript Language: Statements and Declarations
 // https://tc39.es/ecma262/#prod-Statement
-impl Parser {
+impl Parser<'_> {
     // 14.11 The with Statement
     // https://tc39.es/ecma262/#prod-WithStatement
     pub(crate) fn parse_with_statement(&mut self) -> Result<WithStatement, ParserError> {
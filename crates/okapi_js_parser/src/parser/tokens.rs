@@ -0,0 +1,89 @@
+use crate::{
+    ast::{Program, ProgramToken, ProgramTokenKind, Regex},
+    KeywordKind, Parser, Token, TokenKind, TokenValue,
+};
+
+impl Parser<'_> {
+    pub(crate) fn add_tokens(&mut self, program: &mut Program) {
+        let Some(tokens) = self.cursor.tokens.take() else {
+            return;
+        };
+
+        program.tokens = Some(
+            tokens
+                .iter()
+                .enumerate()
+                .map(|(index, token)| {
+                    let mut kind = program_token_kind(&token.kind);
+
+                    // Espree treats a string attribute value as JSX text, as it can't contain escape sequences.
+                    if kind == ProgramTokenKind::String
+                        && index >= 2
+                        && tokens[index - 1].kind == TokenKind::Assignment
+                        && tokens[index - 2].kind == TokenKind::JSXIdentifier
+                    {
+                        kind = ProgramTokenKind::JSXText;
+                    }
+
+                    self.create_program_token(token, kind)
+                })
+                .collect(),
+        );
+    }
+
+    fn create_program_token(&self, token: &Token, kind: ProgramTokenKind) -> ProgramToken {
+        let source = self.cursor.lexer.source_slice(token.start..token.end);
+
+        let value = match (&kind, &token.value) {
+            (
+                ProgramTokenKind::Identifier
+                | ProgramTokenKind::Keyword
+                | ProgramTokenKind::PrivateIdentifier
+                | ProgramTokenKind::JSXIdentifier,
+                TokenValue::String { value, .. },
+            ) => value.to_string(),
+            _ => source.to_string(),
+        };
+
+        let regex = match &token.value {
+            TokenValue::RegularExpression { pattern, flags } => Some(Regex {
+                pattern: pattern.to_string(),
+                flags: flags.to_string(),
+            }),
+            _ => None,
+        };
+
+        ProgramToken {
+            kind,
+            value,
+            regex,
+            node: self.create_node(token.start, token.end),
+        }
+    }
+}
+
+// Espree only treats reserved words as keywords, along with `let`, `static` and `yield`. Every other contextual
+// keyword, such as `async` or `of`, is an identifier.
+fn program_token_kind(kind: &TokenKind) -> ProgramTokenKind {
+    match kind {
+        TokenKind::Keyword(KeywordKind::True | KeywordKind::False) => ProgramTokenKind::Boolean,
+        TokenKind::Keyword(KeywordKind::Null) => ProgramTokenKind::Null,
+        TokenKind::Keyword(KeywordKind::Let | KeywordKind::Static | KeywordKind::Yield) => {
+            ProgramTokenKind::Keyword
+        }
+        TokenKind::Keyword(KeywordKind::Await | KeywordKind::Enum) => ProgramTokenKind::Identifier,
+        TokenKind::Keyword(_) if kind.is_reserved_keyword() => ProgramTokenKind::Keyword,
+        TokenKind::Keyword(_) | TokenKind::Identifier => ProgramTokenKind::Identifier,
+        TokenKind::PrivateIdentifier => ProgramTokenKind::PrivateIdentifier,
+        TokenKind::StringLiteral => ProgramTokenKind::String,
+        TokenKind::NumberLiteral | TokenKind::BigIntLiteral => ProgramTokenKind::Numeric,
+        TokenKind::RegularExpressionLiteral => ProgramTokenKind::RegularExpression,
+        TokenKind::TemplateNoSubstitution
+        | TokenKind::TemplateHead
+        | TokenKind::TemplateMiddle
+        | TokenKind::TemplateTail => ProgramTokenKind::Template,
+        TokenKind::JSXIdentifier => ProgramTokenKind::JSXIdentifier,
+        TokenKind::JSXText => ProgramTokenKind::JSXText,
+        _ => ProgramTokenKind::Punctuator,
+    }
+}
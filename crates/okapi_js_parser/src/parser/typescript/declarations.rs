@@ -0,0 +1,512 @@
+use crate::ast::*;
+use crate::{KeywordKind, Parser, ParserError, TokenKind};
+
+// TypeScript Declarations
+// https://github.com/microsoft/TypeScript/blob/main/doc/spec-ARCHIVED.md#7-interfaces
+impl Parser<'_> {
+    // Most TypeScript declarations start with a contextual keyword, which is only a keyword when it is followed by a
+    // name on the same line.
+    pub(crate) fn is_typescript_declaration_start(&mut self) -> bool {
+        if !self.options.extensions.typescript {
+            return false;
+        }
+
+        let peek_token_kind = self.peek_token_kind();
+
+        match self.token_kind() {
+            TokenKind::Keyword(KeywordKind::Interface) | TokenKind::Keyword(KeywordKind::Enum) => {
+                peek_token_kind.is_binding_identifier()
+            }
+            TokenKind::Keyword(KeywordKind::Const) => {
+                peek_token_kind == TokenKind::Keyword(KeywordKind::Enum)
+            }
+            TokenKind::Keyword(KeywordKind::Type) | TokenKind::Keyword(KeywordKind::Namespace) => {
+                !self.has_current_token_line_terminator() && peek_token_kind.is_binding_identifier()
+            }
+            TokenKind::Keyword(KeywordKind::Module) => {
+                !self.has_current_token_line_terminator()
+                    && (peek_token_kind.is_binding_identifier()
+                        || peek_token_kind == TokenKind::StringLiteral)
+            }
+            TokenKind::Keyword(KeywordKind::Abstract) => {
+                !self.has_current_token_line_terminator()
+                    && peek_token_kind == TokenKind::Keyword(KeywordKind::Class)
+            }
+            TokenKind::Keyword(KeywordKind::Declare) => {
+                !self.has_current_token_line_terminator()
+                    && matches!(
+                        peek_token_kind,
+                        TokenKind::Keyword(
+                            KeywordKind::Var
+                                | KeywordKind::Let
+                                | KeywordKind::Const
+                                | KeywordKind::Function
+                                | KeywordKind::Async
+                                | KeywordKind::Class
+                                | KeywordKind::Abstract
+                                | KeywordKind::Enum
+                                | KeywordKind::Interface
+                                | KeywordKind::Type
+                                | KeywordKind::Namespace
+                                | KeywordKind::Module
+                                | KeywordKind::Global
+                        )
+                    )
+            }
+            TokenKind::Keyword(KeywordKind::Import) => self.is_import_equals_declaration_start(),
+            TokenKind::Keyword(KeywordKind::Function) => self.is_function_overload_start(),
+            TokenKind::Keyword(KeywordKind::Async)
+                if peek_token_kind == TokenKind::Keyword(KeywordKind::Function)
+                    && !self.has_current_token_line_terminator() =>
+            {
+                self.is_function_overload_start()
+            }
+            _ => false,
+        }
+    }
+
+    // `import Identifier =` or `import type Identifier =`, rather than an import declaration.
+    fn is_import_equals_declaration_start(&mut self) -> bool {
+        match self.peek_token_kind() {
+            TokenKind::Keyword(KeywordKind::Type)
+                if self.peek_nth_kind(2).is_binding_identifier() =>
+            {
+                self.peek_nth_kind(3) == TokenKind::Assignment
+            }
+            token_kind if token_kind.is_binding_identifier() => {
+                self.peek_nth_kind(2) == TokenKind::Assignment
+            }
+            _ => false,
+        }
+    }
+
+    // A function declaration without a body is an overload signature.
+    fn is_function_overload_start(&mut self) -> bool {
+        let previous_cursor = self.cursor.checkpoint();
+        let previous_context = self.context.clone();
+
+        let start_index = self.start_node();
+
+        let is_function_overload = self.parse_declare_function(start_index).is_ok();
+
+        self.cursor.restore(previous_cursor);
+        self.context = previous_context;
+
+        is_function_overload
+    }
+
+    pub(crate) fn parse_typescript_declaration(&mut self) -> Result<Declaration, ParserError> {
+        let start_index = self.start_node();
+
+        let declare = self.token_kind() == TokenKind::Keyword(KeywordKind::Declare);
+
+        if declare {
+            self.advance_any()?; // Eat 'declare' token.
+        }
+
+        let mut declaration = match (self.token_kind(), self.peek_token_kind()) {
+            (TokenKind::Keyword(KeywordKind::Interface), _) => {
+                Declaration::TSInterface(self.parse_interface_declaration(start_index)?)
+            }
+            (TokenKind::Keyword(KeywordKind::Type), _) => {
+                Declaration::TSTypeAlias(self.parse_type_alias_declaration(start_index)?)
+            }
+            (TokenKind::Keyword(KeywordKind::Enum), _) => {
+                Declaration::TSEnum(self.parse_enum_declaration(start_index, false)?)
+            }
+            (TokenKind::Keyword(KeywordKind::Const), TokenKind::Keyword(KeywordKind::Enum)) => {
+                self.advance_any()?; // Eat 'const' token.
+
+                Declaration::TSEnum(self.parse_enum_declaration(start_index, true)?)
+            }
+            (
+                TokenKind::Keyword(
+                    KeywordKind::Namespace | KeywordKind::Module | KeywordKind::Global,
+                ),
+                _,
+            ) => Declaration::TSModule(self.parse_module_declaration(start_index)?),
+            (TokenKind::Keyword(KeywordKind::Abstract), TokenKind::Keyword(KeywordKind::Class)) => {
+                self.advance_any()?; // Eat 'abstract' token.
+
+                let mut class_declaration = self.parse_class_declaration()?;
+
+                class_declaration.node = self.end_node(start_index)?;
+                class_declaration.is_abstract = true;
+
+                Declaration::Class(class_declaration)
+            }
+            (TokenKind::Keyword(KeywordKind::Class), _) => {
+                Declaration::Class(self.parse_class_declaration()?)
+            }
+            (TokenKind::Keyword(KeywordKind::Import), _) => {
+                Declaration::TSImportEquals(self.parse_import_equals_declaration(start_index)?)
+            }
+            (TokenKind::Keyword(KeywordKind::Function | KeywordKind::Async), _) => {
+                Declaration::TSDeclareFunction(self.parse_declare_function(start_index)?)
+            }
+            (TokenKind::Keyword(KeywordKind::Var), _) => {
+                Declaration::Variable(self.parse_variable_statement(true)?)
+            }
+            (TokenKind::Keyword(KeywordKind::Let | KeywordKind::Const), _) => {
+                Declaration::Variable(self.parse_lexical_declaration(true)?)
+            }
+            _ => return Err(self.unexpected_current_token_kind()),
+        };
+
+        if declare {
+            self.add_declare_modifier(&mut declaration, start_index)?;
+        }
+
+        Ok(declaration)
+    }
+
+    // The `declare` modifier is part of the declaration node.
+    fn add_declare_modifier(
+        &mut self,
+        declaration: &mut Declaration,
+        start_index: usize,
+    ) -> Result<(), ParserError> {
+        let node = self.end_node(start_index)?;
+
+        match declaration {
+            Declaration::Class(class_declaration) => {
+                class_declaration.node = node;
+                class_declaration.declare = true;
+            }
+            Declaration::Variable(variable_declaration) => {
+                variable_declaration.node = node;
+                variable_declaration.declare = true;
+            }
+            Declaration::TSDeclareFunction(declare_function) => {
+                declare_function.node = node;
+                declare_function.declare = true;
+            }
+            Declaration::TSEnum(enum_declaration) => enum_declaration.declare = true,
+            Declaration::TSInterface(interface_declaration) => interface_declaration.declare = true,
+            Declaration::TSModule(module_declaration) => module_declaration.declare = true,
+            Declaration::TSTypeAlias(type_alias_declaration) => {
+                type_alias_declaration.declare = true
+            }
+            Declaration::Function(_) | Declaration::TSImportEquals(_) => {
+                return Err(self.unexpected_current_token_kind())
+            }
+        }
+
+        Ok(())
+    }
+
+    // `import type? BindingIdentifier = require ( StringLiteral ) ;` or `import type? BindingIdentifier = EntityName ;`
+    fn parse_import_equals_declaration(
+        &mut self,
+        start_index: usize,
+    ) -> Result<TSImportEqualsDeclaration, ParserError> {
+        self.expect_and_advance(TokenKind::Keyword(KeywordKind::Import))?;
+
+        let import_kind = if self.token_kind() == TokenKind::Keyword(KeywordKind::Type)
+            && self.peek_token_kind() != TokenKind::Assignment
+        {
+            self.advance_any()?; // Eat 'type' token.
+
+            TSImportExportKind::Type
+        } else {
+            TSImportExportKind::Value
+        };
+
+        let id = self.parse_binding_identifier()?;
+
+        self.expect_and_advance(TokenKind::Assignment)?;
+
+        let module_reference = if self.token_kind() == TokenKind::Identifier
+            && String::from(self.token_value()) == "require"
+            && self.peek_token_kind() == TokenKind::LeftParenthesis
+        {
+            let reference_start_index = self.start_node();
+
+            self.advance_any()?; // Eat 'require' token.
+
+            self.expect_and_advance(TokenKind::LeftParenthesis)?;
+
+            self.expect(TokenKind::StringLiteral)?;
+
+            let expression = self.parse_literal()?;
+
+            self.expect_and_advance(TokenKind::RightParenthesis)?;
+
+            TSModuleReference::External(TSExternalModuleReference {
+                node: self.end_node(reference_start_index)?,
+                expression,
+            })
+        } else {
+            TSModuleReference::EntityName(self.parse_entity_name()?)
+        };
+
+        self.expect_optional_semicolon_and_advance()?;
+
+        Ok(TSImportEqualsDeclaration {
+            node: self.end_node(start_index)?,
+            id,
+            module_reference,
+            import_kind,
+        })
+    }
+
+    // `async? function *? BindingIdentifier? TypeParameters? ( FormalParameters ) ReturnType? ;`, which is a function
+    // overload signature or a function declared with `declare`.
+    fn parse_declare_function(
+        &mut self,
+        start_index: usize,
+    ) -> Result<TSDeclareFunction, ParserError> {
+        let is_async = self.token_kind() == TokenKind::Keyword(KeywordKind::Async);
+
+        if is_async {
+            self.advance_any()?; // Eat 'async' token.
+        }
+
+        self.expect_and_advance(TokenKind::Keyword(KeywordKind::Function))?;
+
+        let is_generator = self.token_kind() == TokenKind::Multiplication;
+
+        if is_generator {
+            self.advance_any()?; // Eat '*' token.
+        }
+
+        let optional_identifier = if self.token_kind().is_binding_identifier() {
+            Some(self.parse_binding_identifier()?)
+        } else {
+            None
+        };
+
+        let (type_parameters, params, return_type) = self.parse_signature()?;
+
+        if self.token_kind() == TokenKind::LeftCurlyBrace {
+            return Err(self.unexpected_current_token_kind());
+        }
+
+        self.expect_optional_semicolon_and_advance()?;
+
+        Ok(TSDeclareFunction {
+            node: self.end_node(start_index)?,
+            id: optional_identifier,
+            expression: false,
+            generator: is_generator,
+            asynchronous: is_async,
+            type_parameters,
+            params,
+            return_type,
+            declare: false,
+        })
+    }
+
+    // `interface BindingIdentifier TypeParameters? InterfaceExtendsClause? ObjectType`
+    // https://github.com/microsoft/TypeScript/blob/main/doc/spec-ARCHIVED.md#7.1
+    fn parse_interface_declaration(
+        &mut self,
+        start_index: usize,
+    ) -> Result<TSInterfaceDeclaration, ParserError> {
+        self.expect_and_advance(TokenKind::Keyword(KeywordKind::Interface))?;
+
+        let id = self.parse_binding_identifier()?;
+
+        let type_parameters = self.parse_optional_type_parameters()?;
+
+        let mut extends = vec![];
+
+        if self.token_kind() == TokenKind::Keyword(KeywordKind::Extends) {
+            self.advance_any()?; // Eat 'extends' token.
+
+            loop {
+                let heritage_start_index = self.start_node();
+
+                let expression = self.parse_heritage_expression()?;
+
+                let type_arguments = self.parse_optional_heritage_type_arguments()?;
+
+                extends.push(TSInterfaceHeritage {
+                    node: self.end_node(heritage_start_index)?,
+                    expression,
+                    type_arguments,
+                });
+
+                if self.token_kind() == TokenKind::Comma {
+                    self.advance_any()?; // Eat ',' token.
+                } else {
+                    break;
+                }
+            }
+        }
+
+        let body_start_index = self.start_node();
+
+        let members = self.parse_type_members()?;
+
+        let body = TSInterfaceBody {
+            node: self.end_node(body_start_index)?,
+            body: members,
+        };
+
+        Ok(TSInterfaceDeclaration {
+            node: self.end_node(start_index)?,
+            id,
+            type_parameters,
+            extends,
+            body,
+            declare: false,
+        })
+    }
+
+    // `type BindingIdentifier TypeParameters? = Type ;`
+    // https://github.com/microsoft/TypeScript/blob/main/doc/spec-ARCHIVED.md#3.10
+    fn parse_type_alias_declaration(
+        &mut self,
+        start_index: usize,
+    ) -> Result<TSTypeAliasDeclaration, ParserError> {
+        self.expect_and_advance(TokenKind::Keyword(KeywordKind::Type))?;
+
+        let id = self.parse_binding_identifier()?;
+
+        let type_parameters = self.parse_optional_type_parameters()?;
+
+        self.expect_and_advance(TokenKind::Assignment)?;
+
+        let type_annotation = self.parse_type()?;
+
+        self.expect_optional_semicolon_and_advance()?;
+
+        Ok(TSTypeAliasDeclaration {
+            node: self.end_node(start_index)?,
+            id,
+            type_parameters,
+            type_annotation,
+            declare: false,
+        })
+    }
+
+    // `const? enum BindingIdentifier { EnumMember, EnumMember }`
+    // https://github.com/microsoft/TypeScript/blob/main/doc/spec-ARCHIVED.md#9.1
+    fn parse_enum_declaration(
+        &mut self,
+        start_index: usize,
+        is_const: bool,
+    ) -> Result<TSEnumDeclaration, ParserError> {
+        self.expect_and_advance(TokenKind::Keyword(KeywordKind::Enum))?;
+
+        let id = self.parse_binding_identifier()?;
+
+        self.expect_and_advance(TokenKind::LeftCurlyBrace)?;
+
+        let mut members = vec![];
+
+        while self.token_kind() != TokenKind::RightCurlyBrace {
+            members.push(self.parse_enum_member()?);
+
+            if self.token_kind() == TokenKind::Comma {
+                self.advance_any()?; // Eat ',' token.
+            } else {
+                break;
+            }
+        }
+
+        self.expect_and_advance(TokenKind::RightCurlyBrace)?;
+
+        Ok(TSEnumDeclaration {
+            node: self.end_node(start_index)?,
+            id,
+            members,
+            is_const,
+            declare: false,
+        })
+    }
+
+    fn parse_enum_member(&mut self) -> Result<TSEnumMember, ParserError> {
+        let start_index = self.start_node();
+
+        let id = match self.token_kind() {
+            TokenKind::StringLiteral => Expression::Literal(self.parse_literal()?),
+            token_kind if token_kind.is_identifier_name() => {
+                Expression::Identifier(self.parse_identifier_name()?)
+            }
+            _ => return Err(self.unexpected_current_token_kind()),
+        };
+
+        let initializer = if self.token_kind() == TokenKind::Assignment {
+            self.advance_any()?; // Eat '=' token.
+
+            Some(self.with_params(
+                self.params.clone().add_allow_in(true),
+                Self::parse_assignment_expression,
+            )?)
+        } else {
+            None
+        };
+
+        Ok(TSEnumMember {
+            node: self.end_node(start_index)?,
+            id,
+            initializer,
+        })
+    }
+
+    // `namespace IdentifierPath { ... }`, `module "name" { ... }` and `global { ... }`, where an ambient module
+    // declaration may not have a body.
+    // https://github.com/microsoft/TypeScript/blob/main/doc/spec-ARCHIVED.md#10.1
+    fn parse_module_declaration(
+        &mut self,
+        start_index: usize,
+    ) -> Result<TSModuleDeclaration, ParserError> {
+        let kind = match self.token_kind() {
+            TokenKind::Keyword(KeywordKind::Global) => TSModuleDeclarationKind::Global,
+            TokenKind::Keyword(KeywordKind::Module) => TSModuleDeclarationKind::Module,
+            _ => TSModuleDeclarationKind::Namespace,
+        };
+
+        // The `global` keyword is also the name of the declaration.
+        if kind != TSModuleDeclarationKind::Global {
+            self.advance_any()?; // Eat 'namespace' or 'module' token.
+        }
+
+        let id = match self.token_kind() {
+            TokenKind::StringLiteral if kind == TSModuleDeclarationKind::Module => {
+                TSModuleName::Literal(self.parse_literal()?)
+            }
+            TokenKind::Keyword(KeywordKind::Global) => {
+                TSModuleName::EntityName(TSEntityName::Identifier(self.parse_identifier_name()?))
+            }
+            _ => TSModuleName::EntityName(self.parse_entity_name()?),
+        };
+
+        let body = if self.token_kind() == TokenKind::LeftCurlyBrace {
+            Some(self.parse_module_block()?)
+        } else {
+            self.expect_optional_semicolon_and_advance()?;
+
+            None
+        };
+
+        Ok(TSModuleDeclaration {
+            node: self.end_node(start_index)?,
+            id,
+            body,
+            kind,
+            declare: false,
+        })
+    }
+
+    fn parse_module_block(&mut self) -> Result<TSModuleBlock, ParserError> {
+        let start_index = self.start_node();
+
+        self.expect_and_advance(TokenKind::LeftCurlyBrace)?;
+
+        let mut body = vec![];
+
+        while self.token_kind() != TokenKind::RightCurlyBrace {
+            body.push(self.parse_module_item()?);
+        }
+
+        self.expect_and_advance(TokenKind::RightCurlyBrace)?;
+
+        Ok(TSModuleBlock {
+            node: self.end_node(start_index)?,
+            body,
+        })
+    }
+}
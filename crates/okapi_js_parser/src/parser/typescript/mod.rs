@@ -0,0 +1,459 @@
+mod declarations;
+mod types;
+
+use crate::ast::*;
+use crate::{KeywordKind, Parser, ParserError, ParserErrorKind, TokenKind};
+
+// The modifiers which may appear before a TypeScript class element, in any order.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ClassElementModifiers {
+    pub(crate) accessibility: Option<TSAccessibility>,
+    pub(crate) is_abstract: bool,
+    pub(crate) is_override: bool,
+    pub(crate) is_static: bool,
+    pub(crate) readonly: bool,
+    pub(crate) declare: bool,
+}
+
+impl ClassElementModifiers {
+    fn is_empty(&self) -> bool {
+        self.accessibility.is_none()
+            && !self.is_abstract
+            && !self.is_override
+            && !self.readonly
+            && !self.declare
+    }
+}
+
+// TypeScript
+// https://github.com/microsoft/TypeScript/blob/main/doc/spec-ARCHIVED.md
+impl Parser<'_> {
+    // A modifier keyword is only a modifier if it is followed by something which can follow a modifier, as otherwise it
+    // is the name of a parameter or class element, e.g. `readonly: boolean`.
+    fn is_modifier_followed_by_name(&mut self) -> bool {
+        !self.has_current_token_line_terminator()
+            && (self.peek_token_kind().is_class_element_name()
+                || matches!(
+                    self.peek_token_kind(),
+                    TokenKind::Multiplication | TokenKind::Keyword(KeywordKind::This)
+                ))
+    }
+
+    // `public`, `private`, `protected`, `readonly` and `override` before a constructor parameter.
+    // https://github.com/microsoft/TypeScript/blob/main/doc/spec-ARCHIVED.md#8.3.1
+    pub(crate) fn parse_typescript_formal_parameter(
+        &mut self,
+    ) -> Result<FunctionParameter, ParserError> {
+        let start_index = self.start_node();
+
+        let mut accessibility = None;
+        let mut readonly = false;
+        let mut is_override = false;
+
+        loop {
+            let token_kind = self.token_kind();
+
+            if !matches!(
+                token_kind,
+                TokenKind::Keyword(
+                    KeywordKind::Public
+                        | KeywordKind::Private
+                        | KeywordKind::Protected
+                        | KeywordKind::Readonly
+                        | KeywordKind::Override
+                )
+            ) || !self.is_modifier_followed_by_name()
+            {
+                break;
+            }
+
+            match token_kind {
+                TokenKind::Keyword(KeywordKind::Readonly) => readonly = true,
+                TokenKind::Keyword(KeywordKind::Override) => is_override = true,
+                _ => accessibility = Self::match_token_kind_to_accessibility(&token_kind),
+            }
+
+            self.advance_any()?; // Eat modifier token.
+        }
+
+        let binding_start_index = self.start_node();
+
+        let mut binding = match self.token_kind() {
+            // The `this` parameter declares the type of `this` within the function.
+            TokenKind::Keyword(KeywordKind::This) => {
+                self.advance_any()?; // Eat 'this' token.
+
+                Pattern::Identifier(Identifier {
+                    node: self.end_node(binding_start_index)?,
+                    name: "this".to_string(),
+                    type_annotation: None,
+                    optional: false,
+                })
+            }
+            token_kind if token_kind.is_binding_identifier() => {
+                Pattern::Identifier(self.parse_binding_identifier()?)
+            }
+            TokenKind::LeftSquareBracket => Pattern::Array(self.parse_array_binding_pattern()?),
+            TokenKind::LeftCurlyBrace => Pattern::Object(self.parse_object_binding_pattern()?),
+            _ => return Err(self.unexpected_current_token_kind()),
+        };
+
+        if self.token_kind() == TokenKind::QuestionMark {
+            let Pattern::Identifier(identifier) = &mut binding else {
+                return Err(self.unexpected_current_token_kind());
+            };
+
+            self.advance_any()?; // Eat '?' token.
+
+            identifier.optional = true;
+            identifier.node = self.end_node(binding_start_index)?;
+        }
+
+        self.parse_pattern_type_annotation(binding_start_index, &mut binding)?;
+
+        let parameter = if self.token_kind() == TokenKind::Assignment {
+            self.advance_any()?; // Eat '=' token.
+
+            let assignment_expression = self.with_params(
+                self.params.clone().add_allow_in(false),
+                Self::parse_assignment_expression,
+            )?;
+
+            FunctionParameter::Assignment(AssignmentPattern {
+                node: self.end_node(binding_start_index)?,
+                left: Box::new(binding),
+                right: assignment_expression,
+            })
+        } else {
+            FunctionParameter::try_from(ArrayPatternElement::try_from(binding)?)?
+        };
+
+        if accessibility.is_none() && !readonly && !is_override {
+            return Ok(parameter);
+        }
+
+        // A parameter property can not be declared using a binding pattern.
+        let parameter = match parameter {
+            FunctionParameter::Identifier(identifier) => {
+                TSParameterPropertyParameter::Identifier(identifier)
+            }
+            FunctionParameter::Assignment(assignment_pattern)
+                if matches!(*assignment_pattern.left, Pattern::Identifier(_)) =>
+            {
+                TSParameterPropertyParameter::Assignment(assignment_pattern)
+            }
+            _ => {
+                return Err(self.error(
+                    ParserErrorKind::InvalidParameterPropertyBinding,
+                    binding_start_index,
+                    self.cursor.previous_token.end,
+                ))
+            }
+        };
+
+        Ok(FunctionParameter::TSParameterProperty(
+            TSParameterProperty {
+                node: self.end_node(start_index)?,
+                accessibility,
+                readonly,
+                is_override,
+                is_static: false,
+                parameter,
+                decorators: vec![],
+            },
+        ))
+    }
+
+    fn match_token_kind_to_accessibility(token_kind: &TokenKind) -> Option<TSAccessibility> {
+        match token_kind {
+            TokenKind::Keyword(KeywordKind::Public) => Some(TSAccessibility::Public),
+            TokenKind::Keyword(KeywordKind::Private) => Some(TSAccessibility::Private),
+            TokenKind::Keyword(KeywordKind::Protected) => Some(TSAccessibility::Protected),
+            _ => None,
+        }
+    }
+
+    // The type annotation of a binding identifier or pattern is part of its node.
+    pub(crate) fn parse_pattern_type_annotation(
+        &mut self,
+        start_index: usize,
+        pattern: &mut Pattern,
+    ) -> Result<(), ParserError> {
+        let Some(type_annotation) = self.parse_optional_type_annotation()? else {
+            return Ok(());
+        };
+
+        let type_annotation = Some(Box::new(type_annotation));
+
+        match pattern {
+            Pattern::Identifier(identifier) => {
+                identifier.node = self.end_node(start_index)?;
+                identifier.type_annotation = type_annotation;
+            }
+            Pattern::Array(array_pattern) => {
+                array_pattern.node = self.end_node(start_index)?;
+                array_pattern.type_annotation = type_annotation;
+            }
+            Pattern::Object(object_pattern) => {
+                object_pattern.node = self.end_node(start_index)?;
+                object_pattern.type_annotation = type_annotation;
+            }
+            _ => return Err(self.unexpected_current_token_kind()),
+        }
+
+        Ok(())
+    }
+
+    // Arrow function parameters may have type annotations and be followed by a return type, so whether a `(` or `<`
+    // starts an arrow function is found by parsing ahead as far as the `=>` token.
+    pub(crate) fn is_typescript_arrow_function_start(&mut self, allow_return_type: bool) -> bool {
+        let previous_cursor = self.cursor.checkpoint();
+        let previous_context = self.context.clone();
+
+        // An optional 'async' token is eaten before the signature.
+        let is_arrow_function = (self.token_kind() != TokenKind::Keyword(KeywordKind::Async)
+            || self.advance_any().is_ok())
+            && self.parse_optional_type_parameters().is_ok()
+            && self.parse_parenthesized_formal_parameters().is_ok()
+            && (!allow_return_type || self.parse_optional_return_type().is_ok())
+            && self.token_kind() == TokenKind::ArrowFunction
+            && !self.has_previous_token_line_terminator();
+
+        self.cursor.restore(previous_cursor);
+        self.context = previous_context;
+
+        is_arrow_function
+    }
+
+    // `implements TypeReference, TypeReference`
+    pub(crate) fn parse_optional_class_implements(
+        &mut self,
+    ) -> Result<Vec<TSClassImplements>, ParserError> {
+        let mut implements = vec![];
+
+        if !self.options.extensions.typescript
+            || self.token_kind() != TokenKind::Keyword(KeywordKind::Implements)
+        {
+            return Ok(implements);
+        }
+
+        self.advance_any()?; // Eat 'implements' token.
+
+        loop {
+            let start_index = self.start_node();
+
+            let expression = self.parse_heritage_expression()?;
+
+            let type_arguments = self.parse_optional_heritage_type_arguments()?;
+
+            implements.push(TSClassImplements {
+                node: self.end_node(start_index)?,
+                expression,
+                type_arguments,
+            });
+
+            if self.token_kind() == TokenKind::Comma {
+                self.advance_any()?; // Eat ',' token.
+            } else {
+                break;
+            }
+        }
+
+        Ok(implements)
+    }
+
+    // An identifier, or a member expression of identifiers, which names the type of an `implements` or interface
+    // `extends` clause.
+    pub(crate) fn parse_heritage_expression(&mut self) -> Result<Expression, ParserError> {
+        let start_index = self.start_node();
+
+        let mut expression = Expression::Identifier(self.parse_identifier_reference()?);
+
+        while self.token_kind() == TokenKind::Dot {
+            self.advance_any()?; // Eat '.' token.
+
+            let property = self.parse_identifier_name()?;
+
+            expression = Expression::Member(MemberExpression {
+                node: self.end_node(start_index)?,
+                object: Box::new(expression),
+                property: MemberExpressionProperty::Expression(Box::new(Expression::Identifier(
+                    property,
+                ))),
+                computed: false,
+                optional: false,
+            });
+        }
+
+        Ok(expression)
+    }
+
+    pub(crate) fn parse_optional_heritage_type_arguments(
+        &mut self,
+    ) -> Result<Option<TSTypeParameterInstantiation>, ParserError> {
+        if !self.options.extensions.typescript || self.token_kind() != TokenKind::LessThan {
+            return Ok(None);
+        }
+
+        Ok(Some(self.parse_type_arguments()?))
+    }
+
+    // Modifiers are only parsed when TypeScript is enabled, where `static` is also parsed as a modifier so that it can
+    // appear in any order with the others.
+    pub(crate) fn parse_class_element_modifiers(
+        &mut self,
+    ) -> Result<ClassElementModifiers, ParserError> {
+        let mut modifiers = ClassElementModifiers::default();
+
+        if !self.options.extensions.typescript {
+            return Ok(modifiers);
+        }
+
+        loop {
+            let token_kind = self.token_kind();
+
+            let is_modifier = match token_kind {
+                TokenKind::Keyword(
+                    KeywordKind::Public
+                    | KeywordKind::Private
+                    | KeywordKind::Protected
+                    | KeywordKind::Abstract
+                    | KeywordKind::Override
+                    | KeywordKind::Readonly
+                    | KeywordKind::Declare,
+                ) => self.is_modifier_followed_by_name(),
+                // `static {` is a static block.
+                TokenKind::Keyword(KeywordKind::Static) => {
+                    self.peek_token_kind().is_class_element_name()
+                        || self.peek_token_kind() == TokenKind::Multiplication
+                }
+                _ => false,
+            };
+
+            if !is_modifier {
+                break;
+            }
+
+            let is_duplicate = match token_kind {
+                TokenKind::Keyword(KeywordKind::Abstract) => {
+                    std::mem::replace(&mut modifiers.is_abstract, true)
+                }
+                TokenKind::Keyword(KeywordKind::Override) => {
+                    std::mem::replace(&mut modifiers.is_override, true)
+                }
+                TokenKind::Keyword(KeywordKind::Readonly) => {
+                    std::mem::replace(&mut modifiers.readonly, true)
+                }
+                TokenKind::Keyword(KeywordKind::Declare) => {
+                    std::mem::replace(&mut modifiers.declare, true)
+                }
+                TokenKind::Keyword(KeywordKind::Static) => {
+                    std::mem::replace(&mut modifiers.is_static, true)
+                }
+                _ => modifiers
+                    .accessibility
+                    .replace(Self::match_token_kind_to_accessibility(&token_kind).unwrap())
+                    .is_some(),
+            };
+
+            if is_duplicate {
+                return Err(self.current_token_error(ParserErrorKind::InvalidTypeScriptModifier));
+            }
+
+            self.advance_any()?; // Eat modifier token.
+        }
+
+        Ok(modifiers)
+    }
+
+    // Add the modifiers to a class element, where an `abstract` method or field is a different node.
+    pub(crate) fn apply_class_element_modifiers(
+        class_element: ClassBodyBody,
+        modifiers: ClassElementModifiers,
+    ) -> Result<ClassBodyBody, ParserError> {
+        if modifiers.is_empty() {
+            return Ok(class_element);
+        }
+
+        match class_element {
+            ClassBodyBody::MethodDefinition(method_definition)
+                if !modifiers.readonly && !modifiers.declare =>
+            {
+                if !modifiers.is_abstract {
+                    return Ok(ClassBodyBody::MethodDefinition(MethodDefinition {
+                        accessibility: modifiers.accessibility,
+                        is_override: modifiers.is_override,
+                        ..method_definition
+                    }));
+                }
+
+                // An abstract method can not have an implementation.
+                if matches!(
+                    method_definition.value,
+                    Some(MethodDefinitionValue::FunctionExpression(_))
+                ) {
+                    return Err(ParserError::from_node(
+                        ParserErrorKind::InvalidTypeScriptModifier,
+                        &method_definition.node,
+                    ));
+                }
+
+                Ok(ClassBodyBody::TSAbstractMethodDefinition(
+                    TSAbstractMethodDefinition {
+                        node: method_definition.node,
+                        is_static: method_definition.is_static,
+                        computed: method_definition.computed,
+                        key: method_definition.key,
+                        kind: method_definition.kind,
+                        value: method_definition.value,
+                        accessibility: modifiers.accessibility,
+                        optional: method_definition.optional,
+                        is_override: modifiers.is_override,
+                        decorators: method_definition.decorators,
+                    },
+                ))
+            }
+            ClassBodyBody::PropertyDefinition(property_definition) => {
+                if !modifiers.is_abstract {
+                    return Ok(ClassBodyBody::PropertyDefinition(PropertyDefinition {
+                        accessibility: modifiers.accessibility,
+                        readonly: modifiers.readonly,
+                        declare: modifiers.declare,
+                        is_override: modifiers.is_override,
+                        ..property_definition
+                    }));
+                }
+
+                // An abstract field can not have an initializer.
+                if property_definition.value.is_some() || modifiers.declare {
+                    return Err(ParserError::from_node(
+                        ParserErrorKind::InvalidTypeScriptModifier,
+                        &property_definition.node,
+                    ));
+                }
+
+                Ok(ClassBodyBody::TSAbstractPropertyDefinition(
+                    TSAbstractPropertyDefinition {
+                        node: property_definition.node,
+                        is_static: property_definition.is_static,
+                        computed: property_definition.computed,
+                        key: property_definition.key,
+                        value: None,
+                        type_annotation: property_definition
+                            .type_annotation
+                            .map(|type_annotation| *type_annotation),
+                        accessibility: modifiers.accessibility,
+                        readonly: modifiers.readonly,
+                        optional: property_definition.optional,
+                        is_override: modifiers.is_override,
+                        decorators: property_definition.decorators,
+                    },
+                ))
+            }
+            class_element => Err(ParserError::from_node(
+                ParserErrorKind::InvalidTypeScriptModifier,
+                class_element.node(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,1251 @@
+use std::borrow::Cow;
+
+use crate::ast::*;
+use crate::{KeywordKind, Parser, ParserError, TokenKind, TokenValue};
+
+// The type parameters, parameters and return type shared by function types and signatures.
+pub(crate) type Signature = (
+    Option<TSTypeParameterDeclaration>,
+    Vec<FunctionParameter>,
+    Option<TSTypeAnnotation>,
+);
+
+// TypeScript Types
+// https://github.com/microsoft/TypeScript/blob/main/doc/spec-ARCHIVED.md#3-types
+impl Parser<'_> {
+    // https://github.com/microsoft/TypeScript/blob/main/doc/spec-ARCHIVED.md#3.8
+    pub(crate) fn parse_type(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        let check_type = self.parse_non_conditional_type()?;
+
+        // `Type [no LineTerminator here] extends Type ? Type : Type`
+        if self.token_kind() != TokenKind::Keyword(KeywordKind::Extends)
+            || self.has_previous_token_line_terminator()
+        {
+            return Ok(check_type);
+        }
+
+        self.advance_any()?; // Eat 'extends' token.
+
+        let extends_type = self.parse_non_conditional_type()?;
+
+        self.expect_and_advance(TokenKind::QuestionMark)?;
+
+        let true_type = self.parse_type()?;
+
+        self.expect_and_advance(TokenKind::Colon)?;
+
+        let false_type = self.parse_type()?;
+
+        Ok(TSType::Conditional(Box::new(TSConditionalType {
+            node: self.end_node(start_index)?,
+            check_type,
+            extends_type,
+            true_type,
+            false_type,
+        })))
+    }
+
+    fn parse_non_conditional_type(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        match self.token_kind() {
+            TokenKind::Keyword(KeywordKind::New) => {
+                self.advance_any()?; // Eat 'new' token.
+
+                self.parse_constructor_type(start_index, false)
+            }
+            TokenKind::Keyword(KeywordKind::Abstract)
+                if self.peek_token_kind() == TokenKind::Keyword(KeywordKind::New) =>
+            {
+                self.advance_any()?; // Eat 'abstract' token.
+                self.advance_any()?; // Eat 'new' token.
+
+                self.parse_constructor_type(start_index, true)
+            }
+            _ if self.is_function_type_start() => self.parse_function_type(start_index),
+            _ => self.parse_union_type(),
+        }
+    }
+
+    // A `(` starts a function type, rather than a parenthesized type, if the matching `)` is followed by `=>`.
+    fn is_function_type_start(&mut self) -> bool {
+        match self.token_kind() {
+            TokenKind::LessThan => true,
+            TokenKind::LeftParenthesis => {
+                let previous_cursor = self.cursor.checkpoint();
+
+                let mut parentheses_depth = 0;
+
+                while !matches!(self.token_kind(), TokenKind::Illegal | TokenKind::EOF) {
+                    match self.token_kind() {
+                        TokenKind::LeftParenthesis => parentheses_depth += 1,
+                        TokenKind::RightParenthesis => parentheses_depth -= 1,
+                        _ => {}
+                    }
+
+                    if self.advance_any().is_err() {
+                        break;
+                    }
+
+                    if parentheses_depth == 0 {
+                        break;
+                    }
+                }
+
+                let is_function_type = self.token_kind() == TokenKind::ArrowFunction;
+
+                self.cursor.restore(previous_cursor);
+
+                is_function_type
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_function_type(&mut self, start_index: usize) -> Result<TSType, ParserError> {
+        let type_parameters = self.parse_optional_type_parameters()?;
+
+        let params = self.parse_parenthesized_formal_parameters()?;
+
+        let return_type = self.parse_function_type_return_type()?;
+
+        Ok(TSType::Function(Box::new(TSFunctionType {
+            node: self.end_node(start_index)?,
+            type_parameters,
+            params,
+            return_type,
+        })))
+    }
+
+    fn parse_constructor_type(
+        &mut self,
+        start_index: usize,
+        is_abstract: bool,
+    ) -> Result<TSType, ParserError> {
+        let type_parameters = self.parse_optional_type_parameters()?;
+
+        let params = self.parse_parenthesized_formal_parameters()?;
+
+        let return_type = self.parse_function_type_return_type()?;
+
+        Ok(TSType::Constructor(Box::new(TSConstructorType {
+            node: self.end_node(start_index)?,
+            is_abstract,
+            type_parameters,
+            params,
+            return_type,
+        })))
+    }
+
+    // The return type of a function type starts at the `=>` token, rather than a `:` token.
+    fn parse_function_type_return_type(&mut self) -> Result<TSTypeAnnotation, ParserError> {
+        let start_index = self.start_node();
+
+        self.expect_and_advance(TokenKind::ArrowFunction)?;
+
+        let type_annotation = self.parse_type_or_type_predicate()?;
+
+        Ok(TSTypeAnnotation {
+            node: self.end_node(start_index)?,
+            type_annotation,
+        })
+    }
+
+    // A leading `|` is allowed before the first member of a union type.
+    fn parse_union_type(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        let has_leading_operator = self.token_kind() == TokenKind::BitwiseOr;
+
+        if has_leading_operator {
+            self.advance_any()?; // Eat '|' token.
+        }
+
+        let mut types = vec![self.parse_intersection_type()?];
+
+        while self.token_kind() == TokenKind::BitwiseOr {
+            self.advance_any()?; // Eat '|' token.
+
+            types.push(self.parse_intersection_type()?);
+        }
+
+        if types.len() == 1 && !has_leading_operator {
+            return Ok(types.remove(0));
+        }
+
+        Ok(TSType::Union(TSUnionType {
+            node: self.end_node(start_index)?,
+            types,
+        }))
+    }
+
+    // A leading `&` is allowed before the first member of an intersection type.
+    fn parse_intersection_type(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        let has_leading_operator = self.token_kind() == TokenKind::BitwiseAnd;
+
+        if has_leading_operator {
+            self.advance_any()?; // Eat '&' token.
+        }
+
+        let mut types = vec![self.parse_type_operator()?];
+
+        while self.token_kind() == TokenKind::BitwiseAnd {
+            self.advance_any()?; // Eat '&' token.
+
+            types.push(self.parse_type_operator()?);
+        }
+
+        if types.len() == 1 && !has_leading_operator {
+            return Ok(types.remove(0));
+        }
+
+        Ok(TSType::Intersection(TSIntersectionType {
+            node: self.end_node(start_index)?,
+            types,
+        }))
+    }
+
+    fn parse_type_operator(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        let operator = match self.token_kind() {
+            TokenKind::Keyword(KeywordKind::Keyof) => TSTypeOperatorKind::Keyof,
+            TokenKind::Keyword(KeywordKind::Readonly) => TSTypeOperatorKind::Readonly,
+            TokenKind::Keyword(KeywordKind::Unique) => TSTypeOperatorKind::Unique,
+            TokenKind::Keyword(KeywordKind::Infer) => return self.parse_infer_type(),
+            _ => return self.parse_postfix_type(),
+        };
+
+        self.advance_any()?; // Eat type operator token.
+
+        let type_annotation = self.parse_type_operator()?;
+
+        Ok(TSType::TypeOperator(Box::new(TSTypeOperator {
+            node: self.end_node(start_index)?,
+            operator,
+            type_annotation,
+        })))
+    }
+
+    // `infer Identifier` and `infer Identifier extends Type`, where a constraint followed by `?` is instead the
+    // `extends` clause of an enclosing conditional type.
+    fn parse_infer_type(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        self.expect_and_advance(TokenKind::Keyword(KeywordKind::Infer))?;
+
+        let type_parameter_start_index = self.start_node();
+
+        let name = self.parse_binding_identifier()?;
+
+        let mut constraint = None;
+
+        if self.token_kind() == TokenKind::Keyword(KeywordKind::Extends) {
+            let previous_cursor = self.cursor.checkpoint();
+
+            self.advance_any()?; // Eat 'extends' token.
+
+            let constraint_type = self.parse_non_conditional_type()?;
+
+            if self.token_kind() == TokenKind::QuestionMark {
+                self.cursor.restore(previous_cursor);
+            } else {
+                constraint = Some(constraint_type);
+            }
+        }
+
+        let type_parameter = TSTypeParameter {
+            node: self.end_node(type_parameter_start_index)?,
+            name,
+            constraint,
+            default: None,
+            is_in: false,
+            is_out: false,
+            is_const: false,
+        };
+
+        Ok(TSType::Infer(Box::new(TSInferType {
+            node: self.end_node(start_index)?,
+            type_parameter,
+        })))
+    }
+
+    // Array types `Type[]` and indexed access types `Type[Type]`.
+    fn parse_postfix_type(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        let mut postfix_type = self.parse_primary_type()?;
+
+        while self.token_kind() == TokenKind::LeftSquareBracket
+            && !self.has_previous_token_line_terminator()
+        {
+            self.advance_any()?; // Eat '[' token.
+
+            if self.token_kind() == TokenKind::RightSquareBracket {
+                self.advance_any()?; // Eat ']' token.
+
+                postfix_type = TSType::Array(Box::new(TSArrayType {
+                    node: self.end_node(start_index)?,
+                    element_type: postfix_type,
+                }));
+            } else {
+                let index_type = self.parse_type()?;
+
+                self.expect_and_advance(TokenKind::RightSquareBracket)?;
+
+                postfix_type = TSType::IndexedAccess(Box::new(TSIndexedAccessType {
+                    node: self.end_node(start_index)?,
+                    object_type: postfix_type,
+                    index_type,
+                }));
+            }
+        }
+
+        Ok(postfix_type)
+    }
+
+    fn parse_primary_type(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        match self.token_kind() {
+            TokenKind::Identifier if self.peek_token_kind() != TokenKind::Dot => {
+                match TSKeywordKind::from_identifier(&String::from(self.token_value())) {
+                    Some(kind) => self.parse_keyword_type(kind),
+                    None => self.parse_type_reference(),
+                }
+            }
+            TokenKind::Keyword(KeywordKind::Void) => self.parse_keyword_type(TSKeywordKind::Void),
+            TokenKind::Keyword(KeywordKind::Null) => self.parse_keyword_type(TSKeywordKind::Null),
+            TokenKind::Keyword(KeywordKind::This) => {
+                self.advance_any()?; // Eat 'this' token.
+
+                Ok(TSType::This(TSThisType {
+                    node: self.end_node(start_index)?,
+                }))
+            }
+            TokenKind::Keyword(KeywordKind::Typeof) => self.parse_type_query(),
+            TokenKind::StringLiteral
+            | TokenKind::NumberLiteral
+            | TokenKind::Keyword(KeywordKind::True)
+            | TokenKind::Keyword(KeywordKind::False) => {
+                let literal = Expression::Literal(self.parse_literal()?);
+
+                self.create_literal_type(start_index, literal)
+            }
+            TokenKind::BigIntLiteral => {
+                let literal = Expression::BigIntLiteral(self.parse_big_int_literal()?);
+
+                self.create_literal_type(start_index, literal)
+            }
+            TokenKind::TemplateNoSubstitution => {
+                let literal = Expression::TemplateLiteral(self.parse_template_literal()?);
+
+                self.create_literal_type(start_index, literal)
+            }
+            TokenKind::TemplateHead => self.parse_template_literal_type(),
+            TokenKind::Keyword(KeywordKind::Import) => {
+                Ok(TSType::Import(Box::new(self.parse_import_type()?)))
+            }
+            // Negative number and bigint literal types, such as `-1`.
+            TokenKind::Subtraction
+                if matches!(
+                    self.peek_token_kind(),
+                    TokenKind::NumberLiteral | TokenKind::BigIntLiteral
+                ) =>
+            {
+                self.advance_any()?; // Eat '-' token.
+
+                let argument = match self.token_kind() {
+                    TokenKind::NumberLiteral => Expression::Literal(self.parse_literal()?),
+                    _ => Expression::BigIntLiteral(self.parse_big_int_literal()?),
+                };
+
+                let literal = Expression::Unary(UnaryExpression {
+                    node: self.end_node(start_index)?,
+                    operator: UnaryOperator::Minus,
+                    prefix: true,
+                    argument: Box::new(argument),
+                });
+
+                self.create_literal_type(start_index, literal)
+            }
+            TokenKind::LeftCurlyBrace if self.is_mapped_type_start() => self.parse_mapped_type(),
+            TokenKind::LeftCurlyBrace => {
+                let members = self.parse_type_members()?;
+
+                Ok(TSType::TypeLiteral(TSTypeLiteral {
+                    node: self.end_node(start_index)?,
+                    members,
+                }))
+            }
+            TokenKind::LeftSquareBracket => self.parse_tuple_type(),
+            // Parenthesized types are not represented in the tree.
+            TokenKind::LeftParenthesis => {
+                self.advance_any()?; // Eat '(' token.
+
+                let parenthesized_type = self.parse_type()?;
+
+                self.expect_and_advance(TokenKind::RightParenthesis)?;
+
+                Ok(parenthesized_type)
+            }
+            token_kind if token_kind.is_binding_identifier() => self.parse_type_reference(),
+            _ => Err(self.unexpected_current_token_kind()),
+        }
+    }
+
+    // The type of an `as` or `<Type>` assertion, which can also be `const`, e.g. `[1, 2] as const`. typescript-estree
+    // represents `const` as a reference to a type named `const`.
+    #[inline(never)]
+    pub(crate) fn parse_assertion_type(&mut self) -> Result<TSType, ParserError> {
+        if self.token_kind() != TokenKind::Keyword(KeywordKind::Const) {
+            return self.parse_type();
+        }
+
+        let start_index = self.start_node();
+
+        let type_name = TSEntityName::Identifier(self.parse_identifier_name()?);
+
+        Ok(TSType::TypeReference(TSTypeReference {
+            node: self.end_node(start_index)?,
+            type_name,
+            type_arguments: None,
+        }))
+    }
+
+    fn parse_keyword_type(&mut self, kind: TSKeywordKind) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        self.advance_any()?; // Eat keyword type token.
+
+        Ok(TSType::Keyword(TSKeywordType {
+            kind,
+            node: self.end_node(start_index)?,
+        }))
+    }
+
+    fn create_literal_type(
+        &mut self,
+        start_index: usize,
+        literal: Expression,
+    ) -> Result<TSType, ParserError> {
+        Ok(TSType::Literal(Box::new(TSLiteralType {
+            node: self.end_node(start_index)?,
+            literal,
+        })))
+    }
+
+    fn parse_type_reference(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        let type_name = self.parse_entity_name()?;
+
+        let type_arguments = self.parse_optional_type_arguments()?;
+
+        Ok(TSType::TypeReference(TSTypeReference {
+            node: self.end_node(start_index)?,
+            type_name,
+            type_arguments,
+        }))
+    }
+
+    // `typeof EntityName TypeArguments?` or `typeof ImportType`
+    fn parse_type_query(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        self.expect_and_advance(TokenKind::Keyword(KeywordKind::Typeof))?;
+
+        // The type arguments of an import type belong to the import type, e.g. `typeof import("x").A<B>`.
+        if self.token_kind() == TokenKind::Keyword(KeywordKind::Import) {
+            let import_type = self.parse_import_type()?;
+
+            return Ok(TSType::TypeQuery(TSTypeQuery {
+                node: self.end_node(start_index)?,
+                expr_name: TSTypeQueryExprName::Import(Box::new(import_type)),
+                type_arguments: None,
+            }));
+        }
+
+        let expr_name = TSTypeQueryExprName::EntityName(self.parse_entity_name()?);
+
+        let type_arguments = self.parse_optional_type_arguments()?;
+
+        Ok(TSType::TypeQuery(TSTypeQuery {
+            node: self.end_node(start_index)?,
+            expr_name,
+            type_arguments,
+        }))
+    }
+
+    // `import ( StringLiteral ) (. EntityName)? TypeArguments?`
+    fn parse_import_type(&mut self) -> Result<TSImportType, ParserError> {
+        let start_index = self.start_node();
+
+        self.expect_and_advance(TokenKind::Keyword(KeywordKind::Import))?;
+
+        self.expect_and_advance(TokenKind::LeftParenthesis)?;
+
+        let argument_start_index = self.start_node();
+
+        self.expect(TokenKind::StringLiteral)?;
+
+        let literal = Expression::Literal(self.parse_literal()?);
+
+        let argument = self.create_literal_type(argument_start_index, literal)?;
+
+        self.expect_and_advance(TokenKind::RightParenthesis)?;
+
+        let qualifier = if self.token_kind() == TokenKind::Dot {
+            self.advance_any()?; // Eat '.' token.
+
+            Some(self.parse_entity_name()?)
+        } else {
+            None
+        };
+
+        let type_arguments = self.parse_optional_type_arguments()?;
+
+        Ok(TSImportType {
+            node: self.end_node(start_index)?,
+            argument,
+            qualifier,
+            type_arguments,
+        })
+    }
+
+    // `` `head${Type}middle${Type}tail` ``, where each substitution is a type rather than an expression.
+    fn parse_template_literal_type(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        let mut quasis = vec![];
+        let mut types = vec![];
+
+        while matches!(
+            self.token_kind(),
+            TokenKind::TemplateHead | TokenKind::TemplateMiddle
+        ) {
+            quasis.push(self.parse_template_element(false, 1, 2)?);
+
+            types.push(self.parse_type()?);
+        }
+
+        self.expect(TokenKind::TemplateTail)?;
+
+        quasis.push(self.parse_template_element(true, 1, 1)?);
+
+        Ok(TSType::TemplateLiteral(TSTemplateLiteralType {
+            node: self.end_node(start_index)?,
+            quasis,
+            types,
+        }))
+    }
+
+    // An identifier or `this`, optionally followed by `.` separated identifier names.
+    pub(crate) fn parse_entity_name(&mut self) -> Result<TSEntityName, ParserError> {
+        let start_index = self.start_node();
+
+        let mut entity_name = if self.token_kind() == TokenKind::Keyword(KeywordKind::This) {
+            self.advance_any()?; // Eat 'this' token.
+
+            TSEntityName::This(TSThisType {
+                node: self.end_node(start_index)?,
+            })
+        } else if self.token_kind().is_binding_identifier() {
+            TSEntityName::Identifier(self.parse_identifier_name()?)
+        } else {
+            return Err(self.unexpected_current_token_kind());
+        };
+
+        while self.token_kind() == TokenKind::Dot {
+            self.advance_any()?; // Eat '.' token.
+
+            let right = self.parse_identifier_name()?;
+
+            entity_name = TSEntityName::QualifiedName(Box::new(TSQualifiedName {
+                node: self.end_node(start_index)?,
+                left: entity_name,
+                right,
+            }));
+        }
+
+        Ok(entity_name)
+    }
+
+    // `{ readonly [K in Type as Type]?: Type }`, where the modifiers may also be prefixed with `+` or `-`.
+    fn is_mapped_type_start(&mut self) -> bool {
+        match self.peek_token_kind() {
+            TokenKind::LeftSquareBracket => {
+                self.peek_nth_kind(2).is_binding_identifier()
+                    && self.peek_nth_kind(3) == TokenKind::Keyword(KeywordKind::In)
+            }
+            TokenKind::Keyword(KeywordKind::Readonly) => {
+                self.peek_nth_kind(2) == TokenKind::LeftSquareBracket
+            }
+            TokenKind::Addition | TokenKind::Subtraction => {
+                self.peek_nth_kind(2) == TokenKind::Keyword(KeywordKind::Readonly)
+            }
+            _ => false,
+        }
+    }
+
+    fn parse_mapped_type(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        self.expect_and_advance(TokenKind::LeftCurlyBrace)?;
+
+        let readonly =
+            self.parse_optional_mapped_type_modifier(TokenKind::Keyword(KeywordKind::Readonly))?;
+
+        self.expect_and_advance(TokenKind::LeftSquareBracket)?;
+
+        let type_parameter_start_index = self.start_node();
+
+        let name = self.parse_binding_identifier()?;
+
+        self.expect_and_advance(TokenKind::Keyword(KeywordKind::In))?;
+
+        let constraint = self.parse_type()?;
+
+        let type_parameter = TSTypeParameter {
+            node: self.end_node(type_parameter_start_index)?,
+            name,
+            constraint: Some(constraint),
+            default: None,
+            is_in: false,
+            is_out: false,
+            is_const: false,
+        };
+
+        let name_type = if self.token_kind() == TokenKind::Keyword(KeywordKind::As) {
+            self.advance_any()?; // Eat 'as' token.
+
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+
+        self.expect_and_advance(TokenKind::RightSquareBracket)?;
+
+        let optional = self.parse_optional_mapped_type_modifier(TokenKind::QuestionMark)?;
+
+        let type_annotation = if self.token_kind() == TokenKind::Colon {
+            self.advance_any()?; // Eat ':' token.
+
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+
+        if matches!(self.token_kind(), TokenKind::Semicolon | TokenKind::Comma) {
+            self.advance_any()?; // Eat ';' or ',' token.
+        }
+
+        self.expect_and_advance(TokenKind::RightCurlyBrace)?;
+
+        Ok(TSType::Mapped(Box::new(TSMappedType {
+            node: self.end_node(start_index)?,
+            type_parameter,
+            name_type,
+            optional,
+            readonly,
+            type_annotation,
+        })))
+    }
+
+    fn parse_optional_mapped_type_modifier(
+        &mut self,
+        modifier_token_kind: TokenKind,
+    ) -> Result<Option<TSMappedTypeModifier>, ParserError> {
+        let modifier = match self.token_kind() {
+            TokenKind::Addition => TSMappedTypeModifier::Plus,
+            TokenKind::Subtraction => TSMappedTypeModifier::Minus,
+            token_kind if token_kind == modifier_token_kind => {
+                self.advance_any()?; // Eat modifier token.
+
+                return Ok(Some(TSMappedTypeModifier::Present));
+            }
+            _ => return Ok(None),
+        };
+
+        self.advance_any()?; // Eat '+' or '-' token.
+
+        self.expect_and_advance(modifier_token_kind)?;
+
+        Ok(Some(modifier))
+    }
+
+    // `[Type, Label: Type, Type?, ...Type]`
+    fn parse_tuple_type(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        self.expect_and_advance(TokenKind::LeftSquareBracket)?;
+
+        let mut element_types = vec![];
+
+        while self.token_kind() != TokenKind::RightSquareBracket {
+            element_types.push(self.parse_tuple_element_type()?);
+
+            if self.token_kind() == TokenKind::Comma {
+                self.advance_any()?; // Eat ',' token.
+            } else {
+                break;
+            }
+        }
+
+        self.expect_and_advance(TokenKind::RightSquareBracket)?;
+
+        Ok(TSType::Tuple(TSTupleType {
+            node: self.end_node(start_index)?,
+            element_types,
+        }))
+    }
+
+    fn parse_tuple_element_type(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        if self.token_kind() == TokenKind::Ellipsis {
+            self.advance_any()?; // Eat '...' token.
+
+            let type_annotation = self.parse_tuple_element_type()?;
+
+            return Ok(TSType::Rest(Box::new(TSRestType {
+                node: self.end_node(start_index)?,
+                type_annotation,
+            })));
+        }
+
+        let is_named_member = self.token_kind().is_identifier_name()
+            && match self.peek_token_kind() {
+                TokenKind::Colon => true,
+                TokenKind::QuestionMark => self.peek_nth_kind(2) == TokenKind::Colon,
+                _ => false,
+            };
+
+        if is_named_member {
+            let label = self.parse_identifier_name()?;
+
+            let optional = self.token_kind() == TokenKind::QuestionMark;
+
+            if optional {
+                self.advance_any()?; // Eat '?' token.
+            }
+
+            self.expect_and_advance(TokenKind::Colon)?;
+
+            let element_type = self.parse_type()?;
+
+            return Ok(TSType::NamedTupleMember(Box::new(TSNamedTupleMember {
+                node: self.end_node(start_index)?,
+                label,
+                element_type,
+                optional,
+            })));
+        }
+
+        let element_type = self.parse_type()?;
+
+        if self.token_kind() != TokenKind::QuestionMark {
+            return Ok(element_type);
+        }
+
+        self.advance_any()?; // Eat '?' token.
+
+        Ok(TSType::Optional(Box::new(TSOptionalType {
+            node: self.end_node(start_index)?,
+            type_annotation: element_type,
+        })))
+    }
+
+    // `{ TypeMember ; TypeMember ; }`, where members are separated by `;`, `,` or a line terminator.
+    pub(crate) fn parse_type_members(&mut self) -> Result<Vec<TSTypeElement>, ParserError> {
+        self.expect_and_advance(TokenKind::LeftCurlyBrace)?;
+
+        let mut members = vec![];
+
+        while self.token_kind() != TokenKind::RightCurlyBrace {
+            members.push(self.parse_type_member()?);
+        }
+
+        self.expect_and_advance(TokenKind::RightCurlyBrace)?;
+
+        Ok(members)
+    }
+
+    fn parse_type_member(&mut self) -> Result<TSTypeElement, ParserError> {
+        let start_index = self.start_node();
+
+        match (self.token_kind(), self.peek_token_kind()) {
+            (TokenKind::LeftParenthesis | TokenKind::LessThan, _) => {
+                let (type_parameters, params, return_type) = self.parse_signature()?;
+
+                self.parse_type_member_separator()?;
+
+                return Ok(TSTypeElement::CallSignature(TSCallSignatureDeclaration {
+                    node: self.end_node(start_index)?,
+                    type_parameters,
+                    params,
+                    return_type,
+                }));
+            }
+            (
+                TokenKind::Keyword(KeywordKind::New),
+                TokenKind::LeftParenthesis | TokenKind::LessThan,
+            ) => {
+                self.advance_any()?; // Eat 'new' token.
+
+                let (type_parameters, params, return_type) = self.parse_signature()?;
+
+                self.parse_type_member_separator()?;
+
+                return Ok(TSTypeElement::ConstructSignature(
+                    TSConstructSignatureDeclaration {
+                        node: self.end_node(start_index)?,
+                        type_parameters,
+                        params,
+                        return_type,
+                    },
+                ));
+            }
+            _ => {}
+        }
+
+        let readonly = self.token_kind() == TokenKind::Keyword(KeywordKind::Readonly)
+            && self.is_type_member_modifier();
+
+        if readonly {
+            self.advance_any()?; // Eat 'readonly' token.
+        }
+
+        if self.is_index_signature_start() {
+            return Ok(TSTypeElement::IndexSignature(self.parse_index_signature(
+                start_index,
+                readonly,
+                false,
+                None,
+            )?));
+        }
+
+        let kind = match self.token_kind() {
+            TokenKind::Keyword(KeywordKind::Get) if self.is_type_member_modifier() => {
+                TSMethodSignatureKind::Get
+            }
+            TokenKind::Keyword(KeywordKind::Set) if self.is_type_member_modifier() => {
+                TSMethodSignatureKind::Set
+            }
+            _ => TSMethodSignatureKind::Method,
+        };
+
+        if kind != TSMethodSignatureKind::Method {
+            self.advance_any()?; // Eat 'get' or 'set' token.
+        }
+
+        let computed = self.token_kind() == TokenKind::LeftSquareBracket;
+
+        let key = self.parse_property_name()?;
+
+        let optional = self.token_kind() == TokenKind::QuestionMark;
+
+        if optional {
+            self.advance_any()?; // Eat '?' token.
+        }
+
+        if matches!(
+            self.token_kind(),
+            TokenKind::LeftParenthesis | TokenKind::LessThan
+        ) || kind != TSMethodSignatureKind::Method
+        {
+            let (type_parameters, params, return_type) = self.parse_signature()?;
+
+            self.parse_type_member_separator()?;
+
+            return Ok(TSTypeElement::MethodSignature(TSMethodSignature {
+                node: self.end_node(start_index)?,
+                computed,
+                key,
+                kind,
+                optional,
+                type_parameters,
+                params,
+                return_type,
+            }));
+        }
+
+        let type_annotation = self.parse_optional_type_annotation()?;
+
+        self.parse_type_member_separator()?;
+
+        Ok(TSTypeElement::PropertySignature(TSPropertySignature {
+            node: self.end_node(start_index)?,
+            computed,
+            key,
+            optional,
+            readonly,
+            type_annotation,
+        }))
+    }
+
+    // A modifier keyword is a property name when it is followed by anything which is not itself a property name.
+    fn is_type_member_modifier(&mut self) -> bool {
+        !self.has_current_token_line_terminator() && self.peek_token_kind().is_property_name()
+    }
+
+    fn parse_type_member_separator(&mut self) -> Result<(), ParserError> {
+        match self.token_kind() {
+            TokenKind::Semicolon | TokenKind::Comma => {
+                self.advance_any()?; // Eat ';' or ',' token.
+
+                Ok(())
+            }
+            TokenKind::RightCurlyBrace => Ok(()),
+            _ if self.has_previous_token_line_terminator() => Ok(()),
+            _ => Err(self.unexpected_current_token_kind()),
+        }
+    }
+
+    // `TypeParameters? ( ParameterList ) ReturnType?`
+    pub(crate) fn parse_signature(&mut self) -> Result<Signature, ParserError> {
+        let type_parameters = self.parse_optional_type_parameters()?;
+
+        let params = self.parse_parenthesized_formal_parameters()?;
+
+        let return_type = self.parse_optional_return_type()?;
+
+        Ok((type_parameters, params, return_type))
+    }
+
+    // `[ Identifier : Type ]`
+    pub(crate) fn is_index_signature_start(&mut self) -> bool {
+        self.token_kind() == TokenKind::LeftSquareBracket
+            && self.peek_token_kind().is_identifier_name()
+            && self.peek_nth_kind(2) == TokenKind::Colon
+    }
+
+    pub(crate) fn parse_index_signature(
+        &mut self,
+        start_index: usize,
+        readonly: bool,
+        is_static: bool,
+        accessibility: Option<TSAccessibility>,
+    ) -> Result<TSIndexSignature, ParserError> {
+        self.expect_and_advance(TokenKind::LeftSquareBracket)?;
+
+        let parameter_start_index = self.start_node();
+
+        let mut parameter = self.parse_identifier_name()?;
+
+        parameter.type_annotation = Some(Box::new(self.parse_type_annotation()?));
+        parameter.node = self.end_node(parameter_start_index)?;
+
+        self.expect_and_advance(TokenKind::RightSquareBracket)?;
+
+        let type_annotation = self.parse_optional_type_annotation()?;
+
+        self.parse_type_member_separator()?;
+
+        Ok(TSIndexSignature {
+            node: self.end_node(start_index)?,
+            parameters: vec![parameter],
+            type_annotation,
+            readonly,
+            is_static,
+            accessibility,
+        })
+    }
+
+    // `: Type`, where the annotation node starts at the `:` token.
+    pub(crate) fn parse_type_annotation(&mut self) -> Result<TSTypeAnnotation, ParserError> {
+        let start_index = self.start_node();
+
+        self.expect_and_advance(TokenKind::Colon)?;
+
+        let type_annotation = self.parse_type()?;
+
+        Ok(TSTypeAnnotation {
+            node: self.end_node(start_index)?,
+            type_annotation,
+        })
+    }
+
+    pub(crate) fn parse_optional_type_annotation(
+        &mut self,
+    ) -> Result<Option<TSTypeAnnotation>, ParserError> {
+        if !self.options.extensions.typescript || self.token_kind() != TokenKind::Colon {
+            return Ok(None);
+        }
+
+        Ok(Some(self.parse_type_annotation()?))
+    }
+
+    // The return type of a function or method, which may be a type predicate.
+    pub(crate) fn parse_optional_return_type(
+        &mut self,
+    ) -> Result<Option<TSTypeAnnotation>, ParserError> {
+        if !self.options.extensions.typescript || self.token_kind() != TokenKind::Colon {
+            return Ok(None);
+        }
+
+        let start_index = self.start_node();
+
+        self.advance_any()?; // Eat ':' token.
+
+        let type_annotation = self.parse_type_or_type_predicate()?;
+
+        Ok(Some(TSTypeAnnotation {
+            node: self.end_node(start_index)?,
+            type_annotation,
+        }))
+    }
+
+    // `Identifier is Type`, `asserts Identifier` and `asserts Identifier is Type`, where `this` may be used in place
+    // of the identifier.
+    fn parse_type_or_type_predicate(&mut self) -> Result<TSType, ParserError> {
+        let start_index = self.start_node();
+
+        let asserts = self.token_kind() == TokenKind::Keyword(KeywordKind::Asserts)
+            && !self.has_current_token_line_terminator()
+            && matches!(
+                self.peek_token_kind(),
+                TokenKind::Identifier | TokenKind::Keyword(KeywordKind::This)
+            );
+
+        let is_type_predicate = asserts
+            || (matches!(
+                self.token_kind(),
+                TokenKind::Identifier | TokenKind::Keyword(KeywordKind::This)
+            ) && !self.has_current_token_line_terminator()
+                && self.peek_token_kind() == TokenKind::Keyword(KeywordKind::Is));
+
+        if !is_type_predicate {
+            return self.parse_type();
+        }
+
+        if asserts {
+            self.advance_any()?; // Eat 'asserts' token.
+        }
+
+        let parameter_name_start_index = self.start_node();
+
+        let parameter_name = if self.token_kind() == TokenKind::Keyword(KeywordKind::This) {
+            self.advance_any()?; // Eat 'this' token.
+
+            TSTypePredicateName::This(TSThisType {
+                node: self.end_node(parameter_name_start_index)?,
+            })
+        } else {
+            TSTypePredicateName::Identifier(self.parse_identifier_name()?)
+        };
+
+        let type_annotation = if self.token_kind() == TokenKind::Keyword(KeywordKind::Is)
+            && !self.has_previous_token_line_terminator()
+        {
+            self.advance_any()?; // Eat 'is' token.
+
+            let type_start_index = self.start_node();
+
+            let type_annotation = self.parse_type()?;
+
+            Some(TSTypeAnnotation {
+                node: self.end_node(type_start_index)?,
+                type_annotation,
+            })
+        } else {
+            None
+        };
+
+        Ok(TSType::TypePredicate(Box::new(TSTypePredicate {
+            node: self.end_node(start_index)?,
+            asserts,
+            parameter_name,
+            type_annotation,
+        })))
+    }
+
+    // `< TypeParameter, TypeParameter >`
+    pub(crate) fn parse_optional_type_parameters(
+        &mut self,
+    ) -> Result<Option<TSTypeParameterDeclaration>, ParserError> {
+        if !self.options.extensions.typescript || self.token_kind() != TokenKind::LessThan {
+            return Ok(None);
+        }
+
+        let start_index = self.start_node();
+
+        self.advance_any()?; // Eat '<' token.
+
+        let mut params = vec![];
+
+        while !self.is_type_list_end() {
+            params.push(self.parse_type_parameter()?);
+
+            if self.token_kind() == TokenKind::Comma {
+                self.advance_any()?; // Eat ',' token.
+            } else {
+                break;
+            }
+        }
+
+        self.expect_type_list_end_and_advance()?;
+
+        Ok(Some(TSTypeParameterDeclaration {
+            node: self.end_node(start_index)?,
+            params,
+        }))
+    }
+
+    // `const? in? out? Identifier Constraint? Default?`
+    fn parse_type_parameter(&mut self) -> Result<TSTypeParameter, ParserError> {
+        let start_index = self.start_node();
+
+        let mut is_const = false;
+        let mut is_in = false;
+        let mut is_out = false;
+
+        loop {
+            if !self.peek_token_kind().is_binding_identifier() {
+                break;
+            }
+
+            match self.token_kind() {
+                TokenKind::Keyword(KeywordKind::Const) => is_const = true,
+                TokenKind::Keyword(KeywordKind::In) => is_in = true,
+                TokenKind::Identifier if self.token_value() == Self::identifier_value("out") => {
+                    is_out = true
+                }
+                _ => break,
+            }
+
+            self.advance_any()?; // Eat modifier token.
+        }
+
+        let name = self.parse_binding_identifier()?;
+
+        let constraint = if self.token_kind() == TokenKind::Keyword(KeywordKind::Extends) {
+            self.advance_any()?; // Eat 'extends' token.
+
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+
+        let default = if self.token_kind() == TokenKind::Assignment {
+            self.advance_any()?; // Eat '=' token.
+
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+
+        Ok(TSTypeParameter {
+            node: self.end_node(start_index)?,
+            name,
+            constraint,
+            default,
+            is_in,
+            is_out,
+            is_const,
+        })
+    }
+
+    fn identifier_value(name: &str) -> TokenValue<'_> {
+        TokenValue::String {
+            raw: name,
+            value: Cow::Borrowed(name),
+        }
+    }
+
+    // `< Type, Type >`
+    pub(crate) fn parse_type_arguments(
+        &mut self,
+    ) -> Result<TSTypeParameterInstantiation, ParserError> {
+        let start_index = self.start_node();
+
+        self.expect_and_advance(TokenKind::LessThan)?;
+
+        let mut params = vec![];
+
+        while !self.is_type_list_end() {
+            params.push(self.parse_type()?);
+
+            if self.token_kind() == TokenKind::Comma {
+                self.advance_any()?; // Eat ',' token.
+            } else {
+                break;
+            }
+        }
+
+        self.expect_type_list_end_and_advance()?;
+
+        Ok(TSTypeParameterInstantiation {
+            node: self.end_node(start_index)?,
+            params,
+        })
+    }
+
+    // Type arguments of a type reference must be on the same line as the type name.
+    fn parse_optional_type_arguments(
+        &mut self,
+    ) -> Result<Option<TSTypeParameterInstantiation>, ParserError> {
+        if self.token_kind() != TokenKind::LessThan || self.has_previous_token_line_terminator() {
+            return Ok(None);
+        }
+
+        Ok(Some(self.parse_type_arguments()?))
+    }
+
+    // The type arguments of a call or `new` expression, which are only parsed if they are followed by the arguments,
+    // as otherwise the `<` is a relational operator.
+    pub(crate) fn try_parse_type_arguments_in_expression(
+        &mut self,
+    ) -> Option<TSTypeParameterInstantiation> {
+        if !self.options.extensions.typescript || self.token_kind() != TokenKind::LessThan {
+            return None;
+        }
+
+        let previous_cursor = self.cursor.checkpoint();
+        let previous_context = self.context.clone();
+
+        match self.parse_type_arguments() {
+            Ok(type_arguments) if self.token_kind() == TokenKind::LeftParenthesis => {
+                Some(type_arguments)
+            }
+            _ => {
+                self.cursor.restore(previous_cursor);
+                self.context = previous_context;
+
+                None
+            }
+        }
+    }
+
+    // A `>` which closes a list of types may have been scanned as the start of a longer punctuator, such as the `>>`
+    // in `Array<Array<T>>`.
+    fn is_type_list_end(&self) -> bool {
+        matches!(
+            self.token_kind(),
+            TokenKind::GreaterThan
+                | TokenKind::GreaterThanOrEqual
+                | TokenKind::RightShift
+                | TokenKind::RightShiftAssignment
+                | TokenKind::UnsignedRightShift
+                | TokenKind::UnsignedRightShiftAssignment
+        )
+    }
+
+    pub(crate) fn expect_type_list_end_and_advance(&mut self) -> Result<(), ParserError> {
+        if !self.is_type_list_end() {
+            return Err(self.unexpected_current_token_kind());
+        }
+
+        if self.token_kind() != TokenKind::GreaterThan {
+            self.cursor.split_greater_than();
+        }
+
+        self.advance_any()?; // Eat '>' token.
+
+        Ok(())
+    }
+}
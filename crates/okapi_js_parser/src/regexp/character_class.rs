@@ -0,0 +1,461 @@
+use crate::{ast::regexp::*, ParserErrorKind};
+
+use super::{unicode_property::is_binary_property_of_strings, RegExpParser};
+
+// https://tc39.es/ecma262/#prod-ClassSetSyntaxCharacter
+fn is_class_set_syntax_character(ch: char) -> bool {
+    matches!(
+        ch,
+        '(' | ')' | '[' | ']' | '{' | '}' | '/' | '-' | '\\' | '|'
+    )
+}
+
+// https://tc39.es/ecma262/#prod-ClassSetReservedPunctuator
+fn is_class_set_reserved_punctuator(ch: char) -> bool {
+    matches!(
+        ch,
+        '&' | '-' | '!' | '#' | '%' | ',' | ':' | ';' | '<' | '=' | '>' | '@' | '`' | '~'
+    )
+}
+
+// https://tc39.es/ecma262/#prod-ClassSetReservedDoublePunctuator
+fn is_class_set_reserved_double_punctuator(ch: char) -> bool {
+    matches!(
+        ch,
+        '&' | '!'
+            | '#'
+            | '$'
+            | '%'
+            | '*'
+            | '+'
+            | ','
+            | '.'
+            | ':'
+            | ';'
+            | '<'
+            | '='
+            | '>'
+            | '?'
+            | '@'
+            | '^'
+            | '`'
+            | '~'
+    )
+}
+
+// https://tc39.es/ecma262/#sec-static-semantics-maycontainstrings
+fn may_contain_strings(element: &CharacterClassElement) -> bool {
+    match element {
+        CharacterClassElement::CharacterSet(CharacterSet {
+            key: Some(key),
+            value: None,
+            ..
+        }) => is_binary_property_of_strings(key),
+        CharacterClassElement::CharacterClass(character_class) => {
+            !character_class.negate && character_class.elements.iter().any(may_contain_strings)
+        }
+        CharacterClassElement::ClassStringDisjunction(class_string_disjunction) => {
+            class_string_disjunction
+                .alternatives
+                .iter()
+                .any(|alternative| alternative.elements.len() != 1)
+        }
+        CharacterClassElement::ClassIntersection(class_intersection) => {
+            may_contain_strings(&class_intersection.left)
+                && may_contain_strings(&class_intersection.right)
+        }
+        CharacterClassElement::ClassSubtraction(class_subtraction) => {
+            may_contain_strings(&class_subtraction.left)
+        }
+        _ => false,
+    }
+}
+
+// 22.2.1 Patterns
+// https://tc39.es/ecma262/#sec-patterns
+impl RegExpParser<'_> {
+    // https://tc39.es/ecma262/#prod-CharacterClass
+    pub(crate) fn parse_character_class(&mut self) -> Result<CharacterClass, ParserErrorKind> {
+        let start = self.index;
+
+        self.read_char(); // Eat '[' char.
+
+        let negate = self.eat_char('^');
+
+        let elements = if self.unicode_sets_mode {
+            self.parse_class_set_expression(start)?
+        } else {
+            self.parse_class_ranges(start)?
+        };
+
+        // It is a Syntax Error if a negated class may contain strings, e.g. `[^\q{ab}]`.
+        // https://tc39.es/ecma262/#sec-patterns-static-semantics-early-errors
+        if negate && elements.iter().any(may_contain_strings) {
+            return Err(ParserErrorKind::InvalidRegexNegatedClassOfStrings(start));
+        }
+
+        Ok(CharacterClass {
+            start,
+            end: self.index,
+            negate,
+            elements,
+        })
+    }
+
+    // https://tc39.es/ecma262/#prod-ClassContents
+    // https://tc39.es/ecma262/#prod-NonemptyClassRanges
+    fn parse_class_ranges(
+        &mut self,
+        start: usize,
+    ) -> Result<Vec<CharacterClassElement>, ParserErrorKind> {
+        let mut elements = vec![];
+
+        loop {
+            match self.current_char() {
+                Some(']') => {
+                    self.read_char(); // Eat ']' char.
+
+                    return Ok(elements);
+                }
+                None => return Err(ParserErrorKind::UnterminatedRegexCharacterClass(start)),
+                _ => {}
+            }
+
+            let atom_start = self.index;
+
+            let atom = self.parse_class_atom(start)?;
+
+            // A `-` before the closing bracket is a literal character, e.g. `[a-]`.
+            if self.current_char() != Some('-') || matches!(self.peek_char(), Some(']') | None) {
+                elements.push(atom);
+
+                continue;
+            }
+
+            let dash_start = self.index;
+
+            self.read_char(); // Eat '-' char.
+
+            match (atom, self.parse_class_atom(start)?) {
+                (CharacterClassElement::Character(min), CharacterClassElement::Character(max)) => {
+                    // It is a Syntax Error if the range is out of order, e.g. `[z-a]`.
+                    // https://tc39.es/ecma262/#sec-patterns-static-semantics-early-errors
+                    if min.value > max.value {
+                        return Err(ParserErrorKind::InvalidRegexCharacterClassRange(atom_start));
+                    }
+
+                    elements.push(CharacterClassElement::CharacterClassRange(
+                        CharacterClassRange {
+                            start: min.start,
+                            end: max.end,
+                            min,
+                            max,
+                        },
+                    ));
+                }
+                // In Unicode mode, it is a Syntax Error if either side of the range is a character class escape, e.g. `[\d-z]`.
+                (_, _) if self.unicode_mode => {
+                    return Err(ParserErrorKind::InvalidRegexCharacterClassRange(atom_start));
+                }
+                // Otherwise the range is a union of both sides and a literal `-`.
+                // https://tc39.es/ecma262/#prod-annexB-NonemptyClassRanges
+                (min, max) => {
+                    elements.push(min);
+                    elements.push(CharacterClassElement::Character(Character {
+                        start: dash_start,
+                        end: dash_start + 1,
+                        value: u32::from('-'),
+                    }));
+                    elements.push(max);
+                }
+            }
+        }
+    }
+
+    // https://tc39.es/ecma262/#prod-ClassAtom
+    fn parse_class_atom(&mut self, start: usize) -> Result<CharacterClassElement, ParserErrorKind> {
+        match self.current_char() {
+            Some('\\') => self.parse_class_escape(),
+            Some(_) => Ok(CharacterClassElement::Character(
+                self.parse_pattern_character(),
+            )),
+            None => Err(ParserErrorKind::UnterminatedRegexCharacterClass(start)),
+        }
+    }
+
+    // https://tc39.es/ecma262/#prod-ClassEscape
+    fn parse_class_escape(&mut self) -> Result<CharacterClassElement, ParserErrorKind> {
+        let start = self.index;
+
+        self.read_char(); // Eat '\' char.
+
+        if self.eat_char('b') {
+            return Ok(CharacterClassElement::Character(Character {
+                start,
+                end: self.index,
+                value: 0x08,
+            }));
+        }
+
+        if let Some(character_set) = self.parse_character_class_escape(start)? {
+            return Ok(CharacterClassElement::CharacterSet(character_set));
+        }
+
+        Ok(CharacterClassElement::Character(
+            self.parse_character_escape(start, true)?,
+        ))
+    }
+
+    // https://tc39.es/ecma262/#prod-ClassSetExpression
+    fn parse_class_set_expression(
+        &mut self,
+        start: usize,
+    ) -> Result<Vec<CharacterClassElement>, ParserErrorKind> {
+        if self.eat_char(']') {
+            return Ok(vec![]);
+        }
+
+        let first_start = self.index;
+
+        let first = self.parse_class_set_range_or_operand(start)?;
+
+        let elements = if self.source[self.index..].starts_with("&&") {
+            vec![self.parse_class_set_operation(start, first_start, first, "&&")?]
+        } else if self.source[self.index..].starts_with("--") {
+            vec![self.parse_class_set_operation(start, first_start, first, "--")?]
+        } else {
+            self.parse_class_union(start, first)?
+        };
+
+        match self.current_char() {
+            Some(']') => {
+                self.read_char(); // Eat ']' char.
+
+                Ok(elements)
+            }
+            // Different set operations cannot be mixed without nesting classes, e.g. `[a&&b--c]`.
+            Some(_) => Err(ParserErrorKind::InvalidRegexClassSetOperation(self.index)),
+            None => Err(ParserErrorKind::UnterminatedRegexCharacterClass(start)),
+        }
+    }
+
+    // https://tc39.es/ecma262/#prod-ClassUnion
+    fn parse_class_union(
+        &mut self,
+        start: usize,
+        first: CharacterClassElement,
+    ) -> Result<Vec<CharacterClassElement>, ParserErrorKind> {
+        let mut elements = vec![first];
+
+        while !matches!(self.current_char(), Some(']') | None) {
+            // Set operations cannot follow a union without nesting classes, e.g. `[ab&&c]`.
+            if self.source[self.index..].starts_with("&&")
+                || self.source[self.index..].starts_with("--")
+            {
+                return Err(ParserErrorKind::InvalidRegexClassSetOperation(self.index));
+            }
+
+            elements.push(self.parse_class_set_range_or_operand(start)?);
+        }
+
+        Ok(elements)
+    }
+
+    // https://tc39.es/ecma262/#prod-ClassIntersection
+    // https://tc39.es/ecma262/#prod-ClassSubtraction
+    fn parse_class_set_operation(
+        &mut self,
+        start: usize,
+        first_start: usize,
+        first: CharacterClassElement,
+        operator: &str,
+    ) -> Result<CharacterClassElement, ParserErrorKind> {
+        // The operands of a set operation cannot be ranges, e.g. `[a-z&&b]`.
+        if matches!(first, CharacterClassElement::CharacterClassRange(_)) {
+            return Err(ParserErrorKind::InvalidRegexClassSetOperation(self.index));
+        }
+
+        let mut left = first;
+
+        while self.eat_str(operator) {
+            // `&&&` is reserved for future use.
+            if operator == "&&" && self.current_char() == Some('&') {
+                return Err(ParserErrorKind::InvalidRegexClassSetCharacter(self.index));
+            }
+
+            let right = Box::new(self.parse_class_set_operand(start)?);
+
+            left = match operator {
+                "&&" => CharacterClassElement::ClassIntersection(ClassIntersection {
+                    start: first_start,
+                    end: self.index,
+                    left: Box::new(left),
+                    right,
+                }),
+                _ => CharacterClassElement::ClassSubtraction(ClassSubtraction {
+                    start: first_start,
+                    end: self.index,
+                    left: Box::new(left),
+                    right,
+                }),
+            };
+        }
+
+        Ok(left)
+    }
+
+    // https://tc39.es/ecma262/#prod-ClassSetRange
+    fn parse_class_set_range_or_operand(
+        &mut self,
+        start: usize,
+    ) -> Result<CharacterClassElement, ParserErrorKind> {
+        let operand = self.parse_class_set_operand(start)?;
+
+        let CharacterClassElement::Character(min) = operand else {
+            return Ok(operand);
+        };
+
+        if self.current_char() != Some('-') || self.peek_char() == Some('-') {
+            return Ok(CharacterClassElement::Character(min));
+        }
+
+        self.read_char(); // Eat '-' char.
+
+        let max = self.parse_class_set_character(start)?;
+
+        // It is a Syntax Error if the range is out of order, e.g. `[z-a]`.
+        if min.value > max.value {
+            return Err(ParserErrorKind::InvalidRegexCharacterClassRange(min.start));
+        }
+
+        Ok(CharacterClassElement::CharacterClassRange(
+            CharacterClassRange {
+                start: min.start,
+                end: max.end,
+                min,
+                max,
+            },
+        ))
+    }
+
+    // https://tc39.es/ecma262/#prod-ClassSetOperand
+    fn parse_class_set_operand(
+        &mut self,
+        start: usize,
+    ) -> Result<CharacterClassElement, ParserErrorKind> {
+        let operand_start = self.index;
+
+        match self.current_char() {
+            // https://tc39.es/ecma262/#prod-NestedClass
+            Some('[') => Ok(CharacterClassElement::CharacterClass(
+                self.parse_character_class()?,
+            )),
+            Some('\\') => {
+                self.read_char(); // Eat '\' char.
+
+                if self.eat_str("q{") {
+                    return Ok(CharacterClassElement::ClassStringDisjunction(
+                        self.parse_class_string_disjunction(start, operand_start)?,
+                    ));
+                }
+
+                if let Some(character_set) = self.parse_character_class_escape(operand_start)? {
+                    return Ok(CharacterClassElement::CharacterSet(character_set));
+                }
+
+                self.index = operand_start;
+
+                Ok(CharacterClassElement::Character(
+                    self.parse_class_set_character(start)?,
+                ))
+            }
+            _ => Ok(CharacterClassElement::Character(
+                self.parse_class_set_character(start)?,
+            )),
+        }
+    }
+
+    // https://tc39.es/ecma262/#prod-ClassStringDisjunction
+    fn parse_class_string_disjunction(
+        &mut self,
+        start: usize,
+        disjunction_start: usize,
+    ) -> Result<ClassStringDisjunction, ParserErrorKind> {
+        let mut alternatives = vec![];
+
+        loop {
+            let alternative_start = self.index;
+
+            let mut elements = vec![];
+
+            while !matches!(self.current_char(), Some('|' | '}') | None) {
+                elements.push(self.parse_class_set_character(start)?);
+            }
+
+            alternatives.push(StringAlternative {
+                start: alternative_start,
+                end: self.index,
+                elements,
+            });
+
+            if !self.eat_char('|') {
+                break;
+            }
+        }
+
+        if !self.eat_char('}') {
+            return Err(ParserErrorKind::UnterminatedRegexCharacterClass(start));
+        }
+
+        Ok(ClassStringDisjunction {
+            start: disjunction_start,
+            end: self.index,
+            alternatives,
+        })
+    }
+
+    // https://tc39.es/ecma262/#prod-ClassSetCharacter
+    fn parse_class_set_character(&mut self, start: usize) -> Result<Character, ParserErrorKind> {
+        let character_start = self.index;
+
+        match self.current_char() {
+            Some('\\') => {
+                self.read_char(); // Eat '\' char.
+
+                match self.current_char() {
+                    Some('b') => {
+                        self.read_char(); // Eat 'b' char.
+
+                        Ok(Character {
+                            start: character_start,
+                            end: self.index,
+                            value: 0x08,
+                        })
+                    }
+                    Some(ch) if is_class_set_reserved_punctuator(ch) => {
+                        self.read_char(); // Eat punctuator char.
+
+                        Ok(Character {
+                            start: character_start,
+                            end: self.index,
+                            value: u32::from(ch),
+                        })
+                    }
+                    _ => self.parse_character_escape(character_start, true),
+                }
+            }
+            // Syntax characters and doubled punctuators have to be escaped with the `v` flag.
+            Some(ch)
+                if is_class_set_syntax_character(ch)
+                    || (is_class_set_reserved_double_punctuator(ch)
+                        && self.peek_char() == Some(ch)) =>
+            {
+                Err(ParserErrorKind::InvalidRegexClassSetCharacter(
+                    character_start,
+                ))
+            }
+            Some(_) => Ok(self.parse_pattern_character()),
+            None => Err(ParserErrorKind::UnterminatedRegexCharacterClass(start)),
+        }
+    }
+}
@@ -0,0 +1,509 @@
+use crate::{ast::regexp::*, lexer::LexerChar, ParserErrorKind};
+
+use super::RegExpParser;
+
+// 22.2.1 Patterns
+// https://tc39.es/ecma262/#sec-patterns
+impl RegExpParser<'_> {
+    // https://tc39.es/ecma262/#prod-Pattern
+    pub(crate) fn parse_pattern(&mut self) -> Result<Pattern, ParserErrorKind> {
+        let alternatives = self.parse_disjunction()?;
+
+        // A disjunction only stops before the end of the pattern at a `)` which has no matching `(`.
+        if !self.is_end_of_pattern() {
+            return Err(ParserErrorKind::InvalidRegexUnmatchedParenthesis(
+                self.index,
+            ));
+        }
+
+        // It is a Syntax Error if a GroupName is not the name of any group in the pattern.
+        // https://tc39.es/ecma262/#sec-patterns-static-semantics-early-errors
+        for (name, start) in &self.named_backreferences {
+            if !self
+                .group_names
+                .iter()
+                .any(|(group_name, _)| group_name == name)
+            {
+                return Err(ParserErrorKind::InvalidRegexBackreference(*start));
+            }
+        }
+
+        Ok(Pattern {
+            start: 0,
+            end: self.index,
+            alternatives,
+        })
+    }
+
+    // https://tc39.es/ecma262/#prod-Disjunction
+    fn parse_disjunction(&mut self) -> Result<Vec<Alternative>, ParserErrorKind> {
+        let disjunction_index = self.disjunction_count;
+
+        self.disjunction_count += 1;
+
+        let mut alternatives = vec![];
+
+        loop {
+            self.alternative_path
+                .push((disjunction_index, alternatives.len() as u32));
+
+            alternatives.push(self.parse_alternative()?);
+
+            self.alternative_path.pop();
+
+            if !self.eat_char('|') {
+                break;
+            }
+        }
+
+        Ok(alternatives)
+    }
+
+    // https://tc39.es/ecma262/#prod-Alternative
+    fn parse_alternative(&mut self) -> Result<Alternative, ParserErrorKind> {
+        let start = self.index;
+
+        let mut elements = vec![];
+
+        while let Some(ch) = self.current_char() {
+            if matches!(ch, '|' | ')') {
+                break;
+            }
+
+            elements.push(self.parse_term()?);
+        }
+
+        Ok(Alternative {
+            start,
+            end: self.index,
+            elements,
+        })
+    }
+
+    // https://tc39.es/ecma262/#prod-Term
+    // https://tc39.es/ecma262/#prod-annexB-Term
+    fn parse_term(&mut self) -> Result<Element, ParserErrorKind> {
+        let start = self.index;
+
+        if let Some(assertion) = self.parse_assertion()? {
+            // Lookaheads can be quantified outside of Unicode mode for web compatibility.
+            // https://tc39.es/ecma262/#prod-annexB-QuantifiableAssertion
+            return match assertion {
+                Element::LookaroundAssertion(LookaroundAssertion {
+                    kind: LookaroundAssertionKind::Lookahead,
+                    ..
+                }) if !self.unicode_mode => self.parse_quantifier(start, assertion),
+                _ => Ok(assertion),
+            };
+        }
+
+        let atom = self.parse_atom()?;
+
+        self.parse_quantifier(start, atom)
+    }
+
+    // https://tc39.es/ecma262/#prod-Assertion
+    fn parse_assertion(&mut self) -> Result<Option<Element>, ParserErrorKind> {
+        let start = self.index;
+
+        let boundary = if self.eat_char('^') {
+            Some((BoundaryAssertionKind::Start, false))
+        } else if self.eat_char('$') {
+            Some((BoundaryAssertionKind::End, false))
+        } else if self.eat_str("\\b") {
+            Some((BoundaryAssertionKind::Word, false))
+        } else if self.eat_str("\\B") {
+            Some((BoundaryAssertionKind::Word, true))
+        } else {
+            None
+        };
+
+        if let Some((kind, negate)) = boundary {
+            return Ok(Some(Element::BoundaryAssertion(BoundaryAssertion {
+                start,
+                end: self.index,
+                kind,
+                negate,
+            })));
+        }
+
+        let lookaround = if self.eat_str("(?=") {
+            Some((LookaroundAssertionKind::Lookahead, false))
+        } else if self.eat_str("(?!") {
+            Some((LookaroundAssertionKind::Lookahead, true))
+        } else if self.eat_str("(?<=") {
+            Some((LookaroundAssertionKind::Lookbehind, false))
+        } else if self.eat_str("(?<!") {
+            Some((LookaroundAssertionKind::Lookbehind, true))
+        } else {
+            None
+        };
+
+        let Some((kind, negate)) = lookaround else {
+            return Ok(None);
+        };
+
+        let alternatives = self.parse_group_body(start)?;
+
+        Ok(Some(Element::LookaroundAssertion(LookaroundAssertion {
+            start,
+            end: self.index,
+            kind,
+            negate,
+            alternatives,
+        })))
+    }
+
+    // https://tc39.es/ecma262/#prod-Quantifier
+    fn parse_quantifier(
+        &mut self,
+        start: usize,
+        element: Element,
+    ) -> Result<Element, ParserErrorKind> {
+        let quantifier_start = self.index;
+
+        let (min, max) = match self.current_char() {
+            Some('*') => {
+                self.read_char(); // Eat '*' char.
+
+                (0, None)
+            }
+            Some('+') => {
+                self.read_char(); // Eat '+' char.
+
+                (1, None)
+            }
+            Some('?') => {
+                self.read_char(); // Eat '?' char.
+
+                (0, Some(1))
+            }
+            Some('{') => match self.parse_braced_quantifier() {
+                Some(range) => range,
+                None if self.unicode_mode => {
+                    return Err(ParserErrorKind::InvalidRegexLoneBracket(self.index));
+                }
+                // Outside of Unicode mode, a `{` which does not start a quantifier is a literal character.
+                None => return Ok(element),
+            },
+            _ => return Ok(element),
+        };
+
+        // It is a Syntax Error if the first DecimalDigits is larger than the second, e.g. `a{2,1}`.
+        // https://tc39.es/ecma262/#sec-patterns-static-semantics-early-errors
+        if max.is_some_and(|max| max < min) {
+            return Err(ParserErrorKind::InvalidRegexQuantifierRange(
+                quantifier_start,
+            ));
+        }
+
+        let greedy = !self.eat_char('?');
+
+        Ok(Element::Quantifier(Quantifier {
+            start,
+            end: self.index,
+            min,
+            max,
+            greedy,
+            element: Box::new(element),
+        }))
+    }
+
+    // https://tc39.es/ecma262/#prod-QuantifierPrefix
+    fn parse_braced_quantifier(&mut self) -> Option<(u32, Option<u32>)> {
+        let start = self.index;
+
+        self.read_char(); // Eat '{' char.
+
+        if let Some(min) = self.parse_decimal_digits() {
+            let max = if self.eat_char(',') {
+                self.parse_decimal_digits()
+            } else {
+                Some(min)
+            };
+
+            if self.eat_char('}') {
+                return Some((min, max));
+            }
+        }
+
+        self.index = start;
+
+        None
+    }
+
+    // https://tc39.es/ecma262/#prod-DecimalDigits
+    pub(crate) fn parse_decimal_digits(&mut self) -> Option<u32> {
+        let mut value: Option<u32> = None;
+
+        while let Some(digit) = self.current_char().and_then(|ch| ch.to_digit(10)) {
+            value = Some(value.unwrap_or(0).saturating_mul(10).saturating_add(digit));
+
+            self.read_char();
+        }
+
+        value
+    }
+
+    // https://tc39.es/ecma262/#prod-Atom
+    // https://tc39.es/ecma262/#prod-annexB-ExtendedAtom
+    fn parse_atom(&mut self) -> Result<Element, ParserErrorKind> {
+        let start = self.index;
+
+        match self.current_char() {
+            Some('.') => {
+                self.read_char(); // Eat '.' char.
+
+                Ok(Element::CharacterSet(CharacterSet {
+                    start,
+                    end: self.index,
+                    kind: CharacterSetKind::Any,
+                    negate: false,
+                    key: None,
+                    value: None,
+                }))
+            }
+            Some('\\') => self.parse_atom_escape(),
+            Some('[') => Ok(Element::CharacterClass(self.parse_character_class()?)),
+            Some('(') => self.parse_group(),
+            Some('*' | '+' | '?') | None => {
+                Err(ParserErrorKind::InvalidRegexNothingToRepeat(start))
+            }
+            Some('{') => {
+                if self.parse_braced_quantifier().is_some() {
+                    return Err(ParserErrorKind::InvalidRegexNothingToRepeat(start));
+                }
+
+                if self.unicode_mode {
+                    return Err(ParserErrorKind::InvalidRegexLoneBracket(start));
+                }
+
+                Ok(Element::Character(self.parse_pattern_character()))
+            }
+            Some('}' | ']') if self.unicode_mode => {
+                Err(ParserErrorKind::InvalidRegexLoneBracket(start))
+            }
+            Some(_) => Ok(Element::Character(self.parse_pattern_character())),
+        }
+    }
+
+    // https://tc39.es/ecma262/#prod-PatternCharacter
+    // https://tc39.es/ecma262/#prod-annexB-ExtendedPatternCharacter
+    pub(crate) fn parse_pattern_character(&mut self) -> Character {
+        let start = self.index;
+
+        let value = self.current_char().map_or(0, u32::from);
+
+        self.read_char();
+
+        Character {
+            start,
+            end: self.index,
+            value,
+        }
+    }
+
+    fn parse_group(&mut self) -> Result<Element, ParserErrorKind> {
+        let start = self.index;
+
+        self.read_char(); // Eat '(' char.
+
+        if self.current_char() == Some('?')
+            && matches!(self.peek_char(), Some(':' | '-' | 'i' | 'm' | 's'))
+        {
+            self.read_char(); // Eat '?' char.
+
+            let modifiers = self.parse_modifiers(start)?;
+
+            let alternatives = self.parse_group_body(start)?;
+
+            return Ok(Element::Group(Group {
+                start,
+                end: self.index,
+                modifiers,
+                alternatives,
+            }));
+        }
+
+        // https://tc39.es/ecma262/#prod-GroupSpecifier
+        let name = if self.eat_str("?<") {
+            let name = self.parse_group_name()?;
+
+            // Groups can only share a name when they are in different alternatives, so that at most one of them participates in a match.
+            // https://tc39.es/ecma262/#sec-mightbothparticipate
+            let might_both_participate = |path: &Vec<(u32, u32)>| {
+                !path.iter().any(|(disjunction, alternative)| {
+                    self.alternative_path
+                        .iter()
+                        .any(|(other_disjunction, other_alternative)| {
+                            disjunction == other_disjunction && alternative != other_alternative
+                        })
+                })
+            };
+
+            if self
+                .group_names
+                .iter()
+                .any(|(group_name, path)| group_name == &name && might_both_participate(path))
+            {
+                return Err(ParserErrorKind::DuplicateRegexGroupName(start));
+            }
+
+            self.group_names
+                .push((name.clone(), self.alternative_path.clone()));
+
+            Some(name)
+        } else if self.current_char() == Some('?') {
+            return Err(ParserErrorKind::InvalidRegexGroup(start));
+        } else {
+            None
+        };
+
+        // Groups are numbered by the position of their opening parenthesis, so the index is assigned before any nested groups are parsed.
+        self.next_group_index += 1;
+
+        let index = self.next_group_index;
+
+        let alternatives = self.parse_group_body(start)?;
+
+        Ok(Element::CapturingGroup(CapturingGroup {
+            start,
+            end: self.index,
+            index,
+            name,
+            alternatives,
+        }))
+    }
+
+    // The modifiers of a non-capturing group, which are `None` for a plain `(?:` group.
+    // https://tc39.es/ecma262/#prod-Atom
+    fn parse_modifiers(
+        &mut self,
+        group_start: usize,
+    ) -> Result<Option<Modifiers>, ParserErrorKind> {
+        let start = self.index;
+
+        let add = self.read_while(|ch| matches!(ch, 'i' | 'm' | 's'));
+
+        let remove = if self.eat_char('-') {
+            Some((
+                self.index,
+                self.read_while(|ch| matches!(ch, 'i' | 'm' | 's')),
+            ))
+        } else {
+            None
+        };
+
+        let end = self.index;
+
+        if !self.eat_char(':') {
+            return Err(ParserErrorKind::InvalidRegexGroup(group_start));
+        }
+
+        // It is a Syntax Error if a modifier appears more than once in either or both sets of modifiers, or if both
+        // sets are empty.
+        // https://tc39.es/ecma262/#sec-patterns-static-semantics-early-errors
+        let (remove_start, removed) = remove.unwrap_or((end, ""));
+
+        let mut seen = String::new();
+
+        for (index, ch) in add
+            .char_indices()
+            .map(|(index, ch)| (start + index, ch))
+            .chain(
+                removed
+                    .char_indices()
+                    .map(|(index, ch)| (remove_start + index, ch)),
+            )
+        {
+            if seen.contains(ch) {
+                return Err(ParserErrorKind::InvalidRegexModifiers(index));
+            }
+
+            seen.push(ch);
+        }
+
+        if remove.is_some() && seen.is_empty() {
+            return Err(ParserErrorKind::InvalidRegexModifiers(start));
+        }
+
+        if seen.is_empty() {
+            return Ok(None);
+        }
+
+        Ok(Some(Modifiers {
+            start,
+            end,
+            add: modifier_flags(start, add),
+            remove: remove.map(|(remove_start, removed)| modifier_flags(remove_start, removed)),
+        }))
+    }
+
+    fn parse_group_body(&mut self, start: usize) -> Result<Vec<Alternative>, ParserErrorKind> {
+        let alternatives = self.parse_disjunction()?;
+
+        if !self.eat_char(')') {
+            return Err(ParserErrorKind::UnterminatedRegexGroup(start));
+        }
+
+        Ok(alternatives)
+    }
+
+    // https://tc39.es/ecma262/#prod-GroupName
+    pub(crate) fn parse_group_name(&mut self) -> Result<String, ParserErrorKind> {
+        let mut name = String::new();
+
+        loop {
+            let start = self.index;
+
+            let ch = match self.current_char() {
+                Some('>') if !name.is_empty() => {
+                    self.read_char(); // Eat '>' char.
+
+                    return Ok(name);
+                }
+                // Group names can contain Unicode escapes regardless of the flags.
+                // https://tc39.es/ecma262/#prod-RegExpIdentifierStart
+                Some('\\') => {
+                    self.read_char(); // Eat '\' char.
+
+                    if !self.eat_char('u') {
+                        return Err(ParserErrorKind::InvalidRegexGroupName(start));
+                    }
+
+                    self.parse_regexp_unicode_escape(true)
+                        .and_then(char::from_u32)
+                        .ok_or(ParserErrorKind::InvalidRegexGroupName(start))?
+                }
+                Some(ch) => {
+                    self.read_char();
+
+                    ch
+                }
+                None => return Err(ParserErrorKind::InvalidRegexGroupName(start)),
+            };
+
+            let is_valid = if name.is_empty() {
+                ch.is_identifier_start()
+            } else {
+                ch.is_identifier_part()
+            };
+
+            if !is_valid {
+                return Err(ParserErrorKind::InvalidRegexGroupName(start));
+            }
+
+            name.push(ch);
+        }
+    }
+}
+
+fn modifier_flags(start: usize, modifiers: &str) -> ModifierFlags {
+    ModifierFlags {
+        start,
+        end: start + modifiers.len(),
+        ignore_case: modifiers.contains('i'),
+        multiline: modifiers.contains('m'),
+        dot_all: modifiers.contains('s'),
+    }
+}
@@ -0,0 +1,342 @@
+use crate::{ast::regexp::*, ParserErrorKind};
+
+use super::{
+    unicode_property::{
+        is_binary_property, is_binary_property_of_strings, is_general_category_value,
+        is_property_value,
+    },
+    RegExpParser,
+};
+
+// https://tc39.es/ecma262/#prod-SyntaxCharacter
+fn is_syntax_character(ch: char) -> bool {
+    matches!(
+        ch,
+        '^' | '$' | '\\' | '.' | '*' | '+' | '?' | '(' | ')' | '[' | ']' | '{' | '}' | '|'
+    )
+}
+
+// https://tc39.es/ecma262/#prod-UnicodePropertyNameCharacter
+fn is_unicode_property_name_character(ch: char) -> bool {
+    ch.is_ascii_alphabetic() || ch == '_'
+}
+
+// https://tc39.es/ecma262/#prod-UnicodePropertyValueCharacter
+fn is_unicode_property_value_character(ch: char) -> bool {
+    is_unicode_property_name_character(ch) || ch.is_ascii_digit()
+}
+
+// 22.2.1 Patterns
+// https://tc39.es/ecma262/#sec-patterns
+impl RegExpParser<'_> {
+    // https://tc39.es/ecma262/#prod-AtomEscape
+    // https://tc39.es/ecma262/#prod-annexB-AtomEscape
+    pub(crate) fn parse_atom_escape(&mut self) -> Result<Element, ParserErrorKind> {
+        let start = self.index;
+
+        self.read_char(); // Eat '\' char.
+
+        match self.current_char() {
+            // https://tc39.es/ecma262/#prod-DecimalEscape
+            Some('1'..='9') => {
+                let reference = self.parse_decimal_digits().unwrap_or_default();
+
+                if reference <= self.capturing_group_count {
+                    return Ok(Element::Backreference(Backreference {
+                        start,
+                        end: self.index,
+                        reference: BackreferenceReference::Index(reference),
+                    }));
+                }
+
+                // It is a Syntax Error if the number is larger than the number of capturing groups in the pattern.
+                // https://tc39.es/ecma262/#sec-patterns-static-semantics-early-errors
+                if self.unicode_mode {
+                    return Err(ParserErrorKind::InvalidRegexIndexBackreference(start));
+                }
+
+                // Outside of Unicode mode, it is a legacy octal or identity escape instead.
+                self.index = start + 1;
+            }
+            Some('k') if self.named_capture_groups => {
+                self.read_char(); // Eat 'k' char.
+
+                if !self.eat_char('<') {
+                    return Err(ParserErrorKind::InvalidRegexEscape(start));
+                }
+
+                let name = self.parse_group_name()?;
+
+                self.named_backreferences.push((name.clone(), start));
+
+                return Ok(Element::Backreference(Backreference {
+                    start,
+                    end: self.index,
+                    reference: BackreferenceReference::Name(name),
+                }));
+            }
+            Some(_) => {
+                if let Some(character_set) = self.parse_character_class_escape(start)? {
+                    return Ok(Element::CharacterSet(character_set));
+                }
+            }
+            None => return Err(ParserErrorKind::InvalidRegexEscape(start)),
+        }
+
+        Ok(Element::Character(
+            self.parse_character_escape(start, false)?,
+        ))
+    }
+
+    // https://tc39.es/ecma262/#prod-CharacterClassEscape
+    pub(crate) fn parse_character_class_escape(
+        &mut self,
+        start: usize,
+    ) -> Result<Option<CharacterSet>, ParserErrorKind> {
+        let (kind, negate) = match self.current_char() {
+            Some('d') => (CharacterSetKind::Digit, false),
+            Some('D') => (CharacterSetKind::Digit, true),
+            Some('s') => (CharacterSetKind::Space, false),
+            Some('S') => (CharacterSetKind::Space, true),
+            Some('w') => (CharacterSetKind::Word, false),
+            Some('W') => (CharacterSetKind::Word, true),
+            // Outside of Unicode mode, `\p` is an identity escape.
+            Some('p') if self.unicode_mode => (CharacterSetKind::Property, false),
+            Some('P') if self.unicode_mode => (CharacterSetKind::Property, true),
+            _ => return Ok(None),
+        };
+
+        self.read_char(); // Eat escape char.
+
+        let (key, value) = match kind {
+            CharacterSetKind::Property => {
+                self.parse_unicode_property_value_expression(start, negate)?
+            }
+            _ => (None, None),
+        };
+
+        Ok(Some(CharacterSet {
+            start,
+            end: self.index,
+            kind,
+            negate,
+            key,
+            value,
+        }))
+    }
+
+    // https://tc39.es/ecma262/#prod-UnicodePropertyValueExpression
+    fn parse_unicode_property_value_expression(
+        &mut self,
+        start: usize,
+        negate: bool,
+    ) -> Result<(Option<String>, Option<String>), ParserErrorKind> {
+        if !self.eat_char('{') {
+            return Err(ParserErrorKind::InvalidRegexUnicodeProperty(start));
+        }
+
+        let name = self.read_while(is_unicode_property_name_character);
+
+        let value = if self.eat_char('=') {
+            Some(self.read_while(is_unicode_property_value_character))
+        } else {
+            None
+        };
+
+        if !self.eat_char('}') || name.is_empty() || value.is_some_and(str::is_empty) {
+            return Err(ParserErrorKind::InvalidRegexUnicodeProperty(start));
+        }
+
+        // It is a Syntax Error if the name or value is not listed in the property tables.
+        // https://tc39.es/ecma262/#sec-patterns-static-semantics-early-errors
+        match value {
+            Some(value) if is_property_value(name, value) => {
+                Ok((Some(name.to_string()), Some(value.to_string())))
+            }
+            None if is_general_category_value(name) => {
+                Ok((Some("General_Category".to_string()), Some(name.to_string())))
+            }
+            None if is_binary_property(name) => Ok((Some(name.to_string()), None)),
+            // Properties of strings can only be used with the `v` flag, and cannot be negated.
+            None if is_binary_property_of_strings(name) && self.unicode_sets_mode => {
+                if negate {
+                    return Err(ParserErrorKind::InvalidRegexNegatedClassOfStrings(start));
+                }
+
+                Ok((Some(name.to_string()), None))
+            }
+            _ => Err(ParserErrorKind::InvalidRegexUnicodeProperty(start)),
+        }
+    }
+
+    // https://tc39.es/ecma262/#prod-CharacterEscape
+    // https://tc39.es/ecma262/#prod-annexB-CharacterEscape
+    // Invalid control, hexadecimal and Unicode escapes are located after their escape char, where the invalid sequence
+    // starts, rather than at the `\`.
+    pub(crate) fn parse_character_escape(
+        &mut self,
+        start: usize,
+        in_class: bool,
+    ) -> Result<Character, ParserErrorKind> {
+        let Some(ch) = self.current_char() else {
+            return Err(ParserErrorKind::InvalidRegexEscape(start));
+        };
+
+        self.read_char(); // Eat escape char.
+
+        let value = match ch {
+            // https://tc39.es/ecma262/#prod-ControlEscape
+            'f' => 0x0C,
+            'n' => 0x0A,
+            'r' => 0x0D,
+            't' => 0x09,
+            'v' => 0x0B,
+            'c' => match self.current_char() {
+                // Digits and `_` are also control letters in character classes outside of Unicode mode.
+                // https://tc39.es/ecma262/#prod-annexB-ClassControlLetter
+                Some(letter)
+                    if letter.is_ascii_alphabetic()
+                        || (in_class
+                            && !self.unicode_mode
+                            && (letter.is_ascii_digit() || letter == '_')) =>
+                {
+                    self.read_char(); // Eat control letter char.
+
+                    u32::from(letter) % 32
+                }
+                _ if self.unicode_mode => {
+                    return Err(ParserErrorKind::InvalidRegexEscape(self.index))
+                }
+                // Otherwise the `\` is a literal character, and the `c` is parsed again after it.
+                // https://tc39.es/ecma262/#prod-annexB-ExtendedAtom
+                _ => {
+                    self.index = start + 1;
+
+                    u32::from('\\')
+                }
+            },
+            '0' if !self.current_char().is_some_and(|ch| ch.is_ascii_digit()) => 0,
+            '0'..='9' if self.unicode_mode => {
+                return Err(ParserErrorKind::InvalidRegexEscape(start))
+            }
+            // https://tc39.es/ecma262/#prod-annexB-LegacyOctalEscapeSequence
+            '0'..='7' => self.parse_legacy_octal_escape(ch),
+            // https://tc39.es/ecma262/#prod-HexEscapeSequence
+            'x' => match self.parse_hex_digits(2) {
+                Some(value) => value,
+                None if self.unicode_mode => {
+                    return Err(ParserErrorKind::InvalidRegexEscape(self.index))
+                }
+                None => u32::from('x'),
+            },
+            'u' => match self.parse_regexp_unicode_escape(self.unicode_mode) {
+                Some(value) => value,
+                None if self.unicode_mode => {
+                    return Err(ParserErrorKind::InvalidRegexEscape(self.index))
+                }
+                None => u32::from('u'),
+            },
+            // https://tc39.es/ecma262/#prod-IdentityEscape
+            ch if self.unicode_mode => {
+                if !is_syntax_character(ch) && ch != '/' && !(in_class && ch == '-') {
+                    return Err(ParserErrorKind::InvalidRegexEscape(start));
+                }
+
+                u32::from(ch)
+            }
+            // https://tc39.es/ecma262/#prod-annexB-IdentityEscape
+            'k' if self.named_capture_groups => {
+                return Err(ParserErrorKind::InvalidRegexEscape(start))
+            }
+            ch => u32::from(ch),
+        };
+
+        Ok(Character {
+            start,
+            end: self.index,
+            value,
+        })
+    }
+
+    // https://tc39.es/ecma262/#prod-annexB-LegacyOctalEscapeSequence
+    fn parse_legacy_octal_escape(&mut self, first_digit: char) -> u32 {
+        let mut value = first_digit.to_digit(8).unwrap_or_default();
+
+        // Escapes starting with 0-3 can have up to three digits, so that the value never exceeds 0o377.
+        let max_length = if first_digit <= '3' { 3 } else { 2 };
+
+        for _ in 1..max_length {
+            let Some(digit) = self.current_char().and_then(|ch| ch.to_digit(8)) else {
+                break;
+            };
+
+            value = value * 8 + digit;
+
+            self.read_char();
+        }
+
+        value
+    }
+
+    fn parse_hex_digits(&mut self, length: usize) -> Option<u32> {
+        let start = self.index;
+
+        let mut value = 0;
+
+        for _ in 0..length {
+            let Some(digit) = self.current_char().and_then(|ch| ch.to_digit(16)) else {
+                self.index = start;
+
+                return None;
+            };
+
+            value = value * 16 + digit;
+
+            self.read_char();
+        }
+
+        Some(value)
+    }
+
+    // https://tc39.es/ecma262/#prod-RegExpUnicodeEscapeSequence
+    pub(crate) fn parse_regexp_unicode_escape(&mut self, unicode_mode: bool) -> Option<u32> {
+        let start = self.index;
+
+        if unicode_mode && self.eat_char('{') {
+            let mut value: Option<u32> = None;
+
+            while let Some(digit) = self.current_char().and_then(|ch| ch.to_digit(16)) {
+                value = Some(value.unwrap_or(0).saturating_mul(16).saturating_add(digit));
+
+                self.read_char();
+            }
+
+            match value {
+                Some(value) if value <= 0x10FFFF && self.eat_char('}') => return Some(value),
+                _ => {
+                    self.index = start;
+
+                    return None;
+                }
+            }
+        }
+
+        let lead = self.parse_hex_digits(4)?;
+
+        // In Unicode mode, an escaped surrogate pair is a single code point.
+        if unicode_mode && (0xD800..=0xDBFF).contains(&lead) {
+            let trail_start = self.index;
+
+            if self.eat_str("\\u") {
+                match self.parse_hex_digits(4) {
+                    Some(trail) if (0xDC00..=0xDFFF).contains(&trail) => {
+                        return Some(0x10000 + ((lead - 0xD800) << 10) + (trail - 0xDC00));
+                    }
+                    _ => self.index = trail_start,
+                }
+            }
+        }
+
+        Some(lead)
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{
+    ast::regexp::{Flags, RegExp},
+    ParserErrorKind,
+};
+
+mod character_class;
+mod disjunction;
+mod escapes;
+mod offsets;
+mod unicode_property;
+
+pub(crate) use offsets::convert_offsets;
+
+// 22.2 RegExp (Regular Expression) Objects
+// https://tc39.es/ecma262/#sec-regexp-regular-expression-objects
+pub(crate) fn parse_regular_expression(
+    pattern: &str,
+    flags: &str,
+) -> Result<RegExp, ParserErrorKind> {
+    let flags = parse_flags(flags)?;
+
+    let pattern = RegExpParser::new(pattern, &flags).parse_pattern()?;
+
+    Ok(RegExp { pattern, flags })
+}
+
+// https://tc39.es/ecma262/#sec-regexpinitialize
+fn parse_flags(flags: &str) -> Result<Flags, ParserErrorKind> {
+    let mut parsed_flags = Flags::default();
+
+    for (index, flag) in flags.char_indices() {
+        let parsed_flag = match flag {
+            'd' => &mut parsed_flags.has_indices,
+            'g' => &mut parsed_flags.global,
+            'i' => &mut parsed_flags.ignore_case,
+            'm' => &mut parsed_flags.multiline,
+            's' => &mut parsed_flags.dot_all,
+            'u' => &mut parsed_flags.unicode,
+            'v' => &mut parsed_flags.unicode_sets,
+            'y' => &mut parsed_flags.sticky,
+            _ => return Err(ParserErrorKind::InvalidRegexFlag(index)),
+        };
+
+        if *parsed_flag {
+            return Err(ParserErrorKind::DuplicateRegexFlag(index));
+        }
+
+        *parsed_flag = true;
+
+        if parsed_flags.unicode && parsed_flags.unicode_sets {
+            return Err(ParserErrorKind::InvalidRegexFlagCombination(index));
+        }
+    }
+
+    Ok(parsed_flags)
+}
+
+// Count the capturing groups ahead of parsing, as whether `\2` is a backreference depends on groups which may come after it.
+// https://tc39.es/ecma262/#sec-countleftcapturingparenswithin
+fn count_capturing_groups(source: &str, unicode_sets_mode: bool) -> (u32, bool) {
+    let bytes = source.as_bytes();
+
+    let mut count = 0;
+    let mut has_group_names = false;
+    // Character classes can only be nested with the `v` flag.
+    let mut class_depth = 0;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        match bytes[index] {
+            // Skip the escaped character. Only ASCII characters are significant here, so skipping a single byte is enough.
+            b'\\' => index += 1,
+            b'[' if class_depth == 0 || unicode_sets_mode => class_depth += 1,
+            b']' if class_depth > 0 => class_depth -= 1,
+            b'(' if class_depth == 0 => match (bytes.get(index + 1), bytes.get(index + 2)) {
+                (Some(b'?'), Some(b'<')) => {
+                    if !matches!(bytes.get(index + 3), Some(b'=' | b'!')) {
+                        count += 1;
+                        has_group_names = true;
+                    }
+                }
+                (Some(b'?'), _) => {}
+                _ => count += 1,
+            },
+            _ => {}
+        }
+
+        index += 1;
+    }
+
+    (count, has_group_names)
+}
+
+// A recursive descent parser for the pattern of a regular expression literal. The pattern has already been scanned by the lexer, so this only deals with its inner syntax.
+// https://tc39.es/ecma262/#sec-patterns
+pub(crate) struct RegExpParser<'a> {
+    source: &'a str,
+    index: usize,
+    // Set by either the `u` or the `v` flag.
+    unicode_mode: bool,
+    unicode_sets_mode: bool,
+    // `\k` is only a named backreference in Unicode mode or when the pattern contains a group name, otherwise it is an identity escape.
+    // https://tc39.es/ecma262/#sec-parsepattern
+    named_capture_groups: bool,
+    capturing_group_count: u32,
+    next_group_index: u32,
+    // The alternatives the parser is currently inside of, as pairs of disjunction and alternative indexes, used to check whether two groups with the same name can both participate in a match.
+    alternative_path: Vec<(u32, u32)>,
+    disjunction_count: u32,
+    group_names: Vec<(String, Vec<(u32, u32)>)>,
+    // Named backreferences are checked once the whole pattern has been parsed, as they can refer to groups which come after them.
+    named_backreferences: Vec<(String, usize)>,
+}
+
+impl<'a> RegExpParser<'a> {
+    pub(crate) fn new(source: &'a str, flags: &Flags) -> Self {
+        let unicode_mode = flags.unicode || flags.unicode_sets;
+
+        let (capturing_group_count, has_group_names) =
+            count_capturing_groups(source, flags.unicode_sets);
+
+        Self {
+            source,
+            index: 0,
+            unicode_mode,
+            unicode_sets_mode: flags.unicode_sets,
+            named_capture_groups: unicode_mode || has_group_names,
+            capturing_group_count,
+            next_group_index: 0,
+            alternative_path: vec![],
+            disjunction_count: 0,
+            group_names: vec![],
+            named_backreferences: vec![],
+        }
+    }
+
+    fn current_char(&self) -> Option<char> {
+        self.source[self.index..].chars().next()
+    }
+
+    fn peek_char(&self) -> Option<char> {
+        self.source[self.index..].chars().nth(1)
+    }
+
+    fn read_char(&mut self) {
+        if let Some(ch) = self.current_char() {
+            self.index += ch.len_utf8();
+        }
+    }
+
+    fn eat_char(&mut self, ch: char) -> bool {
+        if self.current_char() == Some(ch) {
+            self.read_char();
+
+            return true;
+        }
+
+        false
+    }
+
+    fn eat_str(&mut self, str: &str) -> bool {
+        if self.source[self.index..].starts_with(str) {
+            self.index += str.len();
+
+            return true;
+        }
+
+        false
+    }
+
+    fn read_while(&mut self, predicate: fn(char) -> bool) -> &'a str {
+        let start = self.index;
+
+        while self.current_char().is_some_and(predicate) {
+            self.read_char();
+        }
+
+        &self.source[start..self.index]
+    }
+
+    fn is_end_of_pattern(&self) -> bool {
+        self.index >= self.source.len()
+    }
+}
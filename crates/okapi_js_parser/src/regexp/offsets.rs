@@ -0,0 +1,113 @@
+use crate::ast::regexp::*;
+
+// Converts the byte offsets of every node in the pattern with `offset`, e.g. into UTF-16 code units.
+pub(crate) fn convert_offsets(regexp: &mut RegExp, offset: &impl Fn(usize) -> usize) {
+    let pattern = &mut regexp.pattern;
+
+    convert_span(&mut pattern.start, &mut pattern.end, offset);
+
+    convert_alternatives(&mut pattern.alternatives, offset);
+}
+
+fn convert_span(start: &mut usize, end: &mut usize, offset: &impl Fn(usize) -> usize) {
+    *start = offset(*start);
+    *end = offset(*end);
+}
+
+fn convert_alternatives(alternatives: &mut [Alternative], offset: &impl Fn(usize) -> usize) {
+    for alternative in alternatives {
+        convert_span(&mut alternative.start, &mut alternative.end, offset);
+
+        for element in &mut alternative.elements {
+            convert_element(element, offset);
+        }
+    }
+}
+
+fn convert_element(element: &mut Element, offset: &impl Fn(usize) -> usize) {
+    match element {
+        Element::BoundaryAssertion(assertion) => {
+            convert_span(&mut assertion.start, &mut assertion.end, offset);
+        }
+        Element::LookaroundAssertion(assertion) => {
+            convert_span(&mut assertion.start, &mut assertion.end, offset);
+            convert_alternatives(&mut assertion.alternatives, offset);
+        }
+        Element::Quantifier(quantifier) => {
+            convert_span(&mut quantifier.start, &mut quantifier.end, offset);
+            convert_element(&mut quantifier.element, offset);
+        }
+        Element::Group(group) => {
+            convert_span(&mut group.start, &mut group.end, offset);
+
+            if let Some(modifiers) = &mut group.modifiers {
+                convert_span(&mut modifiers.start, &mut modifiers.end, offset);
+                convert_span(&mut modifiers.add.start, &mut modifiers.add.end, offset);
+
+                if let Some(remove) = &mut modifiers.remove {
+                    convert_span(&mut remove.start, &mut remove.end, offset);
+                }
+            }
+
+            convert_alternatives(&mut group.alternatives, offset);
+        }
+        Element::CapturingGroup(group) => {
+            convert_span(&mut group.start, &mut group.end, offset);
+            convert_alternatives(&mut group.alternatives, offset);
+        }
+        Element::Character(character) => {
+            convert_span(&mut character.start, &mut character.end, offset);
+        }
+        Element::CharacterSet(set) => convert_span(&mut set.start, &mut set.end, offset),
+        Element::CharacterClass(class) => convert_character_class(class, offset),
+        Element::Backreference(backreference) => {
+            convert_span(&mut backreference.start, &mut backreference.end, offset);
+        }
+    }
+}
+
+fn convert_character_class(class: &mut CharacterClass, offset: &impl Fn(usize) -> usize) {
+    convert_span(&mut class.start, &mut class.end, offset);
+
+    for element in &mut class.elements {
+        convert_class_element(element, offset);
+    }
+}
+
+fn convert_class_element(element: &mut CharacterClassElement, offset: &impl Fn(usize) -> usize) {
+    match element {
+        CharacterClassElement::Character(character) => {
+            convert_span(&mut character.start, &mut character.end, offset);
+        }
+        CharacterClassElement::CharacterSet(set) => {
+            convert_span(&mut set.start, &mut set.end, offset);
+        }
+        CharacterClassElement::CharacterClassRange(range) => {
+            convert_span(&mut range.start, &mut range.end, offset);
+            convert_span(&mut range.min.start, &mut range.min.end, offset);
+            convert_span(&mut range.max.start, &mut range.max.end, offset);
+        }
+        CharacterClassElement::CharacterClass(class) => convert_character_class(class, offset),
+        CharacterClassElement::ClassStringDisjunction(disjunction) => {
+            convert_span(&mut disjunction.start, &mut disjunction.end, offset);
+
+            for alternative in &mut disjunction.alternatives {
+                convert_span(&mut alternative.start, &mut alternative.end, offset);
+
+                for character in &mut alternative.elements {
+                    convert_span(&mut character.start, &mut character.end, offset);
+                }
+            }
+        }
+        CharacterClassElement::ClassIntersection(intersection) => {
+            convert_span(&mut intersection.start, &mut intersection.end, offset);
+            convert_class_element(&mut intersection.left, offset);
+            convert_class_element(&mut intersection.right, offset);
+        }
+        CharacterClassElement::ClassSubtraction(subtraction) => {
+            convert_span(&mut subtraction.start, &mut subtraction.end, offset);
+            convert_class_element(&mut subtraction.left, offset);
+            convert_class_element(&mut subtraction.right, offset);
+        }
+    }
+}
@@ -1,3 +1,5 @@
+use std::borrow::Cow;
+
 // 12.7.2 Keywords and Reserved Words
 // https://tc39.es/ecma262/#sec-keywords-and-reserved-words
 #[derive(Clone, Debug, PartialEq)]
@@ -59,6 +61,24 @@ pub enum KeywordKind {
     Of,
     Set,
     Target,
+    Using,
+    Accessor,
+
+    // TypeScript contextual keywords, which are parsed as identifiers unless TypeScript is enabled.
+    Abstract,
+    Asserts,
+    Declare,
+    Global,
+    Infer,
+    Is,
+    Keyof,
+    Module,
+    Namespace,
+    Override,
+    Readonly,
+    Satisfies,
+    Type,
+    Unique,
 }
 
 impl std::fmt::Display for KeywordKind {
@@ -117,54 +137,72 @@ impl std::fmt::Display for KeywordKind {
             KeywordKind::Of => write!(f, "of"),
             KeywordKind::Set => write!(f, "set"),
             KeywordKind::Target => write!(f, "target"),
+            KeywordKind::Using => write!(f, "using"),
+            KeywordKind::Accessor => write!(f, "accessor"),
+            KeywordKind::Abstract => write!(f, "abstract"),
+            KeywordKind::Asserts => write!(f, "asserts"),
+            KeywordKind::Declare => write!(f, "declare"),
+            KeywordKind::Global => write!(f, "global"),
+            KeywordKind::Infer => write!(f, "infer"),
+            KeywordKind::Is => write!(f, "is"),
+            KeywordKind::Keyof => write!(f, "keyof"),
+            KeywordKind::Module => write!(f, "module"),
+            KeywordKind::Namespace => write!(f, "namespace"),
+            KeywordKind::Override => write!(f, "override"),
+            KeywordKind::Readonly => write!(f, "readonly"),
+            KeywordKind::Satisfies => write!(f, "satisfies"),
+            KeywordKind::Type => write!(f, "type"),
+            KeywordKind::Unique => write!(f, "unique"),
         }
     }
 }
 
+// Raw values are borrowed from the source text. Cooked values are only owned when they differ from the source text,
+// e.g. a string literal with an escape sequence or JSX text with an HTML entity.
 #[derive(Clone, Debug, PartialEq)]
-pub enum TokenValue {
-    String { raw: String, value: String },
-    Number { raw: String, value: f64 },
+pub enum TokenValue<'a> {
+    String { raw: &'a str, value: Cow<'a, str> },
+    Number { raw: &'a str, value: f64 },
     Boolean(bool),
-    BigInt(String),
-    RegularExpression { pattern: String, flags: String },
-    Template { raw: String, cooked: String },
+    BigInt(&'a str),
+    RegularExpression { pattern: &'a str, flags: &'a str },
+    Template { raw: &'a str, cooked: Cow<'a, str> },
     Null,
 }
 
-impl From<TokenValue> for String {
+impl From<TokenValue<'_>> for String {
     fn from(value: TokenValue) -> Self {
         match value {
-            TokenValue::String { raw, .. } => raw,
-            TokenValue::Number { raw, .. } => raw,
+            TokenValue::String { raw, .. } => raw.to_string(),
+            TokenValue::Number { raw, .. } => raw.to_string(),
             TokenValue::Boolean(b) => b.to_string(),
-            TokenValue::BigInt(s) => s,
-            TokenValue::RegularExpression { pattern, .. } => pattern,
-            TokenValue::Template { raw, .. } => raw,
+            TokenValue::BigInt(s) => s.to_string(),
+            TokenValue::RegularExpression { pattern, .. } => pattern.to_string(),
+            TokenValue::Template { raw, .. } => raw.to_string(),
             TokenValue::Null => "null".to_string(),
         }
     }
 }
 
 #[derive(Clone, Debug, PartialEq)]
-pub struct Token {
+pub struct Token<'a> {
     pub kind: TokenKind,
     pub start: usize,
     pub end: usize,
     pub line: usize,
     pub column: usize,
-    pub value: TokenValue,
+    pub value: TokenValue<'a>,
     pub line_terminator: bool,
 }
 
-impl Token {
+impl<'a> Token<'a> {
     pub fn new(
         kind: TokenKind,
         start: usize,
         end: usize,
         line: usize,
         column: usize,
-        value: TokenValue,
+        value: TokenValue<'a>,
     ) -> Self {
         Self {
             kind,
@@ -249,6 +287,8 @@ pub enum TokenKind {
     Division,
     DivisionAssignment,
     RightCurlyBrace,
+    // Only used by decorators, which are not yet part of the specification.
+    At,
 
     // Template Literals
     TemplateNoSubstitution,
@@ -256,6 +296,10 @@ pub enum TokenKind {
     TemplateMiddle,
     TemplateTail,
 
+    // JSX, which is only scanned when parsing JSX.
+    JSXIdentifier,
+    JSXText,
+
     // Utility
     Illegal,
     EOF,
@@ -364,7 +408,10 @@ impl TokenKind {
     pub(crate) fn is_property_name(&self) -> bool {
         matches!(
             self,
-            TokenKind::StringLiteral | TokenKind::NumberLiteral | TokenKind::LeftSquareBracket
+            TokenKind::StringLiteral
+                | TokenKind::NumberLiteral
+                | TokenKind::BigIntLiteral
+                | TokenKind::LeftSquareBracket
         ) || self.is_identifier_name()
     }
 
@@ -538,6 +585,74 @@ impl TokenKind {
     pub(crate) fn is_class_element_name(&self) -> bool {
         matches!(self, TokenKind::PrivateIdentifier) || self.is_property_name()
     }
+
+    // 12.8 Punctuators
+    // https://tc39.es/ecma262/#sec-punctuators
+    pub(crate) fn punctuator(&self) -> Option<&'static str> {
+        let punctuator = match self {
+            TokenKind::OptionalChaining => "?.",
+            TokenKind::LeftCurlyBrace => "{",
+            TokenKind::LeftParenthesis => "(",
+            TokenKind::RightParenthesis => ")",
+            TokenKind::LeftSquareBracket => "[",
+            TokenKind::RightSquareBracket => "]",
+            TokenKind::Dot => ".",
+            TokenKind::Ellipsis => "...",
+            TokenKind::Semicolon => ";",
+            TokenKind::Comma => ",",
+            TokenKind::LessThan => "<",
+            TokenKind::GreaterThan => ">",
+            TokenKind::LessThanOrEqual => "<=",
+            TokenKind::GreaterThanOrEqual => ">=",
+            TokenKind::Equal => "==",
+            TokenKind::NotEqual => "!=",
+            TokenKind::StrictEqual => "===",
+            TokenKind::StrictNotEqual => "!==",
+            TokenKind::Addition => "+",
+            TokenKind::Subtraction => "-",
+            TokenKind::Multiplication => "*",
+            TokenKind::Modulus => "%",
+            TokenKind::Exponentiation => "**",
+            TokenKind::Increment => "++",
+            TokenKind::Decrement => "--",
+            TokenKind::LeftShift => "<<",
+            TokenKind::RightShift => ">>",
+            TokenKind::UnsignedRightShift => ">>>",
+            TokenKind::BitwiseAnd => "&",
+            TokenKind::BitwiseOr => "|",
+            TokenKind::BitwiseXor => "^",
+            TokenKind::LogicalNot => "!",
+            TokenKind::BitwiseNot => "~",
+            TokenKind::LogicalAnd => "&&",
+            TokenKind::LogicalOr => "||",
+            TokenKind::NullishCoalescing => "??",
+            TokenKind::QuestionMark => "?",
+            TokenKind::Colon => ":",
+            TokenKind::Assignment => "=",
+            TokenKind::AdditionAssignment => "+=",
+            TokenKind::MinusAssignment => "-=",
+            TokenKind::MultiplyAssignment => "*=",
+            TokenKind::ModulusAssignment => "%=",
+            TokenKind::ExponentiationAssignment => "**=",
+            TokenKind::LeftShiftAssignment => "<<=",
+            TokenKind::RightShiftAssignment => ">>=",
+            TokenKind::UnsignedRightShiftAssignment => ">>>=",
+            TokenKind::BitwiseAndAssignment => "&=",
+            TokenKind::BitwiseOrAssignment => "|=",
+            TokenKind::BitwiseXorAssignment => "^=",
+            TokenKind::LogicalAndAssignment => "&&=",
+            TokenKind::LogicalOrAssignment => "||=",
+            TokenKind::NullishCoalescingAssignment => "??=",
+            TokenKind::ArrowFunction => "=>",
+            TokenKind::Division => "/",
+            TokenKind::DivisionAssignment => "/=",
+            TokenKind::RightCurlyBrace => "}",
+            TokenKind::At => "@",
+            _ => return None,
+        };
+
+        Some(punctuator)
+    }
 }
 
 impl std::fmt::Display for TokenKind {
@@ -607,10 +722,13 @@ impl std::fmt::Display for TokenKind {
             TokenKind::Division => write!(f, "Division"),
             TokenKind::DivisionAssignment => write!(f, "DivisionAssignment"),
             TokenKind::RightCurlyBrace => write!(f, "RightCurlyBrace"),
+            TokenKind::At => write!(f, "At"),
             TokenKind::TemplateNoSubstitution => write!(f, "NoSubstitutionTemplate"),
             TokenKind::TemplateHead => write!(f, "TemplateHead"),
             TokenKind::TemplateMiddle => write!(f, "TemplateMiddle"),
             TokenKind::TemplateTail => write!(f, "TemplateTail"),
+            TokenKind::JSXIdentifier => write!(f, "JSXIdentifier"),
+            TokenKind::JSXText => write!(f, "JSXText"),
             TokenKind::Illegal => write!(f, "Illegal"),
             TokenKind::EOF => write!(f, "EOF"),
         }
@@ -29,3 +29,13 @@ fn multi_line_comment() {
         vec![string_literal("hello", "'hello'", 16, 23, 4, 1)]
     );
 }
+
+#[test]
+fn hashbang_comment() {
+    assert_lexer_eq!(
+        "#!/usr/bin/env node
+'hello'",
+        vec![string_literal("hello", "'hello'", 20, 27, 2, 1)]
+    );
+    assert_lexer_eq!("#!", Vec::<okapi_js_parser::Token>::new());
+}
@@ -18,9 +18,11 @@ macro_rules! assert_lexer_eq {
                 .unwrap_or_else(|| panic!("Unexpected end to queue"));
 
             assert_eq!(
-                expected_token, token,
+                Ok(expected_token.clone()),
+                token,
                 "Expected token {:?}, but found {:?}",
-                expected_token, token,
+                expected_token,
+                token,
             );
         }
 
@@ -1,4 +1,4 @@
-use okapi_js_parser::{KeywordKind, Token, TokenKind, TokenValue};
+use okapi_js_parser::{KeywordKind, Lexer, Token, TokenKind, TokenValue};
 
 use crate::lexer::{
     assert_lexer_eq,
@@ -19,6 +19,37 @@ fn identifier_part() {
     assert_lexer_eq!("$$$$", vec![identifier("$$$$", 0, 4, 1, 1)]);
 }
 
+#[test]
+fn identifier_non_ascii() {
+    // Token positions are byte offsets into the UTF-8 source.
+    assert_lexer_eq!(
+        "ünïcödé = 1",
+        vec![
+            identifier("ünïcödé", 0, 11, 1, 1),
+            punctuator(TokenKind::Assignment, 12, 13, 1, 9),
+            number_literal("1", 1.0, 14, 15, 1, 11),
+        ]
+    );
+    assert_lexer_eq!("ಠ_ಠ", vec![identifier("ಠ_ಠ", 0, 7, 1, 1)]);
+}
+
+#[test]
+fn identifier_source_slice() {
+    let source = "let 𠮷 = 'ünïcödé';";
+
+    let mut lexer = Lexer::new(source);
+
+    let mut slices = vec![];
+
+    while !lexer.is_end_of_file() {
+        let token = lexer.next_token().unwrap();
+
+        slices.push(&source[token.start..token.end]);
+    }
+
+    assert_eq!(slices, vec!["let", "𠮷", "=", "'ünïcödé'", ";"]);
+}
+
 #[test]
 fn complex_keywords_and_identifiers() {
     assert_lexer_eq!(
@@ -31,8 +62,8 @@ fn complex_keywords_and_identifiers() {
                 1,
                 1,
                 TokenValue::String {
-                    raw: "const".to_string(),
-                    value: "const".to_string()
+                    raw: "const",
+                    value: "const".into()
                 }
             ),
             identifier("foo", 6, 9, 1, 7),
@@ -52,8 +83,8 @@ fn complex_keywords_and_identifiers() {
                 1,
                 1,
                 TokenValue::String {
-                    raw: "while".to_string(),
-                    value: "while".to_string()
+                    raw: "while",
+                    value: "while".into()
                 }
             ),
             punctuator(TokenKind::LeftParenthesis, 6, 7, 1, 7),
@@ -77,8 +108,8 @@ fn complex_keywords_and_identifiers() {
                 1,
                 1,
                 TokenValue::String {
-                    raw: "while".to_string(),
-                    value: "while".to_string()
+                    raw: "while",
+                    value: "while".into()
                 }
             ),
             punctuator(TokenKind::LeftParenthesis, 6, 7, 1, 7),
@@ -102,8 +133,8 @@ fn complex_keywords_and_identifiers() {
                 1,
                 1,
                 TokenValue::String {
-                    raw: "let".to_string(),
-                    value: "let".to_string()
+                    raw: "let",
+                    value: "let".into()
                 }
             ),
             identifier("baz", 4, 7, 1, 5),
@@ -123,8 +154,8 @@ fn complex_keywords_and_identifiers() {
                 1,
                 1,
                 TokenValue::String {
-                    raw: "var".to_string(),
-                    value: "var".to_string()
+                    raw: "var",
+                    value: "var".into()
                 }
             ),
             identifier("baz", 4, 7, 1, 5),
@@ -157,8 +188,8 @@ fn complex_keywords_and_identifiers() {
                 1,
                 1,
                 TokenValue::String {
-                    raw: "class".to_string(),
-                    value: "class".to_string()
+                    raw: "class",
+                    value: "class".into()
                 },
             ),
             identifier("Foo", 6, 9, 1, 7),
@@ -170,8 +201,8 @@ fn complex_keywords_and_identifiers() {
                 line: 1,
                 column: 13,
                 value: TokenValue::String {
-                    raw: "bar".to_string(),
-                    value: "bar".to_string()
+                    raw: "bar",
+                    value: "bar".into()
                 },
                 line_terminator: false
             },
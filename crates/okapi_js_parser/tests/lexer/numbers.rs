@@ -1,8 +1,8 @@
-use okapi_js_parser::{ParserError, Token, TokenKind, TokenValue};
+use okapi_js_parser::{Lexer, ParserError, ParserErrorKind, Token, TokenKind, TokenValue};
 
 use crate::lexer::{
     assert_lexer_eq,
-    utils::{identifier, illegal, number_literal},
+    utils::{identifier, number_literal},
 };
 
 #[test]
@@ -169,23 +169,27 @@ fn numbers_numeric_separator() {
 
 #[test]
 fn numbers_numeric_separator_invalid() {
-    assert_lexer_eq!(
-        "123__456_789",
-        vec![
-            illegal(ParserError::InvalidNumericSeparatorAtSibling, 0, 4, 1, 1),
-            identifier("_456_789", 4, 12, 1, 5)
-        ]
+    let mut lexer = Lexer::new("123__456_789");
+
+    assert_eq!(
+        lexer.next_token(),
+        Err(ParserError::new(
+            ParserErrorKind::InvalidNumericSeparatorAtSibling,
+            0,
+            4
+        ))
     );
+    assert_eq!(lexer.next_token(), Ok(identifier("_456_789", 4, 12, 1, 5)));
 
-    assert_lexer_eq!(
-        "123_456_789_",
-        vec![illegal(
-            ParserError::InvalidNumericSeparatorAtEnd,
+    let mut lexer = Lexer::new("123_456_789_");
+
+    assert_eq!(
+        lexer.next_token(),
+        Err(ParserError::new(
+            ParserErrorKind::InvalidNumericSeparatorAtEnd,
             0,
-            12,
-            1,
-            1
-        ),]
+            12
+        ))
     );
 }
 
@@ -201,7 +205,7 @@ fn numbers_exponent() {
             line: 1,
             column: 1,
             value: TokenValue::Number {
-                raw: "123123123e+6".to_string(),
+                raw: "123123123e+6",
                 value: 123123123000000.0
             },
             line_terminator: false
@@ -217,7 +221,7 @@ fn numbers_exponent() {
             line: 1,
             column: 1,
             value: TokenValue::Number {
-                raw: "123.123123E+6".to_string(),
+                raw: "123.123123E+6",
                 value: 123123123.0
             },
             line_terminator: false
@@ -233,7 +237,7 @@ fn numbers_exponent() {
             line: 1,
             column: 1,
             value: TokenValue::Number {
-                raw: "123123123e6".to_string(),
+                raw: "123123123e6",
                 value: 123123123000000.0
             },
             line_terminator: false
@@ -249,7 +253,7 @@ fn numbers_exponent() {
             line: 1,
             column: 1,
             value: TokenValue::Number {
-                raw: "123.123123E6".to_string(),
+                raw: "123.123123E6",
                 value: 123123123.0
             },
             line_terminator: false
@@ -266,7 +270,7 @@ fn numbers_exponent() {
             line: 1,
             column: 1,
             value: TokenValue::Number {
-                raw: "123123123e-15".to_string(),
+                raw: "123123123e-15",
                 value: 0.000000123123123
             },
             line_terminator: false
@@ -282,7 +286,7 @@ fn numbers_exponent() {
             line: 1,
             column: 1,
             value: TokenValue::Number {
-                raw: "123.123123E-15".to_string(),
+                raw: "123.123123E-15",
                 value: 0.000000000000123123123
             },
             line_terminator: false
@@ -300,7 +304,7 @@ fn numbers_big_int() {
             end: 4,
             line: 1,
             column: 1,
-            value: TokenValue::BigInt("123n".to_string()),
+            value: TokenValue::BigInt("123n"),
             line_terminator: false
         },]
     );
@@ -313,7 +317,33 @@ fn numbers_big_int() {
             end: 2,
             line: 1,
             column: 1,
-            value: TokenValue::BigInt("0n".to_string()),
+            value: TokenValue::BigInt("0n"),
+            line_terminator: false
+        }]
+    );
+
+    assert_lexer_eq!(
+        "0xFF_FFn",
+        vec![Token {
+            kind: TokenKind::BigIntLiteral,
+            start: 0,
+            end: 8,
+            line: 1,
+            column: 1,
+            value: TokenValue::BigInt("0xFF_FFn"),
+            line_terminator: false
+        }]
+    );
+
+    assert_lexer_eq!(
+        "0b101n",
+        vec![Token {
+            kind: TokenKind::BigIntLiteral,
+            start: 0,
+            end: 6,
+            line: 1,
+            column: 1,
+            value: TokenValue::BigInt("0b101n"),
             line_terminator: false
         }]
     );
@@ -321,22 +351,22 @@ fn numbers_big_int() {
 
 #[test]
 fn numbers_big_int_invalid() {
-    assert_lexer_eq!(
-        "123.123n",
-        vec![
-            Token {
-                kind: TokenKind::NumberLiteral,
-                start: 0,
-                end: 7,
-                line: 1,
-                column: 1,
-                value: TokenValue::Number {
-                    raw: "123.123".to_string(),
-                    value: 123.123
-                },
-                line_terminator: false
-            },
-            identifier("n", 7, 8, 1, 8)
-        ]
-    );
+    // Only integers without a leading zero can have a BigInt suffix.
+    for source in ["08n", "01n", "0777n", "123.123n", "1.5n", ".5n", "1e3n"] {
+        let mut lexer = Lexer::new(source);
+
+        assert_eq!(
+            lexer.next_token(),
+            Err(ParserError::new(
+                ParserErrorKind::InvalidDecimalBigIntegerLiteral,
+                0,
+                source.len()
+            )),
+            "{source}"
+        );
+        assert_eq!(
+            lexer.next_token().map(|token| token.kind),
+            Ok(TokenKind::EOF)
+        );
+    }
 }
@@ -1,3 +1,7 @@
+use std::borrow::Cow;
+
+use okapi_js_parser::{Lexer, TokenValue};
+
 use crate::lexer::{assert_lexer_eq, utils::string_literal};
 
 #[test]
@@ -158,7 +162,7 @@ fn strings_complex_graphemes() {
             r"abcdefghijklmnopqrstuvwxyz🙂12345678910''10🎉",
             r#""abcdefghijklmnopqrstuvwxyz🙂12345678910''10🎉""#,
             0,
-            45,
+            51,
             1,
             1
         )]
@@ -175,18 +179,18 @@ fn strings_empty() {
 fn strings_non_english_chars() {
     assert_lexer_eq!(
         "'دیوانه'",
-        vec![string_literal("دیوانه", r"'دیوانه'", 0, 8, 1, 1)]
+        vec![string_literal("دیوانه", r"'دیوانه'", 0, 14, 1, 1)]
     );
-    assert_lexer_eq!("'a℮'", vec![string_literal("a℮", r"'a℮'", 0, 4, 1, 1)]);
-    assert_lexer_eq!("'℘'", vec![string_literal("℘", r"'℘'", 0, 3, 1, 1)]);
-    assert_lexer_eq!("'a᧚'", vec![string_literal("a᧚", r"'a᧚'", 0, 4, 1, 1)]);
+    assert_lexer_eq!("'a℮'", vec![string_literal("a℮", r"'a℮'", 0, 6, 1, 1)]);
+    assert_lexer_eq!("'℘'", vec![string_literal("℘", r"'℘'", 0, 5, 1, 1)]);
+    assert_lexer_eq!("'a᧚'", vec![string_literal("a᧚", r"'a᧚'", 0, 6, 1, 1)]);
     assert_lexer_eq!(
         "'б И Й К Л О Ф Ц Ш Э ж з'",
         vec![string_literal(
             "б И Й К Л О Ф Ц Ш Э ж з",
             "'б И Й К Л О Ф Ц Ш Э ж з'",
             0,
-            25,
+            37,
             1,
             1
         )]
@@ -202,3 +206,24 @@ fn strings_line_terminators() {
         vec![string_literal("new-line", r#""new-line""#, 0, 10, 1, 1)]
     );
 }
+
+#[test]
+fn strings_value_is_only_owned_with_escape_sequences() {
+    let values = [
+        r"'hello world'",
+        r"'hello\nworld'",
+        "`hello`",
+        r"`hello\`world`",
+    ]
+    .map(
+        |source| match Lexer::new(source).next_token().unwrap().value {
+            TokenValue::String { value, .. } | TokenValue::Template { cooked: value, .. } => value,
+            _ => panic!("Expected a string or template value."),
+        },
+    );
+
+    assert!(matches!(&values[0], Cow::Borrowed("hello world")));
+    assert!(matches!(&values[1], Cow::Owned(value) if value == "hello\nworld"));
+    assert!(matches!(&values[2], Cow::Borrowed("hello")));
+    assert!(matches!(&values[3], Cow::Owned(value) if value == "hello`world"));
+}
@@ -1,13 +1,13 @@
-use okapi_js_parser::{ParserError, Token, TokenKind, TokenValue};
+use okapi_js_parser::{Token, TokenKind, TokenValue};
 
-pub fn string_literal(
-    value: &str,
-    raw: &str,
+pub fn string_literal<'a>(
+    value: &'a str,
+    raw: &'a str,
     start: usize,
     end: usize,
     line: usize,
     column: usize,
-) -> Token {
+) -> Token<'a> {
     Token {
         kind: TokenKind::StringLiteral,
         start,
@@ -15,14 +15,14 @@ pub fn string_literal(
         line,
         column,
         value: TokenValue::String {
-            raw: raw.to_string(),
-            value: value.to_string(),
+            raw,
+            value: value.into(),
         },
         line_terminator: false,
     }
 }
 
-pub fn identifier(value: &str, start: usize, end: usize, line: usize, column: usize) -> Token {
+pub fn identifier(value: &str, start: usize, end: usize, line: usize, column: usize) -> Token<'_> {
     Token {
         kind: TokenKind::Identifier,
         start,
@@ -30,14 +30,20 @@ pub fn identifier(value: &str, start: usize, end: usize, line: usize, column: us
         line,
         column,
         value: TokenValue::String {
-            raw: value.to_string(),
-            value: value.to_string(),
+            raw: value,
+            value: value.into(),
         },
         line_terminator: false,
     }
 }
 
-pub fn punctuator(kind: TokenKind, start: usize, end: usize, line: usize, column: usize) -> Token {
+pub fn punctuator(
+    kind: TokenKind,
+    start: usize,
+    end: usize,
+    line: usize,
+    column: usize,
+) -> Token<'static> {
     Token {
         kind,
         start,
@@ -49,14 +55,14 @@ pub fn punctuator(kind: TokenKind, start: usize, end: usize, line: usize, column
     }
 }
 
-pub fn number_literal(
-    raw_value: &str,
+pub fn number_literal<'a>(
+    raw_value: &'a str,
     parsed_value: f64,
     start: usize,
     end: usize,
     line: usize,
     column: usize,
-) -> Token {
+) -> Token<'a> {
     Token {
         kind: TokenKind::NumberLiteral,
         start,
@@ -64,20 +70,20 @@ pub fn number_literal(
         line,
         column,
         value: TokenValue::Number {
-            raw: raw_value.to_string(),
+            raw: raw_value,
             value: parsed_value,
         },
         line_terminator: false,
     }
 }
 
-pub fn template_literal_no_substitution(
-    value: &str,
+pub fn template_literal_no_substitution<'a>(
+    value: &'a str,
     start: usize,
     end: usize,
     line: usize,
     column: usize,
-) -> Token {
+) -> Token<'a> {
     Token {
         kind: TokenKind::TemplateNoSubstitution,
         start,
@@ -85,20 +91,20 @@ pub fn template_literal_no_substitution(
         line,
         column,
         value: TokenValue::Template {
-            raw: value.to_string(),
-            cooked: value.to_string(),
+            raw: value,
+            cooked: value.into(),
         },
         line_terminator: false,
     }
 }
 
-pub fn template_literal_head(
-    value: &str,
+pub fn template_literal_head<'a>(
+    value: &'a str,
     start: usize,
     end: usize,
     line: usize,
     column: usize,
-) -> Token {
+) -> Token<'a> {
     Token {
         kind: TokenKind::TemplateHead,
         start,
@@ -106,20 +112,20 @@ pub fn template_literal_head(
         line,
         column,
         value: TokenValue::Template {
-            raw: value.to_string(),
-            cooked: value.to_string(),
+            raw: value,
+            cooked: value.into(),
         },
         line_terminator: false,
     }
 }
 
-pub fn template_literal_middle(
-    value: &str,
+pub fn template_literal_middle<'a>(
+    value: &'a str,
     start: usize,
     end: usize,
     line: usize,
     column: usize,
-) -> Token {
+) -> Token<'a> {
     Token {
         kind: TokenKind::TemplateMiddle,
         start,
@@ -127,20 +133,20 @@ pub fn template_literal_middle(
         line,
         column,
         value: TokenValue::Template {
-            raw: value.to_string(),
-            cooked: value.to_string(),
+            raw: value,
+            cooked: value.into(),
         },
         line_terminator: false,
     }
 }
 
-pub fn template_literal_tail(
-    value: &str,
+pub fn template_literal_tail<'a>(
+    value: &'a str,
     start: usize,
     end: usize,
     line: usize,
     column: usize,
-) -> Token {
+) -> Token<'a> {
     Token {
         kind: TokenKind::TemplateTail,
         start,
@@ -148,23 +154,8 @@ pub fn template_literal_tail(
         line,
         column,
         value: TokenValue::Template {
-            raw: value.to_string(),
-            cooked: value.to_string(),
-        },
-        line_terminator: false,
-    }
-}
-
-pub fn illegal(value: ParserError, start: usize, end: usize, line: usize, column: usize) -> Token {
-    Token {
-        kind: TokenKind::Illegal,
-        start,
-        end,
-        line,
-        column,
-        value: TokenValue::String {
-            raw: value.to_string(),
-            value: value.to_string(),
+            raw: value,
+            cooked: value.into(),
         },
         line_terminator: false,
     }
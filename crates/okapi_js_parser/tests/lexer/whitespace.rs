@@ -17,8 +17,8 @@ fn whitespace_minified() {
                 1,
                 1,
                 TokenValue::String {
-                    raw: "const".to_string(),
-                    value: "const".to_string()
+                    raw: "const",
+                    value: "const".into()
                 }
             ),
             identifier("foo", 6, 9, 1, 7),
@@ -0,0 +1,38 @@
+import React from "react";
+
+const title = <h1 className="title">Hello, world!</h1>;
+
+const empty = <div />;
+
+const fragment = (
+  <>
+    <span>a</span>
+    <span>b</span>
+  </>
+);
+
+function List({ items, ...props }) {
+  return (
+    <ul {...props} data-count={items.length} hidden>
+      {items.map((item) => (
+        <li key={item.id} title='single "quoted"'>
+          {item.label} &amp; {/* comment */}
+        </li>
+      ))}
+      {}
+    </ul>
+  );
+}
+
+const member = <Foo.Bar.Baz value={1} />;
+
+const namespaced = <svg:rect xlink:href="#a" />;
+
+const nested = (
+  <Layout header={<Header title="a" />}>
+    text with &nbsp; entities &#169; &#x1F600;
+    {...children}
+  </Layout>
+);
+
+export default List;
@@ -1,7 +1,7 @@
 use std::{io, path::PathBuf};
 
 use assert_json_diff::assert_json_include;
-use okapi_js_parser::Parser;
+use okapi_js_parser::{OffsetEncoding, Parser, ParserOptions};
 use serde_json::Value;
 
 mod file;
@@ -19,7 +19,12 @@ pub(crate) fn read_fixture(path: &str) -> io::Result<String> {
 fn acorn_equality_react() {
     let fixture = read_fixture("react@18.2.0.development.js").unwrap();
 
-    let parsed = Parser::new(&fixture).parse_module_json().unwrap();
+    let parsed = Parser::new(
+        &fixture,
+        ParserOptions::new().offset_encoding(OffsetEncoding::Utf16),
+    )
+    .parse_module_json()
+    .unwrap();
     let parsed_json = serde_json::from_str::<Value>(&parsed).unwrap();
 
     let acorn_parsed_fixture = read_fixture("acorn/react@18.2.0.development.json").unwrap();
@@ -35,7 +40,12 @@ fn acorn_equality_react() {
 fn acorn_equality_react_dom() {
     let fixture = read_fixture("react-dom@18.2.0.development.js").unwrap();
 
-    let parsed = Parser::new(&fixture).parse_module_json().unwrap();
+    let parsed = Parser::new(
+        &fixture,
+        ParserOptions::new().offset_encoding(OffsetEncoding::Utf16),
+    )
+    .parse_module_json()
+    .unwrap();
     let parsed_json = serde_json::from_str::<Value>(&parsed).unwrap();
 
     let acorn_parsed_fixture = read_fixture("acorn/react-dom@18.2.0.development.json").unwrap();
@@ -48,7 +58,12 @@ fn acorn_equality_react_dom() {
 fn acorn_equality_angular() {
     let fixture = read_fixture("angular@1.8.3.js").unwrap();
 
-    let parsed = Parser::new(&fixture).parse_module_json().unwrap();
+    let parsed = Parser::new(
+        &fixture,
+        ParserOptions::new().offset_encoding(OffsetEncoding::Utf16),
+    )
+    .parse_module_json()
+    .unwrap();
     let parsed_json = serde_json::from_str::<Value>(&parsed).unwrap();
 
     let acorn_parsed_fixture = read_fixture("acorn/angular@1.8.3.json").unwrap();
@@ -61,7 +76,12 @@ fn acorn_equality_angular() {
 fn acorn_equality_three() {
     let fixture = read_fixture("three@0.163.0.js").unwrap();
 
-    let parsed = Parser::new(&fixture).parse_module_json().unwrap();
+    let parsed = Parser::new(
+        &fixture,
+        ParserOptions::new().offset_encoding(OffsetEncoding::Utf16),
+    )
+    .parse_module_json()
+    .unwrap();
     let parsed_json = serde_json::from_str::<Value>(&parsed).unwrap();
 
     let acorn_parsed_fixture = read_fixture("acorn/three@0.163.0.json").unwrap();
@@ -69,3 +89,25 @@ fn acorn_equality_three() {
 
     assert_json_include!( actual: parsed_json, expected: acorn_json);
 }
+
+// The expected output is generated by acorn-jsx, with `npm run generate:acorn`.
+#[test]
+#[ignore = "requires tests/parser/acorn/fixtures/acorn/jsx-elements.json from `npm run generate:acorn`"]
+fn acorn_equality_jsx() {
+    let fixture = read_fixture("jsx-elements.jsx").unwrap();
+
+    let parsed = Parser::new(
+        &fixture,
+        ParserOptions::new()
+            .jsx(true)
+            .offset_encoding(OffsetEncoding::Utf16),
+    )
+    .parse_module_json()
+    .unwrap();
+    let parsed_json = serde_json::from_str::<Value>(&parsed).unwrap();
+
+    let acorn_parsed_fixture = read_fixture("acorn/jsx-elements.json").unwrap();
+    let acorn_json = serde_json::from_str::<Value>(&acorn_parsed_fixture).unwrap();
+
+    assert_json_include!(actual: parsed_json, expected: acorn_json);
+}
@@ -0,0 +1,66 @@
+use okapi_js_parser::ast::{
+    CallExpression, CallExpressionCallee, Declaration, Expression, ExpressionStatement,
+    FunctionDeclaration, Identifier, ModuleItem, Program, ProgramBody, ProgramSource, Statement,
+    StatementListItem, VariableDeclaration, VariableKind,
+};
+use okapi_js_parser::{Parser, ParserOptions};
+
+#[test]
+fn ast_match_script_statements() {
+    let program: Program = Parser::new("function foo() {} foo();", ParserOptions::default())
+        .parse_script()
+        .unwrap();
+
+    assert_eq!(program.source_type, ProgramSource::Script);
+
+    let ProgramBody::StatementList(body) = program.body else {
+        panic!("Expected a script body.");
+    };
+
+    let [StatementListItem::Declaration(Declaration::Function(FunctionDeclaration {
+        id: Some(Identifier {
+            name: function_name,
+            ..
+        }),
+        ..
+    })), StatementListItem::Statement(Statement::Expression(ExpressionStatement {
+        expression: Expression::Call(CallExpression { callee, .. }),
+        ..
+    }))] = body.as_slice()
+    else {
+        panic!("Expected a function declaration followed by a call expression.");
+    };
+
+    assert_eq!(function_name, "foo");
+
+    let CallExpressionCallee::Expression(callee) = callee else {
+        panic!("Expected an expression callee.");
+    };
+
+    assert!(
+        matches!(callee.as_ref(), Expression::Identifier(Identifier { name, .. }) if name == "foo")
+    );
+}
+
+#[test]
+fn ast_match_module_items() {
+    let program = Parser::new("const a = 1;", ParserOptions::default())
+        .parse_module()
+        .unwrap();
+
+    assert_eq!(program.source_type, ProgramSource::Module);
+
+    let ProgramBody::Module(body) = program.body else {
+        panic!("Expected a module body.");
+    };
+
+    assert!(matches!(
+        body.as_slice(),
+        [ModuleItem::StatementListItem(
+            StatementListItem::Declaration(Declaration::Variable(VariableDeclaration {
+                kind: VariableKind::Const,
+                ..
+            }))
+        )]
+    ));
+}
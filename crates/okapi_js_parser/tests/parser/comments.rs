@@ -0,0 +1,138 @@
+use assert_json_diff::assert_json_include;
+use okapi_js_parser::{Parser, ParserOptions};
+use pretty_assertions::assert_eq;
+use serde_json::{json, Value};
+
+fn parse_with_options(source: &str, options: ParserOptions) -> Value {
+    let parsed = Parser::new(source, options).parse_json().unwrap();
+
+    serde_json::from_str::<Value>(&parsed).unwrap()
+}
+
+fn comment_values(source: &str) -> Vec<String> {
+    let program = Parser::new(source, ParserOptions::new().comments(true))
+        .parse()
+        .unwrap();
+
+    program
+        .comments
+        .unwrap()
+        .into_iter()
+        .map(|comment| comment.value)
+        .collect()
+}
+
+#[test]
+fn comments_are_not_added_by_default() {
+    let parsed = parse_with_options("// a\nb; /* c */", ParserOptions::default());
+
+    assert!(parsed.get("comments").is_none());
+    assert!(parsed["body"][0].get("leadingComments").is_none());
+}
+
+#[test]
+fn comments() {
+    assert_json_include!(
+        actual: parse_with_options(
+            "// a\nb; /* c\n*/",
+            ParserOptions::new().comments(true).ranges(true)
+        ),
+        expected: json!({
+            "type": "Program",
+            "comments": [
+                { "type": "Line", "value": " a", "start": 0, "end": 4, "range": [0, 4] },
+                { "type": "Block", "value": " c\n", "start": 8, "end": 15, "range": [8, 15] }
+            ]
+        })
+    );
+}
+
+#[test]
+fn comments_in_source_order() {
+    assert_eq!(
+        comment_values("/*a*/ (/*b*/ c /*c*/) => /*d*/ `${/*e*/ f}`;"),
+        vec!["a", "b", "c", "d", "e"]
+    );
+
+    // Comment-like text inside strings, templates and regular expressions is not a comment.
+    assert_eq!(
+        comment_values("'// a'; `/* b */ ${c /* d */}`; /\\/* e/; // f"),
+        vec![" d ", " f"]
+    );
+}
+
+#[test]
+fn attach_comments() {
+    assert_json_include!(
+        actual: parse_with_options(
+            "// a\nb; /* c */\nd(/* e */);\n// f",
+            ParserOptions::new().attach_comments(true)
+        ),
+        expected: json!({
+            "type": "Program",
+            "body": [
+                {
+                    "type": "ExpressionStatement",
+                    "leadingComments": [{ "type": "Line", "value": " a" }],
+                    "trailingComments": [{ "type": "Block", "value": " c " }]
+                },
+                {
+                    "type": "ExpressionStatement",
+                    "leadingComments": [{ "type": "Block", "value": " c " }],
+                    "trailingComments": [{ "type": "Line", "value": " f" }],
+                    "expression": {
+                        "type": "CallExpression",
+                        "callee": { "type": "Identifier", "name": "d", "trailingComments": [{ "type": "Block", "value": " e " }] }
+                    }
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn attach_comments_to_the_outermost_node() {
+    let parsed = parse_with_options(
+        "function a(/* b */ c) { /* d */ return c; /* e */ }",
+        ParserOptions::new().attach_comments(true),
+    );
+
+    let function = &parsed["body"][0];
+
+    assert_json_include!(
+        actual: function,
+        expected: json!({
+            "params": [{ "type": "Identifier", "name": "c", "leadingComments": [{ "value": " b " }] }],
+            "body": {
+                "body": [{
+                    "type": "ReturnStatement",
+                    "leadingComments": [{ "value": " d " }],
+                    "trailingComments": [{ "value": " e " }]
+                }]
+            }
+        })
+    );
+
+    assert!(function["body"]["body"][0]["argument"]
+        .get("trailingComments")
+        .is_none());
+}
+
+#[test]
+fn jsx_text_is_not_a_comment() {
+    let program = Parser::new(
+        "<a>// b {/* c */}</a>; // d",
+        ParserOptions::new().comments(true).jsx(true),
+    )
+    .parse()
+    .unwrap();
+
+    let values = program
+        .comments
+        .unwrap()
+        .into_iter()
+        .map(|comment| comment.value)
+        .collect::<Vec<String>>();
+
+    assert_eq!(values, vec![" c ", " d"]);
+}
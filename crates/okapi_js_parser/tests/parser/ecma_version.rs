@@ -0,0 +1,285 @@
+use okapi_js_parser::{
+    ast::ProgramSource, ECMAVersion, Feature, Parser, ParserErrorKind, ParserOptions,
+};
+use pretty_assertions::assert_eq;
+
+fn parse_module_with_ecma_version(
+    source: &str,
+    ecma_version: ECMAVersion,
+) -> Result<(), ParserErrorKind> {
+    let options = ParserOptions::new()
+        .ecma_version(ecma_version)
+        .source_type(ProgramSource::Module);
+
+    Parser::new(source, options)
+        .parse()
+        .map(|_| ())
+        .map_err(|error| error.kind)
+}
+
+const ECMA_VERSIONS: [ECMAVersion; 14] = [
+    ECMAVersion::Three,
+    ECMAVersion::Five,
+    ECMAVersion::SixOrTwentyFifteen,
+    ECMAVersion::TwentySixteen,
+    ECMAVersion::TwentySeventeen,
+    ECMAVersion::TwentyEighteen,
+    ECMAVersion::TwentyNineteen,
+    ECMAVersion::TwentyTwenty,
+    ECMAVersion::TwentyTwentyOne,
+    ECMAVersion::TwentyTwentyTwo,
+    ECMAVersion::TwentyTwentyThree,
+    ECMAVersion::TwentyTwentyFour,
+    ECMAVersion::TwentyTwentyFive,
+    ECMAVersion::TwentyTwentySix,
+];
+
+// Each feature is rejected by the edition before it was added, and accepted by the edition it was added in.
+fn assert_feature_requires_version(source: &str, feature: Feature) {
+    let ecma_version = feature.ecma_version();
+
+    let index = ECMA_VERSIONS
+        .iter()
+        .position(|version| *version == ecma_version)
+        .unwrap();
+
+    let previous_ecma_version = ECMA_VERSIONS[index - 1];
+
+    assert_eq!(
+        parse_module_with_ecma_version(source, previous_ecma_version),
+        Err(ParserErrorKind::UnsupportedFeature(feature)),
+        "{}",
+        source
+    );
+
+    assert_eq!(
+        parse_module_with_ecma_version(source, ecma_version),
+        Ok(()),
+        "{}",
+        source
+    );
+}
+
+#[test]
+fn es5_features() {
+    assert_feature_requires_version("({ get a() {} });", Feature::PropertyAccessors);
+    assert_feature_requires_version("({ set a(b) {} });", Feature::PropertyAccessors);
+    assert_feature_requires_version("a.class;", Feature::ReservedWordPropertyNames);
+    assert_feature_requires_version("({ default: a });", Feature::ReservedWordPropertyNames);
+}
+
+#[test]
+fn es2015_features() {
+    assert_feature_requires_version("a => a;", Feature::ArrowFunctions);
+    assert_feature_requires_version("(a, b) => {};", Feature::ArrowFunctions);
+    assert_feature_requires_version("0b10;", Feature::BinaryAndOctalLiterals);
+    assert_feature_requires_version("({ 0O7: a });", Feature::BinaryAndOctalLiterals);
+    assert_feature_requires_version("class A {}", Feature::Classes);
+    assert_feature_requires_version("(class {});", Feature::Classes);
+    assert_feature_requires_version("for (a of b);", Feature::ForOf);
+    assert_feature_requires_version("function* f() {}", Feature::Generators);
+    assert_feature_requires_version("({ *f() {} });", Feature::Generators);
+    assert_feature_requires_version("let a;", Feature::LexicalDeclarations);
+    assert_feature_requires_version("const a = 1;", Feature::LexicalDeclarations);
+    assert_feature_requires_version("for (let a in b);", Feature::LexicalDeclarations);
+    assert_feature_requires_version("function f() { new.target; }", Feature::NewTarget);
+    assert_feature_requires_version("/a/y;", Feature::RegExpStickyFlag);
+    assert_feature_requires_version("/a/u;", Feature::RegExpUnicodeFlag);
+    assert_feature_requires_version("`a`;", Feature::TemplateLiterals);
+    assert_feature_requires_version("a`b${c}`;", Feature::TemplateLiterals);
+}
+
+#[test]
+fn es2015_pattern_parameter_and_object_literal_features() {
+    assert_feature_requires_version("var [a] = b;", Feature::Destructuring);
+    assert_feature_requires_version("var { a: c } = b;", Feature::Destructuring);
+    assert_feature_requires_version("[a] = b;", Feature::Destructuring);
+    assert_feature_requires_version("for ([a] in b);", Feature::Destructuring);
+    assert_feature_requires_version("function f({ a: b }) {}", Feature::Destructuring);
+    assert_feature_requires_version("try {} catch ([a]) {}", Feature::Destructuring);
+    assert_feature_requires_version("function f(a = 1) {}", Feature::DefaultParameters);
+    assert_feature_requires_version("function f(...a) {}", Feature::RestParameters);
+    assert_feature_requires_version("x = { a, b };", Feature::ShorthandProperties);
+    assert_feature_requires_version("({ a() {} });", Feature::ShorthandProperties);
+    assert_feature_requires_version("({ [a]: b });", Feature::ComputedProperties);
+    assert_feature_requires_version("[...a];", Feature::SpreadElements);
+    assert_feature_requires_version("f(...a);", Feature::SpreadElements);
+    assert_feature_requires_version("new F(...a);", Feature::SpreadElements);
+    assert_feature_requires_version("import a from 'a';", Feature::Modules);
+    assert_feature_requires_version("export var a;", Feature::Modules);
+    assert_feature_requires_version("\"\\u{1F600}\";", Feature::UnicodeCodePointEscapes);
+    assert_feature_requires_version("({ '\\u{1F600}': a });", Feature::UnicodeCodePointEscapes);
+}
+
+#[test]
+fn es5_syntax_is_always_supported() {
+    assert_eq!(
+        parse_module_with_ecma_version(
+            "var f = function (a) { return { a: a, 'b': [a, 0x1F] }; }; for (var b in f) /a/gim;",
+            ECMAVersion::Five
+        ),
+        Ok(())
+    );
+}
+
+// Features which are checked after their token has been consumed are still located at the token.
+#[test]
+fn unsupported_features_are_located_at_their_token() {
+    let located = [
+        (
+            "a = 1_000;
+b;",
+            ECMAVersion::TwentySeventeen,
+        ),
+        (
+            "({ 1_000: a });
+b;",
+            ECMAVersion::TwentyTwenty,
+        ),
+        (
+            "1_0n;
+b;",
+            ECMAVersion::TwentyTwenty,
+        ),
+        (
+            "/a/gs;
+b;",
+            ECMAVersion::TwentySeventeen,
+        ),
+        (
+            "a = 0o7;
+b;",
+            ECMAVersion::Five,
+        ),
+        ("var a = b => b;", ECMAVersion::Five),
+    ]
+    .map(|(source, ecma_version)| {
+        let options = ParserOptions::new()
+            .ecma_version(ecma_version)
+            .source_type(ProgramSource::Module);
+
+        let error = Parser::new(source, options).parse().unwrap_err();
+
+        (error.span.start, error.span.end)
+    });
+
+    assert_eq!(located, [(4, 9), (3, 8), (0, 4), (4, 5), (4, 7), (10, 12)]);
+}
+
+#[test]
+fn es2016_features() {
+    assert_feature_requires_version("a ** b;", Feature::ExponentiationOperator);
+    assert_feature_requires_version("a **= b;", Feature::ExponentiationOperator);
+}
+
+#[test]
+fn es2017_features() {
+    assert_feature_requires_version("async function f() {}", Feature::AsyncFunctions);
+    assert_feature_requires_version("(async function () {});", Feature::AsyncFunctions);
+    assert_feature_requires_version("async () => {};", Feature::AsyncFunctions);
+    assert_feature_requires_version("({ async f() {} });", Feature::AsyncFunctions);
+    assert_feature_requires_version("class A { async f() {} }", Feature::AsyncFunctions);
+    assert_feature_requires_version("f(a,);", Feature::FunctionTrailingCommas);
+    assert_feature_requires_version("new F(a,);", Feature::FunctionTrailingCommas);
+    assert_feature_requires_version("function f(a,) {}", Feature::FunctionTrailingCommas);
+    assert_feature_requires_version("(a,) => a;", Feature::FunctionTrailingCommas);
+}
+
+#[test]
+fn es2018_features() {
+    assert_feature_requires_version("async function* f() {}", Feature::AsyncIteration);
+    assert_feature_requires_version("({ async *f() {} });", Feature::AsyncIteration);
+    assert_feature_requires_version(
+        "async function f() { for await (const a of b); }",
+        Feature::AsyncIteration,
+    );
+    assert_feature_requires_version("({ ...a });", Feature::ObjectRestSpread);
+    assert_feature_requires_version("const { ...a } = b;", Feature::ObjectRestSpread);
+    assert_feature_requires_version("({ ...a } = b);", Feature::ObjectRestSpread);
+    assert_feature_requires_version("/a/s;", Feature::RegExpDotAllFlag);
+    assert_feature_requires_version("/(?<=a)/;", Feature::RegExpLookbehindAssertions);
+    assert_feature_requires_version("/(?<n>a)/;", Feature::RegExpNamedCaptureGroups);
+    assert_feature_requires_version("/\\p{L}/u;", Feature::RegExpUnicodePropertyEscapes);
+}
+
+#[test]
+fn es2019_features() {
+    assert_feature_requires_version("try {} catch {}", Feature::OptionalCatchBinding);
+}
+
+#[test]
+fn es2020_features() {
+    assert_feature_requires_version("1n;", Feature::BigInt);
+    assert_feature_requires_version("import('a');", Feature::DynamicImport);
+    assert_feature_requires_version("export * as a from 'a';", Feature::ExportNamespaceFrom);
+    assert_feature_requires_version("import.meta;", Feature::ImportMeta);
+    assert_feature_requires_version("a ?? b;", Feature::NullishCoalescing);
+    assert_feature_requires_version("a?.b;", Feature::OptionalChaining);
+    assert_feature_requires_version("a?.();", Feature::OptionalChaining);
+}
+
+#[test]
+fn es2021_features() {
+    assert_feature_requires_version("a ||= b;", Feature::LogicalAssignment);
+    assert_feature_requires_version("a &&= b;", Feature::LogicalAssignment);
+    assert_feature_requires_version("a ??= b;", Feature::LogicalAssignment);
+    assert_feature_requires_version("1_000;", Feature::NumericSeparators);
+    assert_feature_requires_version("({ 1_000: a });", Feature::NumericSeparators);
+}
+
+#[test]
+fn es2022_features() {
+    assert_feature_requires_version(
+        "export { a as 'b' };",
+        Feature::ArbitraryModuleNamespaceNames,
+    );
+    assert_feature_requires_version("class A { a = 1; }", Feature::ClassFields);
+    assert_feature_requires_version("class A { static {} }", Feature::ClassStaticBlock);
+    assert_feature_requires_version("class A { #a() {} }", Feature::PrivateNames);
+    assert_feature_requires_version("/a/d;", Feature::RegExpMatchIndicesFlag);
+    assert_feature_requires_version("await a;", Feature::TopLevelAwait);
+    assert_feature_requires_version("for await (const a of b);", Feature::TopLevelAwait);
+}
+
+#[test]
+fn await_within_async_functions_is_not_top_level() {
+    assert_eq!(
+        parse_module_with_ecma_version(
+            "async function f() { await a; } async () => await a;",
+            ECMAVersion::TwentySeventeen
+        ),
+        Ok(())
+    );
+}
+
+#[test]
+fn es2023_features() {
+    assert_feature_requires_version("#!/usr/bin/env node\na;", Feature::Hashbang);
+}
+
+#[test]
+fn es2024_features() {
+    assert_feature_requires_version("/[a]/v;", Feature::RegExpUnicodeSetsFlag);
+}
+
+#[test]
+fn es2025_features() {
+    assert_feature_requires_version(
+        "import a from 'a' with { type: 'json' };",
+        Feature::ImportAttributes,
+    );
+    assert_feature_requires_version("/(?i:a)/;", Feature::RegExpModifiers);
+}
+
+#[test]
+fn es2026_features() {
+    assert_feature_requires_version("{ using a = b; }", Feature::UsingDeclarations);
+}
+
+#[test]
+fn unsupported_feature_message() {
+    assert_eq!(
+        ParserErrorKind::UnsupportedFeature(Feature::OptionalChaining).to_string(),
+        "`OptionalChaining` requires ES2020"
+    );
+}
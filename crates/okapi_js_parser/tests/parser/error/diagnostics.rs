@@ -0,0 +1,190 @@
+use okapi_js_parser::{Parser, ParserErrorKind, ParserErrorReport, ParserOptions, TokenKind};
+
+fn parse_error_report(source: &str, options: ParserOptions) -> ParserErrorReport {
+    let (_, mut diagnostics) = Parser::new(source, options).parse_with_recovery();
+
+    diagnostics.remove(0)
+}
+
+#[test]
+fn unexpected_token_messages() {
+    let messages = ["a = }", "a = ", "a b", "a 'b'", "a 1", "a /b/", "a if"].map(|source| {
+        parse_error_report(&format!("({})", source), ParserOptions::default()).message
+    });
+
+    assert_eq!(
+        messages,
+        [
+            "Unexpected token '}' (1:5)",
+            "Unexpected token ')' (1:5)",
+            "Unexpected identifier (1:3)",
+            "Unexpected string (1:3)",
+            "Unexpected number (1:3)",
+            "Unexpected token ')' (1:6)",
+            "Unexpected token 'if' (1:3)",
+        ]
+    );
+}
+
+#[test]
+fn unexpected_end_of_input() {
+    let report = parse_error_report("function a() {\n  b(", ParserOptions::default());
+
+    assert_eq!(report.message, "Unexpected end of input (2:4)");
+    assert_eq!((report.offset, report.end), (19, 19));
+}
+
+#[test]
+fn errors_without_a_token_are_located() {
+    let report = parse_error_report("let a;\n[a, 1] = b;", ParserOptions::default());
+
+    assert_eq!(report.kind, "InvalidExpressionToPatternConversion");
+    assert_eq!(report.code, "E5004");
+    assert_eq!(
+        report.message,
+        "Invalid destructuring assignment target (2:4)"
+    );
+    assert_eq!((report.line, report.column), (2, 4));
+    assert_eq!((report.offset, report.end), (11, 12));
+}
+
+#[test]
+fn error_codes() {
+    assert_eq!(ParserErrorKind::SyntaxError.code(), "E0000");
+    assert_eq!(
+        ParserErrorKind::UnexpectedToken(TokenKind::Semicolon).code(),
+        "E0001"
+    );
+    assert_eq!(
+        ParserErrorKind::InvalidNumericSeparatorAtEnd.code(),
+        "E1028"
+    );
+    assert_eq!(
+        ParserErrorKind::InvalidRegexNothingToRepeat(0).code(),
+        "E2001"
+    );
+    assert_eq!(
+        ParserErrorKind::InvalidLeftHandSideExpression.code(),
+        "E3020"
+    );
+    assert_eq!(ParserErrorKind::MismatchedJSXClosingTag.code(), "E4104");
+}
+
+#[test]
+fn error_display() {
+    assert_eq!(
+        ParserErrorKind::InvalidLeftHandSideExpression.to_string(),
+        "Invalid left-hand side in assignment"
+    );
+    assert_eq!(
+        ParserErrorKind::InvalidRegexNothingToRepeat(2).to_string(),
+        "Invalid regular expression: nothing to repeat at pattern offset 2"
+    );
+    assert_eq!(
+        ParserErrorKind::UnexpectedToken(TokenKind::EOF).to_string(),
+        "Unexpected end of input"
+    );
+}
+
+#[test]
+fn no_code_frame_by_default() {
+    let report = parse_error_report("a = ;", ParserOptions::default());
+
+    assert_eq!(report.code_frame, None);
+    assert!(!report.to_json().contains("codeFrame"));
+}
+
+#[test]
+fn code_frame() {
+    let report = parse_error_report(
+        "let a = 1;\nlet b = 2;\nlet c = 3;\nif (a) {\n  b = c +;\n}\nlet e = 4;\nlet f = 5;\nlet g = 6;\n",
+        ParserOptions::default().code_frame(true),
+    );
+
+    assert_eq!(
+        report.code_frame.as_deref(),
+        Some(
+            "  3 | let c = 3;\n  4 | if (a) {\n> 5 |   b = c +;\n    |          ^\n  6 | }\n  7 | let e = 4;\n  8 | let f = 5;"
+        )
+    );
+    assert_eq!(
+        report.to_string(),
+        format!(
+            "E0001: Unexpected token ';' (5:9)\n{}",
+            report.code_frame.as_deref().unwrap()
+        )
+    );
+}
+
+#[test]
+fn code_frame_marks_the_whole_token() {
+    let report = parse_error_report("\tlet a = if;", ParserOptions::default().code_frame(true));
+
+    assert_eq!(
+        report.code_frame.as_deref(),
+        Some("> 1 | \tlet a = if;\n    | \t        ^^")
+    );
+
+    let report = parse_error_report("a = (b c)", ParserOptions::default().code_frame(true));
+
+    assert_eq!(
+        report.code_frame.as_deref(),
+        Some("> 1 | a = (b c)\n    |        ^")
+    );
+}
+
+#[test]
+fn code_frame_in_json() {
+    let error = Parser::new("a = ;", ParserOptions::default().code_frame(true))
+        .parse_module_json()
+        .unwrap_err();
+
+    let error = serde_json::to_value(&error).unwrap();
+
+    assert_eq!(error["codeFrame"], "> 1 | a = ;\n    |     ^");
+}
+
+#[test]
+fn errors_are_located_by_their_span() {
+    let located = [
+        "let x = /a{2,1}/;",
+        "/a/gg",
+        "function f() { let = = 1; }",
+        "a = 1;\nb + c = d;",
+        "a ¬ b",
+        "a = /é(?ii:b)/",
+    ]
+    .map(|source| {
+        let report = parse_error_report(source, ParserOptions::default());
+
+        (
+            report.kind,
+            report.line,
+            report.column,
+            report.offset,
+            report.end,
+        )
+    });
+
+    assert_eq!(
+        located,
+        [
+            ("InvalidRegexQuantifierRange".to_string(), 1, 10, 10, 11),
+            ("DuplicateRegexFlag".to_string(), 1, 4, 4, 5),
+            ("UnexpectedToken".to_string(), 1, 21, 21, 22),
+            ("InvalidLeftHandSideExpression".to_string(), 2, 0, 7, 12),
+            ("SyntaxError".to_string(), 1, 2, 2, 4),
+            ("InvalidRegexModifiers".to_string(), 1, 10, 10, 11),
+        ]
+    );
+}
+
+#[test]
+fn error_span() {
+    let error = Parser::new("@a let b;", ParserOptions::new().decorators(true))
+        .parse()
+        .unwrap_err();
+
+    assert_eq!(error.kind, ParserErrorKind::InvalidDecoratorPosition);
+    assert_eq!((error.span.start, error.span.end), (0, 2));
+}
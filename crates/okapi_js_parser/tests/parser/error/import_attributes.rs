@@ -0,0 +1,19 @@
+use okapi_js_parser::{ParserErrorKind, TokenKind};
+
+use crate::parser::test_helper_macros::assert_parse_module_to_throw;
+
+#[test]
+fn import_attributes_duplicate_key() {
+    assert_parse_module_to_throw!(
+        r#"import "a" with { type: "json", "type": "css" };"#,
+        ParserErrorKind::DuplicateImportAttributeKey
+    );
+}
+
+#[test]
+fn import_attributes_non_string_value() {
+    assert_parse_module_to_throw!(
+        r#"import "a" with { type: 1 };"#,
+        ParserErrorKind::UnexpectedToken(TokenKind::NumberLiteral)
+    );
+}
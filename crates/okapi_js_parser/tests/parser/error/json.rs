@@ -0,0 +1,55 @@
+use okapi_js_parser::{Parser, ParserOptions};
+use serde_json::{json, Value};
+
+fn parse_module_json_error(source: &str) -> Value {
+    let error = Parser::new(source, ParserOptions::default())
+        .parse_module_json()
+        .unwrap_err();
+
+    serde_json::to_value(&error).unwrap()
+}
+
+#[test]
+fn json_unexpected_token_error() {
+    assert_eq!(
+        parse_module_json_error("let a = 1;\nlet b = ;"),
+        json!({
+            "type": "ParserError",
+            "kind": "UnexpectedToken",
+            "code": "E0001",
+            "message": "Unexpected token ';' (2:8)",
+            "line": 2,
+            "column": 8,
+            "offset": 19,
+            "end": 20
+        })
+    );
+}
+
+#[test]
+fn json_illegal_token_error() {
+    let error = parse_module_json_error("var a = 1_;");
+
+    assert_eq!(error["type"], "ParserError");
+    assert_eq!(error["kind"], "InvalidNumericSeparatorAtEnd");
+    assert_eq!(error["code"], "E1028");
+    assert_eq!(
+        error["message"],
+        "Numeric separators are not allowed at the end of numeric literals (1:8)"
+    );
+    assert_eq!(error["line"], 1);
+    assert_eq!(error["column"], 8);
+    assert_eq!(error["offset"], 8);
+    assert_eq!(error["end"], 10);
+}
+
+#[test]
+fn json_script_ok() {
+    let program = Parser::new("a;", ParserOptions::default())
+        .parse_script_json()
+        .unwrap();
+
+    let program_json = serde_json::from_str::<Value>(&program).unwrap();
+
+    assert_eq!(program_json["type"], "Program");
+}
@@ -0,0 +1,50 @@
+use okapi_js_parser::{ParserErrorKind, ParserOptions, TokenKind};
+
+use crate::parser::test_helper_macros::assert_parse_module_to_throw;
+
+#[test]
+fn jsx_mismatched_closing_tag() {
+    assert_parse_module_to_throw!(
+        r#"<a></b>;"#,
+        ParserErrorKind::MismatchedJSXClosingTag,
+        ParserOptions::new().jsx(true)
+    );
+
+    assert_parse_module_to_throw!(
+        r#"<a.b></a.c>;"#,
+        ParserErrorKind::MismatchedJSXClosingTag,
+        ParserOptions::new().jsx(true)
+    );
+
+    assert_parse_module_to_throw!(
+        r#"<a></a:b>;"#,
+        ParserErrorKind::MismatchedJSXClosingTag,
+        ParserOptions::new().jsx(true)
+    );
+}
+
+#[test]
+fn jsx_unterminated_contents() {
+    assert_parse_module_to_throw!(
+        r#"<a>b"#,
+        ParserErrorKind::UnterminatedJSXContents,
+        ParserOptions::new().jsx(true)
+    );
+}
+
+#[test]
+fn jsx_invalid_text_character() {
+    assert_parse_module_to_throw!(
+        r#"<a>}</a>"#,
+        ParserErrorKind::InvalidJSXTextCharacter,
+        ParserOptions::new().jsx(true)
+    );
+}
+
+#[test]
+fn jsx_without_extension() {
+    assert_parse_module_to_throw!(
+        r#"<a />;"#,
+        ParserErrorKind::UnexpectedToken(TokenKind::LessThan)
+    );
+}
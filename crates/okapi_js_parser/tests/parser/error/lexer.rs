@@ -0,0 +1,65 @@
+use okapi_js_parser::{Parser, ParserErrorKind, ParserOptions};
+
+use crate::parser::test_helper_macros::assert_parse_module_to_throw;
+
+#[test]
+fn lexer_escape_sequence_errors() {
+    assert_parse_module_to_throw!(
+        r#"a = "\xZZ";"#,
+        ParserErrorKind::InvalidHexadecimalEscapeSequence
+    );
+    assert_parse_module_to_throw!(
+        r#"a = "\x";"#,
+        ParserErrorKind::InvalidHexadecimalEscapeSequence
+    );
+    assert_parse_module_to_throw!(
+        r#"a = "\u{110000}";"#,
+        ParserErrorKind::InvalidUnicodeCodePointEscapeSequence
+    );
+}
+
+#[test]
+fn lexer_numeric_literal_errors() {
+    assert_parse_module_to_throw!(
+        "a = 1__0;",
+        ParserErrorKind::InvalidNumericSeparatorAtSibling
+    );
+    assert_parse_module_to_throw!("a = 1_;", ParserErrorKind::InvalidNumericSeparatorAtEnd);
+    assert_parse_module_to_throw!(
+        "a = 0x;",
+        ParserErrorKind::InvalidNonDecimalHexadecimalNumberLiteral
+    );
+    assert_parse_module_to_throw!(
+        "a = 0b2;",
+        ParserErrorKind::InvalidNonDecimalBinaryNumberLiteral
+    );
+    assert_parse_module_to_throw!("a = 08n;", ParserErrorKind::InvalidDecimalBigIntegerLiteral);
+    assert_parse_module_to_throw!(
+        "a = 1.5n;",
+        ParserErrorKind::InvalidDecimalBigIntegerLiteral
+    );
+    assert_parse_module_to_throw!(
+        "a = 1e3n;",
+        ParserErrorKind::InvalidDecimalBigIntegerLiteral
+    );
+}
+
+#[test]
+fn lexer_unterminated_literal_errors() {
+    assert_parse_module_to_throw!(r#"a = "b"#, ParserErrorKind::UnterminatedStringLiteral);
+    assert_parse_module_to_throw!("a = /b", ParserErrorKind::UnterminatedRegExLiteral);
+}
+
+#[test]
+fn lexer_error_span() {
+    let (_, diagnostics) =
+        Parser::new("let a = 1;\nlet b = 1__0;", ParserOptions::default()).parse_with_recovery();
+
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(diagnostics[0].kind, "InvalidNumericSeparatorAtSibling");
+    assert_eq!(
+        diagnostics[0].message,
+        "Only one underscore is allowed as numeric separator (2:8)"
+    );
+    assert_eq!((diagnostics[0].offset, diagnostics[0].end), (19, 21));
+}
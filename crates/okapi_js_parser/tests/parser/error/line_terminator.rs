@@ -1,4 +1,4 @@
-use okapi_js_parser::ParserError;
+use okapi_js_parser::ParserErrorKind;
 
 use crate::parser::test_helper_macros::assert_parse_module_to_throw;
 
@@ -7,6 +7,6 @@ fn line_terminator_throw_expression() {
     assert_parse_module_to_throw!(
         r"throw
 Error()",
-        ParserError::UnexpectedLineTerminator
+        ParserErrorKind::UnexpectedLineTerminator
     );
 }
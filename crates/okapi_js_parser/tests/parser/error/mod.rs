@@ -1 +1,8 @@
+mod diagnostics;
+mod import_attributes;
+mod json;
+mod jsx;
+mod lexer;
 mod line_terminator;
+mod regexp;
+mod typescript;
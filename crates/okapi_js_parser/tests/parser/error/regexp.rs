@@ -0,0 +1,118 @@
+use okapi_js_parser::ParserErrorKind;
+
+use crate::parser::test_helper_macros::assert_parse_module_to_throw;
+
+#[test]
+fn regexp_flags() {
+    assert_parse_module_to_throw!("/a/x", ParserErrorKind::InvalidRegexFlag(0));
+    assert_parse_module_to_throw!("/a/gig", ParserErrorKind::DuplicateRegexFlag(2));
+    assert_parse_module_to_throw!("/a/uv", ParserErrorKind::InvalidRegexFlagCombination(1));
+}
+
+#[test]
+fn regexp_duplicate_group_names() {
+    assert_parse_module_to_throw!(
+        "/(?<a>x)(?<a>y)/",
+        ParserErrorKind::DuplicateRegexGroupName(7)
+    );
+    assert_parse_module_to_throw!(
+        "/(?:(?<a>x)|y)(?<a>z)/",
+        ParserErrorKind::DuplicateRegexGroupName(13)
+    );
+}
+
+#[test]
+fn regexp_backreferences() {
+    assert_parse_module_to_throw!(
+        r"/(a)\2/u",
+        ParserErrorKind::InvalidRegexIndexBackreference(3)
+    );
+    assert_parse_module_to_throw!(
+        r"/\2(a)/u",
+        ParserErrorKind::InvalidRegexIndexBackreference(0)
+    );
+    assert_parse_module_to_throw!(
+        r"/(?<a>x)\k<b>/",
+        ParserErrorKind::InvalidRegexBackreference(7)
+    );
+}
+
+#[test]
+fn regexp_quantifier_range() {
+    assert_parse_module_to_throw!("/a{2,1}/", ParserErrorKind::InvalidRegexQuantifierRange(1));
+}
+
+#[test]
+fn regexp_character_class_range() {
+    assert_parse_module_to_throw!(
+        "/[z-a]/",
+        ParserErrorKind::InvalidRegexCharacterClassRange(1)
+    );
+    assert_parse_module_to_throw!(
+        r"/[\d-z]/u",
+        ParserErrorKind::InvalidRegexCharacterClassRange(1)
+    );
+}
+
+#[test]
+fn regexp_unicode_property() {
+    assert_parse_module_to_throw!(
+        r"/\p{Letters}/u",
+        ParserErrorKind::InvalidRegexUnicodeProperty(0)
+    );
+    assert_parse_module_to_throw!(
+        r"/\p{Script=Latin1}/u",
+        ParserErrorKind::InvalidRegexUnicodeProperty(0)
+    );
+    assert_parse_module_to_throw!(
+        r"/\p{RGI_Emoji}/u",
+        ParserErrorKind::InvalidRegexUnicodeProperty(0)
+    );
+}
+
+#[test]
+fn regexp_unicode_sets() {
+    assert_parse_module_to_throw!(
+        r"/[^\p{RGI_Emoji}]/v",
+        ParserErrorKind::InvalidRegexNegatedClassOfStrings(0)
+    );
+    assert_parse_module_to_throw!(
+        r"/[^\q{ab|c}]/v",
+        ParserErrorKind::InvalidRegexNegatedClassOfStrings(0)
+    );
+    assert_parse_module_to_throw!(
+        r"/\P{RGI_Emoji}/v",
+        ParserErrorKind::InvalidRegexNegatedClassOfStrings(0)
+    );
+    assert_parse_module_to_throw!(
+        "/[ab&&c]/v",
+        ParserErrorKind::InvalidRegexClassSetOperation(3)
+    );
+    assert_parse_module_to_throw!(
+        "/[a&&b--c]/v",
+        ParserErrorKind::InvalidRegexClassSetOperation(5)
+    );
+    assert_parse_module_to_throw!("/[a-]/v", ParserErrorKind::InvalidRegexClassSetCharacter(3));
+    assert_parse_module_to_throw!(
+        "/[a!!]/v",
+        ParserErrorKind::InvalidRegexClassSetCharacter(2)
+    );
+}
+
+#[test]
+fn regexp_modifiers() {
+    assert_parse_module_to_throw!("/(?ii:a)/", ParserErrorKind::InvalidRegexModifiers(3));
+    assert_parse_module_to_throw!("/(?i-i:a)/", ParserErrorKind::InvalidRegexModifiers(4));
+    assert_parse_module_to_throw!("/(?-:a)/", ParserErrorKind::InvalidRegexModifiers(2));
+    assert_parse_module_to_throw!("/(?x:a)/", ParserErrorKind::InvalidRegexGroup(0));
+    assert_parse_module_to_throw!("/(?i-x:a)/", ParserErrorKind::InvalidRegexGroup(0));
+}
+
+#[test]
+fn regexp_escapes() {
+    assert_parse_module_to_throw!(r"/\u{/u", ParserErrorKind::InvalidRegexEscape(2));
+    assert_parse_module_to_throw!(r"/a\u{110000}/u", ParserErrorKind::InvalidRegexEscape(3));
+    assert_parse_module_to_throw!(r"/\x1/u", ParserErrorKind::InvalidRegexEscape(2));
+    assert_parse_module_to_throw!(r"/\c1/u", ParserErrorKind::InvalidRegexEscape(2));
+    assert_parse_module_to_throw!(r"/\a/u", ParserErrorKind::InvalidRegexEscape(0));
+}
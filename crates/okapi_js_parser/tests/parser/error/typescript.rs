@@ -0,0 +1,65 @@
+use okapi_js_parser::{ParserErrorKind, ParserOptions, TokenKind};
+
+use crate::parser::test_helper_macros::assert_parse_module_to_throw;
+
+#[test]
+fn typescript_duplicate_modifier() {
+    assert_parse_module_to_throw!(
+        r#"class A { public public a; }"#,
+        ParserErrorKind::InvalidTypeScriptModifier,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parse_module_to_throw!(
+        r#"class A { static static a; }"#,
+        ParserErrorKind::InvalidTypeScriptModifier,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_abstract_member_with_implementation() {
+    assert_parse_module_to_throw!(
+        r#"abstract class A { abstract a() {} }"#,
+        ParserErrorKind::InvalidTypeScriptModifier,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parse_module_to_throw!(
+        r#"abstract class A { abstract a = 1; }"#,
+        ParserErrorKind::InvalidTypeScriptModifier,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_parameter_property_outside_constructor() {
+    assert_parse_module_to_throw!(
+        r#"function f(public a) {}"#,
+        ParserErrorKind::InvalidParameterPropertyPosition,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parse_module_to_throw!(
+        r#"class A { a(readonly b) {} }"#,
+        ParserErrorKind::InvalidParameterPropertyPosition,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_parameter_property_binding_pattern() {
+    assert_parse_module_to_throw!(
+        r#"class A { constructor(public [a]) {} }"#,
+        ParserErrorKind::InvalidParameterPropertyBinding,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_without_extension() {
+    assert_parse_module_to_throw!(
+        r#"let a: number;"#,
+        ParserErrorKind::UnexpectedToken(TokenKind::Colon)
+    );
+}
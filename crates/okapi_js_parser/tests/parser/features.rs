@@ -0,0 +1,170 @@
+use okapi_js_parser::{
+    ast::{ProgramSource, Span},
+    ECMAVersion, Feature, FeatureReport, Parser, ParserOptions,
+};
+use pretty_assertions::assert_eq;
+
+fn feature_report(source: &str, source_type: ProgramSource) -> FeatureReport {
+    let options = ParserOptions::new().source_type(source_type);
+
+    let program = Parser::new(source, options).parse().unwrap();
+
+    FeatureReport::new(&program)
+}
+
+fn features(source: &str, source_type: ProgramSource) -> Vec<Feature> {
+    feature_report(source, source_type)
+        .usages
+        .iter()
+        .map(|usage| usage.feature)
+        .collect()
+}
+
+fn spans(report: &FeatureReport, feature: Feature) -> Vec<(usize, usize)> {
+    report
+        .get(feature)
+        .unwrap()
+        .spans
+        .iter()
+        .map(|Span { start, end }| (*start, *end))
+        .collect()
+}
+
+#[test]
+fn es5_syntax_uses_no_features() {
+    let report = feature_report(
+        "var a = function (b) { return [b, { c: 1 }]; }; for (var d in a) {}",
+        ProgramSource::Script,
+    );
+
+    assert_eq!(report.usages, vec![]);
+    assert_eq!(report.ecma_version(), ECMAVersion::Five);
+}
+
+#[test]
+fn es5_features() {
+    let report = feature_report(
+        "var a = { get b() {}, set b(c) {}, default: 1 }; a.class;",
+        ProgramSource::Script,
+    );
+
+    assert_eq!(
+        spans(&report, Feature::PropertyAccessors),
+        vec![(10, 20), (22, 33)]
+    );
+    assert_eq!(
+        spans(&report, Feature::ReservedWordPropertyNames),
+        vec![(35, 45), (49, 56)]
+    );
+    assert_eq!(report.ecma_version(), ECMAVersion::Five);
+}
+
+#[test]
+fn es2015_features() {
+    assert_eq!(
+        features(
+            "let a = (b = 1, ...c) => `${b}`; const { d, [e]: f } = [...c]; function* g() {} for (const h of [0b1]) {} class I { j() { new.target; } } '\\u{1F600}';",
+            ProgramSource::Script
+        ),
+        vec![
+            Feature::ArrowFunctions,
+            Feature::BinaryAndOctalLiterals,
+            Feature::Classes,
+            Feature::ComputedProperties,
+            Feature::DefaultParameters,
+            Feature::Destructuring,
+            Feature::ForOf,
+            Feature::Generators,
+            Feature::LexicalDeclarations,
+            Feature::NewTarget,
+            Feature::RestParameters,
+            Feature::ShorthandProperties,
+            Feature::SpreadElements,
+            Feature::TemplateLiterals,
+            Feature::UnicodeCodePointEscapes,
+        ]
+    );
+}
+
+#[test]
+fn later_features() {
+    assert_eq!(
+        features(
+            "async function* a() { for await (const b of c); } a?.b ?? 1_000n; class D { #e() {} static { #e in this; } }",
+            ProgramSource::Script
+        ),
+        vec![
+            Feature::Classes,
+            Feature::ForOf,
+            Feature::LexicalDeclarations,
+            Feature::AsyncIteration,
+            Feature::BigInt,
+            Feature::NullishCoalescing,
+            Feature::OptionalChaining,
+            Feature::NumericSeparators,
+            Feature::ClassPrivateMethods,
+            Feature::ClassStaticBlock,
+            Feature::ErgonomicBrandChecks,
+            Feature::PrivateNames,
+        ]
+    );
+}
+
+#[test]
+fn regexp_features() {
+    assert_eq!(
+        features(
+            "/(?<a>b)(?<=c)\\p{L}/du; /[\\p{L}--\\p{N}]/v; /a/sy; /(?i:a)/;",
+            ProgramSource::Script
+        ),
+        vec![
+            Feature::RegExpStickyFlag,
+            Feature::RegExpUnicodeFlag,
+            Feature::RegExpDotAllFlag,
+            Feature::RegExpLookbehindAssertions,
+            Feature::RegExpNamedCaptureGroups,
+            Feature::RegExpUnicodePropertyEscapes,
+            Feature::RegExpMatchIndicesFlag,
+            Feature::RegExpUnicodeSetsFlag,
+            Feature::RegExpModifiers,
+        ]
+    );
+}
+
+#[test]
+fn top_level_await() {
+    let report = feature_report(
+        "await a; async function b() { await c; } (async () => await d)();",
+        ProgramSource::Module,
+    );
+
+    assert_eq!(spans(&report, Feature::TopLevelAwait), vec![(0, 7)]);
+    assert_eq!(report.ecma_version(), ECMAVersion::TwentyTwentyTwo);
+}
+
+#[test]
+fn modules() {
+    let report = feature_report(
+        "import a from 'a' with { type: 'json' };\nexport * as b from 'b';\nimport.meta;",
+        ProgramSource::Module,
+    );
+
+    assert_eq!(spans(&report, Feature::Modules), vec![(0, 40), (41, 64)]);
+    assert_eq!(spans(&report, Feature::ImportAttributes), vec![(0, 40)]);
+    assert_eq!(spans(&report, Feature::ExportNamespaceFrom), vec![(41, 64)]);
+    assert_eq!(spans(&report, Feature::ImportMeta), vec![(65, 76)]);
+    assert_eq!(report.ecma_version(), ECMAVersion::TwentyTwentyFive);
+}
+
+#[test]
+fn spans_in_source_order() {
+    let report = feature_report("a?.b;\nc?.();\nd ?? (e?.f);", ProgramSource::Script);
+
+    let usage = report.get(Feature::OptionalChaining).unwrap();
+
+    assert_eq!(usage.ecma_version, ECMAVersion::TwentyTwenty);
+    assert_eq!(
+        spans(&report, Feature::OptionalChaining),
+        vec![(0, 4), (6, 11), (19, 23)]
+    );
+}
@@ -0,0 +1,161 @@
+use assert_json_diff::assert_json_include;
+use okapi_js_parser::{OffsetEncoding, Parser, ParserOptions};
+use serde_json::{json, Value};
+
+fn parse_module_with_locations(source: &str, source_file: Option<&str>) -> Value {
+    let mut options = ParserOptions::new()
+        .locations(true)
+        .offset_encoding(OffsetEncoding::Utf16);
+
+    if let Some(source_file) = source_file {
+        options = options.source_file(source_file);
+    }
+
+    let parsed = Parser::new(source, options).parse_module_json().unwrap();
+
+    serde_json::from_str::<Value>(&parsed).unwrap()
+}
+
+#[test]
+fn locations_disabled_by_default() {
+    let parsed = Parser::new("a;", ParserOptions::default())
+        .parse_module_json()
+        .unwrap();
+
+    let parsed_json = serde_json::from_str::<Value>(&parsed).unwrap();
+
+    assert!(parsed_json.get("loc").is_none());
+    assert!(parsed_json["body"][0].get("loc").is_none());
+}
+
+#[test]
+fn locations_multiple_lines() {
+    assert_json_include!(
+        actual: parse_module_with_locations("const a = 1;\r\nfoo(a);", None),
+        expected: json!({
+            "type": "Program",
+            "start": 0,
+            "end": 21,
+            "loc": { "start": { "line": 1, "column": 0 }, "end": { "line": 2, "column": 7 } },
+            "body": [
+                {
+                    "type": "VariableDeclaration",
+                    "loc": { "start": { "line": 1, "column": 0 }, "end": { "line": 1, "column": 12 } },
+                    "declarations": [
+                        {
+                            "type": "VariableDeclarator",
+                            "loc": { "start": { "line": 1, "column": 6 }, "end": { "line": 1, "column": 11 } },
+                            "id": {
+                                "type": "Identifier",
+                                "loc": { "start": { "line": 1, "column": 6 }, "end": { "line": 1, "column": 7 } }
+                            },
+                            "init": {
+                                "type": "Literal",
+                                "loc": { "start": { "line": 1, "column": 10 }, "end": { "line": 1, "column": 11 } }
+                            }
+                        }
+                    ]
+                },
+                {
+                    "type": "ExpressionStatement",
+                    "start": 14,
+                    "end": 21,
+                    "loc": { "start": { "line": 2, "column": 0 }, "end": { "line": 2, "column": 7 } },
+                    "expression": {
+                        "type": "CallExpression",
+                        "loc": { "start": { "line": 2, "column": 0 }, "end": { "line": 2, "column": 6 } },
+                        "callee": {
+                            "type": "Identifier",
+                            "loc": { "start": { "line": 2, "column": 0 }, "end": { "line": 2, "column": 3 } }
+                        },
+                        "arguments": [
+                            {
+                                "type": "Identifier",
+                                "loc": { "start": { "line": 2, "column": 4 }, "end": { "line": 2, "column": 5 } }
+                            }
+                        ]
+                    }
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn locations_template_literal() {
+    assert_json_include!(
+        actual: parse_module_with_locations("`a\n${b}c`", None),
+        expected: json!({
+            "body": [
+                {
+                    "expression": {
+                        "type": "TemplateLiteral",
+                        "loc": { "start": { "line": 1, "column": 0 }, "end": { "line": 2, "column": 6 } },
+                        "quasis": [
+                            {
+                                "type": "TemplateElement",
+                                "loc": { "start": { "line": 1, "column": 1 }, "end": { "line": 2, "column": 0 } }
+                            },
+                            {
+                                "type": "TemplateElement",
+                                "loc": { "start": { "line": 2, "column": 4 }, "end": { "line": 2, "column": 5 } }
+                            }
+                        ],
+                        "expressions": [
+                            {
+                                "type": "Identifier",
+                                "loc": { "start": { "line": 2, "column": 2 }, "end": { "line": 2, "column": 3 } }
+                            }
+                        ]
+                    }
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn locations_non_ascii_columns() {
+    assert_json_include!(
+        actual: parse_module_with_locations("'ü' + x", None),
+        expected: json!({
+            "body": [
+                {
+                    "expression": {
+                        "type": "BinaryExpression",
+                        "right": {
+                            "type": "Identifier",
+                            "start": 6,
+                            "end": 7,
+                            "loc": { "start": { "line": 1, "column": 6 }, "end": { "line": 1, "column": 7 } }
+                        }
+                    }
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn locations_source_file() {
+    assert_json_include!(
+        actual: parse_module_with_locations("a;", Some("index.js")),
+        expected: json!({
+            "loc": {
+                "source": "index.js",
+                "start": { "line": 1, "column": 0 },
+                "end": { "line": 1, "column": 2 }
+            },
+            "body": [
+                {
+                    "type": "ExpressionStatement",
+                    "loc": {
+                        "source": "index.js",
+                        "start": { "line": 1, "column": 0 },
+                        "end": { "line": 1, "column": 2 }
+                    }
+                }
+            ]
+        })
+    );
+}
@@ -1,6 +1,17 @@
 mod acorn;
+mod ast;
+mod comments;
+mod ecma_version;
 mod error;
+mod features;
+mod locations;
+mod offset_encoding;
 mod ok;
+mod options;
+mod recovery;
+mod regexp;
 mod test_helper_macros;
+mod tokenizer;
+mod tokens;
 
 use test_helper_macros::*;
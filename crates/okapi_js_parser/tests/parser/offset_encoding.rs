@@ -0,0 +1,225 @@
+use assert_json_diff::assert_json_include;
+use okapi_js_parser::ast::{
+    regexp::Element, Expression, ExpressionStatement, ProgramBody, Statement, StatementListItem,
+};
+use okapi_js_parser::{OffsetEncoding, Parser, ParserOptions, Tokenizer};
+use serde_json::{json, Value};
+
+const SOURCE: &str = "'😀ü' + x;\ny;";
+
+fn parse_module_with_offset_encoding(source: &str, offset_encoding: OffsetEncoding) -> Value {
+    let parsed = Parser::new(
+        source,
+        ParserOptions::new()
+            .locations(true)
+            .offset_encoding(offset_encoding),
+    )
+    .parse_module_json()
+    .unwrap();
+
+    serde_json::from_str::<Value>(&parsed).unwrap()
+}
+
+fn expected_offsets(literal_end: usize, identifier_start: usize, program_end: usize) -> Value {
+    json!({
+        "start": 0,
+        "end": program_end,
+        "body": [
+            {
+                "type": "ExpressionStatement",
+                "expression": {
+                    "type": "BinaryExpression",
+                    "left": {
+                        "type": "Literal",
+                        "start": 0,
+                        "end": literal_end,
+                        "loc": { "start": { "line": 1, "column": 0 }, "end": { "line": 1, "column": literal_end } }
+                    },
+                    "right": {
+                        "type": "Identifier",
+                        "start": identifier_start,
+                        "end": identifier_start + 1,
+                        "loc": { "start": { "line": 1, "column": identifier_start }, "end": { "line": 1, "column": identifier_start + 1 } }
+                    }
+                }
+            },
+            {
+                "type": "ExpressionStatement",
+                "start": program_end - 2,
+                "end": program_end,
+                "loc": { "start": { "line": 2, "column": 0 }, "end": { "line": 2, "column": 2 } }
+            }
+        ]
+    })
+}
+
+#[test]
+fn offset_encoding_utf8() {
+    let program = parse_module_with_offset_encoding(SOURCE, OffsetEncoding::Utf8);
+
+    assert_json_include!(actual: program, expected: expected_offsets(8, 11, 16));
+
+    // UTF-8 offsets can be used to slice the source directly.
+    let identifier = &program["body"][0]["expression"]["right"];
+
+    assert_eq!(
+        &SOURCE[identifier["start"].as_u64().unwrap() as usize
+            ..identifier["end"].as_u64().unwrap() as usize],
+        "x"
+    );
+}
+
+#[test]
+fn offset_encoding_utf16() {
+    assert_json_include!(
+        actual: parse_module_with_offset_encoding(SOURCE, OffsetEncoding::Utf16),
+        expected: expected_offsets(5, 8, 13)
+    );
+}
+
+#[test]
+fn offset_encoding_code_point() {
+    assert_json_include!(
+        actual: parse_module_with_offset_encoding(SOURCE, OffsetEncoding::CodePoint),
+        expected: expected_offsets(4, 7, 12)
+    );
+}
+
+#[test]
+fn offset_encoding_default_is_utf8() {
+    let parsed = Parser::new(SOURCE, ParserOptions::default())
+        .parse_module_json()
+        .unwrap();
+
+    assert_json_include!(
+        actual: serde_json::from_str::<Value>(&parsed).unwrap(),
+        expected: json!({ "start": 0, "end": 16 })
+    );
+}
+
+#[test]
+fn offset_encoding_error_position() {
+    let error = Parser::new(
+        "'😀' + ;",
+        ParserOptions::new().offset_encoding(OffsetEncoding::Utf16),
+    )
+    .parse_module_json()
+    .unwrap_err();
+
+    assert_json_include!(
+        actual: serde_json::to_value(&error).unwrap(),
+        expected: json!({ "line": 1, "column": 7, "offset": 7 })
+    );
+}
+
+#[test]
+fn offset_encoding_error_span() {
+    let error = Parser::new(
+        "'😀';\n'😀' + a = b;",
+        ParserOptions::new()
+            .code_frame(true)
+            .offset_encoding(OffsetEncoding::Utf16),
+    )
+    .parse_module_json()
+    .unwrap_err();
+
+    assert_json_include!(
+        actual: serde_json::to_value(&error).unwrap(),
+        expected: json!({
+            "kind": "InvalidLeftHandSideExpression",
+            "line": 2,
+            "column": 0,
+            "offset": 6,
+            "end": 14,
+            "codeFrame": "  1 | '😀';\n> 2 | '😀' + a = b;\n    | ^^^^^^^"
+        })
+    );
+}
+
+#[test]
+fn offset_encoding_tokens() {
+    let parsed = Parser::new(
+        "'😀' + a;",
+        ParserOptions::new()
+            .tokens(true)
+            .offset_encoding(OffsetEncoding::Utf16),
+    )
+    .parse_module_json()
+    .unwrap();
+
+    assert_json_include!(
+        actual: serde_json::from_str::<Value>(&parsed).unwrap(),
+        expected: json!({
+            "tokens": [
+                { "type": "String", "start": 0, "end": 4 },
+                { "type": "Punctuator", "start": 5, "end": 6 },
+                { "type": "Identifier", "start": 7, "end": 8 },
+                { "type": "Punctuator", "start": 8, "end": 9 }
+            ]
+        })
+    );
+}
+
+#[test]
+fn offset_encoding_tokenizer() {
+    let spans = Tokenizer::new("'😀' + a 1__0")
+        .offset_encoding(OffsetEncoding::Utf16)
+        .map(|token| match token {
+            Ok(token) => (token.start, token.end),
+            Err(error) => (error.span.start, error.span.end),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(spans, vec![(0, 4), (5, 6), (7, 8), (9, 11)]);
+
+    // Without an encoding, the tokenizer uses the lexer's byte offsets.
+    let spans = Tokenizer::new("'😀' + a")
+        .map(|token| token.map(|token| (token.start, token.end)).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(spans, vec![(0, 6), (7, 8), (9, 10)]);
+}
+
+#[test]
+fn offset_encoding_regular_expression() {
+    let program = Parser::new(
+        "'😀'; /😀(ü)/u;",
+        ParserOptions::new().offset_encoding(OffsetEncoding::Utf16),
+    )
+    .parse_script()
+    .unwrap();
+
+    let ProgramBody::StatementList(body) = program.body else {
+        panic!("Expected a script body.");
+    };
+
+    let StatementListItem::Statement(Statement::Expression(ExpressionStatement {
+        expression: Expression::RegExpLiteral(regexp_literal),
+        ..
+    })) = &body[1]
+    else {
+        panic!("Expected a regular expression literal.");
+    };
+
+    // The literal is located in the source, and its pattern nodes are relative to the start of the pattern.
+    assert_eq!(
+        (regexp_literal.node.start, regexp_literal.node.end),
+        (6, 14)
+    );
+
+    let pattern = &regexp_literal.regexp.pattern;
+
+    assert_eq!((pattern.start, pattern.end), (0, 5));
+
+    let spans = pattern.alternatives[0]
+        .elements
+        .iter()
+        .map(|element| match element {
+            Element::Character(character) => (character.start, character.end),
+            Element::CapturingGroup(group) => (group.start, group.end),
+            _ => panic!("Unexpected element."),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(spans, vec![(0, 2), (2, 5)]);
+}
@@ -1,10 +1,68 @@
-use crate::parser::assert_parser_eq;
+use crate::parser::{assert_parse_module_to_throw, assert_parser_script_eq};
+use okapi_js_parser::{ParserErrorKind, ParserOptions, TokenKind};
 
 #[test]
 fn class_decorator() {
-    assert_parser_eq!(
-        r#"@decorator 
-        class Foo { }"#,
-        r#"{"type":"Program","start":0,"end":13,"body":[{"type":"ClassDeclaration","start":0,"end":13,"id":{"type":"Identifier","start":6,"end":9,"name":"Foo"},"superClass":null,"body":{"type":"ClassBody","start":10,"end":13,"body":[]}}],"sourceType":"script"}"#
+    assert_parser_script_eq!(
+        r#"@decorator
+class Foo { }"#,
+        r#"{"type":"Program","start":0,"end":24,"body":[{"type":"ClassDeclaration","start":0,"end":24,"id":{"type":"Identifier","start":17,"end":20,"name":"Foo"},"superClass":null,"body":{"type":"ClassBody","start":21,"end":24,"body":[]},"decorators":[{"type":"Decorator","start":0,"end":10,"expression":{"type":"Identifier","start":1,"end":10,"name":"decorator"}}]}],"sourceType":"script"}"#,
+        ParserOptions::new().decorators(true)
+    );
+}
+
+#[test]
+fn class_element_decorator() {
+    assert_parser_script_eq!(
+        r#"class Foo { @dec static accessor #x = 1; @a.b() method() {} @c field; }"#,
+        r#"{"type":"Program","start":0,"end":71,"body":[{"type":"ClassDeclaration","start":0,"end":71,"id":{"type":"Identifier","start":6,"end":9,"name":"Foo"},"superClass":null,"body":{"type":"ClassBody","start":10,"end":71,"body":[{"type":"AccessorProperty","start":12,"end":40,"static":true,"computed":false,"key":{"type":"PrivateIdentifier","start":33,"end":35,"name":"x"},"value":{"type":"Literal","start":38,"end":39,"value":1.0,"raw":"1"},"decorators":[{"type":"Decorator","start":12,"end":16,"expression":{"type":"Identifier","start":13,"end":16,"name":"dec"}}]},{"type":"MethodDefinition","start":41,"end":59,"static":false,"computed":false,"key":{"type":"Identifier","start":48,"end":54,"name":"method"},"kind":"method","value":{"type":"FunctionExpression","start":54,"end":59,"id":null,"expression":false,"generator":false,"async":false,"params":[],"body":{"type":"BlockStatement","start":57,"end":59,"body":[]}},"decorators":[{"type":"Decorator","start":41,"end":47,"expression":{"type":"CallExpression","start":42,"end":47,"callee":{"type":"MemberExpression","start":42,"end":45,"object":{"type":"Identifier","start":42,"end":43,"name":"a"},"property":{"type":"Identifier","start":44,"end":45,"name":"b"},"computed":false,"optional":false},"arguments":[],"optional":false}}]},{"type":"PropertyDefinition","start":60,"end":69,"static":false,"computed":false,"key":{"type":"Identifier","start":63,"end":68,"name":"field"},"value":null,"decorators":[{"type":"Decorator","start":60,"end":62,"expression":{"type":"Identifier","start":61,"end":62,"name":"c"}}]}]}}],"sourceType":"script"}"#,
+        ParserOptions::new().decorators(true)
+    );
+}
+
+#[test]
+fn class_accessor_without_decorators_option() {
+    assert_parser_script_eq!(
+        r#"class Foo { accessor = 1 }"#,
+        r#"{"type":"Program","start":0,"end":26,"body":[{"type":"ClassDeclaration","start":0,"end":26,"id":{"type":"Identifier","start":6,"end":9,"name":"Foo"},"superClass":null,"body":{"type":"ClassBody","start":10,"end":26,"body":[{"type":"PropertyDefinition","start":12,"end":24,"static":false,"computed":false,"key":{"type":"Identifier","start":12,"end":20,"name":"accessor"},"value":{"type":"Literal","start":23,"end":24,"value":1.0,"raw":"1"}}]}}],"sourceType":"script"}"#
+    );
+}
+
+#[test]
+fn class_decorator_errors() {
+    assert_parse_module_to_throw!(
+        r#"@decorator class Foo {}"#,
+        ParserErrorKind::UnexpectedToken(TokenKind::At)
+    );
+
+    assert_parse_module_to_throw!(
+        r#"@decorator function foo() {}"#,
+        ParserErrorKind::InvalidDecoratorPosition,
+        ParserOptions::new().decorators(true)
+    );
+
+    assert_parse_module_to_throw!(
+        r#"class Foo { @decorator static {} }"#,
+        ParserErrorKind::InvalidDecoratorPosition,
+        ParserOptions::new().decorators(true)
+    );
+
+    assert_parse_module_to_throw!(
+        r#"class Foo { @decorator; }"#,
+        ParserErrorKind::InvalidDecoratorPosition,
+        ParserOptions::new().decorators(true)
+    );
+
+    assert_parse_module_to_throw!(
+        r#"@first export @second class Foo {}"#,
+        ParserErrorKind::InvalidDecoratorPosition,
+        ParserOptions::new().decorators(true)
+    );
+
+    // Only a single call is allowed after a decorator member expression.
+    assert_parse_module_to_throw!(
+        r#"@(() => decorator)() class Bar {}"#,
+        ParserErrorKind::InvalidDecoratorPosition,
+        ParserOptions::new().decorators(true)
     );
 }
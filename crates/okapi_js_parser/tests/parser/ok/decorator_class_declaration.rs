@@ -1,9 +1,11 @@
-use crate::parser::assert_parser_eq;
+use crate::parser::assert_parser_script_eq;
+use okapi_js_parser::ParserOptions;
 
 #[test]
 fn decorator_class_declaration() {
-    assert_parser_eq!(
-        r#"function foo() { @decorator class Foo {} @firt.field @second @((() => decorator)()) class Bar {} }"#,
-        r#"undefined"#
+    assert_parser_script_eq!(
+        r#"function foo() { @decorator class Foo {} @first.field @second @((() => decorator)()) class Bar {} }"#,
+        r#"{"type":"Program","start":0,"end":99,"body":[{"type":"FunctionDeclaration","start":0,"end":99,"id":{"type":"Identifier","start":9,"end":12,"name":"foo"},"expression":false,"generator":false,"async":false,"params":[],"body":{"type":"BlockStatement","start":15,"end":99,"body":[{"type":"ClassDeclaration","start":17,"end":40,"id":{"type":"Identifier","start":34,"end":37,"name":"Foo"},"superClass":null,"body":{"type":"ClassBody","start":38,"end":40,"body":[]},"decorators":[{"type":"Decorator","start":17,"end":27,"expression":{"type":"Identifier","start":18,"end":27,"name":"decorator"}}]},{"type":"ClassDeclaration","start":41,"end":97,"id":{"type":"Identifier","start":91,"end":94,"name":"Bar"},"superClass":null,"body":{"type":"ClassBody","start":95,"end":97,"body":[]},"decorators":[{"type":"Decorator","start":41,"end":53,"expression":{"type":"MemberExpression","start":42,"end":53,"object":{"type":"Identifier","start":42,"end":47,"name":"first"},"property":{"type":"Identifier","start":48,"end":53,"name":"field"},"computed":false,"optional":false}},{"type":"Decorator","start":54,"end":61,"expression":{"type":"Identifier","start":55,"end":61,"name":"second"}},{"type":"Decorator","start":62,"end":84,"expression":{"type":"CallExpression","start":64,"end":83,"callee":{"type":"ArrowFunctionExpression","start":65,"end":80,"id":null,"expression":true,"generator":false,"async":false,"params":[],"body":{"type":"Identifier","start":71,"end":80,"name":"decorator"}},"arguments":[],"optional":false}}]}]}}],"sourceType":"script"}"#,
+        ParserOptions::new().decorators(true)
     );
 }
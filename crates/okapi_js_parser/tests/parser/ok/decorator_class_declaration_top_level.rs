@@ -1,20 +1,12 @@
-use crate::parser::assert_parser_eq;
+use crate::parser::assert_parser_script_eq;
+use okapi_js_parser::ParserOptions;
 
 #[test]
 fn decorator_class_declaration_top_level() {
-    assert_parser_eq!(r#"@decorator"#, r#"undefined"#);
-    assert_parser_eq!(
-        r#"class Foo { }"#,
-        r#"{"type":"Program","start":0,"end":13,"body":[{"type":"ClassDeclaration","start":0,"end":13,"id":{"type":"Identifier","start":6,"end":9,"name":"Foo"},"superClass":null,"body":{"type":"ClassBody","start":10,"end":13,"body":[]}}],"sourceType":"script"}"#
-    );
-
-    assert_parser_eq!(
-        r#"@first.field @second @(() => decorator)()"#,
-        r#"undefined"#
-    );
-
-    assert_parser_eq!(
-        r#"class Bar {}"#,
-        r#"{"type":"Program","start":0,"end":12,"body":[{"type":"ClassDeclaration","start":0,"end":12,"id":{"type":"Identifier","start":6,"end":9,"name":"Bar"},"superClass":null,"body":{"type":"ClassBody","start":10,"end":12,"body":[]}}],"sourceType":"script"}"#
+    assert_parser_script_eq!(
+        r#"@first.field @second @(() => decorator)
+class Bar {}"#,
+        r#"{"type":"Program","start":0,"end":52,"body":[{"type":"ClassDeclaration","start":0,"end":52,"id":{"type":"Identifier","start":46,"end":49,"name":"Bar"},"superClass":null,"body":{"type":"ClassBody","start":50,"end":52,"body":[]},"decorators":[{"type":"Decorator","start":0,"end":12,"expression":{"type":"MemberExpression","start":1,"end":12,"object":{"type":"Identifier","start":1,"end":6,"name":"first"},"property":{"type":"Identifier","start":7,"end":12,"name":"field"},"computed":false,"optional":false}},{"type":"Decorator","start":13,"end":20,"expression":{"type":"Identifier","start":14,"end":20,"name":"second"}},{"type":"Decorator","start":21,"end":39,"expression":{"type":"ArrowFunctionExpression","start":23,"end":38,"id":null,"expression":true,"generator":false,"async":false,"params":[],"body":{"type":"Identifier","start":29,"end":38,"name":"decorator"}}}]}],"sourceType":"script"}"#,
+        ParserOptions::new().decorators(true)
     );
 }
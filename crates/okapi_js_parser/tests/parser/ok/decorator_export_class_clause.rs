@@ -1,11 +1,23 @@
-use crate::parser::assert_parser_eq;
+use crate::parser::assert_parser_module_eq;
+use okapi_js_parser::ParserOptions;
 
 #[test]
 fn decorator_export_class_clause() {
-    assert_parser_eq!(r#"export @decorator class Bar {}"#, r#"undefined"#);
+    assert_parser_module_eq!(
+        r#"export @decorator class Bar {}"#,
+        r#"{"type":"Program","start":0,"end":30,"body":[{"type":"ExportNamedDeclaration","start":0,"end":30,"declaration":{"type":"ClassDeclaration","start":7,"end":30,"id":{"type":"Identifier","start":24,"end":27,"name":"Bar"},"superClass":null,"body":{"type":"ClassBody","start":28,"end":30,"body":[]},"decorators":[{"type":"Decorator","start":7,"end":17,"expression":{"type":"Identifier","start":8,"end":17,"name":"decorator"}}]},"specifiers":[],"source":null,"attributes":[]}],"sourceType":"module"}"#,
+        ParserOptions::new().decorators(true)
+    );
+
+    assert_parser_module_eq!(
+        r#"export @first @second class Foo { constructor() {} }"#,
+        r#"{"type":"Program","start":0,"end":52,"body":[{"type":"ExportNamedDeclaration","start":0,"end":52,"declaration":{"type":"ClassDeclaration","start":7,"end":52,"id":{"type":"Identifier","start":28,"end":31,"name":"Foo"},"superClass":null,"body":{"type":"ClassBody","start":32,"end":52,"body":[{"type":"MethodDefinition","start":34,"end":50,"static":false,"computed":false,"key":{"type":"Identifier","start":34,"end":45,"name":"constructor"},"kind":"constructor","value":{"type":"FunctionExpression","start":45,"end":50,"id":null,"expression":false,"generator":false,"async":false,"params":[],"body":{"type":"BlockStatement","start":48,"end":50,"body":[]}}}]},"decorators":[{"type":"Decorator","start":7,"end":13,"expression":{"type":"Identifier","start":8,"end":13,"name":"first"}},{"type":"Decorator","start":14,"end":21,"expression":{"type":"Identifier","start":15,"end":21,"name":"second"}}]},"specifiers":[],"source":null,"attributes":[]}],"sourceType":"module"}"#,
+        ParserOptions::new().decorators(true)
+    );
 
-    assert_parser_eq!(
-        r#"export @firt @second class Foo { contructor() {} }"#,
-        r#"undefined"#
+    assert_parser_module_eq!(
+        r#"export default @decorator class {}"#,
+        r#"{"type":"Program","start":0,"end":34,"body":[{"type":"ExportDefaultDeclaration","start":0,"end":34,"declaration":{"type":"ClassDeclaration","start":15,"end":34,"id":null,"superClass":null,"body":{"type":"ClassBody","start":32,"end":34,"body":[]},"decorators":[{"type":"Decorator","start":15,"end":25,"expression":{"type":"Identifier","start":16,"end":25,"name":"decorator"}}]}}],"sourceType":"module"}"#,
+        ParserOptions::new().decorators(true)
     );
 }
@@ -1,19 +1,19 @@
-use crate::parser::assert_parser_eq;
+use crate::parser::assert_parser_module_eq;
+use okapi_js_parser::ParserOptions;
 
 #[test]
 fn decorator_export_top_level() {
-    assert_parser_eq!(r#"@decorator"#, r#"undefined"#);
-    assert_parser_eq!(r#"export class Foo { }"#, r#"undefined"#);
-    assert_parser_eq!(
-        r#"@first.field @second @(() => decorator)()"#,
-        r#"undefined"#
+    assert_parser_module_eq!(
+        r#"@before
+export class Foo { }"#,
+        r#"{"type":"Program","start":0,"end":28,"body":[{"type":"ExportNamedDeclaration","start":0,"end":28,"declaration":{"type":"ClassDeclaration","start":0,"end":28,"id":{"type":"Identifier","start":21,"end":24,"name":"Foo"},"superClass":null,"body":{"type":"ClassBody","start":25,"end":28,"body":[]},"decorators":[{"type":"Decorator","start":0,"end":7,"expression":{"type":"Identifier","start":1,"end":7,"name":"before"}}]},"specifiers":[],"source":null,"attributes":[]}],"sourceType":"module"}"#,
+        ParserOptions::new().decorators(true)
     );
 
-    assert_parser_eq!(r#"export class Bar {}"#, r#"undefined"#);
-    assert_parser_eq!(r#"@before"#, r#"undefined"#);
-    assert_parser_eq!(r#"export @after class Foo { }"#, r#"undefined"#);
-    assert_parser_eq!(r#"@before"#, r#"undefined"#);
-    assert_parser_eq!(r#"export abstract class Foo { }"#, r#"undefined"#);
-    assert_parser_eq!(r#"@before"#, r#"undefined"#);
-    assert_parser_eq!(r#"export @after abstract class Foo { }"#, r#"undefined"#);
+    assert_parser_module_eq!(
+        r#"@before
+export default class Foo { }"#,
+        r#"{"type":"Program","start":0,"end":36,"body":[{"type":"ExportDefaultDeclaration","start":0,"end":36,"declaration":{"type":"ClassDeclaration","start":0,"end":36,"id":{"type":"Identifier","start":29,"end":32,"name":"Foo"},"superClass":null,"body":{"type":"ClassBody","start":33,"end":36,"body":[]},"decorators":[{"type":"Decorator","start":0,"end":7,"expression":{"type":"Identifier","start":1,"end":7,"name":"before"}}]}}],"sourceType":"module"}"#,
+        ParserOptions::new().decorators(true)
+    );
 }
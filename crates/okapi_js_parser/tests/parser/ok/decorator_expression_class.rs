@@ -1,11 +1,17 @@
-use crate::parser::assert_parser_eq;
+use crate::parser::assert_parser_script_eq;
+use okapi_js_parser::ParserOptions;
 
 #[test]
 fn decorator_expression_class() {
-    assert_parser_eq!(r#"let a = ( @decorator class {} );"#, r#"undefined"#);
+    assert_parser_script_eq!(
+        r#"let a = ( @decorator class {} );"#,
+        r#"{"type":"Program","start":0,"end":32,"body":[{"type":"VariableDeclaration","start":0,"end":32,"declarations":[{"type":"VariableDeclarator","start":4,"end":31,"id":{"type":"Identifier","start":4,"end":5,"name":"a"},"init":{"type":"ClassExpression","start":10,"end":29,"id":null,"superClass":null,"body":{"type":"ClassBody","start":27,"end":29,"body":[]},"decorators":[{"type":"Decorator","start":10,"end":20,"expression":{"type":"Identifier","start":11,"end":20,"name":"decorator"}}]}}],"kind":"let"}],"sourceType":"script"}"#,
+        ParserOptions::new().decorators(true)
+    );
 
-    assert_parser_eq!(
+    assert_parser_script_eq!(
         r#"let b = ( @first @second class foo { constructor() {} } );"#,
-        r#"undefined"#
+        r#"{"type":"Program","start":0,"end":58,"body":[{"type":"VariableDeclaration","start":0,"end":58,"declarations":[{"type":"VariableDeclarator","start":4,"end":57,"id":{"type":"Identifier","start":4,"end":5,"name":"b"},"init":{"type":"ClassExpression","start":10,"end":55,"id":{"type":"Identifier","start":31,"end":34,"name":"foo"},"superClass":null,"body":{"type":"ClassBody","start":35,"end":55,"body":[{"type":"MethodDefinition","start":37,"end":53,"static":false,"computed":false,"key":{"type":"Identifier","start":37,"end":48,"name":"constructor"},"kind":"constructor","value":{"type":"FunctionExpression","start":48,"end":53,"id":null,"expression":false,"generator":false,"async":false,"params":[],"body":{"type":"BlockStatement","start":51,"end":53,"body":[]}}}]},"decorators":[{"type":"Decorator","start":10,"end":16,"expression":{"type":"Identifier","start":11,"end":16,"name":"first"}},{"type":"Decorator","start":17,"end":24,"expression":{"type":"Identifier","start":18,"end":24,"name":"second"}}]}}],"kind":"let"}],"sourceType":"script"}"#,
+        ParserOptions::new().decorators(true)
     );
 }
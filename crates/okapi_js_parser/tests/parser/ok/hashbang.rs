@@ -0,0 +1,28 @@
+use crate::parser::{
+    assert_parse_module_to_throw, assert_parser_module_eq, assert_parser_script_eq,
+};
+use okapi_js_parser::ParserErrorKind;
+
+#[test]
+fn hashbang() {
+    assert_parser_script_eq!(
+        "#!/usr/bin/env node\nfoo;",
+        r#"{"type":"Program","start":0,"end":24,"body":[{"type":"ExpressionStatement","start":20,"end":24,"expression":{"type":"Identifier","start":20,"end":23,"name":"foo"}}],"sourceType":"script","hashbang":"/usr/bin/env node"}"#
+    );
+    assert_parser_module_eq!(
+        "#!/usr/bin/env node\r\nfoo;",
+        r#"{"type":"Program","start":0,"end":25,"body":[{"type":"ExpressionStatement","start":21,"end":25,"expression":{"type":"Identifier","start":21,"end":24,"name":"foo"}}],"sourceType":"module","hashbang":"/usr/bin/env node"}"#
+    );
+    assert_parser_script_eq!(
+        "#!",
+        r#"{"type":"Program","start":0,"end":2,"body":[],"sourceType":"script","hashbang":""}"#
+    );
+}
+
+#[test]
+fn hashbang_only_at_start_of_source() {
+    assert_parse_module_to_throw!(
+        " #!/usr/bin/env node",
+        ParserErrorKind::InvalidIdentifierCharacter
+    );
+}
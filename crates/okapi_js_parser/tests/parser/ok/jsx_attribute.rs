@@ -0,0 +1,23 @@
+use crate::parser::assert_parser_script_eq;
+use okapi_js_parser::ParserOptions;
+
+#[test]
+fn jsx_attribute() {
+    assert_parser_script_eq!(
+        r#"<div id="a" disabled data-x='b' />;"#,
+        r#"{"type":"Program","start":0,"end":35,"body":[{"type":"ExpressionStatement","start":0,"end":35,"expression":{"type":"JSXElement","start":0,"end":34,"openingElement":{"type":"JSXOpeningElement","start":0,"end":34,"attributes":[{"type":"JSXAttribute","start":5,"end":11,"name":{"type":"JSXIdentifier","start":5,"end":7,"name":"id"},"value":{"type":"Literal","start":8,"end":11,"value":"a","raw":"\"a\""}},{"type":"JSXAttribute","start":12,"end":20,"name":{"type":"JSXIdentifier","start":12,"end":20,"name":"disabled"},"value":null},{"type":"JSXAttribute","start":21,"end":31,"name":{"type":"JSXIdentifier","start":21,"end":27,"name":"data-x"},"value":{"type":"Literal","start":28,"end":31,"value":"b","raw":"'b'"}}],"name":{"type":"JSXIdentifier","start":1,"end":4,"name":"div"},"selfClosing":true},"closingElement":null,"children":[]}}],"sourceType":"script"}"#,
+        ParserOptions::new().jsx(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"<a b={c} {...d} e:f="g" />;"#,
+        r#"{"type":"Program","start":0,"end":27,"body":[{"type":"ExpressionStatement","start":0,"end":27,"expression":{"type":"JSXElement","start":0,"end":26,"openingElement":{"type":"JSXOpeningElement","start":0,"end":26,"attributes":[{"type":"JSXAttribute","start":3,"end":8,"name":{"type":"JSXIdentifier","start":3,"end":4,"name":"b"},"value":{"type":"JSXExpressionContainer","start":5,"end":8,"expression":{"type":"Identifier","start":6,"end":7,"name":"c"}}},{"type":"JSXSpreadAttribute","start":9,"end":15,"argument":{"type":"Identifier","start":13,"end":14,"name":"d"}},{"type":"JSXAttribute","start":16,"end":23,"name":{"type":"JSXNamespacedName","start":16,"end":19,"namespace":{"type":"JSXIdentifier","start":16,"end":17,"name":"e"},"name":{"type":"JSXIdentifier","start":18,"end":19,"name":"f"}},"value":{"type":"Literal","start":20,"end":23,"value":"g","raw":"\"g\""}}],"name":{"type":"JSXIdentifier","start":1,"end":2,"name":"a"},"selfClosing":true},"closingElement":null,"children":[]}}],"sourceType":"script"}"#,
+        ParserOptions::new().jsx(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"<a b=<c /> />;"#,
+        r#"{"type":"Program","start":0,"end":14,"body":[{"type":"ExpressionStatement","start":0,"end":14,"expression":{"type":"JSXElement","start":0,"end":13,"openingElement":{"type":"JSXOpeningElement","start":0,"end":13,"attributes":[{"type":"JSXAttribute","start":3,"end":10,"name":{"type":"JSXIdentifier","start":3,"end":4,"name":"b"},"value":{"type":"JSXElement","start":5,"end":10,"openingElement":{"type":"JSXOpeningElement","start":5,"end":10,"attributes":[],"name":{"type":"JSXIdentifier","start":6,"end":7,"name":"c"},"selfClosing":true},"closingElement":null,"children":[]}}],"name":{"type":"JSXIdentifier","start":1,"end":2,"name":"a"},"selfClosing":true},"closingElement":null,"children":[]}}],"sourceType":"script"}"#,
+        ParserOptions::new().jsx(true)
+    );
+}
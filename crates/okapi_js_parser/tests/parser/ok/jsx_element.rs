@@ -0,0 +1,29 @@
+use crate::parser::assert_parser_script_eq;
+use okapi_js_parser::ParserOptions;
+
+#[test]
+fn jsx_element() {
+    assert_parser_script_eq!(
+        r#"<a />;"#,
+        r#"{"type":"Program","start":0,"end":6,"body":[{"type":"ExpressionStatement","start":0,"end":6,"expression":{"type":"JSXElement","start":0,"end":5,"openingElement":{"type":"JSXOpeningElement","start":0,"end":5,"attributes":[],"name":{"type":"JSXIdentifier","start":1,"end":2,"name":"a"},"selfClosing":true},"closingElement":null,"children":[]}}],"sourceType":"script"}"#,
+        ParserOptions::new().jsx(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"<a:b></a:b>;"#,
+        r#"{"type":"Program","start":0,"end":12,"body":[{"type":"ExpressionStatement","start":0,"end":12,"expression":{"type":"JSXElement","start":0,"end":11,"openingElement":{"type":"JSXOpeningElement","start":0,"end":5,"attributes":[],"name":{"type":"JSXNamespacedName","start":1,"end":4,"namespace":{"type":"JSXIdentifier","start":1,"end":2,"name":"a"},"name":{"type":"JSXIdentifier","start":3,"end":4,"name":"b"}},"selfClosing":false},"closingElement":{"type":"JSXClosingElement","start":5,"end":11,"name":{"type":"JSXNamespacedName","start":7,"end":10,"namespace":{"type":"JSXIdentifier","start":7,"end":8,"name":"a"},"name":{"type":"JSXIdentifier","start":9,"end":10,"name":"b"}}},"children":[]}}],"sourceType":"script"}"#,
+        ParserOptions::new().jsx(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"<a.b.c></a.b.c>;"#,
+        r#"{"type":"Program","start":0,"end":16,"body":[{"type":"ExpressionStatement","start":0,"end":16,"expression":{"type":"JSXElement","start":0,"end":15,"openingElement":{"type":"JSXOpeningElement","start":0,"end":7,"attributes":[],"name":{"type":"JSXMemberExpression","start":1,"end":6,"object":{"type":"JSXMemberExpression","start":1,"end":4,"object":{"type":"JSXIdentifier","start":1,"end":2,"name":"a"},"property":{"type":"JSXIdentifier","start":3,"end":4,"name":"b"}},"property":{"type":"JSXIdentifier","start":5,"end":6,"name":"c"}},"selfClosing":false},"closingElement":{"type":"JSXClosingElement","start":7,"end":15,"name":{"type":"JSXMemberExpression","start":9,"end":14,"object":{"type":"JSXMemberExpression","start":9,"end":12,"object":{"type":"JSXIdentifier","start":9,"end":10,"name":"a"},"property":{"type":"JSXIdentifier","start":11,"end":12,"name":"b"}},"property":{"type":"JSXIdentifier","start":13,"end":14,"name":"c"}}},"children":[]}}],"sourceType":"script"}"#,
+        ParserOptions::new().jsx(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"<a>{/* comment */}</a>;"#,
+        r#"{"type":"Program","start":0,"end":23,"body":[{"type":"ExpressionStatement","start":0,"end":23,"expression":{"type":"JSXElement","start":0,"end":22,"openingElement":{"type":"JSXOpeningElement","start":0,"end":3,"attributes":[],"name":{"type":"JSXIdentifier","start":1,"end":2,"name":"a"},"selfClosing":false},"closingElement":{"type":"JSXClosingElement","start":18,"end":22,"name":{"type":"JSXIdentifier","start":20,"end":21,"name":"a"}},"children":[{"type":"JSXExpressionContainer","start":3,"end":18,"expression":{"type":"JSXEmptyExpression","start":4,"end":17}}]}}],"sourceType":"script"}"#,
+        ParserOptions::new().jsx(true)
+    );
+}
@@ -0,0 +1,11 @@
+use crate::parser::assert_parser_script_eq;
+use okapi_js_parser::ParserOptions;
+
+#[test]
+fn jsx_fragment() {
+    assert_parser_script_eq!(
+        r#"<><a /><>b</></>;"#,
+        r#"{"type":"Program","start":0,"end":17,"body":[{"type":"ExpressionStatement","start":0,"end":17,"expression":{"type":"JSXFragment","start":0,"end":16,"openingFragment":{"type":"JSXOpeningFragment","start":0,"end":2},"closingFragment":{"type":"JSXClosingFragment","start":13,"end":16},"children":[{"type":"JSXElement","start":2,"end":7,"openingElement":{"type":"JSXOpeningElement","start":2,"end":7,"attributes":[],"name":{"type":"JSXIdentifier","start":3,"end":4,"name":"a"},"selfClosing":true},"closingElement":null,"children":[]},{"type":"JSXFragment","start":7,"end":13,"openingFragment":{"type":"JSXOpeningFragment","start":7,"end":9},"closingFragment":{"type":"JSXClosingFragment","start":10,"end":13},"children":[{"type":"JSXText","start":9,"end":10,"value":"b","raw":"b"}]}]}}],"sourceType":"script"}"#,
+        ParserOptions::new().jsx(true)
+    );
+}
@@ -0,0 +1,17 @@
+use crate::parser::assert_parser_script_eq;
+use okapi_js_parser::ParserOptions;
+
+#[test]
+fn jsx_in_expression() {
+    assert_parser_script_eq!(
+        r#"let a = (<b>{c ? <d /> : null}</b>) / 2;"#,
+        r#"{"type":"Program","start":0,"end":40,"body":[{"type":"VariableDeclaration","start":0,"end":40,"declarations":[{"type":"VariableDeclarator","start":4,"end":39,"id":{"type":"Identifier","start":4,"end":5,"name":"a"},"init":{"type":"BinaryExpression","start":8,"end":39,"left":{"type":"JSXElement","start":9,"end":34,"openingElement":{"type":"JSXOpeningElement","start":9,"end":12,"attributes":[],"name":{"type":"JSXIdentifier","start":10,"end":11,"name":"b"},"selfClosing":false},"closingElement":{"type":"JSXClosingElement","start":30,"end":34,"name":{"type":"JSXIdentifier","start":32,"end":33,"name":"b"}},"children":[{"type":"JSXExpressionContainer","start":12,"end":30,"expression":{"type":"ConditionalExpression","start":13,"end":29,"test":{"type":"Identifier","start":13,"end":14,"name":"c"},"consequent":{"type":"JSXElement","start":17,"end":22,"openingElement":{"type":"JSXOpeningElement","start":17,"end":22,"attributes":[],"name":{"type":"JSXIdentifier","start":18,"end":19,"name":"d"},"selfClosing":true},"closingElement":null,"children":[]},"alternate":{"type":"Literal","start":25,"end":29,"value":null,"raw":"null"}}}]},"operator":"/","right":{"type":"Literal","start":38,"end":39,"value":2.0,"raw":"2"}}}],"kind":"let"}],"sourceType":"script"}"#,
+        ParserOptions::new().jsx(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"f(() => <a />, b < c);"#,
+        r#"{"type":"Program","start":0,"end":22,"body":[{"type":"ExpressionStatement","start":0,"end":22,"expression":{"type":"CallExpression","start":0,"end":21,"callee":{"type":"Identifier","start":0,"end":1,"name":"f"},"arguments":[{"type":"ArrowFunctionExpression","start":2,"end":13,"id":null,"expression":true,"generator":false,"async":false,"params":[],"body":{"type":"JSXElement","start":8,"end":13,"openingElement":{"type":"JSXOpeningElement","start":8,"end":13,"attributes":[],"name":{"type":"JSXIdentifier","start":9,"end":10,"name":"a"},"selfClosing":true},"closingElement":null,"children":[]}},{"type":"BinaryExpression","start":15,"end":20,"left":{"type":"Identifier","start":15,"end":16,"name":"b"},"operator":"<","right":{"type":"Identifier","start":19,"end":20,"name":"c"}}],"optional":false}}],"sourceType":"script"}"#,
+        ParserOptions::new().jsx(true)
+    );
+}
@@ -0,0 +1,11 @@
+use crate::parser::assert_parser_script_eq;
+use okapi_js_parser::ParserOptions;
+
+#[test]
+fn jsx_spread_child() {
+    assert_parser_script_eq!(
+        r#"<div>{...children}</div>;"#,
+        r#"{"type":"Program","start":0,"end":25,"body":[{"type":"ExpressionStatement","start":0,"end":25,"expression":{"type":"JSXElement","start":0,"end":24,"openingElement":{"type":"JSXOpeningElement","start":0,"end":5,"attributes":[],"name":{"type":"JSXIdentifier","start":1,"end":4,"name":"div"},"selfClosing":false},"closingElement":{"type":"JSXClosingElement","start":18,"end":24,"name":{"type":"JSXIdentifier","start":20,"end":23,"name":"div"}},"children":[{"type":"JSXSpreadChild","start":5,"end":18,"expression":{"type":"Identifier","start":9,"end":17,"name":"children"}}]}}],"sourceType":"script"}"#,
+        ParserOptions::new().jsx(true)
+    );
+}
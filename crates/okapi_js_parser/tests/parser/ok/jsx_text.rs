@@ -0,0 +1,19 @@
+use crate::parser::assert_parser_script_eq;
+use okapi_js_parser::ParserOptions;
+
+#[test]
+fn jsx_text() {
+    assert_parser_script_eq!(
+        r#"<a>hello &amp; &#x41;&#65; & world</a>;"#,
+        r#"{"type":"Program","start":0,"end":39,"body":[{"type":"ExpressionStatement","start":0,"end":39,"expression":{"type":"JSXElement","start":0,"end":38,"openingElement":{"type":"JSXOpeningElement","start":0,"end":3,"attributes":[],"name":{"type":"JSXIdentifier","start":1,"end":2,"name":"a"},"selfClosing":false},"closingElement":{"type":"JSXClosingElement","start":34,"end":38,"name":{"type":"JSXIdentifier","start":36,"end":37,"name":"a"}},"children":[{"type":"JSXText","start":3,"end":34,"value":"hello & AA & world","raw":"hello &amp; &#x41;&#65; & world"}]}}],"sourceType":"script"}"#,
+        ParserOptions::new().jsx(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"<div>
+  one {two}
+</div>;"#,
+        r#"{"type":"Program","start":0,"end":25,"body":[{"type":"ExpressionStatement","start":0,"end":25,"expression":{"type":"JSXElement","start":0,"end":24,"openingElement":{"type":"JSXOpeningElement","start":0,"end":5,"attributes":[],"name":{"type":"JSXIdentifier","start":1,"end":4,"name":"div"},"selfClosing":false},"closingElement":{"type":"JSXClosingElement","start":18,"end":24,"name":{"type":"JSXIdentifier","start":20,"end":23,"name":"div"}},"children":[{"type":"JSXText","start":5,"end":12,"value":"\n  one ","raw":"\n  one "},{"type":"JSXExpressionContainer","start":12,"end":17,"expression":{"type":"Identifier","start":13,"end":16,"name":"two"}},{"type":"JSXText","start":17,"end":18,"value":"\n","raw":"\n"}]}}],"sourceType":"script"}"#,
+        ParserOptions::new().jsx(true)
+    );
+}
@@ -13,10 +13,23 @@ fn literals_number() {
     );
 }
 
-// #[test]
-// fn literals_big_n() {
-//     assert_parser_eq!(r#"5n"#, r#"undefined"#);
-// }
+#[test]
+fn literals_big_int() {
+    assert_parser_script_eq!(
+        r#"5n"#,
+        r#"{"type":"Program","start":0,"end":2,"body":[{"type":"ExpressionStatement","start":0,"end":2,"expression":{"type":"Literal","start":0,"end":2,"value":null,"raw":"5n","bigint":"5"}}],"sourceType":"script"}"#
+    );
+
+    assert_parser_script_eq!(
+        r#"1_000n, 0xFF_FFn, 0o17n, 0B101n"#,
+        r#"{"type":"Program","start":0,"end":31,"body":[{"type":"ExpressionStatement","start":0,"end":31,"expression":{"type":"SequenceExpression","start":0,"end":31,"expressions":[{"type":"Literal","start":0,"end":6,"value":null,"raw":"1_000n","bigint":"1000"},{"type":"Literal","start":8,"end":16,"value":null,"raw":"0xFF_FFn","bigint":"0xFFFF"},{"type":"Literal","start":18,"end":23,"value":null,"raw":"0o17n","bigint":"0o17"},{"type":"Literal","start":25,"end":31,"value":null,"raw":"0B101n","bigint":"0B101"}]}}],"sourceType":"script"}"#
+    );
+
+    assert_parser_script_eq!(
+        r#"({ 1n: a })"#,
+        r#"{"type":"Program","start":0,"end":11,"body":[{"type":"ExpressionStatement","start":0,"end":11,"expression":{"type":"ObjectExpression","start":1,"end":10,"properties":[{"type":"Property","start":3,"end":8,"method":false,"shorthand":false,"computed":false,"key":{"type":"Literal","start":3,"end":5,"value":null,"raw":"1n","bigint":"1"},"value":{"type":"Identifier","start":7,"end":8,"name":"a"},"kind":"init"}]}}],"sourceType":"script"}"#
+    );
+}
 
 #[test]
 fn literals_string() {
@@ -26,6 +26,7 @@ mod break_statement;
 mod call_arguments;
 mod call_expression;
 mod class_declaration;
+mod class_decorator;
 mod class_empty_element;
 mod class_expression;
 mod class_member_modifiers;
@@ -39,6 +40,11 @@ mod conditional_expression;
 mod constructor_class_member;
 mod continue_statement;
 mod debugger_statement;
+mod decorator_class_declaration;
+mod decorator_class_declaration_top_level;
+mod decorator_export_class_clause;
+mod decorator_export_top_level;
+mod decorator_expression_class;
 mod destructuring_initializer_binding;
 mod directives;
 mod directives_redundant;
@@ -55,11 +61,18 @@ mod function_identifier;
 mod getter_class_member;
 mod getter_object_member;
 mod grouping_expression;
+mod hashbang;
 mod hoisted_declaration_in_single_statement_context;
 mod identifier;
 mod identifier_reference;
 mod if_statement;
 mod in_expression_in_arguments;
+mod jsx_attribute;
+mod jsx_element;
+mod jsx_fragment;
+mod jsx_in_expression;
+mod jsx_spread_child;
+mod jsx_text;
 mod labeled_statement;
 mod labelled_function_declaration;
 mod labelled_statement_in_single_statement_context;
@@ -109,19 +122,19 @@ mod switch_statement;
 mod template_literal;
 mod this_expression;
 mod throw_statement;
+mod top_level_await;
 mod try_statement;
+mod typescript_class;
+mod typescript_declarations;
+mod typescript_expressions;
+mod typescript_type_annotation;
+mod typescript_types;
 mod unary_delete;
 mod unary_delete_nested;
 mod unary_expression;
+mod using_declaration;
 mod variable_statement;
 mod while_statement;
 mod with_statement;
 mod yield_expression;
 mod yield_in_generator_function;
-
-// mod class_decorator;
-// mod decorator_class_declaration;
-// mod decorator_class_declaration_top_level;
-// mod decorator_export_class_clause;
-// mod decorator_export_top_level;
-// mod decorator_expression_class;
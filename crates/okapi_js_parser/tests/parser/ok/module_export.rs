@@ -82,3 +82,16 @@ fn module_export_with_default_declaration() {
         r#"{"type":"Program","start":0,"end":23,"body":[{"type":"ExportDefaultDeclaration","start":0,"end":23,"declaration":{"type":"ClassDeclaration","start":15,"end":23,"id":null,"superClass":null,"body":{"type":"ClassBody","start":21,"end":23,"body":[]}}}],"sourceType":"module"}"#
     );
 }
+
+#[test]
+fn module_export_from_with_attributes() {
+    assert_parser_module_eq!(
+        r#"export * from "a" with { "type": "css", };"#,
+        r#"{"type":"Program","start":0,"end":42,"body":[{"type":"ExportAllDeclaration","start":0,"end":42,"exported":null,"source":{"type":"Literal","start":14,"end":17,"value":"a","raw":"\"a\""},"attributes":[{"type":"ImportAttribute","start":25,"end":38,"key":{"type":"Literal","start":25,"end":31,"value":"type","raw":"\"type\""},"value":{"type":"Literal","start":33,"end":38,"value":"css","raw":"\"css\""}}]}],"sourceType":"module"}"#
+    );
+
+    assert_parser_module_eq!(
+        r#"export { a } from "a" with {};"#,
+        r#"{"type":"Program","start":0,"end":30,"body":[{"type":"ExportNamedDeclaration","start":0,"end":30,"declaration":null,"specifiers":[{"type":"ExportSpecifier","start":9,"end":10,"local":{"type":"Identifier","start":9,"end":10,"name":"a"},"exported":{"type":"Identifier","start":9,"end":10,"name":"a"}}],"source":{"type":"Literal","start":18,"end":21,"value":"a","raw":"\"a\""},"attributes":[]}],"sourceType":"module"}"#
+    );
+}
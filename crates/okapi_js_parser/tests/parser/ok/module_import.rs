@@ -52,3 +52,29 @@ fn module_imported_default_binding_and_named_import() {
         r#"{"type":"Program","start":0,"end":36,"body":[{"type":"ImportDeclaration","start":0,"end":36,"specifiers":[{"type":"ImportDefaultSpecifier","start":7,"end":10,"local":{"type":"Identifier","start":7,"end":10,"name":"foo"}},{"type":"ImportSpecifier","start":14,"end":17,"imported":{"type":"Identifier","start":14,"end":17,"name":"bar"},"local":{"type":"Identifier","start":14,"end":17,"name":"bar"}},{"type":"ImportSpecifier","start":19,"end":22,"imported":{"type":"Identifier","start":19,"end":22,"name":"baz"},"local":{"type":"Identifier","start":19,"end":22,"name":"baz"}}],"source":{"type":"Literal","start":30,"end":35,"value":"bar","raw":"\"bar\""}}],"sourceType":"module"}"#
     );
 }
+
+#[test]
+fn module_import_attributes() {
+    assert_parser_module_eq!(
+        r#"import json from "./foo.json" with { type: "json" };"#,
+        r#"{"type":"Program","start":0,"end":52,"body":[{"type":"ImportDeclaration","start":0,"end":52,"specifiers":[{"type":"ImportDefaultSpecifier","start":7,"end":11,"local":{"type":"Identifier","start":7,"end":11,"name":"json"}}],"source":{"type":"Literal","start":17,"end":29,"value":"./foo.json","raw":"\"./foo.json\""},"attributes":[{"type":"ImportAttribute","start":37,"end":49,"key":{"type":"Identifier","start":37,"end":41,"name":"type"},"value":{"type":"Literal","start":43,"end":49,"value":"json","raw":"\"json\""}}]}],"sourceType":"module"}"#
+    );
+
+    assert_parser_module_eq!(
+        r#"import "foo" with {};"#,
+        r#"{"type":"Program","start":0,"end":21,"body":[{"type":"ImportDeclaration","start":0,"end":21,"specifiers":[],"source":{"type":"Literal","start":7,"end":12,"value":"foo","raw":"\"foo\""},"attributes":[]}],"sourceType":"module"}"#
+    );
+}
+
+#[test]
+fn module_import_call_options() {
+    assert_parser_module_eq!(
+        r#"import("a", b);"#,
+        r#"{"type":"Program","start":0,"end":15,"body":[{"type":"ExpressionStatement","start":0,"end":15,"expression":{"type":"ImportExpression","start":0,"end":14,"source":{"type":"Literal","start":7,"end":10,"value":"a","raw":"\"a\""},"options":{"type":"Identifier","start":12,"end":13,"name":"b"}}}],"sourceType":"module"}"#
+    );
+
+    assert_parser_module_eq!(
+        r#"x = import("a",);"#,
+        r#"{"type":"Program","start":0,"end":17,"body":[{"type":"ExpressionStatement","start":0,"end":17,"expression":{"type":"AssignmentExpression","start":0,"end":16,"operator":"=","left":{"type":"Identifier","start":0,"end":1,"name":"x"},"right":{"type":"ImportExpression","start":4,"end":16,"source":{"type":"Literal","start":11,"end":14,"value":"a","raw":"\"a\""},"options":null}}}],"sourceType":"module"}"#
+    );
+}
@@ -0,0 +1,41 @@
+use crate::parser::{
+    assert_parse_module_to_throw, assert_parser_module_eq, assert_parser_script_eq,
+};
+use okapi_js_parser::{KeywordKind, ParserErrorKind, TokenKind};
+
+#[test]
+fn top_level_await() {
+    assert_parser_module_eq!(
+        r#"await fetchConfig();"#,
+        r#"{"type":"Program","start":0,"end":20,"body":[{"type":"ExpressionStatement","start":0,"end":20,"expression":{"type":"AwaitExpression","start":0,"end":19,"argument":{"type":"CallExpression","start":6,"end":19,"callee":{"type":"Identifier","start":6,"end":17,"name":"fetchConfig"},"arguments":[],"optional":false}}}],"sourceType":"module"}"#
+    );
+
+    assert_parser_module_eq!(
+        r#"for await (const x of y);"#,
+        r#"{"type":"Program","start":0,"end":25,"body":[{"type":"ForOfStatement","start":0,"end":25,"await":true,"left":{"type":"VariableDeclaration","start":11,"end":18,"declarations":[{"type":"VariableDeclarator","start":17,"end":18,"id":{"type":"Identifier","start":17,"end":18,"name":"x"},"init":null}],"kind":"const"},"right":{"type":"Identifier","start":22,"end":23,"name":"y"},"body":{"type":"EmptyStatement","start":24,"end":25}}],"sourceType":"module"}"#
+    );
+}
+
+#[test]
+fn top_level_await_in_script() {
+    assert_parser_script_eq!(
+        r#"await(1);"#,
+        r#"{"type":"Program","start":0,"end":9,"body":[{"type":"ExpressionStatement","start":0,"end":9,"expression":{"type":"CallExpression","start":0,"end":8,"callee":{"type":"Identifier","start":0,"end":5,"name":"await"},"arguments":[{"type":"Literal","start":6,"end":7,"value":1.0,"raw":"1"}],"optional":false}}],"sourceType":"script"}"#
+    );
+}
+
+#[test]
+fn top_level_await_early_errors() {
+    assert_parse_module_to_throw!(
+        r#"let await = 1;"#,
+        ParserErrorKind::UnexpectedToken(TokenKind::Keyword(KeywordKind::Await))
+    );
+    assert_parse_module_to_throw!(
+        r#"function f() { await(1); }"#,
+        ParserErrorKind::UnexpectedToken(TokenKind::Keyword(KeywordKind::Await))
+    );
+    assert_parse_module_to_throw!(
+        r#"const f = () => await x;"#,
+        ParserErrorKind::UnexpectedToken(TokenKind::Keyword(KeywordKind::Await))
+    );
+}
@@ -0,0 +1,62 @@
+use crate::parser::assert_parser_script_eq;
+use okapi_js_parser::ParserOptions;
+
+#[test]
+fn typescript_class_heritage() {
+    assert_parser_script_eq!(
+        r#"class A<T> extends B<T> implements C, D<T> {}"#,
+        r#"{"type":"Program","start":0,"end":45,"body":[{"type":"ClassDeclaration","start":0,"end":45,"id":{"type":"Identifier","start":6,"end":7,"name":"A"},"superClass":{"type":"Identifier","start":19,"end":20,"name":"B"},"body":{"type":"ClassBody","start":43,"end":45,"body":[]},"typeParameters":{"type":"TSTypeParameterDeclaration","start":7,"end":10,"params":[{"type":"TSTypeParameter","start":8,"end":9,"name":{"type":"Identifier","start":8,"end":9,"name":"T"},"in":false,"out":false,"const":false}]},"superTypeArguments":{"type":"TSTypeParameterInstantiation","start":20,"end":23,"params":[{"type":"TSTypeReference","start":21,"end":22,"typeName":{"type":"Identifier","start":21,"end":22,"name":"T"}}]},"implements":[{"type":"TSClassImplements","start":35,"end":36,"expression":{"type":"Identifier","start":35,"end":36,"name":"C"}},{"type":"TSClassImplements","start":38,"end":42,"expression":{"type":"Identifier","start":38,"end":39,"name":"D"},"typeArguments":{"type":"TSTypeParameterInstantiation","start":39,"end":42,"params":[{"type":"TSTypeReference","start":40,"end":41,"typeName":{"type":"Identifier","start":40,"end":41,"name":"T"}}]}}]}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"abstract class A {}"#,
+        r#"{"type":"Program","start":0,"end":19,"body":[{"type":"ClassDeclaration","start":0,"end":19,"id":{"type":"Identifier","start":15,"end":16,"name":"A"},"superClass":null,"body":{"type":"ClassBody","start":17,"end":19,"body":[]},"abstract":true}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_class_member_modifiers() {
+    assert_parser_script_eq!(
+        r#"class A { private readonly a?: number; static declare b; protected override c() {} }"#,
+        r#"{"type":"Program","start":0,"end":84,"body":[{"type":"ClassDeclaration","start":0,"end":84,"id":{"type":"Identifier","start":6,"end":7,"name":"A"},"superClass":null,"body":{"type":"ClassBody","start":8,"end":84,"body":[{"type":"PropertyDefinition","start":10,"end":38,"static":false,"computed":false,"key":{"type":"Identifier","start":27,"end":28,"name":"a"},"value":null,"typeAnnotation":{"type":"TSTypeAnnotation","start":29,"end":37,"typeAnnotation":{"type":"TSNumberKeyword","start":31,"end":37}},"accessibility":"private","readonly":true,"optional":true},{"type":"PropertyDefinition","start":39,"end":56,"static":true,"computed":false,"key":{"type":"Identifier","start":54,"end":55,"name":"b"},"value":null,"declare":true},{"type":"MethodDefinition","start":57,"end":82,"static":false,"computed":false,"key":{"type":"Identifier","start":76,"end":77,"name":"c"},"kind":"method","value":{"type":"FunctionExpression","start":77,"end":82,"id":null,"expression":false,"generator":false,"async":false,"params":[],"body":{"type":"BlockStatement","start":80,"end":82,"body":[]}},"accessibility":"protected","override":true}]}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_abstract_class_members() {
+    assert_parser_script_eq!(
+        r#"abstract class A { abstract a: string; abstract b(): void; }"#,
+        r#"{"type":"Program","start":0,"end":60,"body":[{"type":"ClassDeclaration","start":0,"end":60,"id":{"type":"Identifier","start":15,"end":16,"name":"A"},"superClass":null,"body":{"type":"ClassBody","start":17,"end":60,"body":[{"type":"TSAbstractPropertyDefinition","start":19,"end":38,"static":false,"computed":false,"key":{"type":"Identifier","start":28,"end":29,"name":"a"},"value":null,"typeAnnotation":{"type":"TSTypeAnnotation","start":29,"end":37,"typeAnnotation":{"type":"TSStringKeyword","start":31,"end":37}},"readonly":false,"optional":false,"override":false},{"type":"TSAbstractMethodDefinition","start":39,"end":58,"static":false,"computed":false,"key":{"type":"Identifier","start":48,"end":49,"name":"b"},"kind":"method","value":{"type":"TSEmptyBodyFunctionExpression","start":49,"end":58,"id":null,"expression":false,"generator":false,"async":false,"params":[],"returnType":{"type":"TSTypeAnnotation","start":51,"end":57,"typeAnnotation":{"type":"TSVoidKeyword","start":53,"end":57}},"body":null},"optional":false,"override":false}]},"abstract":true}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_class_index_signature() {
+    assert_parser_script_eq!(
+        r#"class A { static [a: string]: number; }"#,
+        r#"{"type":"Program","start":0,"end":39,"body":[{"type":"ClassDeclaration","start":0,"end":39,"id":{"type":"Identifier","start":6,"end":7,"name":"A"},"superClass":null,"body":{"type":"ClassBody","start":8,"end":39,"body":[{"type":"TSIndexSignature","start":10,"end":37,"parameters":[{"type":"Identifier","start":18,"end":27,"name":"a","typeAnnotation":{"type":"TSTypeAnnotation","start":19,"end":27,"typeAnnotation":{"type":"TSStringKeyword","start":21,"end":27}}}],"typeAnnotation":{"type":"TSTypeAnnotation","start":28,"end":36,"typeAnnotation":{"type":"TSNumberKeyword","start":30,"end":36}},"readonly":false,"static":true}]}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_parameter_property() {
+    assert_parser_script_eq!(
+        r#"class A { constructor(public a: string, readonly b = 1) {} }"#,
+        r#"{"type":"Program","start":0,"end":60,"body":[{"type":"ClassDeclaration","start":0,"end":60,"id":{"type":"Identifier","start":6,"end":7,"name":"A"},"superClass":null,"body":{"type":"ClassBody","start":8,"end":60,"body":[{"type":"MethodDefinition","start":10,"end":58,"static":false,"computed":false,"key":{"type":"Identifier","start":10,"end":21,"name":"constructor"},"kind":"constructor","value":{"type":"FunctionExpression","start":21,"end":58,"id":null,"expression":false,"generator":false,"async":false,"params":[{"type":"TSParameterProperty","start":22,"end":38,"accessibility":"public","readonly":false,"override":false,"static":false,"parameter":{"type":"Identifier","start":29,"end":38,"name":"a","typeAnnotation":{"type":"TSTypeAnnotation","start":30,"end":38,"typeAnnotation":{"type":"TSStringKeyword","start":32,"end":38}}}},{"type":"TSParameterProperty","start":40,"end":54,"readonly":true,"override":false,"static":false,"parameter":{"type":"AssignmentPattern","start":49,"end":54,"left":{"type":"Identifier","start":49,"end":50,"name":"b"},"right":{"type":"Literal","start":53,"end":54,"value":1.0,"raw":"1"}}}],"body":{"type":"BlockStatement","start":56,"end":58,"body":[]}}}]}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_method_overload() {
+    assert_parser_script_eq!(
+        r#"class A { a(): void; a() {} }"#,
+        r#"{"type":"Program","start":0,"end":29,"body":[{"type":"ClassDeclaration","start":0,"end":29,"id":{"type":"Identifier","start":6,"end":7,"name":"A"},"superClass":null,"body":{"type":"ClassBody","start":8,"end":29,"body":[{"type":"MethodDefinition","start":10,"end":20,"static":false,"computed":false,"key":{"type":"Identifier","start":10,"end":11,"name":"a"},"kind":"method","value":{"type":"TSEmptyBodyFunctionExpression","start":11,"end":20,"id":null,"expression":false,"generator":false,"async":false,"params":[],"returnType":{"type":"TSTypeAnnotation","start":13,"end":19,"typeAnnotation":{"type":"TSVoidKeyword","start":15,"end":19}},"body":null}},{"type":"MethodDefinition","start":21,"end":27,"static":false,"computed":false,"key":{"type":"Identifier","start":21,"end":22,"name":"a"},"kind":"method","value":{"type":"FunctionExpression","start":22,"end":27,"id":null,"expression":false,"generator":false,"async":false,"params":[],"body":{"type":"BlockStatement","start":25,"end":27,"body":[]}}}]}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
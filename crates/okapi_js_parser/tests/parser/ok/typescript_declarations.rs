@@ -0,0 +1,165 @@
+use crate::parser::{assert_parser_module_eq, assert_parser_script_eq};
+use okapi_js_parser::ParserOptions;
+
+#[test]
+fn typescript_interface_declaration() {
+    assert_parser_script_eq!(
+        r#"interface A<T> extends B<T>, C.D { a: T }"#,
+        r#"{"type":"Program","start":0,"end":41,"body":[{"type":"TSInterfaceDeclaration","start":0,"end":41,"id":{"type":"Identifier","start":10,"end":11,"name":"A"},"typeParameters":{"type":"TSTypeParameterDeclaration","start":11,"end":14,"params":[{"type":"TSTypeParameter","start":12,"end":13,"name":{"type":"Identifier","start":12,"end":13,"name":"T"},"in":false,"out":false,"const":false}]},"extends":[{"type":"TSInterfaceHeritage","start":23,"end":27,"expression":{"type":"Identifier","start":23,"end":24,"name":"B"},"typeArguments":{"type":"TSTypeParameterInstantiation","start":24,"end":27,"params":[{"type":"TSTypeReference","start":25,"end":26,"typeName":{"type":"Identifier","start":25,"end":26,"name":"T"}}]}},{"type":"TSInterfaceHeritage","start":29,"end":32,"expression":{"type":"MemberExpression","start":29,"end":32,"object":{"type":"Identifier","start":29,"end":30,"name":"C"},"property":{"type":"Identifier","start":31,"end":32,"name":"D"},"computed":false,"optional":false}}],"body":{"type":"TSInterfaceBody","start":33,"end":41,"body":[{"type":"TSPropertySignature","start":35,"end":39,"computed":false,"key":{"type":"Identifier","start":35,"end":36,"name":"a"},"optional":false,"readonly":false,"typeAnnotation":{"type":"TSTypeAnnotation","start":36,"end":39,"typeAnnotation":{"type":"TSTypeReference","start":38,"end":39,"typeName":{"type":"Identifier","start":38,"end":39,"name":"T"}}}}]},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_type_alias_declaration() {
+    assert_parser_script_eq!(
+        r#"type A<T = string> = T;"#,
+        r#"{"type":"Program","start":0,"end":23,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":23,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"typeParameters":{"type":"TSTypeParameterDeclaration","start":6,"end":18,"params":[{"type":"TSTypeParameter","start":7,"end":17,"name":{"type":"Identifier","start":7,"end":8,"name":"T"},"default":{"type":"TSStringKeyword","start":11,"end":17},"in":false,"out":false,"const":false}]},"typeAnnotation":{"type":"TSTypeReference","start":21,"end":22,"typeName":{"type":"Identifier","start":21,"end":22,"name":"T"}},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_enum_declaration() {
+    assert_parser_script_eq!(
+        r#"enum A { B, "C" = 1 }"#,
+        r#"{"type":"Program","start":0,"end":21,"body":[{"type":"TSEnumDeclaration","start":0,"end":21,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"members":[{"type":"TSEnumMember","start":9,"end":10,"id":{"type":"Identifier","start":9,"end":10,"name":"B"}},{"type":"TSEnumMember","start":12,"end":19,"id":{"type":"Literal","start":12,"end":15,"value":"C","raw":"\"C\""},"initializer":{"type":"Literal","start":18,"end":19,"value":1.0,"raw":"1"}}],"const":false,"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"const enum A {}"#,
+        r#"{"type":"Program","start":0,"end":15,"body":[{"type":"TSEnumDeclaration","start":0,"end":15,"id":{"type":"Identifier","start":11,"end":12,"name":"A"},"members":[],"const":true,"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_module_declaration() {
+    assert_parser_script_eq!(
+        r#"namespace A.B { let c; }"#,
+        r#"{"type":"Program","start":0,"end":24,"body":[{"type":"TSModuleDeclaration","start":0,"end":24,"id":{"type":"TSQualifiedName","start":10,"end":13,"left":{"type":"Identifier","start":10,"end":11,"name":"A"},"right":{"type":"Identifier","start":12,"end":13,"name":"B"}},"body":{"type":"TSModuleBlock","start":14,"end":24,"body":[{"type":"VariableDeclaration","start":16,"end":22,"declarations":[{"type":"VariableDeclarator","start":20,"end":21,"id":{"type":"Identifier","start":20,"end":21,"name":"c"},"init":null}],"kind":"let"}]},"kind":"namespace","declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"declare module "a";"#,
+        r#"{"type":"Program","start":0,"end":19,"body":[{"type":"TSModuleDeclaration","start":0,"end":19,"id":{"type":"Literal","start":15,"end":18,"value":"a","raw":"\"a\""},"kind":"module","declare":true}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"declare global {}"#,
+        r#"{"type":"Program","start":0,"end":17,"body":[{"type":"TSModuleDeclaration","start":0,"end":17,"id":{"type":"Identifier","start":8,"end":14,"name":"global"},"body":{"type":"TSModuleBlock","start":15,"end":17,"body":[]},"kind":"global","declare":true}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_declare_declaration() {
+    assert_parser_script_eq!(
+        r#"declare const a: number;"#,
+        r#"{"type":"Program","start":0,"end":24,"body":[{"type":"VariableDeclaration","start":0,"end":24,"declarations":[{"type":"VariableDeclarator","start":14,"end":23,"id":{"type":"Identifier","start":14,"end":23,"name":"a","typeAnnotation":{"type":"TSTypeAnnotation","start":15,"end":23,"typeAnnotation":{"type":"TSNumberKeyword","start":17,"end":23}}},"init":null}],"kind":"const","declare":true}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"declare function f(): void;"#,
+        r#"{"type":"Program","start":0,"end":27,"body":[{"type":"TSDeclareFunction","start":0,"end":27,"id":{"type":"Identifier","start":17,"end":18,"name":"f"},"expression":false,"generator":false,"async":false,"params":[],"returnType":{"type":"TSTypeAnnotation","start":20,"end":26,"typeAnnotation":{"type":"TSVoidKeyword","start":22,"end":26}},"declare":true}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"declare class A {}"#,
+        r#"{"type":"Program","start":0,"end":18,"body":[{"type":"ClassDeclaration","start":0,"end":18,"id":{"type":"Identifier","start":14,"end":15,"name":"A"},"superClass":null,"body":{"type":"ClassBody","start":16,"end":18,"body":[]},"declare":true}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_function_overload() {
+    assert_parser_script_eq!(
+        r#"function f(a: string): void;
+function f(a) {}"#,
+        r#"{"type":"Program","start":0,"end":45,"body":[{"type":"TSDeclareFunction","start":0,"end":28,"id":{"type":"Identifier","start":9,"end":10,"name":"f"},"expression":false,"generator":false,"async":false,"params":[{"type":"Identifier","start":11,"end":20,"name":"a","typeAnnotation":{"type":"TSTypeAnnotation","start":12,"end":20,"typeAnnotation":{"type":"TSStringKeyword","start":14,"end":20}}}],"returnType":{"type":"TSTypeAnnotation","start":21,"end":27,"typeAnnotation":{"type":"TSVoidKeyword","start":23,"end":27}},"declare":false},{"type":"FunctionDeclaration","start":29,"end":45,"id":{"type":"Identifier","start":38,"end":39,"name":"f"},"expression":false,"generator":false,"async":false,"params":[{"type":"Identifier","start":40,"end":41,"name":"a"}],"body":{"type":"BlockStatement","start":43,"end":45,"body":[]}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_import_type() {
+    assert_parser_module_eq!(
+        r#"import type A from "a";"#,
+        r#"{"type":"Program","start":0,"end":23,"body":[{"type":"ImportDeclaration","start":0,"end":23,"specifiers":[{"type":"ImportDefaultSpecifier","start":12,"end":13,"local":{"type":"Identifier","start":12,"end":13,"name":"A"}}],"source":{"type":"Literal","start":19,"end":22,"value":"a","raw":"\"a\""},"attributes":[],"importKind":"type"}],"sourceType":"module"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_module_eq!(
+        r#"import type * as B from "b";"#,
+        r#"{"type":"Program","start":0,"end":28,"body":[{"type":"ImportDeclaration","start":0,"end":28,"specifiers":[{"type":"ImportNamespaceSpecifier","start":12,"end":18,"local":{"type":"Identifier","start":17,"end":18,"name":"B"}}],"source":{"type":"Literal","start":24,"end":27,"value":"b","raw":"\"b\""},"attributes":[],"importKind":"type"}],"sourceType":"module"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_module_eq!(
+        r#"import { type C, D as E } from "c";"#,
+        r#"{"type":"Program","start":0,"end":35,"body":[{"type":"ImportDeclaration","start":0,"end":35,"specifiers":[{"type":"ImportSpecifier","start":9,"end":15,"imported":{"type":"Identifier","start":14,"end":15,"name":"C"},"local":{"type":"Identifier","start":14,"end":15,"name":"C"},"importKind":"type"},{"type":"ImportSpecifier","start":17,"end":23,"imported":{"type":"Identifier","start":17,"end":18,"name":"D"},"local":{"type":"Identifier","start":22,"end":23,"name":"E"},"importKind":"value"}],"source":{"type":"Literal","start":31,"end":34,"value":"c","raw":"\"c\""},"attributes":[],"importKind":"value"}],"sourceType":"module"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_module_eq!(
+        r#"import type from "d";"#,
+        r#"{"type":"Program","start":0,"end":21,"body":[{"type":"ImportDeclaration","start":0,"end":21,"specifiers":[{"type":"ImportDefaultSpecifier","start":7,"end":11,"local":{"type":"Identifier","start":7,"end":11,"name":"type"}}],"source":{"type":"Literal","start":17,"end":20,"value":"d","raw":"\"d\""},"attributes":[],"importKind":"value"}],"sourceType":"module"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_export_type() {
+    assert_parser_module_eq!(
+        r#"export type { A } from "a";"#,
+        r#"{"type":"Program","start":0,"end":27,"body":[{"type":"ExportNamedDeclaration","start":0,"end":27,"declaration":null,"specifiers":[{"type":"ExportSpecifier","start":14,"end":15,"local":{"type":"Identifier","start":14,"end":15,"name":"A"},"exported":{"type":"Identifier","start":14,"end":15,"name":"A"},"exportKind":"value"}],"source":{"type":"Literal","start":23,"end":26,"value":"a","raw":"\"a\""},"attributes":[],"exportKind":"type"}],"sourceType":"module"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_module_eq!(
+        r#"export type * from "b";"#,
+        r#"{"type":"Program","start":0,"end":23,"body":[{"type":"ExportAllDeclaration","start":0,"end":23,"exported":null,"source":{"type":"Literal","start":19,"end":22,"value":"b","raw":"\"b\""},"attributes":[],"exportKind":"type"}],"sourceType":"module"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_module_eq!(
+        r#"export { type C, D };"#,
+        r#"{"type":"Program","start":0,"end":21,"body":[{"type":"ExportNamedDeclaration","start":0,"end":21,"declaration":null,"specifiers":[{"type":"ExportSpecifier","start":9,"end":15,"local":{"type":"Identifier","start":14,"end":15,"name":"C"},"exported":{"type":"Identifier","start":14,"end":15,"name":"C"},"exportKind":"type"},{"type":"ExportSpecifier","start":17,"end":18,"local":{"type":"Identifier","start":17,"end":18,"name":"D"},"exported":{"type":"Identifier","start":17,"end":18,"name":"D"},"exportKind":"value"}],"source":null,"attributes":[],"exportKind":"value"}],"sourceType":"module"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_module_eq!(
+        r#"export type E = string;"#,
+        r#"{"type":"Program","start":0,"end":23,"body":[{"type":"ExportNamedDeclaration","start":0,"end":23,"declaration":{"type":"TSTypeAliasDeclaration","start":7,"end":23,"id":{"type":"Identifier","start":12,"end":13,"name":"E"},"typeAnnotation":{"type":"TSStringKeyword","start":16,"end":22},"declare":false},"specifiers":[],"source":null,"attributes":[],"exportKind":"type"}],"sourceType":"module"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_export_assignment() {
+    assert_parser_module_eq!(
+        r#"export = a.b;"#,
+        r#"{"type":"Program","start":0,"end":13,"body":[{"type":"TSExportAssignment","start":0,"end":13,"expression":{"type":"MemberExpression","start":9,"end":12,"object":{"type":"Identifier","start":9,"end":10,"name":"a"},"property":{"type":"Identifier","start":11,"end":12,"name":"b"},"computed":false,"optional":false}}],"sourceType":"module"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_import_equals_declaration() {
+    assert_parser_module_eq!(
+        r#"import a = require("a");"#,
+        r#"{"type":"Program","start":0,"end":24,"body":[{"type":"TSImportEqualsDeclaration","start":0,"end":24,"id":{"type":"Identifier","start":7,"end":8,"name":"a"},"moduleReference":{"type":"TSExternalModuleReference","start":11,"end":23,"expression":{"type":"Literal","start":19,"end":22,"value":"a","raw":"\"a\""}},"importKind":"value"}],"sourceType":"module"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_module_eq!(
+        r#"import type B = C.D;"#,
+        r#"{"type":"Program","start":0,"end":20,"body":[{"type":"TSImportEqualsDeclaration","start":0,"end":20,"id":{"type":"Identifier","start":12,"end":13,"name":"B"},"moduleReference":{"type":"TSQualifiedName","start":16,"end":19,"left":{"type":"Identifier","start":16,"end":17,"name":"C"},"right":{"type":"Identifier","start":18,"end":19,"name":"D"}},"importKind":"type"}],"sourceType":"module"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
@@ -0,0 +1,114 @@
+use crate::parser::assert_parser_script_eq;
+use okapi_js_parser::ParserOptions;
+
+#[test]
+fn typescript_as_and_satisfies_expression() {
+    assert_parser_script_eq!(
+        r#"a as B;"#,
+        r#"{"type":"Program","start":0,"end":7,"body":[{"type":"ExpressionStatement","start":0,"end":7,"expression":{"type":"TSAsExpression","start":0,"end":6,"expression":{"type":"Identifier","start":0,"end":1,"name":"a"},"typeAnnotation":{"type":"TSTypeReference","start":5,"end":6,"typeName":{"type":"Identifier","start":5,"end":6,"name":"B"}}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"a satisfies B;"#,
+        r#"{"type":"Program","start":0,"end":14,"body":[{"type":"ExpressionStatement","start":0,"end":14,"expression":{"type":"TSSatisfiesExpression","start":0,"end":13,"expression":{"type":"Identifier","start":0,"end":1,"name":"a"},"typeAnnotation":{"type":"TSTypeReference","start":12,"end":13,"typeName":{"type":"Identifier","start":12,"end":13,"name":"B"}}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"a + b as C;"#,
+        r#"{"type":"Program","start":0,"end":11,"body":[{"type":"ExpressionStatement","start":0,"end":11,"expression":{"type":"TSAsExpression","start":0,"end":10,"expression":{"type":"BinaryExpression","start":0,"end":5,"left":{"type":"Identifier","start":0,"end":1,"name":"a"},"operator":"+","right":{"type":"Identifier","start":4,"end":5,"name":"b"}},"typeAnnotation":{"type":"TSTypeReference","start":9,"end":10,"typeName":{"type":"Identifier","start":9,"end":10,"name":"C"}}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_non_null_expression() {
+    assert_parser_script_eq!(
+        r#"a!.b!;"#,
+        r#"{"type":"Program","start":0,"end":6,"body":[{"type":"ExpressionStatement","start":0,"end":6,"expression":{"type":"TSNonNullExpression","start":0,"end":5,"expression":{"type":"MemberExpression","start":0,"end":4,"object":{"type":"TSNonNullExpression","start":0,"end":2,"expression":{"type":"Identifier","start":0,"end":1,"name":"a"}},"property":{"type":"Identifier","start":3,"end":4,"name":"b"},"computed":false,"optional":false}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_type_assertion() {
+    assert_parser_script_eq!(
+        r#"<A>b;"#,
+        r#"{"type":"Program","start":0,"end":5,"body":[{"type":"ExpressionStatement","start":0,"end":5,"expression":{"type":"TSTypeAssertion","start":0,"end":4,"typeAnnotation":{"type":"TSTypeReference","start":1,"end":2,"typeName":{"type":"Identifier","start":1,"end":2,"name":"A"}},"expression":{"type":"Identifier","start":3,"end":4,"name":"b"}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_type_arguments() {
+    assert_parser_script_eq!(
+        r#"f<T>(a);"#,
+        r#"{"type":"Program","start":0,"end":8,"body":[{"type":"ExpressionStatement","start":0,"end":8,"expression":{"type":"CallExpression","start":0,"end":7,"callee":{"type":"Identifier","start":0,"end":1,"name":"f"},"arguments":[{"type":"Identifier","start":5,"end":6,"name":"a"}],"optional":false,"typeArguments":{"type":"TSTypeParameterInstantiation","start":1,"end":4,"params":[{"type":"TSTypeReference","start":2,"end":3,"typeName":{"type":"Identifier","start":2,"end":3,"name":"T"}}]}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"new A<T>();"#,
+        r#"{"type":"Program","start":0,"end":11,"body":[{"type":"ExpressionStatement","start":0,"end":11,"expression":{"type":"NewExpression","start":0,"end":10,"callee":{"type":"Identifier","start":4,"end":5,"name":"A"},"arguments":[],"typeArguments":{"type":"TSTypeParameterInstantiation","start":5,"end":8,"params":[{"type":"TSTypeReference","start":6,"end":7,"typeName":{"type":"Identifier","start":6,"end":7,"name":"T"}}]}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"a < b;"#,
+        r#"{"type":"Program","start":0,"end":6,"body":[{"type":"ExpressionStatement","start":0,"end":6,"expression":{"type":"BinaryExpression","start":0,"end":5,"left":{"type":"Identifier","start":0,"end":1,"name":"a"},"operator":"<","right":{"type":"Identifier","start":4,"end":5,"name":"b"}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_arrow_function() {
+    assert_parser_script_eq!(
+        r#"<T>(a: T): T => a;"#,
+        r#"{"type":"Program","start":0,"end":18,"body":[{"type":"ExpressionStatement","start":0,"end":18,"expression":{"type":"ArrowFunctionExpression","start":0,"end":17,"id":null,"expression":true,"generator":false,"async":false,"params":[{"type":"Identifier","start":4,"end":8,"name":"a","typeAnnotation":{"type":"TSTypeAnnotation","start":5,"end":8,"typeAnnotation":{"type":"TSTypeReference","start":7,"end":8,"typeName":{"type":"Identifier","start":7,"end":8,"name":"T"}}}}],"body":{"type":"Identifier","start":16,"end":17,"name":"a"},"typeParameters":{"type":"TSTypeParameterDeclaration","start":0,"end":3,"params":[{"type":"TSTypeParameter","start":1,"end":2,"name":{"type":"Identifier","start":1,"end":2,"name":"T"},"in":false,"out":false,"const":false}]},"returnType":{"type":"TSTypeAnnotation","start":9,"end":12,"typeAnnotation":{"type":"TSTypeReference","start":11,"end":12,"typeName":{"type":"Identifier","start":11,"end":12,"name":"T"}}}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"async (a?: number) => a;"#,
+        r#"{"type":"Program","start":0,"end":24,"body":[{"type":"ExpressionStatement","start":0,"end":24,"expression":{"type":"ArrowFunctionExpression","start":0,"end":23,"id":null,"expression":true,"generator":false,"async":true,"params":[{"type":"Identifier","start":7,"end":17,"name":"a","typeAnnotation":{"type":"TSTypeAnnotation","start":9,"end":17,"typeAnnotation":{"type":"TSNumberKeyword","start":11,"end":17}},"optional":true}],"body":{"type":"Identifier","start":22,"end":23,"name":"a"}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"(a): a is B => true;"#,
+        r#"{"type":"Program","start":0,"end":20,"body":[{"type":"ExpressionStatement","start":0,"end":20,"expression":{"type":"ArrowFunctionExpression","start":0,"end":19,"id":null,"expression":true,"generator":false,"async":false,"params":[{"type":"Identifier","start":1,"end":2,"name":"a"}],"body":{"type":"Literal","start":15,"end":19,"value":true,"raw":"true"},"returnType":{"type":"TSTypeAnnotation","start":3,"end":11,"typeAnnotation":{"type":"TSTypePredicate","start":5,"end":11,"asserts":false,"parameterName":{"type":"Identifier","start":5,"end":6,"name":"a"},"typeAnnotation":{"type":"TSTypeAnnotation","start":10,"end":11,"typeAnnotation":{"type":"TSTypeReference","start":10,"end":11,"typeName":{"type":"Identifier","start":10,"end":11,"name":"B"}}}}}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_arrow_function_in_conditional_consequent() {
+    // Without a `:` after its body, `(b) : c => d` is not an arrow function with a return type.
+    assert_parser_script_eq!(
+        r#"let x = a ? (b) : c => d;"#,
+        r#"{"type":"Program","start":0,"end":25,"body":[{"type":"VariableDeclaration","start":0,"end":25,"declarations":[{"type":"VariableDeclarator","start":4,"end":24,"id":{"type":"Identifier","start":4,"end":5,"name":"x"},"init":{"type":"ConditionalExpression","start":8,"end":24,"test":{"type":"Identifier","start":8,"end":9,"name":"a"},"consequent":{"type":"Identifier","start":13,"end":14,"name":"b"},"alternate":{"type":"ArrowFunctionExpression","start":18,"end":24,"id":null,"expression":true,"generator":false,"async":false,"params":[{"type":"Identifier","start":18,"end":19,"name":"c"}],"body":{"type":"Identifier","start":23,"end":24,"name":"d"}}}}],"kind":"let"}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"a ? (b): c => d : e;"#,
+        r#"{"type":"Program","start":0,"end":20,"body":[{"type":"ExpressionStatement","start":0,"end":20,"expression":{"type":"ConditionalExpression","start":0,"end":19,"test":{"type":"Identifier","start":0,"end":1,"name":"a"},"consequent":{"type":"ArrowFunctionExpression","start":4,"end":15,"id":null,"expression":true,"generator":false,"async":false,"params":[{"type":"Identifier","start":5,"end":6,"name":"b"}],"body":{"type":"Identifier","start":14,"end":15,"name":"d"},"returnType":{"type":"TSTypeAnnotation","start":7,"end":10,"typeAnnotation":{"type":"TSTypeReference","start":9,"end":10,"typeName":{"type":"Identifier","start":9,"end":10,"name":"c"}}}},"alternate":{"type":"Identifier","start":18,"end":19,"name":"e"}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_const_assertion() {
+    assert_parser_script_eq!(
+        r#"a as const;"#,
+        r#"{"type":"Program","start":0,"end":11,"body":[{"type":"ExpressionStatement","start":0,"end":11,"expression":{"type":"TSAsExpression","start":0,"end":10,"expression":{"type":"Identifier","start":0,"end":1,"name":"a"},"typeAnnotation":{"type":"TSTypeReference","start":5,"end":10,"typeName":{"type":"Identifier","start":5,"end":10,"name":"const"}}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"<const>b;"#,
+        r#"{"type":"Program","start":0,"end":9,"body":[{"type":"ExpressionStatement","start":0,"end":9,"expression":{"type":"TSTypeAssertion","start":0,"end":8,"typeAnnotation":{"type":"TSTypeReference","start":1,"end":6,"typeName":{"type":"Identifier","start":1,"end":6,"name":"const"}},"expression":{"type":"Identifier","start":7,"end":8,"name":"b"}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
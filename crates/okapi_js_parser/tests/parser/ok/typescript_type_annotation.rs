@@ -0,0 +1,53 @@
+use crate::parser::assert_parser_script_eq;
+use okapi_js_parser::ParserOptions;
+
+#[test]
+fn typescript_variable_type_annotation() {
+    assert_parser_script_eq!(
+        r#"let a: number = 1;"#,
+        r#"{"type":"Program","start":0,"end":18,"body":[{"type":"VariableDeclaration","start":0,"end":18,"declarations":[{"type":"VariableDeclarator","start":4,"end":17,"id":{"type":"Identifier","start":4,"end":13,"name":"a","typeAnnotation":{"type":"TSTypeAnnotation","start":5,"end":13,"typeAnnotation":{"type":"TSNumberKeyword","start":7,"end":13}}},"init":{"type":"Literal","start":16,"end":17,"value":1.0,"raw":"1"}}],"kind":"let"}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"let b!: string;"#,
+        r#"{"type":"Program","start":0,"end":15,"body":[{"type":"VariableDeclaration","start":0,"end":15,"declarations":[{"type":"VariableDeclarator","start":4,"end":14,"id":{"type":"Identifier","start":4,"end":14,"name":"b","typeAnnotation":{"type":"TSTypeAnnotation","start":6,"end":14,"typeAnnotation":{"type":"TSStringKeyword","start":8,"end":14}}},"init":null,"definite":true}],"kind":"let"}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"let [c]: T[] = d;"#,
+        r#"{"type":"Program","start":0,"end":17,"body":[{"type":"VariableDeclaration","start":0,"end":17,"declarations":[{"type":"VariableDeclarator","start":4,"end":16,"id":{"type":"ArrayPattern","start":4,"end":12,"elements":[{"type":"Identifier","start":5,"end":6,"name":"c"}],"typeAnnotation":{"type":"TSTypeAnnotation","start":7,"end":12,"typeAnnotation":{"type":"TSArrayType","start":9,"end":12,"elementType":{"type":"TSTypeReference","start":9,"end":10,"typeName":{"type":"Identifier","start":9,"end":10,"name":"T"}}}}},"init":{"type":"Identifier","start":15,"end":16,"name":"d"}}],"kind":"let"}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_parameter_type_annotation() {
+    assert_parser_script_eq!(
+        r#"function f(a: string, b?: number, ...c: any[]): void {}"#,
+        r#"{"type":"Program","start":0,"end":55,"body":[{"type":"FunctionDeclaration","start":0,"end":55,"id":{"type":"Identifier","start":9,"end":10,"name":"f"},"expression":false,"generator":false,"async":false,"params":[{"type":"Identifier","start":11,"end":20,"name":"a","typeAnnotation":{"type":"TSTypeAnnotation","start":12,"end":20,"typeAnnotation":{"type":"TSStringKeyword","start":14,"end":20}}},{"type":"Identifier","start":22,"end":32,"name":"b","typeAnnotation":{"type":"TSTypeAnnotation","start":24,"end":32,"typeAnnotation":{"type":"TSNumberKeyword","start":26,"end":32}},"optional":true},{"type":"RestElement","start":34,"end":45,"argument":{"type":"Identifier","start":37,"end":38,"name":"c"},"typeAnnotation":{"type":"TSTypeAnnotation","start":38,"end":45,"typeAnnotation":{"type":"TSArrayType","start":40,"end":45,"elementType":{"type":"TSAnyKeyword","start":40,"end":43}}}}],"body":{"type":"BlockStatement","start":53,"end":55,"body":[]},"returnType":{"type":"TSTypeAnnotation","start":46,"end":52,"typeAnnotation":{"type":"TSVoidKeyword","start":48,"end":52}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"function f(this: Window) {}"#,
+        r#"{"type":"Program","start":0,"end":27,"body":[{"type":"FunctionDeclaration","start":0,"end":27,"id":{"type":"Identifier","start":9,"end":10,"name":"f"},"expression":false,"generator":false,"async":false,"params":[{"type":"Identifier","start":11,"end":23,"name":"this","typeAnnotation":{"type":"TSTypeAnnotation","start":15,"end":23,"typeAnnotation":{"type":"TSTypeReference","start":17,"end":23,"typeName":{"type":"Identifier","start":17,"end":23,"name":"Window"}}}}],"body":{"type":"BlockStatement","start":25,"end":27,"body":[]}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_type_predicate() {
+    assert_parser_script_eq!(
+        r#"function f(a): a is string {}"#,
+        r#"{"type":"Program","start":0,"end":29,"body":[{"type":"FunctionDeclaration","start":0,"end":29,"id":{"type":"Identifier","start":9,"end":10,"name":"f"},"expression":false,"generator":false,"async":false,"params":[{"type":"Identifier","start":11,"end":12,"name":"a"}],"body":{"type":"BlockStatement","start":27,"end":29,"body":[]},"returnType":{"type":"TSTypeAnnotation","start":13,"end":26,"typeAnnotation":{"type":"TSTypePredicate","start":15,"end":26,"asserts":false,"parameterName":{"type":"Identifier","start":15,"end":16,"name":"a"},"typeAnnotation":{"type":"TSTypeAnnotation","start":20,"end":26,"typeAnnotation":{"type":"TSStringKeyword","start":20,"end":26}}}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"function f(a): asserts a {}"#,
+        r#"{"type":"Program","start":0,"end":27,"body":[{"type":"FunctionDeclaration","start":0,"end":27,"id":{"type":"Identifier","start":9,"end":10,"name":"f"},"expression":false,"generator":false,"async":false,"params":[{"type":"Identifier","start":11,"end":12,"name":"a"}],"body":{"type":"BlockStatement","start":25,"end":27,"body":[]},"returnType":{"type":"TSTypeAnnotation","start":13,"end":24,"typeAnnotation":{"type":"TSTypePredicate","start":15,"end":24,"asserts":true,"parameterName":{"type":"Identifier","start":23,"end":24,"name":"a"},"typeAnnotation":null}}}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
@@ -0,0 +1,113 @@
+use crate::parser::assert_parser_script_eq;
+use okapi_js_parser::ParserOptions;
+
+#[test]
+fn typescript_keyword_and_literal_types() {
+    assert_parser_script_eq!(
+        r#"type A = string | "b" | 1 | -1 | true | null | void;"#,
+        r#"{"type":"Program","start":0,"end":52,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":52,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"typeAnnotation":{"type":"TSUnionType","start":9,"end":51,"types":[{"type":"TSStringKeyword","start":9,"end":15},{"type":"TSLiteralType","start":18,"end":21,"literal":{"type":"Literal","start":18,"end":21,"value":"b","raw":"\"b\""}},{"type":"TSLiteralType","start":24,"end":25,"literal":{"type":"Literal","start":24,"end":25,"value":1.0,"raw":"1"}},{"type":"TSLiteralType","start":28,"end":30,"literal":{"type":"UnaryExpression","start":28,"end":30,"operator":"-","prefix":true,"argument":{"type":"Literal","start":29,"end":30,"value":1.0,"raw":"1"}}},{"type":"TSLiteralType","start":33,"end":37,"literal":{"type":"Literal","start":33,"end":37,"value":true,"raw":"true"}},{"type":"TSNullKeyword","start":40,"end":44},{"type":"TSVoidKeyword","start":47,"end":51}]},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_array_and_tuple_types() {
+    assert_parser_script_eq!(
+        r#"type A = T[][];"#,
+        r#"{"type":"Program","start":0,"end":15,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":15,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"typeAnnotation":{"type":"TSArrayType","start":9,"end":14,"elementType":{"type":"TSArrayType","start":9,"end":12,"elementType":{"type":"TSTypeReference","start":9,"end":10,"typeName":{"type":"Identifier","start":9,"end":10,"name":"T"}}}},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"type A = [a: string, b?: number, ...c: T[]];"#,
+        r#"{"type":"Program","start":0,"end":44,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":44,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"typeAnnotation":{"type":"TSTupleType","start":9,"end":43,"elementTypes":[{"type":"TSNamedTupleMember","start":10,"end":19,"label":{"type":"Identifier","start":10,"end":11,"name":"a"},"elementType":{"type":"TSStringKeyword","start":13,"end":19},"optional":false},{"type":"TSNamedTupleMember","start":21,"end":31,"label":{"type":"Identifier","start":21,"end":22,"name":"b"},"elementType":{"type":"TSNumberKeyword","start":25,"end":31},"optional":true},{"type":"TSRestType","start":33,"end":42,"typeAnnotation":{"type":"TSNamedTupleMember","start":36,"end":42,"label":{"type":"Identifier","start":36,"end":37,"name":"c"},"elementType":{"type":"TSArrayType","start":39,"end":42,"elementType":{"type":"TSTypeReference","start":39,"end":40,"typeName":{"type":"Identifier","start":39,"end":40,"name":"T"}}},"optional":false}}]},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_function_and_constructor_types() {
+    assert_parser_script_eq!(
+        r#"type A = <T>(a: T) => T;"#,
+        r#"{"type":"Program","start":0,"end":24,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":24,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"typeAnnotation":{"type":"TSFunctionType","start":9,"end":23,"typeParameters":{"type":"TSTypeParameterDeclaration","start":9,"end":12,"params":[{"type":"TSTypeParameter","start":10,"end":11,"name":{"type":"Identifier","start":10,"end":11,"name":"T"},"in":false,"out":false,"const":false}]},"params":[{"type":"Identifier","start":13,"end":17,"name":"a","typeAnnotation":{"type":"TSTypeAnnotation","start":14,"end":17,"typeAnnotation":{"type":"TSTypeReference","start":16,"end":17,"typeName":{"type":"Identifier","start":16,"end":17,"name":"T"}}}}],"returnType":{"type":"TSTypeAnnotation","start":19,"end":23,"typeAnnotation":{"type":"TSTypeReference","start":22,"end":23,"typeName":{"type":"Identifier","start":22,"end":23,"name":"T"}}}},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"type A = abstract new () => B;"#,
+        r#"{"type":"Program","start":0,"end":30,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":30,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"typeAnnotation":{"type":"TSConstructorType","start":9,"end":29,"abstract":true,"params":[],"returnType":{"type":"TSTypeAnnotation","start":25,"end":29,"typeAnnotation":{"type":"TSTypeReference","start":28,"end":29,"typeName":{"type":"Identifier","start":28,"end":29,"name":"B"}}}},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_type_operators() {
+    assert_parser_script_eq!(
+        r#"type A = keyof typeof b;"#,
+        r#"{"type":"Program","start":0,"end":24,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":24,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"typeAnnotation":{"type":"TSTypeOperator","start":9,"end":23,"operator":"keyof","typeAnnotation":{"type":"TSTypeQuery","start":15,"end":23,"exprName":{"type":"Identifier","start":22,"end":23,"name":"b"}}},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"type A = readonly string[];"#,
+        r#"{"type":"Program","start":0,"end":27,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":27,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"typeAnnotation":{"type":"TSTypeOperator","start":9,"end":26,"operator":"readonly","typeAnnotation":{"type":"TSArrayType","start":18,"end":26,"elementType":{"type":"TSStringKeyword","start":18,"end":24}}},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_conditional_type() {
+    assert_parser_script_eq!(
+        r#"type A<T> = T extends (infer U)[] ? U : never;"#,
+        r#"{"type":"Program","start":0,"end":46,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":46,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"typeParameters":{"type":"TSTypeParameterDeclaration","start":6,"end":9,"params":[{"type":"TSTypeParameter","start":7,"end":8,"name":{"type":"Identifier","start":7,"end":8,"name":"T"},"in":false,"out":false,"const":false}]},"typeAnnotation":{"type":"TSConditionalType","start":12,"end":45,"checkType":{"type":"TSTypeReference","start":12,"end":13,"typeName":{"type":"Identifier","start":12,"end":13,"name":"T"}},"extendsType":{"type":"TSArrayType","start":22,"end":33,"elementType":{"type":"TSInferType","start":23,"end":30,"typeParameter":{"type":"TSTypeParameter","start":29,"end":30,"name":{"type":"Identifier","start":29,"end":30,"name":"U"},"in":false,"out":false,"const":false}}},"trueType":{"type":"TSTypeReference","start":36,"end":37,"typeName":{"type":"Identifier","start":36,"end":37,"name":"U"}},"falseType":{"type":"TSNeverKeyword","start":40,"end":45}},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_mapped_type() {
+    assert_parser_script_eq!(
+        r#"type A = { readonly [K in keyof T]?: T[K] };"#,
+        r#"{"type":"Program","start":0,"end":44,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":44,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"typeAnnotation":{"type":"TSMappedType","start":9,"end":43,"typeParameter":{"type":"TSTypeParameter","start":21,"end":33,"name":{"type":"Identifier","start":21,"end":22,"name":"K"},"constraint":{"type":"TSTypeOperator","start":26,"end":33,"operator":"keyof","typeAnnotation":{"type":"TSTypeReference","start":32,"end":33,"typeName":{"type":"Identifier","start":32,"end":33,"name":"T"}}},"in":false,"out":false,"const":false},"nameType":null,"optional":true,"readonly":true,"typeAnnotation":{"type":"TSIndexedAccessType","start":37,"end":41,"objectType":{"type":"TSTypeReference","start":37,"end":38,"typeName":{"type":"Identifier","start":37,"end":38,"name":"T"}},"indexType":{"type":"TSTypeReference","start":39,"end":40,"typeName":{"type":"Identifier","start":39,"end":40,"name":"K"}}}},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"type A = { -readonly [K in T as Exclude<K, "a">]-?: T[K] };"#,
+        r#"{"type":"Program","start":0,"end":59,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":59,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"typeAnnotation":{"type":"TSMappedType","start":9,"end":58,"typeParameter":{"type":"TSTypeParameter","start":22,"end":28,"name":{"type":"Identifier","start":22,"end":23,"name":"K"},"constraint":{"type":"TSTypeReference","start":27,"end":28,"typeName":{"type":"Identifier","start":27,"end":28,"name":"T"}},"in":false,"out":false,"const":false},"nameType":{"type":"TSTypeReference","start":32,"end":47,"typeName":{"type":"Identifier","start":32,"end":39,"name":"Exclude"},"typeArguments":{"type":"TSTypeParameterInstantiation","start":39,"end":47,"params":[{"type":"TSTypeReference","start":40,"end":41,"typeName":{"type":"Identifier","start":40,"end":41,"name":"K"}},{"type":"TSLiteralType","start":43,"end":46,"literal":{"type":"Literal","start":43,"end":46,"value":"a","raw":"\"a\""}}]}},"optional":"-","readonly":"-","typeAnnotation":{"type":"TSIndexedAccessType","start":52,"end":56,"objectType":{"type":"TSTypeReference","start":52,"end":53,"typeName":{"type":"Identifier","start":52,"end":53,"name":"T"}},"indexType":{"type":"TSTypeReference","start":54,"end":55,"typeName":{"type":"Identifier","start":54,"end":55,"name":"K"}}}},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_type_literal() {
+    assert_parser_script_eq!(
+        r#"type A = { a: string; b?(): void; [c: string]: any; new (): A };"#,
+        r#"{"type":"Program","start":0,"end":64,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":64,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"typeAnnotation":{"type":"TSTypeLiteral","start":9,"end":63,"members":[{"type":"TSPropertySignature","start":11,"end":21,"computed":false,"key":{"type":"Identifier","start":11,"end":12,"name":"a"},"optional":false,"readonly":false,"typeAnnotation":{"type":"TSTypeAnnotation","start":12,"end":20,"typeAnnotation":{"type":"TSStringKeyword","start":14,"end":20}}},{"type":"TSMethodSignature","start":22,"end":33,"computed":false,"key":{"type":"Identifier","start":22,"end":23,"name":"b"},"kind":"method","optional":true,"params":[],"returnType":{"type":"TSTypeAnnotation","start":26,"end":32,"typeAnnotation":{"type":"TSVoidKeyword","start":28,"end":32}}},{"type":"TSIndexSignature","start":34,"end":51,"parameters":[{"type":"Identifier","start":35,"end":44,"name":"c","typeAnnotation":{"type":"TSTypeAnnotation","start":36,"end":44,"typeAnnotation":{"type":"TSStringKeyword","start":38,"end":44}}}],"typeAnnotation":{"type":"TSTypeAnnotation","start":45,"end":50,"typeAnnotation":{"type":"TSAnyKeyword","start":47,"end":50}},"readonly":false,"static":false},{"type":"TSConstructSignatureDeclaration","start":52,"end":61,"params":[],"returnType":{"type":"TSTypeAnnotation","start":58,"end":61,"typeAnnotation":{"type":"TSTypeReference","start":60,"end":61,"typeName":{"type":"Identifier","start":60,"end":61,"name":"A"}}}}]},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_template_literal_type() {
+    assert_parser_script_eq!(
+        r#"type A = `a${B}c${`d`}`;"#,
+        r#"{"type":"Program","start":0,"end":24,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":24,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"typeAnnotation":{"type":"TSTemplateLiteralType","start":9,"end":23,"quasis":[{"type":"TemplateElement","start":10,"end":11,"value":{"raw":"a","cooked":"a"},"tail":false},{"type":"TemplateElement","start":15,"end":16,"value":{"raw":"c","cooked":"c"},"tail":false},{"type":"TemplateElement","start":22,"end":22,"value":{"raw":"","cooked":""},"tail":true}],"types":[{"type":"TSTypeReference","start":13,"end":14,"typeName":{"type":"Identifier","start":13,"end":14,"name":"B"}},{"type":"TSLiteralType","start":18,"end":21,"literal":{"type":"TemplateLiteral","start":18,"end":21,"expressions":[],"quasis":[{"type":"TemplateElement","start":19,"end":20,"value":{"raw":"d","cooked":"d"},"tail":true}]}}]},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
+
+#[test]
+fn typescript_import_type() {
+    assert_parser_script_eq!(
+        r#"type A = import("a").B<C>;"#,
+        r#"{"type":"Program","start":0,"end":26,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":26,"id":{"type":"Identifier","start":5,"end":6,"name":"A"},"typeAnnotation":{"type":"TSImportType","start":9,"end":25,"argument":{"type":"TSLiteralType","start":16,"end":19,"literal":{"type":"Literal","start":16,"end":19,"value":"a","raw":"\"a\""}},"qualifier":{"type":"Identifier","start":21,"end":22,"name":"B"},"typeArguments":{"type":"TSTypeParameterInstantiation","start":22,"end":25,"params":[{"type":"TSTypeReference","start":23,"end":24,"typeName":{"type":"Identifier","start":23,"end":24,"name":"C"}}]}},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+
+    assert_parser_script_eq!(
+        r#"type D = typeof import("d");"#,
+        r#"{"type":"Program","start":0,"end":28,"body":[{"type":"TSTypeAliasDeclaration","start":0,"end":28,"id":{"type":"Identifier","start":5,"end":6,"name":"D"},"typeAnnotation":{"type":"TSTypeQuery","start":9,"end":27,"exprName":{"type":"TSImportType","start":16,"end":27,"argument":{"type":"TSLiteralType","start":23,"end":26,"literal":{"type":"Literal","start":23,"end":26,"value":"d","raw":"\"d\""}},"qualifier":null}},"declare":false}],"sourceType":"script"}"#,
+        ParserOptions::new().typescript(true)
+    );
+}
@@ -0,0 +1,67 @@
+use crate::parser::{
+    assert_parse_module_to_throw, assert_parser_module_eq, assert_parser_script_eq,
+};
+use okapi_js_parser::{Parser, ParserErrorKind, ParserOptions};
+
+#[test]
+fn using_declaration() {
+    assert_parser_module_eq!(
+        r#"{ using x = a; }"#,
+        r#"{"type":"Program","start":0,"end":16,"body":[{"type":"BlockStatement","start":0,"end":16,"body":[{"type":"VariableDeclaration","start":2,"end":14,"declarations":[{"type":"VariableDeclarator","start":8,"end":13,"id":{"type":"Identifier","start":8,"end":9,"name":"x"},"init":{"type":"Identifier","start":12,"end":13,"name":"a"}}],"kind":"using"}]}],"sourceType":"module"}"#
+    );
+
+    assert_parser_module_eq!(
+        r#"await using res = open();"#,
+        r#"{"type":"Program","start":0,"end":25,"body":[{"type":"VariableDeclaration","start":0,"end":25,"declarations":[{"type":"VariableDeclarator","start":12,"end":24,"id":{"type":"Identifier","start":12,"end":15,"name":"res"},"init":{"type":"CallExpression","start":18,"end":24,"callee":{"type":"Identifier","start":18,"end":22,"name":"open"},"arguments":[],"optional":false}}],"kind":"await using"}],"sourceType":"module"}"#
+    );
+}
+
+#[test]
+fn using_declaration_for_of_head() {
+    assert_parser_module_eq!(
+        r#"for (using of of y);"#,
+        r#"{"type":"Program","start":0,"end":20,"body":[{"type":"ForOfStatement","start":0,"end":20,"await":false,"left":{"type":"VariableDeclaration","start":5,"end":13,"declarations":[{"type":"VariableDeclarator","start":11,"end":13,"id":{"type":"Identifier","start":11,"end":13,"name":"of"},"init":null}],"kind":"using"},"right":{"type":"Identifier","start":17,"end":18,"name":"y"},"body":{"type":"EmptyStatement","start":19,"end":20}}],"sourceType":"module"}"#
+    );
+
+    assert_parser_module_eq!(
+        r#"for (using of y);"#,
+        r#"{"type":"Program","start":0,"end":17,"body":[{"type":"ForOfStatement","start":0,"end":17,"await":false,"left":{"type":"Identifier","start":5,"end":10,"name":"using"},"right":{"type":"Identifier","start":14,"end":15,"name":"y"},"body":{"type":"EmptyStatement","start":16,"end":17}}],"sourceType":"module"}"#
+    );
+}
+
+#[test]
+fn using_as_identifier() {
+    assert_parser_script_eq!(
+        r#"using = 1;"#,
+        r#"{"type":"Program","start":0,"end":10,"body":[{"type":"ExpressionStatement","start":0,"end":10,"expression":{"type":"AssignmentExpression","start":0,"end":9,"operator":"=","left":{"type":"Identifier","start":0,"end":5,"name":"using"},"right":{"type":"Literal","start":8,"end":9,"value":1.0,"raw":"1"}}}],"sourceType":"script"}"#
+    );
+}
+
+#[test]
+fn using_declaration_early_errors() {
+    assert_parse_module_to_throw!(
+        r#"{ using x; }"#,
+        ParserErrorKind::InvalidUsingDeclarationInitializer
+    );
+    assert_parse_module_to_throw!(
+        r#"{ using let = a; }"#,
+        ParserErrorKind::InvalidUsingDeclarationBinding
+    );
+    assert_parse_module_to_throw!(
+        r#"for (using x in y);"#,
+        ParserErrorKind::InvalidUsingDeclarationPosition
+    );
+    assert_parse_module_to_throw!(
+        r#"switch (a) { case 1: using x = y; }"#,
+        ParserErrorKind::InvalidUsingDeclarationPosition
+    );
+
+    // Using declarations cannot be at the top level of a script.
+    assert_eq!(
+        Parser::new("using x = a;", ParserOptions::default())
+            .parse_script()
+            .unwrap_err()
+            .kind,
+        ParserErrorKind::InvalidUsingDeclarationPosition
+    );
+}
@@ -0,0 +1,269 @@
+use assert_json_diff::assert_json_include;
+use okapi_js_parser::{
+    ast::ProgramSource, ECMAVersion, Feature, Parser, ParserErrorKind, ParserOptions,
+};
+use pretty_assertions::assert_eq;
+use serde_json::{json, Value};
+
+fn parse_with_options(source: &str, options: ParserOptions) -> Value {
+    let parsed = Parser::new(source, options).parse_json().unwrap();
+
+    serde_json::from_str::<Value>(&parsed).unwrap()
+}
+
+fn parse_with_options_to_throw(source: &str, options: ParserOptions) -> ParserErrorKind {
+    Parser::new(source, options).parse().unwrap_err().kind
+}
+
+#[test]
+fn source_type() {
+    assert_json_include!(
+        actual: parse_with_options("a;", ParserOptions::default()),
+        expected: json!({ "type": "Program", "sourceType": "script" })
+    );
+
+    assert_json_include!(
+        actual: parse_with_options("a;", ParserOptions::new().source_type(ProgramSource::Module)),
+        expected: json!({ "type": "Program", "sourceType": "module" })
+    );
+}
+
+#[test]
+fn ecma_version_from_number_or_year() {
+    assert_eq!(
+        ECMAVersion::try_from(6),
+        Ok(ECMAVersion::SixOrTwentyFifteen)
+    );
+    assert_eq!(
+        ECMAVersion::try_from(2022),
+        Ok(ECMAVersion::TwentyTwentyTwo)
+    );
+    assert_eq!(ECMAVersion::try_from(3), Ok(ECMAVersion::Three));
+    assert_eq!(ECMAVersion::try_from(5), Ok(ECMAVersion::Five));
+    assert_eq!(
+        ECMAVersion::try_from(4).map_err(|error| error.kind),
+        Err(ParserErrorKind::UnsupportedECMAVersion(4))
+    );
+}
+
+#[test]
+fn ranges() {
+    let parsed = parse_with_options("a;", ParserOptions::default());
+
+    assert!(parsed.get("range").is_none());
+
+    assert_json_include!(
+        actual: parse_with_options("a;\nb + c;", ParserOptions::new().ranges(true)),
+        expected: json!({
+            "type": "Program",
+            "range": [0, 9],
+            "body": [
+                {
+                    "type": "ExpressionStatement",
+                    "range": [0, 2],
+                    "expression": { "type": "Identifier", "range": [0, 1] }
+                },
+                {
+                    "type": "ExpressionStatement",
+                    "range": [3, 9],
+                    "expression": {
+                        "type": "BinaryExpression",
+                        "range": [3, 8],
+                        "left": { "type": "Identifier", "range": [3, 4] },
+                        "right": { "type": "Identifier", "range": [7, 8] }
+                    }
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn allow_return_outside_function() {
+    assert_eq!(
+        parse_with_options_to_throw("return;", ParserOptions::default()),
+        ParserErrorKind::InvalidReturnStatement
+    );
+
+    assert_json_include!(
+        actual: parse_with_options("return a;", ParserOptions::new().allow_return_outside_function(true)),
+        expected: json!({
+            "type": "Program",
+            "body": [
+                {
+                    "type": "ReturnStatement",
+                    "start": 0,
+                    "end": 9,
+                    "argument": { "type": "Identifier", "name": "a" }
+                }
+            ]
+        })
+    );
+
+    // Class static blocks are never function bodies.
+    assert_eq!(
+        parse_with_options_to_throw(
+            "class A { static { return; } }",
+            ParserOptions::new().allow_return_outside_function(true)
+        ),
+        ParserErrorKind::InvalidReturnStatement
+    );
+}
+
+#[test]
+fn allow_hash_bang() {
+    assert_json_include!(
+        actual: parse_with_options("#!/usr/bin/env node\na;", ParserOptions::default()),
+        expected: json!({ "type": "Program", "hashbang": "/usr/bin/env node" })
+    );
+
+    assert_eq!(
+        parse_with_options_to_throw(
+            "#!/usr/bin/env node\na;",
+            ParserOptions::new().ecma_version(ECMAVersion::TwentyTwentyTwo)
+        ),
+        ParserErrorKind::UnsupportedFeature(Feature::Hashbang)
+    );
+
+    assert_eq!(
+        parse_with_options_to_throw(
+            "#!/usr/bin/env node\na;",
+            ParserOptions::new().allow_hash_bang(false)
+        ),
+        ParserErrorKind::UnexpectedHashbang
+    );
+
+    assert_json_include!(
+        actual: parse_with_options(
+            "#!/usr/bin/env node\na;",
+            ParserOptions::new()
+                .ecma_version(ECMAVersion::TwentyTwentyTwo)
+                .allow_hash_bang(true)
+        ),
+        expected: json!({ "type": "Program", "hashbang": "/usr/bin/env node" })
+    );
+}
+
+#[test]
+fn allow_import_export_everywhere() {
+    let source = "if (a) { import b from \"b\"; export { b }; }";
+
+    assert!(Parser::new(
+        source,
+        ParserOptions::new().source_type(ProgramSource::Module)
+    )
+    .parse()
+    .is_err());
+
+    assert_json_include!(
+        actual: parse_with_options(
+            source,
+            ParserOptions::new()
+                .source_type(ProgramSource::Module)
+                .allow_import_export_everywhere(true)
+        ),
+        expected: json!({
+            "type": "Program",
+            "body": [
+                {
+                    "type": "IfStatement",
+                    "consequent": {
+                        "type": "BlockStatement",
+                        "body": [
+                            {
+                                "type": "ImportDeclaration",
+                                "start": 9,
+                                "end": 27,
+                                "source": { "type": "Literal", "value": "b" }
+                            },
+                            {
+                                "type": "ExportNamedDeclaration",
+                                "start": 28,
+                                "end": 41,
+                                "specifiers": [{ "type": "ExportSpecifier" }]
+                            }
+                        ]
+                    }
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn allow_await_outside_function() {
+    assert_json_include!(
+        actual: parse_with_options("await a;", ParserOptions::new().allow_await_outside_function(true)),
+        expected: json!({
+            "type": "Program",
+            "sourceType": "script",
+            "body": [
+                {
+                    "type": "ExpressionStatement",
+                    "expression": {
+                        "type": "AwaitExpression",
+                        "argument": { "type": "Identifier", "name": "a" }
+                    }
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn preserve_parens() {
+    assert_json_include!(
+        actual: parse_with_options("(a + b) * c;", ParserOptions::new().preserve_parens(true)),
+        expected: json!({
+            "type": "Program",
+            "body": [
+                {
+                    "type": "ExpressionStatement",
+                    "expression": {
+                        "type": "BinaryExpression",
+                        "left": {
+                            "type": "ParenthesizedExpression",
+                            "start": 0,
+                            "end": 7,
+                            "expression": {
+                                "type": "BinaryExpression",
+                                "start": 1,
+                                "end": 6
+                            }
+                        },
+                        "operator": "*"
+                    }
+                }
+            ]
+        })
+    );
+
+    assert_json_include!(
+        actual: parse_with_options("(a) = 1; [(b)] = c;", ParserOptions::new().preserve_parens(true)),
+        expected: json!({
+            "type": "Program",
+            "body": [
+                {
+                    "type": "ExpressionStatement",
+                    "expression": {
+                        "type": "AssignmentExpression",
+                        "left": {
+                            "type": "ParenthesizedExpression",
+                            "expression": { "type": "Identifier", "name": "a" }
+                        }
+                    }
+                },
+                {
+                    "type": "ExpressionStatement",
+                    "expression": {
+                        "type": "AssignmentExpression",
+                        "left": {
+                            "type": "ArrayPattern",
+                            "elements": [{ "type": "Identifier", "name": "b" }]
+                        }
+                    }
+                }
+            ]
+        })
+    );
+}
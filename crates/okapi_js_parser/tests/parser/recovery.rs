@@ -0,0 +1,273 @@
+use assert_json_diff::assert_json_include;
+use okapi_js_parser::{ast::ProgramSource, Parser, ParserErrorReport, ParserOptions};
+use pretty_assertions::assert_eq;
+use serde_json::{json, Value};
+
+fn parse_with_recovery(source: &str, options: ParserOptions) -> (Value, Vec<ParserErrorReport>) {
+    let (program, diagnostics) = Parser::new(source, options).parse_with_recovery();
+
+    (serde_json::to_value(program).unwrap(), diagnostics)
+}
+
+fn offsets(diagnostics: &[ParserErrorReport]) -> Vec<usize> {
+    diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.offset)
+        .collect()
+}
+
+#[test]
+fn valid_source_has_no_diagnostics() {
+    let source = "function a(b) { return b + 1; }\nclass C { d() { if (e) { f(); } } }";
+
+    let (program, diagnostics) = parse_with_recovery(source, ParserOptions::default());
+
+    assert_eq!(diagnostics, vec![]);
+    assert_eq!(
+        program,
+        serde_json::from_str::<Value>(
+            &Parser::new(source, ParserOptions::default())
+                .parse_json()
+                .unwrap()
+        )
+        .unwrap()
+    );
+}
+
+#[test]
+fn recovers_at_statement_boundaries() {
+    let (program, diagnostics) = parse_with_recovery(
+        "let a = ;\nb();\nc d e\nif (f) {}",
+        ParserOptions::default(),
+    );
+
+    assert_json_include!(
+        actual: program,
+        expected: json!({
+            "body": [
+                { "type": "InvalidStatement", "start": 0, "end": 9 },
+                { "type": "ExpressionStatement", "start": 10, "end": 14 },
+                { "type": "ExpressionStatement" },
+                { "type": "ExpressionStatement" },
+                { "type": "ExpressionStatement" },
+                { "type": "IfStatement", "start": 21, "end": 30 }
+            ]
+        })
+    );
+    assert_eq!(offsets(&diagnostics), vec![8]);
+    assert_eq!(
+        diagnostics[0],
+        ParserErrorReport {
+            kind: "UnexpectedToken".to_string(),
+            code: "E0001".to_string(),
+            message: "Unexpected token ';' (1:8)".to_string(),
+            line: 1,
+            column: 8,
+            offset: 8,
+            end: 9,
+            code_frame: None,
+        }
+    );
+}
+
+#[test]
+fn recovers_within_nested_statement_lists() {
+    let (program, diagnostics) = parse_with_recovery(
+        "function a() {\n  let b = 1 +;\n  return b;\n}\nclass C { d() { e(; } f() {} }",
+        ParserOptions::default(),
+    );
+
+    assert_json_include!(
+        actual: program,
+        expected: json!({
+            "body": [
+                {
+                    "type": "FunctionDeclaration",
+                    "body": {
+                        "body": [
+                            { "type": "InvalidStatement", "start": 17, "end": 29 },
+                            { "type": "ReturnStatement", "start": 32, "end": 41 }
+                        ]
+                    }
+                },
+                {
+                    "type": "ClassDeclaration",
+                    "body": {
+                        "body": [
+                            { "type": "MethodDefinition", "value": { "body": { "body": [{ "type": "InvalidStatement" }] } } },
+                            { "type": "MethodDefinition" }
+                        ]
+                    }
+                }
+            ]
+        })
+    );
+    assert_eq!(offsets(&diagnostics), vec![28, 62]);
+}
+
+#[test]
+fn keeps_unterminated_blocks() {
+    let (program, diagnostics) = parse_with_recovery(
+        "a;\nfunction b() {\n  if (c) {\n    d();",
+        ParserOptions::default(),
+    );
+
+    assert_json_include!(
+        actual: program,
+        expected: json!({
+            "body": [
+                { "type": "ExpressionStatement" },
+                {
+                    "type": "FunctionDeclaration",
+                    "body": {
+                        "type": "BlockStatement",
+                        "body": [{ "type": "IfStatement", "consequent": { "body": [{ "type": "ExpressionStatement" }] } }]
+                    }
+                }
+            ]
+        })
+    );
+    assert_eq!(offsets(&diagnostics), vec![37]);
+}
+
+#[test]
+fn restores_the_context_after_an_error() {
+    let (program, diagnostics) = parse_with_recovery(
+        "function a(b c) {}\nreturn 1;\nd;",
+        ParserOptions::default(),
+    );
+
+    assert_json_include!(
+        actual: program,
+        expected: json!({
+            "body": [
+                { "type": "InvalidStatement", "start": 0, "end": 18 },
+                { "type": "InvalidStatement", "start": 19, "end": 28 },
+                { "type": "ExpressionStatement", "start": 29, "end": 31 }
+            ]
+        })
+    );
+    assert_eq!(
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.kind.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["UnexpectedToken", "InvalidReturnStatement"]
+    );
+}
+
+#[test]
+fn recovers_in_modules() {
+    let (program, diagnostics) = parse_with_recovery(
+        "import { a } from;\nexport const b = 1;\n}",
+        ParserOptions::new().source_type(ProgramSource::Module),
+    );
+
+    assert_json_include!(
+        actual: program,
+        expected: json!({
+            "sourceType": "module",
+            "body": [
+                { "type": "InvalidStatement", "start": 0, "end": 18 },
+                { "type": "ExportNamedDeclaration" },
+                { "type": "InvalidStatement", "start": 39, "end": 40 }
+            ]
+        })
+    );
+    assert_eq!(offsets(&diagnostics), vec![17, 39]);
+}
+
+#[test]
+fn reports_invalid_tokens() {
+    let (program, diagnostics) =
+        parse_with_recovery("1__0;\na = 2__0;\nb;", ParserOptions::default());
+
+    assert_json_include!(
+        actual: program,
+        expected: json!({
+            "body": [
+                { "type": "InvalidStatement", "start": 0, "end": 5 },
+                { "type": "InvalidStatement", "start": 6, "end": 15 },
+                { "type": "ExpressionStatement", "start": 16, "end": 18 }
+            ]
+        })
+    );
+    assert_eq!(offsets(&diagnostics), vec![0, 10]);
+    assert_eq!(
+        diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.kind.as_str())
+            .collect::<Vec<_>>(),
+        vec![
+            "InvalidNumericSeparatorAtSibling",
+            "InvalidNumericSeparatorAtSibling"
+        ]
+    );
+}
+
+#[test]
+fn recovers_after_lexer_errors() {
+    let (program, diagnostics) = parse_with_recovery(
+        "a = \"\\x4\";\nb = 1;\nfunction f() {}\nlet c = 2;",
+        ParserOptions::default(),
+    );
+
+    assert_json_include!(
+        actual: program,
+        expected: json!({
+            "body": [
+                { "type": "InvalidStatement", "start": 0, "end": 10 },
+                { "type": "ExpressionStatement", "start": 11, "end": 17 },
+                { "type": "FunctionDeclaration", "start": 18, "end": 33 },
+                { "type": "VariableDeclaration", "start": 34, "end": 44 }
+            ]
+        })
+    );
+    assert_eq!(offsets(&diagnostics), vec![4]);
+    assert_eq!(diagnostics[0].kind, "InvalidHexadecimalEscapeSequence");
+}
+
+#[test]
+fn skips_unmatched_closing_braces() {
+    let (program, diagnostics) = parse_with_recovery("a = {b: ;}\nc = 1", ParserOptions::default());
+
+    assert_json_include!(
+        actual: program,
+        expected: json!({
+            "body": [
+                { "type": "InvalidStatement", "start": 0, "end": 9 },
+                { "type": "InvalidStatement", "start": 9, "end": 10 },
+                { "type": "ExpressionStatement", "start": 11, "end": 16 }
+            ]
+        })
+    );
+    assert_eq!(offsets(&diagnostics), vec![8, 9]);
+
+    let (program, diagnostics) =
+        parse_with_recovery("class A { m( { } }\nfoo()", ParserOptions::default());
+
+    assert_json_include!(
+        actual: program,
+        expected: json!({
+            "body": [
+                { "type": "InvalidStatement", "start": 0, "end": 18 },
+                { "type": "ExpressionStatement", "start": 19, "end": 24 }
+            ]
+        })
+    );
+    assert_eq!(offsets(&diagnostics), vec![17]);
+
+    let (program, diagnostics) =
+        parse_with_recovery("if (a {\n b();\n}\nc();", ParserOptions::default());
+
+    assert_json_include!(
+        actual: program,
+        expected: json!({
+            "body": [
+                { "type": "InvalidStatement", "start": 0, "end": 15 },
+                { "type": "ExpressionStatement", "start": 16, "end": 20 }
+            ]
+        })
+    );
+    assert_eq!(offsets(&diagnostics), vec![6]);
+}
@@ -0,0 +1,367 @@
+use okapi_js_parser::ast::regexp::{
+    Alternative, Backreference, BackreferenceReference, BoundaryAssertion, BoundaryAssertionKind,
+    CapturingGroup, Character, CharacterClass, CharacterClassElement, CharacterClassRange,
+    CharacterSet, CharacterSetKind, ClassIntersection, ClassStringDisjunction, ClassSubtraction,
+    Element, Group, LookaroundAssertion, LookaroundAssertionKind, ModifierFlags, Modifiers,
+    Quantifier, RegExp,
+};
+use okapi_js_parser::ast::{
+    Expression, ExpressionStatement, ProgramBody, Statement, StatementListItem,
+};
+use okapi_js_parser::{Parser, ParserErrorKind, ParserOptions};
+
+use crate::parser::test_helper_macros::assert_parse_module_to_throw;
+
+fn parse_regexp(input: &str) -> RegExp {
+    let program = Parser::new(input, ParserOptions::default())
+        .parse_script()
+        .unwrap();
+
+    let ProgramBody::StatementList(body) = program.body else {
+        panic!("Expected a script body.");
+    };
+
+    let [StatementListItem::Statement(Statement::Expression(ExpressionStatement {
+        expression: Expression::RegExpLiteral(regexp_literal),
+        ..
+    }))] = body.as_slice()
+    else {
+        panic!("Expected a single regular expression literal.");
+    };
+
+    *regexp_literal.regexp.clone()
+}
+
+fn parse_elements(input: &str) -> Vec<Element> {
+    let regexp = parse_regexp(input);
+
+    let [Alternative { elements, .. }] = regexp.pattern.alternatives.as_slice() else {
+        panic!("Expected a single alternative.");
+    };
+
+    elements.clone()
+}
+
+#[test]
+fn regexp_alternatives() {
+    let regexp = parse_regexp("/a|bc|/g");
+
+    assert!(regexp.flags.global);
+    assert!(!regexp.flags.unicode);
+
+    let lengths: Vec<usize> = regexp
+        .pattern
+        .alternatives
+        .iter()
+        .map(|alternative| alternative.elements.len())
+        .collect();
+
+    assert_eq!(lengths, vec![1, 2, 0]);
+    assert_eq!(regexp.pattern.end, 5);
+}
+
+#[test]
+fn regexp_quantifiers() {
+    let elements = parse_elements("/a*b+?c{2}d{2,}e{2,3}?/");
+
+    let quantifiers: Vec<(u32, Option<u32>, bool)> = elements
+        .iter()
+        .map(|element| match element {
+            Element::Quantifier(Quantifier {
+                min, max, greedy, ..
+            }) => (*min, *max, *greedy),
+            _ => panic!("Expected a quantifier."),
+        })
+        .collect();
+
+    assert_eq!(
+        quantifiers,
+        vec![
+            (0, None, true),
+            (1, None, false),
+            (2, Some(2), true),
+            (2, None, true),
+            (2, Some(3), false)
+        ]
+    );
+}
+
+#[test]
+fn regexp_groups() {
+    let elements = parse_elements(r"/(?:a)(b(?<year>\d{4}))\1\k<year>/");
+
+    let [Element::Group(Group { .. }), Element::CapturingGroup(CapturingGroup {
+        index: 1,
+        name: None,
+        alternatives,
+        ..
+    }), Element::Backreference(Backreference {
+        reference: BackreferenceReference::Index(1),
+        ..
+    }), Element::Backreference(Backreference {
+        reference: BackreferenceReference::Name(reference_name),
+        ..
+    })] = elements.as_slice()
+    else {
+        panic!("Expected groups followed by backreferences.");
+    };
+
+    assert_eq!(reference_name, "year");
+
+    assert!(matches!(
+        alternatives[0].elements.as_slice(),
+        [
+            Element::Character(Character { value: 98, .. }),
+            Element::CapturingGroup(CapturingGroup { index: 2, name: Some(name), .. })
+        ] if name == "year"
+    ));
+}
+
+#[test]
+fn regexp_modifiers() {
+    let elements = parse_elements(r"/(?i:a)(?m-s:b)(?-i:c)(?:d)/");
+
+    let modifiers: Vec<Option<Modifiers>> = elements
+        .iter()
+        .map(|element| match element {
+            Element::Group(Group { modifiers, .. }) => modifiers.clone(),
+            _ => panic!("Expected a group."),
+        })
+        .collect();
+
+    let flags = |start, end, ignore_case, multiline, dot_all| ModifierFlags {
+        start,
+        end,
+        ignore_case,
+        multiline,
+        dot_all,
+    };
+
+    assert_eq!(
+        modifiers,
+        vec![
+            Some(Modifiers {
+                start: 2,
+                end: 3,
+                add: flags(2, 3, true, false, false),
+                remove: None,
+            }),
+            Some(Modifiers {
+                start: 8,
+                end: 11,
+                add: flags(8, 9, false, true, false),
+                remove: Some(flags(10, 11, false, false, true)),
+            }),
+            Some(Modifiers {
+                start: 16,
+                end: 18,
+                add: flags(16, 16, false, false, false),
+                remove: Some(flags(17, 18, true, false, false)),
+            }),
+            None,
+        ]
+    );
+}
+
+#[test]
+fn regexp_assertions() {
+    let elements = parse_elements(r"/^\b(?=a)(?<!b)\B$/");
+
+    assert!(matches!(
+        elements.as_slice(),
+        [
+            Element::BoundaryAssertion(BoundaryAssertion {
+                kind: BoundaryAssertionKind::Start,
+                ..
+            }),
+            Element::BoundaryAssertion(BoundaryAssertion {
+                kind: BoundaryAssertionKind::Word,
+                negate: false,
+                ..
+            }),
+            Element::LookaroundAssertion(LookaroundAssertion {
+                kind: LookaroundAssertionKind::Lookahead,
+                negate: false,
+                ..
+            }),
+            Element::LookaroundAssertion(LookaroundAssertion {
+                kind: LookaroundAssertionKind::Lookbehind,
+                negate: true,
+                ..
+            }),
+            Element::BoundaryAssertion(BoundaryAssertion {
+                kind: BoundaryAssertionKind::Word,
+                negate: true,
+                ..
+            }),
+            Element::BoundaryAssertion(BoundaryAssertion {
+                kind: BoundaryAssertionKind::End,
+                ..
+            }),
+        ]
+    ));
+}
+
+#[test]
+fn regexp_character_classes() {
+    let elements = parse_elements(r"/[^a-z\d-]/");
+
+    let [Element::CharacterClass(CharacterClass {
+        start: 0,
+        end: 9,
+        negate: true,
+        elements,
+    })] = elements.as_slice()
+    else {
+        panic!("Expected a negated character class.");
+    };
+
+    assert!(matches!(
+        elements.as_slice(),
+        [
+            CharacterClassElement::CharacterClassRange(CharacterClassRange {
+                min: Character { value: 97, .. },
+                max: Character { value: 122, .. },
+                ..
+            }),
+            CharacterClassElement::CharacterSet(CharacterSet {
+                kind: CharacterSetKind::Digit,
+                negate: false,
+                ..
+            }),
+            CharacterClassElement::Character(Character { value: 45, .. }),
+        ]
+    ));
+}
+
+#[test]
+fn regexp_unicode_property_escapes() {
+    let elements = parse_elements(r"/\p{Script=Greek}\P{L}/u");
+
+    let [Element::CharacterSet(script), Element::CharacterSet(letter)] = elements.as_slice() else {
+        panic!("Expected two character sets.");
+    };
+
+    assert_eq!(script.kind, CharacterSetKind::Property);
+    assert_eq!(script.key.as_deref(), Some("Script"));
+    assert_eq!(script.value.as_deref(), Some("Greek"));
+    assert!(letter.negate);
+
+    // Without the `u` flag, `\p` is an identity escape and the braces are literal characters.
+    assert!(matches!(
+        parse_elements(r"/\p{L}/").as_slice(),
+        [
+            Element::Character(Character { value: 112, .. }),
+            Element::Character(Character { value: 123, .. }),
+            Element::Character(Character { value: 76, .. }),
+            Element::Character(Character { value: 125, .. }),
+        ]
+    ));
+}
+
+#[test]
+fn regexp_unicode_sets() {
+    let regexp = parse_regexp(r"/[\p{L}--[a-z]][[0-9]&&\d][\q{abc|d}\p{RGI_Emoji}]/v");
+
+    assert!(regexp.flags.unicode_sets);
+
+    let [Alternative { elements, .. }] = regexp.pattern.alternatives.as_slice() else {
+        panic!("Expected a single alternative.");
+    };
+
+    let [Element::CharacterClass(subtraction), Element::CharacterClass(intersection), Element::CharacterClass(union)] =
+        elements.as_slice()
+    else {
+        panic!("Expected three character classes.");
+    };
+
+    let [CharacterClassElement::ClassSubtraction(ClassSubtraction { left, right, .. })] =
+        subtraction.elements.as_slice()
+    else {
+        panic!("Expected a class subtraction.");
+    };
+
+    assert!(matches!(
+        left.as_ref(),
+        CharacterClassElement::CharacterSet(CharacterSet { key: Some(key), value: Some(value), .. })
+            if key == "General_Category" && value == "L"
+    ));
+    assert!(matches!(
+        right.as_ref(),
+        CharacterClassElement::CharacterClass(CharacterClass { elements, .. })
+            if matches!(elements.as_slice(), [CharacterClassElement::CharacterClassRange(_)])
+    ));
+
+    assert!(matches!(
+        intersection.elements.as_slice(),
+        [CharacterClassElement::ClassIntersection(
+            ClassIntersection { .. }
+        )]
+    ));
+
+    let [CharacterClassElement::ClassStringDisjunction(ClassStringDisjunction {
+        alternatives, ..
+    }), CharacterClassElement::CharacterSet(CharacterSet {
+        key: Some(key),
+        value: None,
+        ..
+    })] = union.elements.as_slice()
+    else {
+        panic!("Expected a class string disjunction and a property of strings.");
+    };
+
+    assert_eq!(key, "RGI_Emoji");
+    assert_eq!(
+        alternatives
+            .iter()
+            .map(|alternative| alternative.elements.len())
+            .collect::<Vec<usize>>(),
+        vec![3, 1]
+    );
+}
+
+#[test]
+fn regexp_duplicate_group_names_in_alternatives() {
+    let elements = parse_elements(r"/(?:(?<year>\d{4})|(?<year>\d{2}))\k<year>/");
+
+    assert!(matches!(
+        elements.as_slice(),
+        [Element::Group(_), Element::Backreference(_)]
+    ));
+}
+
+#[test]
+fn regexp_character_escapes() {
+    let values: Vec<u32> = parse_elements(r"/\n\x41B\u{1F600}\cJ\0\//u")
+        .iter()
+        .map(|element| match element {
+            Element::Character(Character { value, .. }) => *value,
+            _ => panic!("Expected a character."),
+        })
+        .collect();
+
+    assert_eq!(values, vec![0x0A, 0x41, 0x42, 0x1F600, 0x0A, 0x00, 0x2F]);
+
+    // Outside of Unicode mode, Annex B allows legacy octal and identity escapes.
+    let values: Vec<u32> = parse_elements(r"/\1\8\a\u/")
+        .iter()
+        .map(|element| match element {
+            Element::Character(Character { value, .. }) => *value,
+            _ => panic!("Expected a character."),
+        })
+        .collect();
+
+    assert_eq!(values, vec![0x01, 0x38, 0x61, 0x75]);
+}
+
+#[test]
+fn regexp_pattern_errors() {
+    assert_parse_module_to_throw!("/a**/", ParserErrorKind::InvalidRegexNothingToRepeat(2));
+    assert_parse_module_to_throw!("/(a/", ParserErrorKind::UnterminatedRegexGroup(0));
+    assert_parse_module_to_throw!("/a)/", ParserErrorKind::InvalidRegexUnmatchedParenthesis(1));
+    assert_parse_module_to_throw!("/{1}/", ParserErrorKind::InvalidRegexNothingToRepeat(0));
+    assert_parse_module_to_throw!("/a{/u", ParserErrorKind::InvalidRegexLoneBracket(1));
+    assert_parse_module_to_throw!(r"/\a/u", ParserErrorKind::InvalidRegexEscape(0));
+    assert_parse_module_to_throw!("/(?<1a>x)/", ParserErrorKind::InvalidRegexGroupName(3));
+    assert_parse_module_to_throw!(r"/\p{/u", ParserErrorKind::InvalidRegexUnicodeProperty(0));
+}
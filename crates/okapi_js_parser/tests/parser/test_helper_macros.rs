@@ -1,10 +1,16 @@
 macro_rules! assert_parser_script_eq {
     ($test_case: expr, $expected_result: expr) => {{
+        assert_parser_script_eq!($test_case, $expected_result, okapi_js_parser::ParserOptions::default())
+    }};
+    // The third argument is the parser options, e.g. to enable an extension such as decorators.
+    ($test_case: expr, $expected_result: expr, $options: expr) => {{
         use assert_json_diff::assert_json_include;
         use okapi_js_parser::Parser;
         use serde_json::Value;
 
-        let parsed = Parser::new(&$test_case).parse_script_json().unwrap();
+        let parsed = Parser::new(&$test_case, $options)
+            .parse_script_json()
+            .unwrap();
         let parsed_json = serde_json::from_str::<Value>(&parsed).unwrap();
 
         let expected_json = serde_json::from_str::<Value>(&$expected_result).unwrap();
@@ -15,11 +21,17 @@ macro_rules! assert_parser_script_eq {
 
 macro_rules! assert_parser_module_eq {
     ($test_case: expr, $expected_result: expr) => {{
+        assert_parser_module_eq!($test_case, $expected_result, okapi_js_parser::ParserOptions::default())
+    }};
+    // The third argument is the parser options, e.g. to enable an extension such as decorators.
+    ($test_case: expr, $expected_result: expr, $options: expr) => {{
         use assert_json_diff::assert_json_include;
         use okapi_js_parser::Parser;
         use serde_json::Value;
 
-        let parsed = Parser::new(&$test_case).parse_module_json().unwrap();
+        let parsed = Parser::new(&$test_case, $options)
+            .parse_module_json()
+            .unwrap();
         let parsed_json = serde_json::from_str::<Value>(&parsed).unwrap();
 
         let expected_json = serde_json::from_str::<Value>(&$expected_result).unwrap();
@@ -30,15 +42,22 @@ macro_rules! assert_parser_module_eq {
 
 macro_rules! assert_parse_module_to_throw {
     ($input_str: expr, $expected_error: expr) => {{
+        assert_parse_module_to_throw!(
+            $input_str,
+            $expected_error,
+            okapi_js_parser::ParserOptions::default()
+        )
+    }};
+    ($input_str: expr, $expected_error: expr, $options: expr) => {{
         use okapi_js_parser::Parser;
 
         use pretty_assertions::assert_eq;
 
-        let mut parser = Parser::new($input_str);
+        let mut parser = Parser::new($input_str, $options);
 
-        let ast_json = parser.parse_module().unwrap_err();
+        let error = parser.parse_module().unwrap_err();
 
-        assert_eq!(ast_json, $expected_error);
+        assert_eq!(error.kind, $expected_error);
     }};
 }
 
@@ -0,0 +1,169 @@
+use okapi_js_parser::{KeywordKind, ParserError, ParserErrorKind, TokenKind, Tokenizer};
+use pretty_assertions::assert_eq;
+
+fn token_kinds(source: &str) -> Vec<TokenKind> {
+    Tokenizer::new(source)
+        .map(|token| token.unwrap().kind)
+        .collect()
+}
+
+#[test]
+fn tokenizes_without_parsing() {
+    assert_eq!(
+        token_kinds("let a = b;\n// c\nd(1, 'e')"),
+        vec![
+            TokenKind::Keyword(KeywordKind::Let),
+            TokenKind::Identifier,
+            TokenKind::Assignment,
+            TokenKind::Identifier,
+            TokenKind::Semicolon,
+            TokenKind::Identifier,
+            TokenKind::LeftParenthesis,
+            TokenKind::NumberLiteral,
+            TokenKind::Comma,
+            TokenKind::StringLiteral,
+            TokenKind::RightParenthesis,
+        ]
+    );
+}
+
+#[test]
+fn division_or_regular_expression() {
+    assert_eq!(
+        token_kinds("a / b / c; x = /d/g; (e) / f; if (g) /h/.test(i); return /j/"),
+        vec![
+            TokenKind::Identifier,
+            TokenKind::Division,
+            TokenKind::Identifier,
+            TokenKind::Division,
+            TokenKind::Identifier,
+            TokenKind::Semicolon,
+            TokenKind::Identifier,
+            TokenKind::Assignment,
+            TokenKind::RegularExpressionLiteral,
+            TokenKind::Semicolon,
+            TokenKind::LeftParenthesis,
+            TokenKind::Identifier,
+            TokenKind::RightParenthesis,
+            TokenKind::Division,
+            TokenKind::Identifier,
+            TokenKind::Semicolon,
+            TokenKind::Keyword(KeywordKind::If),
+            TokenKind::LeftParenthesis,
+            TokenKind::Identifier,
+            TokenKind::RightParenthesis,
+            TokenKind::RegularExpressionLiteral,
+            TokenKind::Dot,
+            TokenKind::Identifier,
+            TokenKind::LeftParenthesis,
+            TokenKind::Identifier,
+            TokenKind::RightParenthesis,
+            TokenKind::Semicolon,
+            TokenKind::Keyword(KeywordKind::Return),
+            TokenKind::RegularExpressionLiteral,
+        ]
+    );
+}
+
+#[test]
+fn keywords_as_property_names_are_followed_by_division() {
+    assert_eq!(
+        token_kinds("x = a.default / 2 / 3; a.in / b / c; a?.if / d; a.for(e) / f"),
+        vec![
+            TokenKind::Identifier,
+            TokenKind::Assignment,
+            TokenKind::Identifier,
+            TokenKind::Dot,
+            TokenKind::Keyword(KeywordKind::Default),
+            TokenKind::Division,
+            TokenKind::NumberLiteral,
+            TokenKind::Division,
+            TokenKind::NumberLiteral,
+            TokenKind::Semicolon,
+            TokenKind::Identifier,
+            TokenKind::Dot,
+            TokenKind::Keyword(KeywordKind::In),
+            TokenKind::Division,
+            TokenKind::Identifier,
+            TokenKind::Division,
+            TokenKind::Identifier,
+            TokenKind::Semicolon,
+            TokenKind::Identifier,
+            TokenKind::OptionalChaining,
+            TokenKind::Keyword(KeywordKind::If),
+            TokenKind::Division,
+            TokenKind::Identifier,
+            TokenKind::Semicolon,
+            TokenKind::Identifier,
+            TokenKind::Dot,
+            TokenKind::Keyword(KeywordKind::For),
+            TokenKind::LeftParenthesis,
+            TokenKind::Identifier,
+            TokenKind::RightParenthesis,
+            TokenKind::Division,
+            TokenKind::Identifier,
+        ]
+    );
+}
+
+#[test]
+fn blocks_and_object_literals() {
+    assert_eq!(
+        token_kinds("{} /a/; ({} / b); this / c"),
+        vec![
+            TokenKind::LeftCurlyBrace,
+            TokenKind::RightCurlyBrace,
+            TokenKind::RegularExpressionLiteral,
+            TokenKind::Semicolon,
+            TokenKind::LeftParenthesis,
+            TokenKind::LeftCurlyBrace,
+            TokenKind::RightCurlyBrace,
+            TokenKind::Division,
+            TokenKind::Identifier,
+            TokenKind::RightParenthesis,
+            TokenKind::Semicolon,
+            TokenKind::Keyword(KeywordKind::This),
+            TokenKind::Division,
+            TokenKind::Identifier,
+        ]
+    );
+}
+
+#[test]
+fn template_literals() {
+    assert_eq!(
+        token_kinds("`a${ { b: c } }d${ /e/ }f` / g"),
+        vec![
+            TokenKind::TemplateHead,
+            TokenKind::LeftCurlyBrace,
+            TokenKind::Identifier,
+            TokenKind::Colon,
+            TokenKind::Identifier,
+            TokenKind::RightCurlyBrace,
+            TokenKind::TemplateMiddle,
+            TokenKind::RegularExpressionLiteral,
+            TokenKind::TemplateTail,
+            TokenKind::Division,
+            TokenKind::Identifier,
+        ]
+    );
+}
+
+#[test]
+fn invalid_token_ends_the_iteration() {
+    let mut tokenizer = Tokenizer::new("a 1__0 b");
+
+    assert_eq!(
+        tokenizer.next().unwrap().unwrap().kind,
+        TokenKind::Identifier
+    );
+    assert_eq!(
+        tokenizer.next(),
+        Some(Err(ParserError::new(
+            ParserErrorKind::InvalidNumericSeparatorAtSibling,
+            2,
+            4
+        )))
+    );
+    assert_eq!(tokenizer.next(), None);
+}
@@ -0,0 +1,153 @@
+use assert_json_diff::assert_json_eq;
+use okapi_js_parser::{Parser, ParserOptions};
+use pretty_assertions::assert_eq;
+use serde_json::{json, Value};
+
+fn token_values(mut parser: Parser) -> Vec<(String, String)> {
+    let parsed = serde_json::from_str::<Value>(&parser.parse_json().unwrap()).unwrap();
+
+    parsed["tokens"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|token| {
+            (
+                token["type"].as_str().unwrap().to_string(),
+                token["value"].as_str().unwrap().to_string(),
+            )
+        })
+        .collect()
+}
+
+fn token_pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(kind, value)| (kind.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn tokens_are_not_added_by_default() {
+    let parsed = Parser::new("a;", ParserOptions::default()).parse().unwrap();
+
+    assert_eq!(parsed.tokens, None);
+}
+
+#[test]
+fn espree_token_format() {
+    let parsed = Parser::new("a = /b/g;", ParserOptions::new().tokens(true).ranges(true))
+        .parse_json()
+        .unwrap();
+
+    assert_json_eq!(
+        serde_json::from_str::<Value>(&parsed).unwrap()["tokens"],
+        json!([
+            { "type": "Identifier", "value": "a", "start": 0, "end": 1, "range": [0, 1] },
+            { "type": "Punctuator", "value": "=", "start": 2, "end": 3, "range": [2, 3] },
+            {
+                "type": "RegularExpression",
+                "value": "/b/g",
+                "regex": { "pattern": "b", "flags": "g" },
+                "start": 4,
+                "end": 8,
+                "range": [4, 8]
+            },
+            { "type": "Punctuator", "value": ";", "start": 8, "end": 9, "range": [8, 9] }
+        ])
+    );
+}
+
+#[test]
+fn token_types() {
+    assert_eq!(
+        token_values(Parser::new(
+            "let a = async (b) => `c${b}d${this}`; class E { static #f = null; } a / 2 / true",
+            ParserOptions::new().tokens(true)
+        )),
+        token_pairs(&[
+            ("Keyword", "let"),
+            ("Identifier", "a"),
+            ("Punctuator", "="),
+            ("Identifier", "async"),
+            ("Punctuator", "("),
+            ("Identifier", "b"),
+            ("Punctuator", ")"),
+            ("Punctuator", "=>"),
+            ("Template", "`c${"),
+            ("Identifier", "b"),
+            ("Template", "}d${"),
+            ("Keyword", "this"),
+            ("Template", "}`"),
+            ("Punctuator", ";"),
+            ("Keyword", "class"),
+            ("Identifier", "E"),
+            ("Punctuator", "{"),
+            ("Keyword", "static"),
+            ("PrivateIdentifier", "f"),
+            ("Punctuator", "="),
+            ("Null", "null"),
+            ("Punctuator", ";"),
+            ("Punctuator", "}"),
+            ("Identifier", "a"),
+            ("Punctuator", "/"),
+            ("Numeric", "2"),
+            ("Punctuator", "/"),
+            ("Boolean", "true"),
+        ])
+    );
+}
+
+#[test]
+fn jsx_tokens() {
+    assert_eq!(
+        token_values(Parser::new(
+            "<a b=\"c\">d {e}</a>",
+            ParserOptions::new().tokens(true).jsx(true)
+        )),
+        token_pairs(&[
+            ("Punctuator", "<"),
+            ("JSXIdentifier", "a"),
+            ("JSXIdentifier", "b"),
+            ("Punctuator", "="),
+            ("JSXText", "\"c\""),
+            ("Punctuator", ">"),
+            ("JSXText", "d "),
+            ("Punctuator", "{"),
+            ("Identifier", "e"),
+            ("Punctuator", "}"),
+            ("Punctuator", "<"),
+            ("Punctuator", "/"),
+            ("JSXIdentifier", "a"),
+            ("Punctuator", ">"),
+        ])
+    );
+}
+
+#[test]
+fn split_type_argument_tokens() {
+    assert_eq!(
+        token_values(Parser::new(
+            "let a: B<C<d>> = (e) => e;",
+            ParserOptions::new().tokens(true).typescript(true)
+        )),
+        token_pairs(&[
+            ("Keyword", "let"),
+            ("Identifier", "a"),
+            ("Punctuator", ":"),
+            ("Identifier", "B"),
+            ("Punctuator", "<"),
+            ("Identifier", "C"),
+            ("Punctuator", "<"),
+            ("Identifier", "d"),
+            ("Punctuator", ">"),
+            ("Punctuator", ">"),
+            ("Punctuator", "="),
+            ("Punctuator", "("),
+            ("Identifier", "e"),
+            ("Punctuator", ")"),
+            ("Punctuator", "=>"),
+            ("Identifier", "e"),
+            ("Punctuator", ";"),
+        ])
+    );
+}
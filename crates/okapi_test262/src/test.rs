@@ -1,6 +1,6 @@
 use std::{fs::File, io::BufReader, path::PathBuf};
 
-use okapi_js_parser::Parser;
+use okapi_js_parser::{Parser, ParserOptions};
 
 use crate::errors::ErrorKind;
 
@@ -27,7 +27,7 @@ pub(crate) trait Test<'a> {
     fn parse_module(&self) -> Result<bool, ErrorKind> {
         let source = self.source();
 
-        let mut parser = Parser::new(source);
+        let mut parser = Parser::new(source, ParserOptions::default());
 
         match parser.parse_module() {
             Ok(_) => Ok(true),
@@ -38,7 +38,7 @@ pub(crate) trait Test<'a> {
     fn parse_script(&self) -> Result<bool, ErrorKind> {
         let source: &str = self.source();
 
-        let mut parser = Parser::new(source);
+        let mut parser = Parser::new(source, ParserOptions::default());
 
         match parser.parse_script() {
             Ok(_) => Ok(true),
@@ -194,7 +194,7 @@ function getCodePoints(codePoints: Set<string>): string {
 }
 
 function getPragma(version: string) {
-  return `/**
+  return `/*
  * This file is generated. Do not modify it manually!
  *
  * This file was generated by:
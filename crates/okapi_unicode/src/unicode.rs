@@ -1,4 +1,4 @@
-/**
+/*
  * This file is generated. Do not modify it manually!
  *
  * This file was generated by:
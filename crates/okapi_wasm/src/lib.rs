@@ -1,18 +1,32 @@
-use okapi_js_parser::Parser;
+use okapi_js_parser::{OffsetEncoding, Parser, ParserOptions};
 use wasm_bindgen::prelude::*;
 
+// Both functions return a JSON string, which is either the ESTree program or, if the source could not be parsed,
+// the serialized `ParserErrorReport`: an error object of type `ParserError` with its kind, code, message, line,
+// column, offset and end.
+// Offsets are UTF-16 code units so that they can be used as indices into the JavaScript source string.
 #[wasm_bindgen(js_name = parseModule)]
 pub fn parse_module(source: String) -> JsValue {
-    match Parser::new(&source).parse_module_json() {
+    match Parser::new(
+        &source,
+        ParserOptions::new().offset_encoding(OffsetEncoding::Utf16),
+    )
+    .parse_module_json()
+    {
         Ok(ast) => JsValue::from_str(&ast),
-        Err(err) => JsValue::from(err.to_string()),
+        Err(error) => JsValue::from_str(&error.to_json()),
     }
 }
 
 #[wasm_bindgen(js_name = parseScript)]
 pub fn parse_script(source: String) -> JsValue {
-    match Parser::new(&source).parse_script_json() {
+    match Parser::new(
+        &source,
+        ParserOptions::new().offset_encoding(OffsetEncoding::Utf16),
+    )
+    .parse_script_json()
+    {
         Ok(ast) => JsValue::from_str(&ast),
-        Err(err) => JsValue::from(err.to_string()),
+        Err(error) => JsValue::from_str(&error.to_json()),
     }
 }